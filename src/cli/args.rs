@@ -12,6 +12,7 @@ mod arg_enums;
 mod commands;
 mod config_commands;
 mod env_commands;
+mod export_commands;
 mod lock_commands;
 mod preset_commands;
 mod profile_commands;
@@ -19,8 +20,9 @@ mod profile_commands;
 pub(crate) use crate::javascript::{
     PackageManager as PackageManagerArg, VersionManager as VersionManagerArg,
 };
-pub(crate) use arg_enums::{PortStrategyArg, PullPolicyArg, ShareProviderArg};
+pub(crate) use arg_enums::{InitSourceArg, PortStrategyArg, PullPolicyArg, ShareProviderArg};
 pub(crate) use commands::Commands;
+pub(crate) use commands::InitArgs;
 pub(crate) use commands::ShareCommands;
 pub(crate) use commands::ShareProviderSelectionArgs;
 pub(crate) use commands::TaskCommands;
@@ -29,6 +31,7 @@ pub(crate) use commands::TaskDepsArgs;
 pub(crate) use commands::TaskDepsCommands;
 pub(crate) use config_commands::ConfigCommands;
 pub(crate) use env_commands::EnvCommands;
+pub(crate) use export_commands::ExportCommands;
 pub(crate) use lock_commands::LockCommands;
 pub(crate) use preset_commands::PresetCommands;
 pub(crate) use profile_commands::ProfileCommands;
@@ -66,9 +69,20 @@ pub(crate) struct Cli {
     /// Runtime environment namespace (for example: `test`)
     #[arg(global = true, long, value_name = "NAME")]
     pub(crate) env: Option<String>,
-    /// Container runtime engine (`docker` or `podman`)
+    /// Container runtime engine (`docker`, `podman`, or `nerdctl`)
     #[arg(global = true, long, value_name = "ENGINE")]
     pub(crate) engine: Option<ContainerEngine>,
+    /// Engine endpoint URL such as `ssh://user@box` or `tcp://10.0.0.5:2376`
+    #[arg(
+        global = true,
+        long,
+        value_name = "URL",
+        conflicts_with = "engine_context"
+    )]
+    pub(crate) engine_host: Option<String>,
+    /// Named engine context (Docker context or Podman connection)
+    #[arg(global = true, long, value_name = "NAME")]
+    pub(crate) engine_context: Option<String>,
     /// Max concurrent heavy Docker operations
     #[arg(global = true, long, value_name = "N")]
     pub(crate) docker_max_heavy_ops: Option<usize>,
@@ -17,6 +17,12 @@ pub(crate) enum PortStrategyArg {
     Stable,
 }
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+pub(crate) enum InitSourceArg {
+    Compose,
+    Sail,
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
 pub(crate) enum ShareProviderArg {
     Cloudflare,
@@ -25,19 +25,22 @@ pub(crate) use lifecycle::{
     StopArgs, UpArgs, UpdateArgs, UrlArgs,
 };
 
-pub(crate) use meta::{CompletionsArgs, ConfigArgs, DoctorArgs, LockArgs, PresetArgs, ProfileArgs};
+pub(crate) use meta::{
+    CompletionsArgs, ConfigArgs, DoctorArgs, ExportArgs, InitArgs, LockArgs, PresetArgs,
+    ProfileArgs,
+};
 
 pub(crate) use operations::{
-    AboutArgs, AttachArgs, CpArgs, DumpArgs, EnvArgs, EventsArgs, HealthArgs, InspectArgs,
-    KillArgs, LogsArgs, LsArgs, PauseArgs, PortArgs, PruneArgs, PsArgs, PullArgs, RestoreArgs,
-    StatsArgs, SwarmArgs, TopArgs, UnpauseArgs, WaitArgs,
+    AboutArgs, AttachArgs, CpArgs, DiffArgs, DumpArgs, EnvArgs, EventsArgs, HealthArgs,
+    InspectArgs, KillArgs, LogsArgs, LsArgs, PauseArgs, PortArgs, PruneArgs, PsArgs, PullArgs,
+    RestoreArgs, StatsArgs, SwarmArgs, TopArgs, UnpauseArgs, WaitArgs,
 };
 
 #[derive(Subcommand)]
 #[non_exhaustive]
 pub(crate) enum Commands {
     /// Initialize a new .helm.toml config file
-    Init,
+    Init(InitArgs),
     /// Print resolved configuration
     Config(ConfigArgs),
     /// Inspect available service presets
@@ -48,6 +51,8 @@ pub(crate) enum Commands {
     Doctor(DoctorArgs),
     /// Manage workspace lockfile for reproducible image resolution
     Lock(LockArgs),
+    /// Export the resolved stack for other tooling
+    Export(ExportArgs),
     /// Prepare service(s)
     Setup(SetupArgs),
     /// Run doctor, start services, bootstrap app runtime, then open app URLs
@@ -83,6 +88,8 @@ pub(crate) enum Commands {
     About(AboutArgs),
     /// Check if service(s) are ready to accept connections
     Health(HealthArgs),
+    /// Show config drift between containers and .helm.toml
+    Diff(DiffArgs),
     /// Update .env with service connection values
     Env(EnvArgs),
     /// Show container logs
@@ -33,7 +33,7 @@ pub(crate) struct ServeArgs {
     pub(crate) service: Option<String>,
     #[arg(long, value_enum)]
     pub(crate) kind: Option<config::Kind>,
-    /// Select a service profile (full, infra, data, app, web, api)
+    /// Select a built-in or configured service profile
     #[arg(long, conflicts_with_all = ["service", "kind"])]
     pub(crate) profile: Option<String>,
     /// Recreate the app container before starting serve
@@ -67,7 +67,7 @@ pub(crate) struct OpenArgs {
     pub(crate) service: Option<String>,
     #[arg(long, value_enum)]
     pub(crate) kind: Option<config::Kind>,
-    /// Select a service profile (full, infra, data, app, web, api)
+    /// Select a built-in or configured service profile
     #[arg(long, conflicts_with_all = ["service", "kind", "all"])]
     pub(crate) profile: Option<String>,
     #[arg(long, default_value_t = false, conflicts_with = "service")]
@@ -14,7 +14,7 @@ pub(crate) struct ExecArgs {
     pub(crate) service: Option<String>,
     #[arg(long, value_enum)]
     pub(crate) kind: Option<config::Kind>,
-    /// Select a service profile (full, infra, data, app, web, api)
+    /// Select a built-in or configured service profile
     #[arg(long, conflicts_with_all = ["service", "kind"])]
     pub(crate) profile: Option<String>,
     #[arg(long, default_value_t = true, conflicts_with = "no_tty")]
@@ -42,7 +42,7 @@ pub(crate) struct ArtisanArgs {
     pub(crate) service: Option<String>,
     #[arg(long, value_enum)]
     pub(crate) kind: Option<config::Kind>,
-    /// Select a service profile (full, infra, data, app, web, api)
+    /// Select a built-in or configured service profile
     #[arg(long, conflicts_with_all = ["service", "kind"])]
     pub(crate) profile: Option<String>,
     /// Enable Playwright bootstrap for browser-style artisan test runs
@@ -73,7 +73,7 @@ pub(crate) struct ComposerArgs {
     pub(crate) service: Option<String>,
     #[arg(long, value_enum)]
     pub(crate) kind: Option<config::Kind>,
-    /// Select a service profile (full, infra, data, app, web, api)
+    /// Select a built-in or configured service profile
     #[arg(long, conflicts_with_all = ["service", "kind"])]
     pub(crate) profile: Option<String>,
     #[arg(long, default_value_t = true, conflicts_with = "no_tty")]
@@ -101,7 +101,7 @@ pub(crate) struct NodeArgs {
     pub(crate) service: Option<String>,
     #[arg(long, value_enum)]
     pub(crate) kind: Option<config::Kind>,
-    /// Select a service profile (full, infra, data, app, web, api)
+    /// Select a built-in or configured service profile
     #[arg(long, conflicts_with_all = ["service", "kind"])]
     pub(crate) profile: Option<String>,
     #[arg(long = "package-manager", value_enum)]
@@ -135,7 +135,7 @@ pub(crate) struct BunArgs {
     pub(crate) service: Option<String>,
     #[arg(long, value_enum)]
     pub(crate) kind: Option<config::Kind>,
-    /// Select a service profile (full, infra, data, app, web, api)
+    /// Select a built-in or configured service profile
     #[arg(long, conflicts_with_all = ["service", "kind"])]
     pub(crate) profile: Option<String>,
     #[arg(long = "bun-version")]
@@ -165,7 +165,7 @@ pub(crate) struct DenoArgs {
     pub(crate) service: Option<String>,
     #[arg(long, value_enum)]
     pub(crate) kind: Option<config::Kind>,
-    /// Select a service profile (full, infra, data, app, web, api)
+    /// Select a built-in or configured service profile
     #[arg(long, conflicts_with_all = ["service", "kind"])]
     pub(crate) profile: Option<String>,
     #[arg(long = "deno-version")]
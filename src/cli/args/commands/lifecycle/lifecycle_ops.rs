@@ -3,6 +3,7 @@
 //! Contains cli args commands lifecycle lifecycle ops logic used by Helm command workflows.
 
 use clap::Args;
+use std::path::PathBuf;
 
 use crate::cli::args::default_parallelism;
 use crate::config;
@@ -27,6 +28,17 @@ pub(crate) struct DownArgs {
     pub(crate) timeout: u64,
     #[arg(long, default_value_t = default_parallelism())]
     pub(crate) parallel: usize,
+    /// Down Helm containers of the project given by --project, from any directory
+    #[arg(
+        long,
+        default_value_t = false,
+        requires = "project",
+        conflicts_with_all = ["service", "kind", "profile"]
+    )]
+    pub(crate) global: bool,
+    /// Workspace root whose containers --global removes
+    #[arg(long, requires = "global")]
+    pub(crate) project: Option<PathBuf>,
 }
 
 impl DownArgs {
@@ -261,6 +273,8 @@ mod tests {
             force: false,
             timeout: 30,
             parallel: 1,
+            global: false,
+            project: None,
         };
 
         assert_eq!(args.service(), Some("api"));
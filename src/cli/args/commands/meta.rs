@@ -3,8 +3,21 @@
 //! Contains cli args commands meta logic used by Helm command workflows.
 
 use clap::Args;
+use std::path::PathBuf;
 
-use crate::cli::args::{ConfigCommands, LockCommands, PresetCommands, ProfileCommands};
+use crate::cli::args::{
+    ConfigCommands, ExportCommands, InitSourceArg, LockCommands, PresetCommands, ProfileCommands,
+};
+
+#[derive(Args)]
+pub(crate) struct InitArgs {
+    /// Import services from a compose or Laravel Sail file
+    #[arg(long, value_enum)]
+    pub(crate) from: Option<InitSourceArg>,
+    /// Compose file to import (default: docker-compose.yml or compose.yaml)
+    #[arg(long, requires = "from")]
+    pub(crate) file: Option<PathBuf>,
+}
 
 #[derive(Args)]
 pub(crate) struct ConfigArgs {
@@ -12,6 +25,9 @@ pub(crate) struct ConfigArgs {
     pub(crate) command: Option<ConfigCommands>,
     #[arg(long, default_value = "toml")]
     pub(crate) format: String,
+    /// Print each merged value with the config file it came from
+    #[arg(long, default_value_t = false)]
+    pub(crate) show_origin: bool,
 }
 
 #[derive(Args)]
@@ -32,6 +48,12 @@ pub(crate) struct LockArgs {
     pub(crate) command: LockCommands,
 }
 
+#[derive(Args)]
+pub(crate) struct ExportArgs {
+    #[command(subcommand)]
+    pub(crate) command: ExportCommands,
+}
+
 #[derive(Args)]
 pub(crate) struct DoctorArgs {
     #[arg(long, default_value = "table")]
@@ -22,6 +22,9 @@ pub(crate) struct PsArgs {
     pub(crate) kind: Option<config::Kind>,
     #[arg(long, value_enum)]
     pub(crate) driver: Option<config::Driver>,
+    /// List Helm containers from every project on this host
+    #[arg(long, default_value_t = false, conflicts_with_all = ["kind", "driver"])]
+    pub(crate) global: bool,
 }
 
 impl PsArgs {
@@ -76,6 +79,37 @@ impl HealthArgs {
     }
 }
 
+#[derive(Args)]
+pub(crate) struct DiffArgs {
+    #[arg(long)]
+    pub(crate) service: Vec<String>,
+    #[arg(long, value_enum)]
+    pub(crate) kind: Option<config::Kind>,
+    /// Compare a named service profile (full, infra, data, app, web, api)
+    #[arg(long, conflicts_with_all = ["service", "kind"])]
+    pub(crate) profile: Option<String>,
+    #[arg(long, default_value = "text")]
+    pub(crate) format: String,
+}
+
+impl DiffArgs {
+    pub(crate) fn service(&self) -> Option<&str> {
+        self.service.first().map(String::as_str)
+    }
+
+    pub(crate) fn services(&self) -> &[String] {
+        &self.service
+    }
+
+    pub(crate) const fn kind(&self) -> Option<config::Kind> {
+        self.kind
+    }
+
+    pub(crate) fn profile(&self) -> Option<&str> {
+        self.profile.as_deref()
+    }
+}
+
 #[derive(Args)]
 pub(crate) struct EnvArgs {
     #[command(subcommand)]
@@ -12,7 +12,7 @@ pub(crate) struct InspectArgs {
     pub(crate) service: Vec<String>,
     #[arg(long, value_enum)]
     pub(crate) kind: Option<config::Kind>,
-    /// Select a service profile (full, infra, data, app, web, api)
+    /// Select a built-in or configured service profile
     #[arg(long, conflicts_with_all = ["service", "kind"])]
     pub(crate) profile: Option<String>,
     #[arg(long, conflicts_with = "json")]
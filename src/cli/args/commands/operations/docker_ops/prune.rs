@@ -2,14 +2,15 @@
 //!
 //! Contains prune command args used by Helm command workflows.
 
-use clap::Args;
+use clap::{ArgGroup, Args};
 
 use crate::cli::args::default_parallelism;
 use crate::config;
 
 #[derive(Args)]
 #[command(
-    after_help = "Examples:\n  helm prune\n  helm prune --kind database\n  helm prune --all --force"
+    after_help = "Examples:\n  helm prune\n  helm prune --kind database\n  helm prune --orphans\n  helm prune --orphans --force\n  helm prune --all --force",
+    group(ArgGroup::new("force_scope").args(["all", "orphans"]))
 )]
 pub(crate) struct PruneArgs {
     #[arg(long, conflicts_with = "all")]
@@ -21,18 +22,22 @@ pub(crate) struct PruneArgs {
     /// Prune all stopped Docker containers (not only Helm-managed services)
     #[arg(long, default_value_t = false)]
     pub(crate) all: bool,
-    /// Required with --all; confirms intentional global prune
+    /// Required with --all; confirms intentional global prune. With
+    /// --orphans, also removes running orphaned containers
     #[arg(
         long,
         short,
         default_value_t = false,
-        requires = "all",
+        requires = "force_scope",
         required_if_eq("all", "true")
     )]
     pub(crate) force: bool,
     /// Additional Docker prune filters (global mode only)
     #[arg(long, requires = "all")]
     pub(crate) filter: Vec<String>,
+    /// Remove Helm containers from deleted projects and stopped test runtimes on this host
+    #[arg(long, default_value_t = false, conflicts_with_all = ["service", "kind", "all"])]
+    pub(crate) orphans: bool,
 }
 
 impl PruneArgs {
@@ -7,5 +7,26 @@ use clap::Subcommand;
 #[derive(Subcommand)]
 pub(crate) enum ConfigCommands {
     /// Migrate local .helm.toml to the latest supported schema
-    Migrate,
+    Migrate {
+        /// Print a unified diff of the migration without writing
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Print the JSON Schema for .helm.toml (for Taplo/VS Code validation)
+    Schema,
+    /// Print a value from .helm.toml (e.g. `service.db.image`)
+    Get { key: String },
+    /// Set a value in .helm.toml in place, keeping comments and ordering
+    Set {
+        key: String,
+        /// TOML literal (`5433`, `true`, `["a"]`); anything else is a string
+        value: String,
+    },
+    /// Remove a value from .helm.toml in place
+    Unset { key: String },
+    /// Report every config problem with file positions and stable codes
+    Validate {
+        #[arg(long, default_value = "text")]
+        format: String,
+    },
 }
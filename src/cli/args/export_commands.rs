@@ -0,0 +1,28 @@
+//! cli args export commands module.
+//!
+//! Contains cli args export commands logic used by Helm command workflows.
+
+use clap::Subcommand;
+use std::path::PathBuf;
+
+#[derive(Subcommand)]
+pub(crate) enum ExportCommands {
+    /// Render the resolved stack as a docker-compose file
+    Compose {
+        /// Write to this path instead of stdout
+        #[arg(long, short = 'o')]
+        output: Option<PathBuf>,
+    },
+    /// Write a Dev Container definition for the app runtime Helm serves
+    Devcontainer {
+        /// Write to this path (default: .devcontainer/devcontainer.json)
+        #[arg(long, short = 'o')]
+        output: Option<PathBuf>,
+        /// App service to export (default: the primary app)
+        #[arg(long)]
+        service: Option<String>,
+        /// Overwrite an existing definition and Dockerfile
+        #[arg(long, short, default_value_t = false)]
+        force: bool,
+    },
+}
@@ -6,7 +6,7 @@ use clap::Subcommand;
 
 #[derive(Subcommand)]
 pub(crate) enum PresetCommands {
-    /// List all available preset names
+    /// List built-in and custom preset names
     List,
     /// Show resolved default values for one preset
     Show {
@@ -6,7 +6,7 @@ use clap::Subcommand;
 
 #[derive(Subcommand)]
 pub(crate) enum ProfileCommands {
-    /// List built-in profile names
+    /// List built-in and configured profile names
     List,
     /// Show services included by a profile
     Show {
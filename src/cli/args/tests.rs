@@ -8,8 +8,9 @@ use clap::Parser;
 
 use crate::cli::args::commands;
 use crate::cli::args::{
-    Cli, ConfigCommands, EnvCommands, LockCommands, PackageManagerArg, PresetCommands,
-    ProfileCommands, ShareProviderArg, VersionManagerArg, default_parallelism,
+    Cli, ConfigCommands, EnvCommands, ExportCommands, InitSourceArg, LockCommands,
+    PackageManagerArg, PresetCommands, ProfileCommands, ShareProviderArg, VersionManagerArg,
+    default_parallelism,
 };
 use crate::config;
 
@@ -724,6 +725,38 @@ fn operations_argument_methods() {
     }
 }
 
+#[test]
+fn global_flags_parse_and_require_their_companions() {
+    let cli = Cli::parse_from(["helm", "ps", "--global", "--format", "json"]);
+    if let commands::Commands::Ps(args) = cli.command {
+        assert!(args.global);
+    } else {
+        panic!("expected ps command");
+    }
+
+    let cli = Cli::parse_from(["helm", "down", "--global", "--project", "/work/acme"]);
+    if let commands::Commands::Down(args) = cli.command {
+        assert!(args.global);
+        assert_eq!(args.project, Some(PathBuf::from("/work/acme")));
+    } else {
+        panic!("expected down command");
+    }
+
+    let cli = Cli::parse_from(["helm", "prune", "--orphans"]);
+    if let commands::Commands::Prune(args) = cli.command {
+        assert!(args.orphans);
+    } else {
+        panic!("expected prune command");
+    }
+
+    assert!(Cli::try_parse_from(["helm", "down", "--global"]).is_err());
+    assert!(Cli::try_parse_from(["helm", "down", "--project", "/work/acme"]).is_err());
+    assert!(Cli::try_parse_from(["helm", "ps", "--global", "--kind", "app"]).is_err());
+    assert!(Cli::try_parse_from(["helm", "prune", "--orphans", "--all", "--force"]).is_err());
+    assert!(Cli::try_parse_from(["helm", "prune", "--orphans", "--force"]).is_ok());
+    assert!(Cli::try_parse_from(["helm", "prune", "--force"]).is_err());
+}
+
 #[test]
 fn command_variants_parse() {
     let completion = Cli::parse_from(["helm", "completions", "bash"]);
@@ -760,7 +793,10 @@ fn command_variants_parse() {
 
     let config = Cli::parse_from(["helm", "config", "migrate"]);
     if let commands::Commands::Config(commands::ConfigArgs { command, .. }) = config.command {
-        assert!(matches!(command, Some(ConfigCommands::Migrate)));
+        assert!(matches!(
+            command,
+            Some(ConfigCommands::Migrate { dry_run: false })
+        ));
     } else {
         panic!("expected config command");
     }
@@ -780,6 +816,47 @@ fn command_variants_parse() {
     } else {
         panic!("expected lock command");
     }
+
+    let export = Cli::parse_from(["helm", "export", "compose", "--output", "compose.yml"]);
+    if let commands::Commands::Export(commands::ExportArgs { command }) = export.command {
+        assert!(
+            matches!(command, ExportCommands::Compose { output } if output == Some(PathBuf::from("compose.yml")))
+        );
+    } else {
+        panic!("expected export command");
+    }
+
+    let devcontainer = Cli::parse_from(["helm", "export", "devcontainer", "--service", "web"]);
+    if let commands::Commands::Export(commands::ExportArgs { command }) = devcontainer.command {
+        assert!(matches!(
+            command,
+            ExportCommands::Devcontainer { output: None, service, force: false } if service.as_deref() == Some("web")
+        ));
+    } else {
+        panic!("expected export command");
+    }
+}
+
+#[test]
+fn init_command_accepts_import_source() {
+    let plain = Cli::parse_from(["helm", "init"]);
+    assert!(matches!(
+        plain.command,
+        commands::Commands::Init(commands::InitArgs {
+            from: None,
+            file: None
+        })
+    ));
+
+    let sail = Cli::parse_from(["helm", "init", "--from", "sail", "--file", "sail.yml"]);
+    if let commands::Commands::Init(args) = sail.command {
+        assert_eq!(args.from, Some(InitSourceArg::Sail));
+        assert_eq!(args.file, Some(PathBuf::from("sail.yml")));
+    } else {
+        panic!("expected init command");
+    }
+
+    assert!(Cli::try_parse_from(["helm", "init", "--file", "compose.yml"]).is_err());
 }
 
 #[test]
@@ -37,22 +37,43 @@ pub(crate) fn run(cli: Cli) -> Result<()> {
         return Ok(());
     }
 
-    let configured_engine = config::load_container_engine_with(
+    let engine_settings = config::load_engine_settings_with(
         config::LoadConfigPathOptions::new(
             dispatch_context.config_path(),
             dispatch_context.project_root(),
         )
         .with_runtime_env(dispatch_context.runtime_env()),
     )?;
-    docker::set_container_engine(cli.engine.or(configured_engine).unwrap_or_default());
+    docker::set_container_engine(
+        cli.engine
+            .or(engine_settings.container_engine)
+            .unwrap_or_default(),
+    );
+    docker::set_podman_pod(engine_settings.podman_pod);
+    docker::set_engine_endpoint(engine_endpoint(&cli, engine_settings))?;
 
     let mut config = bootstrap::load_config_for_cli(&cli, &dispatch_context)?;
+    docker::register_pod_services(&config.service);
     if let Some(result) = primary::dispatch_primary(&cli, &mut config, &dispatch_context) {
         return result;
     }
     secondary::dispatch_secondary(&cli, &mut config, &dispatch_context)
 }
 
+/// CLI endpoint flags override the config; a host wins over a context.
+fn engine_endpoint(cli: &Cli, settings: config::EngineSettings) -> Option<docker::EngineEndpoint> {
+    if let Some(url) = &cli.engine_host {
+        return Some(docker::EngineEndpoint::Host(url.clone()));
+    }
+    if let Some(name) = &cli.engine_context {
+        return Some(docker::EngineEndpoint::Context(name.clone()));
+    }
+    settings
+        .engine_host
+        .map(docker::EngineEndpoint::Host)
+        .or_else(|| settings.engine_context.map(docker::EngineEndpoint::Context))
+}
+
 fn apply_runtime_policy_overrides(cli: &Cli) {
     docker::set_policy_overrides(docker::DockerPolicyOverrides {
         max_heavy_ops: cli.docker_max_heavy_ops,
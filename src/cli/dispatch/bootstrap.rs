@@ -1,15 +1,17 @@
 //! Bootstrap helpers used before regular command dispatch.
 //!
 //! These helpers handle commands that must run without reading project config
-//! (init and shell completions), then build the effective `Config` for all other
+//! (init, shell completions, and config migrate/schema/validate/get/set/unset), then build the effective `Config` for all other
 //! commands.
 
 use anyhow::Result;
 use clap::CommandFactory;
 use clap_complete::generate;
 
-use crate::cli::args::{Cli, Commands};
+use crate::cli::args::{Cli, Commands, ConfigCommands, InitArgs, InitSourceArg};
+use crate::cli::handlers;
 use crate::config::{self, Config};
+use crate::docker;
 use crate::output::{self, LogLevel, Persistence};
 
 /// Handles setup-style commands that short-circuit normal dispatch.
@@ -20,9 +22,72 @@ pub(super) fn handle_setup_commands(
     cli: &Cli,
     context: &super::context::CliDispatchContext<'_>,
 ) -> Result<bool> {
-    if matches!(&cli.command, Commands::Init) {
+    if let Commands::Init(args) = &cli.command {
+        handle_init(args, context.quiet())?;
+        return Ok(true);
+    }
+
+    if let Commands::Completions(args) = &cli.command {
+        let mut cmd = Cli::command();
+        generate(args.shell, &mut cmd, "helm", &mut std::io::stdout());
+        return Ok(true);
+    }
+
+    if let Some(result) = handle_global_commands(cli, context) {
+        result?;
+        return Ok(true);
+    }
+
+    if let Commands::Config(args) = &cli.command {
+        match &args.command {
+            Some(ConfigCommands::Schema) => {
+                handlers::handle_config_schema()?;
+                return Ok(true);
+            }
+            Some(ConfigCommands::Validate { format }) => {
+                handlers::handle_config_validate(
+                    format,
+                    context.quiet(),
+                    context.config_path(),
+                    context.project_root(),
+                    context.runtime_env(),
+                )?;
+                return Ok(true);
+            }
+            Some(ConfigCommands::Get { key }) => {
+                handlers::handle_config_get(key, edit_options(context))?;
+                return Ok(true);
+            }
+            Some(ConfigCommands::Set { key, value }) => {
+                handlers::handle_config_set(key, value, context.quiet(), edit_options(context))?;
+                return Ok(true);
+            }
+            Some(ConfigCommands::Unset { key }) => {
+                handlers::handle_config_unset(key, context.quiet(), edit_options(context))?;
+                return Ok(true);
+            }
+            Some(ConfigCommands::Migrate { dry_run }) => {
+                handlers::handle_config_migrate(
+                    *dry_run,
+                    context.quiet(),
+                    context.config_path(),
+                    context.project_root(),
+                )?;
+                return Ok(true);
+            }
+            None => {}
+        }
+    }
+
+    Ok(false)
+}
+
+/// Writes a starter config, or one imported from a compose file with its
+/// migration report.
+fn handle_init(args: &InitArgs, quiet: bool) -> Result<()> {
+    let Some(from) = args.from else {
         let path = config::init_config()?;
-        if !context.quiet() {
+        if !quiet {
             output::event(
                 "init",
                 LogLevel::Success,
@@ -30,16 +95,87 @@ pub(super) fn handle_setup_commands(
                 Persistence::Persistent,
             );
         }
-        return Ok(true);
+        return Ok(());
+    };
+
+    let source = match from {
+        InitSourceArg::Compose => config::ImportSource::Compose,
+        InitSourceArg::Sail => config::ImportSource::Sail,
+    };
+    let report =
+        config::init_config_from(config::InitFromOptions::new(source, args.file.as_deref()))?;
+    if !quiet {
+        for note in &report.notes {
+            output::event(
+                "init",
+                LogLevel::Info,
+                &format!("{}: {}", note.service, note.message),
+                Persistence::Persistent,
+            );
+        }
+        output::event(
+            "init",
+            LogLevel::Success,
+            &format!(
+                "Created {} from {}",
+                report.path.display(),
+                report.source_file.display()
+            ),
+            Persistence::Persistent,
+        );
     }
+    Ok(())
+}
 
-    if let Commands::Completions(args) = &cli.command {
-        let mut cmd = Cli::command();
-        generate(args.shell, &mut cmd, "helm", &mut std::io::stdout());
-        return Ok(true);
+/// Runs host-wide commands that act on every Helm project, so they work
+/// outside any project directory.
+fn handle_global_commands(
+    cli: &Cli,
+    context: &super::context::CliDispatchContext<'_>,
+) -> Option<Result<()>> {
+    let run = |handler: &dyn Fn() -> Result<()>| {
+        let settings = match global_engine_settings(context) {
+            Ok(settings) => settings,
+            Err(err) => return Some(Err(err)),
+        };
+        docker::set_container_engine(cli.engine.or(settings.container_engine).unwrap_or_default());
+        if let Err(err) = docker::set_engine_endpoint(super::engine_endpoint(cli, settings)) {
+            return Some(Err(err));
+        }
+        Some(handler())
+    };
+    match &cli.command {
+        Commands::Ps(args) if args.global => run(&|| handlers::handle_ps_global(&args.format)),
+        Commands::Down(args) if args.global => {
+            let project = args.project.as_deref()?;
+            run(&|| handlers::handle_down_global(project, args.timeout, context.quiet()))
+        }
+        Commands::Prune(args) if args.orphans => {
+            run(&|| handlers::handle_prune_orphans(args.force, context.quiet()))
+        }
+        _ => None,
+    }
+}
+
+/// Reads engine settings from the enclosing project, if any, so host-wide
+/// commands reach the engine its `engine_host`/`engine_context` selects.
+fn global_engine_settings(
+    context: &super::context::CliDispatchContext<'_>,
+) -> Result<config::EngineSettings> {
+    let options = config::LoadConfigPathOptions::new(context.config_path(), context.project_root())
+        .with_runtime_env(context.runtime_env());
+    if config::project_root_with(options).is_err() {
+        return Ok(config::EngineSettings::default());
     }
+    config::load_engine_settings_with(options)
+}
 
-    Ok(false)
+/// Targets config edits at the `--env` config file when one is selected.
+fn edit_options<'a>(
+    context: &super::context::CliDispatchContext<'a>,
+) -> config::EditConfigPathOptions<'a> {
+    config::EditConfigPathOptions::new(context.config_path(), context.project_root())
+        .with_runtime_env(context.runtime_env())
 }
 
 /// Loads config for this CLI invocation and applies `--env` overrides.
@@ -1,7 +1,7 @@
 //! Primary command dispatch.
 //!
-//! This layer handles config/preset/profile/doctor/lock and delegates operational
-//! commands to `operations`.
+//! This layer handles config/preset/profile/doctor/lock/export and delegates
+//! operational commands to `operations`.
 
 use anyhow::Result;
 
@@ -24,25 +24,33 @@ pub(super) fn dispatch_primary(
     context: &CliDispatchContext<'_>,
 ) -> Option<Result<()>> {
     match &cli.command {
-        Commands::Init | Commands::Completions(_) => Some(Ok(())),
+        Commands::Init(_) | Commands::Completions(_) => Some(Ok(())),
         Commands::Config(args) => Some(match args.command {
-            Some(ConfigCommands::Migrate) => handlers::handle_config_migrate(
-                context.quiet(),
+            Some(
+                ConfigCommands::Migrate { .. }
+                | ConfigCommands::Schema
+                | ConfigCommands::Validate { .. }
+                | ConfigCommands::Get { .. }
+                | ConfigCommands::Set { .. }
+                | ConfigCommands::Unset { .. },
+            ) => Ok(()),
+            None if args.show_origin => handlers::handle_config_origins(
+                &args.format,
                 context.config_path(),
                 context.project_root(),
+                context.runtime_env(),
             ),
             None => handlers::handle_config(config, &args.format),
         }),
         Commands::Preset(args) => Some(match &args.command {
-            PresetCommands::List => {
-                handlers::handle_preset_list();
-                Ok(())
+            PresetCommands::List => handlers::handle_preset_list(config),
+            PresetCommands::Show { name, format } => {
+                handlers::handle_preset_show(config, name, format)
             }
-            PresetCommands::Show { name, format } => handlers::handle_preset_show(name, format),
         }),
         Commands::Profile(args) => Some(match &args.command {
             ProfileCommands::List => {
-                handlers::handle_profile_list();
+                handlers::handle_profile_list(config);
                 Ok(())
             }
             ProfileCommands::Show { name, format } => {
@@ -67,6 +75,13 @@ pub(super) fn dispatch_primary(
             context.config_path(),
             context.project_root(),
         )),
+        Commands::Export(args) => Some(handlers::handle_export(
+            config,
+            &args.command,
+            context.quiet(),
+            context.config_path(),
+            context.project_root(),
+        )),
         _ => dispatch_operation_commands(cli, config, context),
     }
 }
@@ -88,6 +103,8 @@ mod tests {
             domain_strategy: None,
             service: Vec::new(),
             swarm: Vec::new(),
+            profile: std::collections::BTreeMap::new(),
+            preset: std::collections::BTreeMap::new(),
         }
     }
 
@@ -39,6 +39,8 @@ mod tests {
             domain_strategy: None,
             service: Vec::new(),
             swarm: Vec::new(),
+            profile: std::collections::BTreeMap::new(),
+            preset: std::collections::BTreeMap::new(),
         }
     }
 
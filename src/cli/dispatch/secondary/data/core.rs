@@ -61,6 +61,16 @@ pub(super) fn dispatch(
                 parallel: args.parallel,
             },
         )),
+        Commands::Diff(args) => Some(handlers::handle_diff(
+            config,
+            handlers::HandleDiffOptions {
+                service: args.service(),
+                services: args.services(),
+                kind: args.kind(),
+                profile: args.profile(),
+                format: &args.format,
+            },
+        )),
         Commands::Env(args) => Some(handlers::handle_env(
             config,
             handlers::HandleEnvOptions {
@@ -121,6 +131,8 @@ mod tests {
             domain_strategy: None,
             service: Vec::new(),
             swarm: Vec::new(),
+            profile: std::collections::BTreeMap::new(),
+            preset: std::collections::BTreeMap::new(),
         }
     }
 
@@ -156,6 +168,11 @@ mod tests {
         assert!(dispatch_result(&["helm", "health"]).is_some());
     }
 
+    #[test]
+    fn data_core_dispatches_diff() {
+        assert!(dispatch_result(&["helm", "diff"]).is_some());
+    }
+
     #[test]
     fn data_core_dispatches_env() {
         assert!(dispatch_result(&["helm", "env"]).is_some());
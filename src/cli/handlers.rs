@@ -6,6 +6,7 @@ mod about_cmd;
 mod app_create_cmd;
 mod artisan_cmd;
 mod config_cmd;
+mod diff_cmd;
 mod docker_ops;
 mod doctor_cmd;
 mod down_cmd;
@@ -13,6 +14,8 @@ mod dump_cmd;
 mod env_cmd;
 mod env_scrub_cmd;
 mod exec_cmd;
+mod export_cmd;
+mod global_cmd;
 mod health_cmd;
 mod list_cmd;
 mod lock_cmd;
@@ -46,7 +49,11 @@ pub(crate) use app_create_cmd::{HandleAppCreateOptions, handle_app_create};
 pub(crate) use artisan_cmd::{
     HandleArtisanOptions, handle_artisan, set_testing_runtime_pool_size_override,
 };
-pub(crate) use config_cmd::{handle_config, handle_config_migrate};
+pub(crate) use config_cmd::{
+    handle_config, handle_config_get, handle_config_migrate, handle_config_origins,
+    handle_config_schema, handle_config_set, handle_config_unset, handle_config_validate,
+};
+pub(crate) use diff_cmd::{HandleDiffOptions, handle_diff};
 pub(crate) use docker_ops::{
     HandleAttachOptions, HandleCpOptions, HandleEventsOptions, HandleInspectOptions,
     HandlePortOptions, HandlePruneOptions, handle_attach, handle_cp, handle_events, handle_inspect,
@@ -59,6 +66,8 @@ pub(crate) use dump_cmd::{HandleDumpOptions, handle_dump};
 pub(crate) use env_cmd::{HandleEnvOptions, handle_env};
 pub(crate) use env_scrub_cmd::handle_env_scrub;
 pub(crate) use exec_cmd::{HandleExecOptions, handle_exec};
+pub(crate) use export_cmd::handle_export;
+pub(crate) use global_cmd::{handle_down_global, handle_prune_orphans, handle_ps_global};
 pub(crate) use health_cmd::{HandleHealthOptions, handle_health};
 pub(crate) use list_cmd::handle_list;
 pub(crate) use lock_cmd::handle_lock;
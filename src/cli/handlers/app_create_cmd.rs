@@ -54,6 +54,14 @@ pub(crate) fn handle_app_create(
     Ok(())
 }
 
+/// Commands `helm app-create` runs with its default flags, in order.
+pub(super) fn default_create_commands() -> Vec<Vec<String>> {
+    let mut commands = commands::setup_commands();
+    commands.push(commands::storage_link_command());
+    commands.push(commands::migrate_command());
+    commands
+}
+
 #[cfg(test)]
 mod tests {
     use super::{HandleAppCreateOptions, handle_app_create};
@@ -68,6 +76,8 @@ mod tests {
             domain_strategy: None,
             service: Vec::new(),
             swarm: Vec::new(),
+            profile: std::collections::BTreeMap::new(),
+            preset: std::collections::BTreeMap::new(),
         };
 
         let result = handle_app_create(
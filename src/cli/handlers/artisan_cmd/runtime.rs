@@ -68,6 +68,8 @@ mod tests {
             domain_strategy: None,
             service: vec![service("valkey", configured_port, None)],
             swarm: Vec::new(),
+            profile: std::collections::BTreeMap::new(),
+            preset: std::collections::BTreeMap::new(),
         };
 
         let remapped = assign_testing_runtime_ports(&mut config).expect("assign ports");
@@ -88,6 +90,8 @@ mod tests {
             domain_strategy: None,
             service: vec![service("mailhog", app_port, Some(smtp_port))],
             swarm: Vec::new(),
+            profile: std::collections::BTreeMap::new(),
+            preset: std::collections::BTreeMap::new(),
         };
 
         let remapped = assign_testing_runtime_ports(&mut config).expect("assign ports");
@@ -107,6 +111,8 @@ mod tests {
             domain_strategy: None,
             service: vec![app_service("app", app_port)],
             swarm: Vec::new(),
+            profile: std::collections::BTreeMap::new(),
+            preset: std::collections::BTreeMap::new(),
         };
 
         assign_testing_runtime_ports(&mut config).expect("assign ports");
@@ -125,6 +131,8 @@ mod tests {
             domain_strategy: None,
             service: vec![app_service("app", app_port)],
             swarm: Vec::new(),
+            profile: std::collections::BTreeMap::new(),
+            preset: std::collections::BTreeMap::new(),
         };
         config.service[0].domain = Some("acme-app.localhost".to_owned());
         config.service[0].domains = Some(vec!["alt.localhost".to_owned()]);
@@ -150,6 +158,8 @@ mod tests {
                 service("redis", cache_port, None),
             ],
             swarm: Vec::new(),
+            profile: std::collections::BTreeMap::new(),
+            preset: std::collections::BTreeMap::new(),
         };
 
         let (_, app_env) = prepare_testing_runtime(&mut config).expect("prepare runtime");
@@ -195,6 +205,7 @@ mod tests {
             hook: Vec::new(),
             health_path: None,
             health_statuses: None,
+            health: None,
             localhost_tls: false,
             octane: false,
             octane_workers: None,
@@ -204,7 +215,21 @@ mod tests {
             env_mapping: None,
             javascript: None,
             container_name: None,
+            memory: None,
+            cpus: None,
+            pids_limit: None,
+            shm_size: None,
+            ulimits: None,
+            tmpfs: None,
+            platform: None,
+            user: None,
+            cap_add: None,
+            restart: None,
+            build: None,
             resolved_container_name: None,
+            resolved_project_root: None,
+            resolved_runtime_env: None,
+            secret_fields: Vec::new(),
         }
     }
 
@@ -10,9 +10,9 @@ pub(super) fn resolve_testing_startup_services<'a>(
     config: &'a config::Config,
     selected_service: Option<&'a str>,
 ) -> Result<Vec<&'a config::ServiceConfig>> {
-    if selected_service.is_none() {
+    let Some(selected_service) = selected_service else {
         return cli::support::resolve_up_services(config, None, None, None);
-    }
+    };
 
     let mut selected_names = config
         .service
@@ -20,7 +20,7 @@ pub(super) fn resolve_testing_startup_services<'a>(
         .filter(|service| service.kind != config::Kind::App)
         .map(|service| service.name.clone())
         .collect::<Vec<_>>();
-    selected_names.push(selected_service.expect("selected service should exist").to_owned());
+    selected_names.push(selected_service.to_owned());
 
     let by_name = config
         .service
@@ -33,7 +33,7 @@ pub(super) fn resolve_testing_startup_services<'a>(
             let Some(service) = by_name.get(current).copied() else {
                 anyhow::bail!("service '{current}' is missing");
             };
-            Ok(service.depends_on.clone().unwrap_or_default())
+            Ok(service.dependency_names())
         },
         |current| format!("circular dependency detected at service '{current}'"),
     )?;
@@ -77,7 +77,9 @@ where
 #[cfg(test)]
 mod tests {
     use super::resolve_testing_startup_services;
-    use crate::config::{Config, Driver, Kind, ProjectType, ServiceConfig};
+    use crate::config::{
+        Config, DependencyCondition, Driver, Kind, ProjectType, ServiceConfig, ServiceDependency,
+    };
 
     #[test]
     fn resolve_testing_startup_services_keeps_infra_and_selected_app_only() {
@@ -90,9 +92,16 @@ mod tests {
                 service("db", Kind::Database, Driver::Postgres, None),
                 service("cache", Kind::Cache, Driver::Valkey, None),
                 service("app", Kind::App, Driver::Frankenphp, Some(vec!["db"])),
-                service("profile-app", Kind::App, Driver::Frankenphp, Some(vec!["db"])),
+                service(
+                    "profile-app",
+                    Kind::App,
+                    Driver::Frankenphp,
+                    Some(vec!["db"]),
+                ),
             ],
             swarm: Vec::new(),
+            profile: std::collections::BTreeMap::new(),
+            preset: std::collections::BTreeMap::new(),
         };
 
         let scoped =
@@ -132,11 +141,17 @@ mod tests {
             volumes: None,
             env: None,
             command: None,
-            depends_on: depends_on.map(|items| items.into_iter().map(str::to_owned).collect()),
+            depends_on: depends_on.map(|items| {
+                items
+                    .into_iter()
+                    .map(|item| ServiceDependency::new(item, DependencyCondition::Started))
+                    .collect()
+            }),
             seed_file: None,
             hook: Vec::new(),
             health_path: None,
             health_statuses: None,
+            health: None,
             localhost_tls: false,
             octane: false,
             octane_workers: None,
@@ -146,7 +161,21 @@ mod tests {
             env_mapping: None,
             javascript: None,
             container_name: None,
+            memory: None,
+            cpus: None,
+            pids_limit: None,
+            shm_size: None,
+            ulimits: None,
+            tmpfs: None,
+            platform: None,
+            user: None,
+            cap_add: None,
+            restart: None,
+            build: None,
             resolved_container_name: None,
+            resolved_project_root: None,
+            resolved_runtime_env: None,
+            secret_fields: Vec::new(),
         }
     }
 }
@@ -149,6 +149,8 @@ fn handle_artisan_rejects_library_project_type() {
         domain_strategy: None,
         service: Vec::new(),
         swarm: Vec::new(),
+        profile: std::collections::BTreeMap::new(),
+        preset: std::collections::BTreeMap::new(),
     };
 
     let result = handle_artisan(
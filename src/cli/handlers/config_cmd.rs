@@ -9,22 +9,206 @@ use crate::cli::handlers::{log, serialize};
 use crate::config;
 
 /// Handles the `config` CLI command.
+///
+/// Values resolved from secret references are redacted.
 pub(crate) fn handle_config(config: &config::Config, format: &str) -> Result<()> {
-    serialize::print_pretty(config, format)
+    let mut redacted = config.clone();
+    redacted.service = config
+        .service
+        .iter()
+        .map(config::ServiceConfig::redacted)
+        .collect();
+    serialize::print_pretty(&redacted, format)
+}
+
+/// Handles the `config --show-origin` CLI command.
+pub(crate) fn handle_config_origins(
+    format: &str,
+    config_path: Option<&Path>,
+    project_root: Option<&Path>,
+    runtime_env: Option<&str>,
+) -> Result<()> {
+    let origins = config::config_value_origins(
+        config::ConfigOriginPathOptions::new(config_path, project_root)
+            .with_runtime_env(runtime_env),
+    )?;
+    if format == "json" {
+        return serialize::print_json_pretty(&origins);
+    }
+
+    for origin in origins {
+        println!(
+            "{}\t{} = {}",
+            origin.source.display(),
+            origin.key,
+            origin.value
+        );
+    }
+    Ok(())
+}
+
+/// Handles the `config schema` CLI command.
+pub(crate) fn handle_config_schema() -> Result<()> {
+    serialize::print_json_pretty(&config::config_schema()?)
+}
+
+/// Handles the `config validate` CLI command.
+///
+/// Fails when any diagnostic has error severity so CI can gate on it.
+pub(crate) fn handle_config_validate(
+    format: &str,
+    quiet: bool,
+    config_path: Option<&Path>,
+    project_root: Option<&Path>,
+    runtime_env: Option<&str>,
+) -> Result<()> {
+    let diagnostics = config::validate_config_with(
+        config::ValidateConfigPathOptions::new(config_path, project_root)
+            .with_runtime_env(runtime_env),
+    )?;
+    match format {
+        "json" => serialize::print_json_pretty(&diagnostics)?,
+        "text" => {
+            for diagnostic in &diagnostics {
+                let severity = match diagnostic.severity {
+                    config::DiagnosticSeverity::Error => "error",
+                    config::DiagnosticSeverity::Warning => "warning",
+                };
+                println!(
+                    "{}:{}:{}: {severity}[{}]: {}",
+                    diagnostic.file.display(),
+                    diagnostic.line,
+                    diagnostic.column,
+                    diagnostic.code,
+                    diagnostic.message
+                );
+            }
+            if diagnostics.is_empty() {
+                log::info_if_not_quiet(quiet, "config", "No config problems found");
+            }
+        }
+        _ => anyhow::bail!("unsupported format: {format}"),
+    }
+
+    let errors = diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.severity == config::DiagnosticSeverity::Error)
+        .count();
+    if errors > 0 {
+        anyhow::bail!("config validation failed with {errors} error(s)");
+    }
+    Ok(())
+}
+
+/// Handles the `config get` CLI command.
+pub(crate) fn handle_config_get(
+    key: &str,
+    options: config::EditConfigPathOptions<'_>,
+) -> Result<()> {
+    let value = config::config_value_with(options, key)?;
+    let Some(value) = value else {
+        anyhow::bail!("'{key}' is not set");
+    };
+    println!("{value}");
+    Ok(())
+}
+
+/// Handles the `config set` CLI command.
+pub(crate) fn handle_config_set(
+    key: &str,
+    value: &str,
+    quiet: bool,
+    options: config::EditConfigPathOptions<'_>,
+) -> Result<()> {
+    let paths = config::edit_config_with(
+        options,
+        &[config::ConfigEdit::set(key, parse_cli_value(value))],
+    )?;
+    log::info_if_not_quiet(
+        quiet,
+        "config",
+        &format!("Set {key} in {}", config::display_paths(&paths)),
+    );
+    Ok(())
+}
+
+/// Handles the `config unset` CLI command.
+pub(crate) fn handle_config_unset(
+    key: &str,
+    quiet: bool,
+    options: config::EditConfigPathOptions<'_>,
+) -> Result<()> {
+    let paths = config::edit_config_with(options, &[config::ConfigEdit::unset(key)])?;
+    log::info_if_not_quiet(
+        quiet,
+        "config",
+        &format!("Unset {key} in {}", config::display_paths(&paths)),
+    );
+    Ok(())
+}
+
+/// Parses a CLI value as a TOML literal, falling back to a plain string.
+fn parse_cli_value(value: &str) -> toml::Value {
+    format!("value = {value}")
+        .parse::<toml::Table>()
+        .ok()
+        .and_then(|mut table| table.remove("value"))
+        .unwrap_or_else(|| toml::Value::String(value.to_owned()))
 }
 
 /// Handles the `config migrate` CLI command.
+///
+/// With `dry_run`, prints the unified diff instead of writing the file.
 pub(crate) fn handle_config_migrate(
+    dry_run: bool,
     quiet: bool,
     config_path: Option<&Path>,
     project_root: Option<&Path>,
 ) -> Result<()> {
-    let path = config::migrate_config_with(config::MigrateConfigOptions {
-        config_path,
-        project_root,
-        runtime_env: None,
-    })?;
-    log::info_if_not_quiet(quiet, "config", &format!("Migrated {}", path.display()));
+    let options = config::MigrateConfigOptions::new(config_path, project_root);
+    let migration = if dry_run {
+        config::preview_config_migration_with(options)?
+    } else {
+        config::migrate_config_with(options)?
+    };
+    for step in &migration.applied {
+        log::info_if_not_quiet(quiet, "config", &format!("Migration: {step}"));
+    }
+
+    if migration.diff.is_empty() {
+        log::info_if_not_quiet(
+            quiet,
+            "config",
+            &format!(
+                "Config {} is already at schema_version {}",
+                migration.path.display(),
+                migration.to_version
+            ),
+        );
+    } else if dry_run {
+        print!("{}", migration.diff);
+    } else {
+        let backup = migration
+            .backup
+            .as_ref()
+            .map(|path| format!(" (backup: {})", path.display()))
+            .unwrap_or_default();
+        let message = if migration.from_version == migration.to_version {
+            format!(
+                "Set schema_version {} in {}{backup}",
+                migration.to_version,
+                migration.path.display()
+            )
+        } else {
+            format!(
+                "Migrated {} from schema_version {} to {}{backup}",
+                migration.path.display(),
+                migration.from_version,
+                migration.to_version
+            )
+        };
+        log::info_if_not_quiet(quiet, "config", &message);
+    }
     Ok(())
 }
 
@@ -36,7 +220,7 @@ mod tests {
 
     use crate::config::{Config, Driver, Kind, ServiceConfig};
 
-    use super::{handle_config, handle_config_migrate};
+    use super::{handle_config, handle_config_migrate, handle_config_origins};
 
     fn service(name: &str, kind: Kind, driver: Driver) -> ServiceConfig {
         ServiceConfig {
@@ -68,6 +252,7 @@ mod tests {
             hook: Vec::new(),
             health_path: None,
             health_statuses: None,
+            health: None,
             localhost_tls: false,
             octane: false,
             octane_workers: None,
@@ -77,7 +262,21 @@ mod tests {
             env_mapping: None,
             javascript: None,
             container_name: Some(format!("{name}-container")),
+            memory: None,
+            cpus: None,
+            pids_limit: None,
+            shm_size: None,
+            ulimits: None,
+            tmpfs: None,
+            platform: None,
+            user: None,
+            cap_add: None,
+            restart: None,
+            build: None,
             resolved_container_name: None,
+            resolved_project_root: None,
+            resolved_runtime_env: None,
+            secret_fields: Vec::new(),
         }
     }
 
@@ -89,6 +288,8 @@ mod tests {
             domain_strategy: None,
             service: vec![service("app", Kind::App, Driver::Frankenphp)],
             swarm: Vec::new(),
+            profile: std::collections::BTreeMap::new(),
+            preset: std::collections::BTreeMap::new(),
         }
     }
 
@@ -142,15 +343,28 @@ image = "nginx:1.29"
 "#,
         );
 
-        handle_config_migrate(false, Some(&config_path), None)?;
+        handle_config_migrate(false, false, Some(&config_path), None)?;
         assert!(config_path.exists());
         Ok(())
     }
 
+    #[test]
+    fn handle_config_origins_renders_supported_formats() -> Result<()> {
+        let root = temp_root();
+        let config_path = write_config(
+            &root,
+            "project_type = \"project\"\ncontainer_prefix = \"helm\"\n",
+        );
+
+        handle_config_origins("json", Some(&config_path), None, None)?;
+        handle_config_origins("toml", Some(&config_path), None, None)?;
+        Ok(())
+    }
+
     #[test]
     fn handle_config_migrate_fails_without_config_path() {
         let root = temp_root();
-        let result = handle_config_migrate(false, Some(&root.join("missing.toml")), None);
+        let result = handle_config_migrate(false, false, Some(&root.join("missing.toml")), None);
         assert!(result.is_err());
     }
 }
@@ -0,0 +1,82 @@
+//! cli handlers diff cmd module.
+//!
+//! Compares running containers with the resolved service config.
+
+use anyhow::Result;
+use serde::Serialize;
+
+use super::serialize;
+use super::service_scope::selected_services_in_scope;
+use crate::config::Kind;
+use crate::docker::{DriftState, FieldChange, drift_state, field_changes};
+use crate::{config, docker};
+
+pub(crate) struct HandleDiffOptions<'a> {
+    pub(crate) service: Option<&'a str>,
+    pub(crate) services: &'a [String],
+    pub(crate) kind: Option<Kind>,
+    pub(crate) profile: Option<&'a str>,
+    pub(crate) format: &'a str,
+}
+
+#[derive(Debug, Serialize)]
+struct ServiceDiff {
+    service: String,
+    container: String,
+    state: &'static str,
+    changes: Vec<FieldChange>,
+}
+
+/// Handles the `diff` CLI command.
+pub(crate) fn handle_diff(config: &config::Config, options: HandleDiffOptions<'_>) -> Result<()> {
+    let selected = selected_services_in_scope(
+        config,
+        options.service,
+        options.services,
+        options.kind,
+        options.profile,
+    )?;
+
+    let mut diffs = Vec::with_capacity(selected.len());
+    for service in selected {
+        let container = service.container_name()?;
+        let state = drift_state(service, &container)?;
+        let changes = match state {
+            DriftState::Missing => Vec::new(),
+            DriftState::InSync | DriftState::Drifted | DriftState::Unlabeled => {
+                docker::inspect_json(&container)
+                    .map(|inspect| field_changes(service, &inspect))
+                    .unwrap_or_default()
+            }
+        };
+        diffs.push(ServiceDiff {
+            service: service.name.clone(),
+            container,
+            state: state.as_str(),
+            changes,
+        });
+    }
+
+    match options.format {
+        "json" => serialize::print_json_pretty(&diffs),
+        "text" => {
+            for diff in &diffs {
+                print_text(diff);
+            }
+            Ok(())
+        }
+        _ => anyhow::bail!("unsupported format: {}", options.format),
+    }
+}
+
+fn print_text(diff: &ServiceDiff) {
+    println!("{} ({}): {}", diff.service, diff.container, diff.state);
+    for change in &diff.changes {
+        println!(
+            "  {}: {} -> {}",
+            change.field,
+            change.running.as_deref().unwrap_or("<unset>"),
+            change.config.as_deref().unwrap_or("<unset>")
+        );
+    }
+}
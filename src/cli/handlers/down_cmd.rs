@@ -55,6 +55,11 @@ pub(crate) fn handle_down(config: &config::Config, options: HandleDownOptions<'_
     cli::support::run_selected_services(&selected, options.parallel, |svc| {
         stop_selected_service(svc, options.quiet, options.timeout)
     })?;
+    // Removing the pod takes every member down, so keep it for partial downs.
+    if selected.len() == config.service.len() {
+        docker::remove_service_pods(&selected)?;
+    }
+    docker::remove_unused_service_networks(&selected)?;
 
     cli::hooks::run_phase_hooks_for_services(
         &selected,
@@ -47,6 +47,8 @@ mod tests {
             domain_strategy: None,
             service: vec![laravel, mysql],
             swarm: Vec::new(),
+            profile: std::collections::BTreeMap::new(),
+            preset: std::collections::BTreeMap::new(),
         };
 
         let output_path = PathBuf::from(format!(
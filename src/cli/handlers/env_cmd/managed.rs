@@ -141,6 +141,7 @@ mod tests {
             hook: Vec::new(),
             health_path: None,
             health_statuses: None,
+            health: None,
             localhost_tls: false,
             octane: false,
             octane_workers: None,
@@ -150,7 +151,21 @@ mod tests {
             env_mapping: None,
             javascript: None,
             container_name: Some(container_name.to_owned()),
+            memory: None,
+            cpus: None,
+            pids_limit: None,
+            shm_size: None,
+            ulimits: None,
+            tmpfs: None,
+            platform: None,
+            user: None,
+            cap_add: None,
+            restart: None,
+            build: None,
             resolved_container_name: None,
+            resolved_project_root: None,
+            resolved_runtime_env: None,
+            secret_fields: Vec::new(),
         }
     }
 
@@ -162,6 +177,8 @@ mod tests {
             domain_strategy: None,
             service: services,
             swarm: Vec::new(),
+            profile: std::collections::BTreeMap::new(),
+            preset: std::collections::BTreeMap::new(),
         }
     }
 
@@ -294,7 +311,7 @@ exit 1
         let config_path = root.join(".helm.toml");
         fs::write(
             &config_path,
-            "schema_version = 1\nproject_type = \"project\"\n",
+            "schema_version = 1\nproject_type = \"project\"\n\n[[service]]\nname = \"app\"\npreset = \"laravel\"\n",
         )
         .expect("seed config file");
 
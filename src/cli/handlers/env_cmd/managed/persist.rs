@@ -33,6 +33,7 @@ pub(super) fn persist_runtime_host_ports(
     project_root: Option<&Path>,
 ) -> Result<()> {
     let mut changed_services = Vec::new();
+    let mut edits = Vec::new();
     for (service_name, container_name, container_port) in persist_targets {
         if docker::inspect_status(container_name).as_deref() != Some("running") {
             continue;
@@ -44,21 +45,29 @@ pub(super) fn persist_runtime_host_ports(
         };
 
         if config::update_service_host_port(config, service_name, &host, port)? {
+            edits.push(config::ConfigEdit::set(
+                format!("service.{service_name}.host"),
+                host,
+            ));
+            edits.push(config::ConfigEdit::set(
+                format!("service.{service_name}.port"),
+                i64::from(port),
+            ));
             changed_services.push(service_name.clone());
         }
     }
 
     if !changed_services.is_empty() {
-        let path = config::save_config_with(
-            config,
-            config::SaveConfigPathOptions::new(config_path, project_root),
+        let paths = config::edit_config_with(
+            config::EditConfigPathOptions::new(config_path, project_root),
+            &edits,
         )?;
         log::success_if_not_quiet(
             quiet,
             "env",
             &format!(
                 "Persisted runtime host/port to {} for: {}",
-                path.display(),
+                config::display_paths(&paths),
                 changed_services.join(", ")
             ),
         );
@@ -109,6 +118,7 @@ mod tests {
             hook: Vec::new(),
             health_path: None,
             health_statuses: None,
+            health: None,
             localhost_tls: false,
             octane: false,
             octane_workers: None,
@@ -118,7 +128,21 @@ mod tests {
             env_mapping: None,
             javascript: None,
             container_name: container_name.map(ToOwned::to_owned),
+            memory: None,
+            cpus: None,
+            pids_limit: None,
+            shm_size: None,
+            ulimits: None,
+            tmpfs: None,
+            platform: None,
+            user: None,
+            cap_add: None,
+            restart: None,
+            build: None,
             resolved_container_name: None,
+            resolved_project_root: None,
+            resolved_runtime_env: None,
+            secret_fields: Vec::new(),
         }
     }
 
@@ -130,6 +154,8 @@ mod tests {
             domain_strategy: None,
             service: services,
             swarm: Vec::new(),
+            profile: std::collections::BTreeMap::new(),
+            preset: std::collections::BTreeMap::new(),
         }
     }
 
@@ -198,7 +224,7 @@ mod tests {
         fs::create_dir_all(config_path.parent().expect("config dir")).expect("create config dir");
         fs::write(
             &config_path,
-            "schema_version = 1\nproject_type = \"project\"\n",
+            "schema_version = 1\nproject_type = \"project\"\n\n[[service]]\nname = \"db\"\npreset = \"mysql\"\nport = 3306\n",
         )
         .expect("seed config file");
 
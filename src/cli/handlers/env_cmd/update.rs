@@ -66,6 +66,8 @@ mod tests {
             domain_strategy: None,
             service: vec![laravel, mysql],
             swarm: Vec::new(),
+            profile: std::collections::BTreeMap::new(),
+            preset: std::collections::BTreeMap::new(),
         };
 
         let env_path = std::env::temp_dir().join(format!(
@@ -103,6 +105,8 @@ mod tests {
             domain_strategy: None,
             service: Vec::new(),
             swarm: Vec::new(),
+            profile: std::collections::BTreeMap::new(),
+            preset: std::collections::BTreeMap::new(),
         };
         let env_path = std::env::temp_dir().join("helm-env-update-missing.env");
 
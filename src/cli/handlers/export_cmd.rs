@@ -0,0 +1,145 @@
+//! cli handlers export cmd module.
+//!
+//! Renders the resolved stack in formats other tooling understands.
+
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::cli::args::ExportCommands;
+use crate::cli::handlers::log;
+use crate::output::{self, LogLevel, Persistence};
+use crate::{cli, config, docker, serve, swarm};
+
+mod compose;
+mod devcontainer;
+mod run_args;
+
+use compose::ComposeFile;
+
+pub(crate) fn handle_export(
+    config_data: &config::Config,
+    command: &ExportCommands,
+    quiet: bool,
+    config_path: Option<&Path>,
+    project_root: Option<&Path>,
+) -> Result<()> {
+    match command {
+        ExportCommands::Compose { output } => {
+            let workspace_root = cli::support::workspace_root(config_path, project_root)?;
+            let rendered = render_compose(config_data, &workspace_root)?;
+            match output {
+                Some(path) => {
+                    std::fs::write(path, rendered)
+                        .with_context(|| format!("failed to write {}", path.display()))?;
+                    log::info_if_not_quiet(
+                        quiet,
+                        "export",
+                        &format!(
+                            "Wrote {} with {} services",
+                            path.display(),
+                            config_data.service.len()
+                        ),
+                    );
+                }
+                None => print!("{rendered}"),
+            }
+            Ok(())
+        }
+        ExportCommands::Devcontainer {
+            output,
+            service,
+            force,
+        } => {
+            let workspace_root = cli::support::workspace_root(config_path, project_root)?;
+            let app = config::resolve_app_service(config_data, service.as_deref())?;
+            let plan = serve::plan_serve_run(
+                app,
+                &workspace_root,
+                &app_env(config_data, &workspace_root)?,
+            )?;
+            let secrets = config_data
+                .service
+                .iter()
+                .flat_map(config::ServiceConfig::secret_values)
+                .collect::<Vec<_>>();
+            let rendered = devcontainer::render(
+                &plan.run_args,
+                plan.derived_image.as_ref(),
+                &workspace_root,
+                &super::app_create_cmd::default_create_commands(),
+                &secrets,
+            )?;
+
+            let path = output
+                .clone()
+                .unwrap_or_else(|| workspace_root.join(devcontainer::DEFAULT_OUTPUT));
+            let dockerfile_path = path.with_file_name("Dockerfile");
+            if !force {
+                let written = rendered.dockerfile.is_some().then_some(&dockerfile_path);
+                if let Some(existing) = std::iter::once(&path)
+                    .chain(written)
+                    .find(|path| path.exists())
+                {
+                    anyhow::bail!(
+                        "{} already exists; pass --force to overwrite it",
+                        existing.display()
+                    );
+                }
+            }
+            if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+                std::fs::create_dir_all(dir)
+                    .with_context(|| format!("failed to create {}", dir.display()))?;
+            }
+            std::fs::write(&path, &rendered.json)
+                .with_context(|| format!("failed to write {}", path.display()))?;
+            if let Some(dockerfile) = &rendered.dockerfile {
+                std::fs::write(&dockerfile_path, dockerfile)
+                    .with_context(|| format!("failed to write {}", dockerfile_path.display()))?;
+            }
+            if !rendered.dropped_command.is_empty() {
+                output::event(
+                    "export",
+                    LogLevel::Warn,
+                    &format!(
+                        "Dev containers run the image's default command; `{}` was not exported",
+                        rendered.dropped_command.join(" ")
+                    ),
+                    Persistence::Persistent,
+                );
+            }
+            log::info_if_not_quiet(
+                quiet,
+                "export",
+                &format!("Wrote {} for {}", path.display(), app.name),
+            );
+            Ok(())
+        }
+    }
+}
+
+/// Env the app container receives under `up` and `serve`.
+fn app_env(config_data: &config::Config, workspace_root: &Path) -> Result<HashMap<String, String>> {
+    let project_dependency_env = swarm::resolve_project_dependency_injected_env(workspace_root)?;
+    Ok(cli::support::runtime_app_env(
+        config_data,
+        &project_dependency_env,
+    ))
+}
+
+/// Builds each service from the same run args `up` and `serve` execute.
+fn render_compose(config_data: &config::Config, workspace_root: &Path) -> Result<String> {
+    let project_dependency_env = swarm::resolve_project_dependency_injected_env(workspace_root)?;
+    let app_env = compose::app_env(config_data, &project_dependency_env);
+
+    let mut compose = ComposeFile::default();
+    for service in &config_data.service {
+        if service.kind == config::Kind::App {
+            let plan = serve::plan_serve_run(service, workspace_root, &app_env)?;
+            compose.add_service(service, &plan.run_args, plan.derived_image.as_ref())?;
+        } else {
+            compose.add_service(service, &docker::service_run_args(service)?, None)?;
+        }
+    }
+    Ok(crate::yaml::render(&compose.into_node()))
+}
@@ -0,0 +1,404 @@
+//! cli handlers export cmd compose module.
+//!
+//! Translates the `docker run` args Helm builds into compose service entries.
+//! Values resolved from secret references are exported as `${VAR}`
+//! references, and Helm's ownership labels are left out so `prune --orphans`
+//! and `ps --global` never mistake compose containers for Helm's.
+
+use anyhow::{Result, bail};
+use std::collections::{BTreeMap, HashMap};
+
+use super::run_args::{RunArgs, contains_secret};
+use crate::config::{Config, DependencyCondition, ServiceBuild, ServiceConfig};
+use crate::serve::DerivedImagePlan;
+use crate::yaml::Node;
+use crate::{cli, docker, env};
+
+/// Prefix of the labels Helm uses to recognise containers it created.
+const HELM_LABEL_PREFIX: &str = "com.helm.";
+
+/// Env the exported app service receives.
+///
+/// Compose runs every service as its own container, so backends resolve to
+/// their service names and container ports even when Helm itself runs them in
+/// a pod.
+pub(super) fn app_env(
+    config: &Config,
+    project_dependency_env: &HashMap<String, String>,
+) -> HashMap<String, String> {
+    env::with_separate_containers(|| cli::support::runtime_app_env(config, project_dependency_env))
+}
+
+/// Compose document assembled service by service.
+#[derive(Default)]
+pub(super) struct ComposeFile {
+    services: Vec<(String, Node)>,
+    volumes: BTreeMap<String, Node>,
+    networks: BTreeMap<String, Node>,
+}
+
+impl ComposeFile {
+    /// Adds one service from the exact run args `up` or `serve` would use.
+    pub(super) fn add_service(
+        &mut self,
+        service: &ServiceConfig,
+        run_args: &[String],
+        derived_image: Option<&DerivedImagePlan>,
+    ) -> Result<()> {
+        let mut run = ParsedRun::parse(run_args)?;
+        run.labels
+            .retain(|(key, _)| !key.starts_with(HELM_LABEL_PREFIX));
+        // Pods replace the project network, and compose's default network
+        // already resolves service names.
+        if docker::pod_mode() {
+            run.networks.clear();
+        }
+        let secrets = secrets_longest_first(service);
+        run.reject_secrets(service, &secrets)?;
+        let mut entry = Vec::new();
+
+        if let Some(plan) = derived_image {
+            entry.push((
+                "build".to_owned(),
+                Node::Map(vec![
+                    ("context".to_owned(), Node::text(".")),
+                    ("dockerfile_inline".to_owned(), text(&plan.dockerfile)),
+                ]),
+            ));
+        } else if let Some(build) = &service.build {
+            entry.push(("build".to_owned(), build_entry(build)));
+        }
+        entry.push(("image".to_owned(), text(&run.image)));
+        if let Some(name) = &run.container_name {
+            entry.push(("container_name".to_owned(), text(name)));
+        }
+        if !run.command.is_empty() {
+            entry.push(("command".to_owned(), texts(&run.command)));
+        }
+        push_list(&mut entry, "ports", &run.ports);
+        push_list(&mut entry, "volumes", &run.volumes);
+        if !run.environment.is_empty() {
+            let environment = run
+                .environment
+                .iter()
+                .map(|(name, value)| (name.clone(), env_value(name, value, &secrets)))
+                .collect();
+            entry.push(("environment".to_owned(), Node::Map(environment)));
+        }
+        push_pairs(&mut entry, "labels", &run.labels);
+        push_list(&mut entry, "extra_hosts", &run.extra_hosts);
+        if !run.networks.is_empty() {
+            let networks = run
+                .networks
+                .iter()
+                .map(|(name, aliases)| {
+                    let options = if aliases.is_empty() {
+                        Vec::new()
+                    } else {
+                        vec![("aliases".to_owned(), texts(aliases))]
+                    };
+                    (name.clone(), Node::Map(options))
+                })
+                .collect();
+            entry.push(("networks".to_owned(), Node::Map(networks)));
+        }
+        entry.extend(run.options.iter().cloned());
+        if let Some(depends_on) = depends_on(service) {
+            entry.push(("depends_on".to_owned(), depends_on));
+        }
+        if let Some(command) = &run.health_cmd {
+            let test = health_test(service, command, &run.environment, &secrets)?;
+            run.healthcheck.insert(
+                0,
+                (
+                    "test".to_owned(),
+                    Node::List(vec![Node::text("CMD-SHELL"), text(&test)]),
+                ),
+            );
+        }
+        if !run.healthcheck.is_empty() {
+            entry.push(("healthcheck".to_owned(), Node::Map(run.healthcheck.clone())));
+        }
+
+        for volume in &run.volumes {
+            if let Some(name) = named_volume(volume) {
+                self.volumes.insert(name.to_owned(), named(name));
+            }
+        }
+        for (network, _) in &run.networks {
+            self.networks.insert(network.clone(), named(network));
+        }
+        self.services.push((service.name.clone(), Node::Map(entry)));
+        Ok(())
+    }
+
+    pub(super) fn into_node(self) -> Node {
+        let mut document = vec![("services".to_owned(), Node::Map(self.services))];
+        if !self.volumes.is_empty() {
+            document.push((
+                "volumes".to_owned(),
+                Node::Map(self.volumes.into_iter().collect()),
+            ));
+        }
+        if !self.networks.is_empty() {
+            document.push((
+                "networks".to_owned(),
+                Node::Map(self.networks.into_iter().collect()),
+            ));
+        }
+        Node::Map(document)
+    }
+}
+
+/// Service fields recovered from a `docker run` arg list.
+#[derive(Default)]
+struct ParsedRun {
+    container_name: Option<String>,
+    image: String,
+    command: Vec<String>,
+    ports: Vec<String>,
+    volumes: Vec<String>,
+    environment: Vec<(String, String)>,
+    labels: Vec<(String, String)>,
+    extra_hosts: Vec<String>,
+    networks: Vec<(String, Vec<String>)>,
+    health_cmd: Option<String>,
+    healthcheck: Vec<(String, Node)>,
+    options: Vec<(String, Node)>,
+}
+
+impl ParsedRun {
+    fn parse(run_args: &[String]) -> Result<Self> {
+        let parsed = RunArgs::parse(run_args)?;
+        let mut run = Self {
+            image: parsed.image.to_owned(),
+            command: parsed.command.to_vec(),
+            ..Self::default()
+        };
+        let mut ulimits = Vec::new();
+        let mut tmpfs = Vec::new();
+        let mut cap_add = Vec::new();
+
+        for (flag, value) in parsed.flags {
+            match flag {
+                "--name" => run.container_name = Some(value.to_owned()),
+                "-p" => run.ports.push(value.to_owned()),
+                "-v" => run.volumes.push(value.to_owned()),
+                "-e" => set_pair(&mut run.environment, value),
+                "--label" => set_pair(&mut run.labels, value),
+                "--add-host" => run.extra_hosts.push(value.to_owned()),
+                "--network" => run.networks.push((value.to_owned(), Vec::new())),
+                "--network-alias" => match run.networks.last_mut() {
+                    Some((_, aliases)) => aliases.push(value.to_owned()),
+                    None => bail!("--network-alias '{value}' has no --network"),
+                },
+                "--platform" => run.option("platform", text(value)),
+                "--user" => run.option("user", text(value)),
+                "--memory" => run.option("mem_limit", text(value)),
+                "--shm-size" => run.option("shm_size", text(value)),
+                "--cpus" => run.option("cpus", Node::Literal(value.to_owned())),
+                "--pids-limit" => run.option("pids_limit", Node::Literal(value.to_owned())),
+                "--ulimit" => ulimits.push(ulimit(value)?),
+                "--tmpfs" => tmpfs.push(text(value)),
+                "--cap-add" => cap_add.push(text(value)),
+                "--restart" => run.option("restart", text(value)),
+                "--health-cmd" => run.health_cmd = Some(value.to_owned()),
+                "--health-interval" => run.health("interval", text(value)),
+                "--health-retries" => run.health("retries", Node::Literal(value.to_owned())),
+                "--health-start-period" => run.health("start_period", text(value)),
+                _ => bail!("cannot export run flag '{flag}' to compose"),
+            }
+        }
+
+        // Inferred env comes from hash maps; sort so exports diff cleanly.
+        run.environment.sort();
+        if !ulimits.is_empty() {
+            run.option("ulimits", Node::Map(ulimits));
+        }
+        if !tmpfs.is_empty() {
+            run.option("tmpfs", Node::List(tmpfs));
+        }
+        if !cap_add.is_empty() {
+            run.option("cap_add", Node::List(cap_add));
+        }
+        Ok(run)
+    }
+
+    fn option(&mut self, key: &str, value: Node) {
+        self.options.push((key.to_owned(), value));
+    }
+
+    fn health(&mut self, key: &str, value: Node) {
+        self.healthcheck.push((key.to_owned(), value));
+    }
+
+    /// Fails when a secret lands somewhere compose cannot reference it from
+    /// the environment.
+    fn reject_secrets(&self, service: &ServiceConfig, secrets: &[String]) -> Result<()> {
+        let fields = [
+            ("image", std::slice::from_ref(&self.image)),
+            ("command", self.command.as_slice()),
+            ("ports", self.ports.as_slice()),
+            ("volumes", self.volumes.as_slice()),
+            ("extra_hosts", self.extra_hosts.as_slice()),
+        ];
+        for (field, values) in fields {
+            if values.iter().any(|value| contains_secret(value, secrets)) {
+                bail!(
+                    "service '{}' puts a secret into `{field}`, which cannot be exported without revealing it",
+                    service.name
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the service's secret values, longest first so a secret that
+/// contains another is replaced whole.
+fn secrets_longest_first(service: &ServiceConfig) -> Vec<String> {
+    let mut secrets = service.secret_values();
+    secrets.sort_by_key(|secret| std::cmp::Reverse(secret.len()));
+    secrets
+}
+
+/// Exports secret env values as `${NAME}` so compose reads them from the
+/// shell or `.env` instead of the file.
+fn env_value(name: &str, value: &str, secrets: &[String]) -> Node {
+    if contains_secret(value, secrets) {
+        Node::text(format!("${{{name}}}"))
+    } else {
+        text(value)
+    }
+}
+
+/// Rewrites secrets in a health command to the container env var holding
+/// them, which the shell expands inside the container.
+fn health_test(
+    service: &ServiceConfig,
+    command: &str,
+    environment: &[(String, String)],
+    secrets: &[String],
+) -> Result<String> {
+    let mut test = command.to_owned();
+    for secret in secrets {
+        if !test.contains(secret.as_str()) {
+            continue;
+        }
+        let Some((name, _)) = environment.iter().find(|(_, value)| value == secret) else {
+            bail!(
+                "service '{}' embeds a secret in its health check, which cannot be exported without revealing it",
+                service.name
+            );
+        };
+        test = test.replace(secret.as_str(), &format!("${name}"));
+    }
+    Ok(test)
+}
+
+/// Sets `KEY=value`; a repeated key keeps the last value, like `docker run`.
+fn set_pair(pairs: &mut Vec<(String, String)>, raw: &str) {
+    let (key, value) = raw.split_once('=').unwrap_or((raw, ""));
+    match pairs.iter_mut().find(|(existing, _)| existing == key) {
+        Some((_, existing)) => value.clone_into(existing),
+        None => pairs.push((key.to_owned(), value.to_owned())),
+    }
+}
+
+fn ulimit(raw: &str) -> Result<(String, Node)> {
+    let Some((name, limits)) = raw.split_once('=') else {
+        bail!("invalid ulimit '{raw}'");
+    };
+    let node = match limits.split_once(':') {
+        Some((soft, hard)) => Node::Map(vec![
+            ("soft".to_owned(), Node::Literal(soft.to_owned())),
+            ("hard".to_owned(), Node::Literal(hard.to_owned())),
+        ]),
+        None => Node::Literal(limits.to_owned()),
+    };
+    Ok((name.to_owned(), node))
+}
+
+fn depends_on(service: &ServiceConfig) -> Option<Node> {
+    let dependencies = service
+        .depends_on
+        .as_ref()
+        .filter(|deps| !deps.is_empty())?;
+    let entries = dependencies
+        .iter()
+        .map(|dependency| {
+            let condition = match dependency.condition {
+                DependencyCondition::Started => "service_started",
+                DependencyCondition::Healthy => "service_healthy",
+                DependencyCondition::Completed => "service_completed_successfully",
+            };
+            (
+                dependency.service.clone(),
+                Node::Map(vec![("condition".to_owned(), Node::text(condition))]),
+            )
+        })
+        .collect();
+    Some(Node::Map(entries))
+}
+
+/// Returns the volume name for `name:/path` mounts, `None` for bind mounts.
+fn named_volume(volume: &str) -> Option<&str> {
+    let (source, _) = volume.split_once(':')?;
+    let is_path = source.starts_with(['/', '.', '~']) || source.contains('/');
+    (!is_path && !source.is_empty()).then_some(source)
+}
+
+/// Mirrors `[service.build]`; secrets stay out since compose declares them
+/// top-level.
+fn build_entry(build: &ServiceBuild) -> Node {
+    let mut entry = vec![
+        ("context".to_owned(), text(build.context_dir())),
+        ("dockerfile".to_owned(), text(build.dockerfile_path())),
+    ];
+    if let Some(args) = &build.args {
+        let mut args = args.iter().collect::<Vec<_>>();
+        args.sort();
+        let args = args
+            .into_iter()
+            .map(|(name, value)| (name.clone(), text(value)))
+            .collect();
+        entry.push(("args".to_owned(), Node::Map(args)));
+    }
+    if let Some(target) = &build.target {
+        entry.push(("target".to_owned(), text(target)));
+    }
+    Node::Map(entry)
+}
+
+/// Top-level entry that pins the engine-side name so compose reuses Helm's.
+fn named(name: &str) -> Node {
+    Node::Map(vec![("name".to_owned(), text(name))])
+}
+
+fn push_list(entry: &mut Vec<(String, Node)>, key: &str, values: &[String]) {
+    if !values.is_empty() {
+        entry.push((key.to_owned(), texts(values)));
+    }
+}
+
+fn push_pairs(entry: &mut Vec<(String, Node)>, key: &str, pairs: &[(String, String)]) {
+    if !pairs.is_empty() {
+        let map = pairs
+            .iter()
+            .map(|(name, value)| (name.clone(), text(value)))
+            .collect();
+        entry.push((key.to_owned(), Node::Map(map)));
+    }
+}
+
+fn texts(values: &[String]) -> Node {
+    Node::List(values.iter().map(|value| text(value)).collect())
+}
+
+/// Escapes `$` so compose does not interpolate resolved values.
+fn text(value: &str) -> Node {
+    Node::text(value.replace('$', "$$"))
+}
+
+#[cfg(test)]
+mod tests;
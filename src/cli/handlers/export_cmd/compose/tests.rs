@@ -0,0 +1,351 @@
+use super::{ComposeFile, app_env};
+use crate::config::{
+    Config, DependencyCondition, Driver, Kind, ProjectType, ServiceBuild, ServiceConfig,
+    ServiceDependency,
+};
+use crate::yaml::render;
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+
+fn service() -> ServiceConfig {
+    ServiceConfig {
+        database: Some("laravel".to_owned()),
+        username: Some("laravel".to_owned()),
+        password: Some("laravel".to_owned()),
+        container_name: Some("acme-db".to_owned()),
+        resolved_container_name: Some("acme-db".to_owned()),
+        ..ServiceConfig::fixture("db", Kind::Database, Driver::Mysql, "mysql:8.4")
+    }
+}
+
+fn args(raw: &[&str]) -> Vec<String> {
+    raw.iter().map(|arg| (*arg).to_owned()).collect()
+}
+
+fn export(services: &[(&ServiceConfig, Vec<String>)]) -> anyhow::Result<String> {
+    let mut compose = ComposeFile::default();
+    for (service, run_args) in services {
+        compose.add_service(service, run_args, None)?;
+    }
+    Ok(render(&compose.into_node()))
+}
+
+#[test]
+fn maps_run_args_to_compose_fields() -> anyhow::Result<()> {
+    let rendered = export(&[(
+        &service(),
+        args(&[
+            "run",
+            "-d",
+            "--name",
+            "acme-db",
+            "-p",
+            "127.0.0.1:3306:3306",
+            "--memory",
+            "1g",
+            "--cpus",
+            "1.5",
+            "--ulimit",
+            "nofile=1024:65535",
+            "--restart",
+            "on-failure:3",
+            "-v",
+            "acme-db-data:/var/lib/mysql",
+            "-e",
+            "MYSQL_PASSWORD=pa$$word",
+            "-e",
+            "MYSQL_DATABASE=laravel",
+            "-e",
+            "MYSQL_PASSWORD=secret",
+            "--network",
+            "helm-acme-1234abcd-local",
+            "--network-alias",
+            "db",
+            "--label",
+            "com.helm.managed=true",
+            "mysql:8.4",
+            "--max-connections=50",
+        ]),
+    )])?;
+
+    assert_eq!(
+        rendered,
+        "services:
+  db:
+    image: \"mysql:8.4\"
+    container_name: acme-db
+    command:
+      - \"--max-connections=50\"
+    ports:
+      - \"127.0.0.1:3306:3306\"
+    volumes:
+      - \"acme-db-data:/var/lib/mysql\"
+    environment:
+      MYSQL_DATABASE: laravel
+      MYSQL_PASSWORD: secret
+    networks:
+      helm-acme-1234abcd-local:
+        aliases:
+          - db
+    mem_limit: \"1g\"
+    cpus: 1.5
+    restart: \"on-failure:3\"
+    ulimits:
+      nofile:
+        soft: 1024
+        hard: 65535
+volumes:
+  acme-db-data:
+    name: acme-db-data
+networks:
+  helm-acme-1234abcd-local:
+    name: helm-acme-1234abcd-local
+"
+    );
+    Ok(())
+}
+
+#[test]
+fn escapes_dollar_signs_against_compose_interpolation() -> anyhow::Result<()> {
+    let rendered = export(&[(
+        &service(),
+        args(&["run", "-e", "APP_KEY=base64:a$b", "mysql:8.4"]),
+    )])?;
+    assert!(rendered.contains("APP_KEY: \"base64:a$$b\""), "{rendered}");
+    Ok(())
+}
+
+#[test]
+fn bind_mounts_are_not_declared_as_named_volumes() -> anyhow::Result<()> {
+    let rendered = export(&[(
+        &service(),
+        args(&[
+            "run",
+            "-v",
+            "/srv/app:/app",
+            "-v",
+            "./data:/data",
+            "php:8.4",
+        ]),
+    )])?;
+    assert!(!rendered.contains("\nvolumes:"), "{rendered}");
+    Ok(())
+}
+
+#[test]
+fn adds_depends_on_conditions_and_healthchecks() -> anyhow::Result<()> {
+    let mut app = service();
+    app.name = "app".to_owned();
+    app.depends_on = Some(vec![
+        ServiceDependency::new("db", DependencyCondition::Healthy),
+        ServiceDependency::new("migrate", DependencyCondition::Completed),
+        ServiceDependency::new("redis", DependencyCondition::Started),
+    ]);
+
+    let rendered = export(&[(
+        &app,
+        args(&[
+            "run",
+            "-p",
+            "127.0.0.1:8080:80",
+            "--health-cmd",
+            "curl -fsS -o /dev/null http://127.0.0.1:80/up",
+            "--health-interval",
+            "5s",
+            "--health-retries",
+            "3",
+            "php:8.4",
+        ]),
+    )])?;
+
+    assert!(
+        rendered.contains(
+            "    depends_on:
+      db:
+        condition: service_healthy
+      migrate:
+        condition: service_completed_successfully
+      redis:
+        condition: service_started
+    healthcheck:
+      test:
+        - CMD-SHELL
+        - \"curl -fsS -o /dev/null http://127.0.0.1:80/up\"
+      interval: \"5s\"
+      retries: 3
+"
+        ),
+        "{rendered}"
+    );
+    Ok(())
+}
+
+#[test]
+fn health_flags_become_shell_healthchecks() -> anyhow::Result<()> {
+    let rendered = export(&[(
+        &service(),
+        args(&[
+            "run",
+            "--health-cmd",
+            "mysqladmin ping -h 127.0.0.1 -u root -p'pa$$' --silent",
+            "--health-interval",
+            "2s",
+            "--health-retries",
+            "3",
+            "--health-start-period",
+            "10s",
+            "mysql:8.4",
+        ]),
+    )])?;
+    assert!(
+        rendered.contains(
+            "    healthcheck:
+      test:
+        - CMD-SHELL
+        - \"mysqladmin ping -h 127.0.0.1 -u root -p'pa$$$$' --silent\"
+      interval: \"2s\"
+      retries: 3
+      start_period: \"10s\"
+"
+        ),
+        "{rendered}"
+    );
+    Ok(())
+}
+
+#[test]
+fn service_builds_become_build_entries() -> anyhow::Result<()> {
+    let mut service = service();
+    service.build = Some(ServiceBuild {
+        context: Some("docker/sidecar".to_owned()),
+        dockerfile: None,
+        args: Some(HashMap::from([("VERSION".to_owned(), "2".to_owned())])),
+        target: Some("dev".to_owned()),
+        secrets: Some(vec!["id=npm,src=.npmrc".to_owned()]),
+    });
+    let rendered = export(&[(&service, args(&["run", "helm/db"]))])?;
+    assert!(
+        rendered.contains(
+            "    build:
+      context: docker/sidecar
+      dockerfile: Dockerfile
+      args:
+        VERSION: \"2\"
+      target: dev
+    image: helm/db
+"
+        ),
+        "{rendered}"
+    );
+    Ok(())
+}
+
+#[test]
+fn secrets_are_exported_as_env_references() -> anyhow::Result<()> {
+    let mut service = service();
+    service.password = Some("hunter2".to_owned());
+    service.secret_fields = vec!["password".to_owned()];
+    let rendered = export(&[(
+        &service,
+        args(&[
+            "run",
+            "-e",
+            "MYSQL_PASSWORD=hunter2",
+            "-e",
+            "DATABASE_URL=mysql://laravel:hunter2@db/laravel",
+            "--health-cmd",
+            "mysqladmin ping -u laravel -phunter2 --silent",
+            "--label",
+            "com.helm.project.path=/work/acme",
+            "--label",
+            "team=payments",
+            "mysql:8.4",
+        ]),
+    )])?;
+
+    assert!(!rendered.contains("hunter2"), "{rendered}");
+    assert!(!rendered.contains("com.helm."), "{rendered}");
+    assert!(
+        rendered.contains(
+            "    environment:
+      DATABASE_URL: \"${DATABASE_URL}\"
+      MYSQL_PASSWORD: \"${MYSQL_PASSWORD}\"
+    labels:
+      team: payments
+"
+        ),
+        "{rendered}"
+    );
+    assert!(
+        rendered.contains("- \"mysqladmin ping -u laravel -p$$MYSQL_PASSWORD --silent\""),
+        "{rendered}"
+    );
+    Ok(())
+}
+
+#[test]
+fn secrets_outside_the_environment_are_rejected() {
+    let mut service = service();
+    service.command = Some(vec!["--token=hunter2".to_owned()]);
+    service.secret_fields = vec!["command.0".to_owned()];
+    let result = export(&[(&service, args(&["run", "mysql:8.4", "--token=hunter2"]))]);
+    assert!(result.is_err_and(|err| err.to_string().contains("`command`")));
+}
+
+#[test]
+fn pod_mode_leaves_out_the_project_network() -> anyhow::Result<()> {
+    let mut db = service();
+    db.port = 33060;
+    db.resolved_project_root = Some(PathBuf::from("/work/acme"));
+    let app = ServiceConfig::fixture("app", Kind::App, Driver::Frankenphp, "php:8.4");
+    let config = Config {
+        schema_version: 1,
+        project_type: ProjectType::Project,
+        container_prefix: None,
+        domain_strategy: None,
+        service: vec![db.clone(), app.clone()],
+        swarm: Vec::new(),
+        profile: BTreeMap::new(),
+        preset: BTreeMap::new(),
+    };
+
+    let rendered = crate::docker::with_podman_pod(|| {
+        let env = app_env(&config, &HashMap::new());
+        let mut app_args = args(&["run"]);
+        for key in ["DB_HOST", "DB_PORT"] {
+            app_args.push("-e".to_owned());
+            app_args.push(format!(
+                "{key}={}",
+                env.get(key).cloned().unwrap_or_default()
+            ));
+        }
+        app_args.push("php:8.4".to_owned());
+        export(&[
+            (
+                &db,
+                args(&[
+                    "run",
+                    "--network",
+                    "helm-acme-1234abcd-local",
+                    "--network-alias",
+                    "db",
+                    "mysql:8.4",
+                ]),
+            ),
+            (&app, app_args),
+        ])
+    })?;
+    assert!(!rendered.contains("networks:"), "{rendered}");
+    assert!(rendered.contains("DB_HOST: db\n"), "{rendered}");
+    assert!(rendered.contains("DB_PORT: \"3306\"\n"), "{rendered}");
+    Ok(())
+}
+
+#[test]
+fn rejects_run_flags_it_cannot_translate() {
+    let result = export(&[(
+        &service(),
+        args(&["run", "--privileged", "true", "mysql:8.4"]),
+    )]);
+    assert!(result.is_err_and(|err| err.to_string().contains("--privileged")));
+}
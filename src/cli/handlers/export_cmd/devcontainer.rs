@@ -0,0 +1,315 @@
+//! cli handlers export cmd devcontainer module.
+//!
+//! Translates the app's serve run args into a Dev Container definition.
+//! Env values holding secrets become `${localEnv:NAME}` so the definition
+//! reads them from the host instead of storing them.
+
+use anyhow::{Result, bail};
+use serde_json::{Map, Value, json};
+use std::path::Path;
+
+use super::run_args::{RunArgs, contains_secret};
+use crate::serve::DerivedImagePlan;
+
+/// Definition path used when no `--output` is given, relative to the project.
+pub(super) const DEFAULT_OUTPUT: &str = ".devcontainer/devcontainer.json";
+
+/// Rendered `devcontainer.json` plus the Dockerfile it builds, if any.
+pub(super) struct Devcontainer {
+    pub(super) json: String,
+    /// Derived-image Dockerfile, written next to `devcontainer.json`.
+    pub(super) dockerfile: Option<String>,
+    /// Serve command the definition cannot express; the image default runs.
+    pub(super) dropped_command: Vec<String>,
+}
+
+/// Builds the definition from the exact run args `helm serve` uses.
+///
+/// The project mount becomes the workspace mount, published ports become
+/// `forwardPorts`, env becomes `containerEnv`, and the remaining flags
+/// (network, host mappings, runtime limits) pass through as `runArgs`.
+/// `secrets` are the resolved secret values of the whole config.
+pub(super) fn render(
+    run_args: &[String],
+    derived_image: Option<&DerivedImagePlan>,
+    project_root: &Path,
+    post_create: &[Vec<String>],
+    secrets: &[String],
+) -> Result<Devcontainer> {
+    let mut definition = Map::new();
+    let mut forward_ports = Vec::new();
+    let mut container_env = Map::new();
+    let mut mounts = Vec::new();
+    let mut passthrough = Vec::new();
+
+    let run = RunArgs::parse(run_args)?;
+    if contains_secret(run.image, secrets) {
+        bail!("cannot export the image without revealing a secret");
+    }
+    for (arg, value) in run.flags {
+        if !matches!(arg, "-e" | "--label") && contains_secret(value, secrets) {
+            bail!("cannot export `{arg}` without revealing a secret");
+        }
+        match arg {
+            "--name" => {
+                definition.insert("name".to_owned(), json!(value));
+            }
+            // Helm labels would make the dev container look like a managed one.
+            "--label" => {}
+            "-p" => {
+                let port = value
+                    .rsplit(':')
+                    .next()
+                    .and_then(|port| port.parse::<u16>().ok());
+                let Some(port) = port else {
+                    bail!("cannot forward port mapping '{value}'");
+                };
+                forward_ports.push(json!(port));
+            }
+            "-e" => {
+                let (key, value) = value.split_once('=').unwrap_or((value, ""));
+                let value = if contains_secret(value, secrets) {
+                    format!("${{localEnv:{key}}}")
+                } else {
+                    value.to_owned()
+                };
+                container_env.insert(key.to_owned(), json!(value));
+            }
+            "-v" => match mount(value, project_root) {
+                Mount::Workspace(target) => {
+                    definition.insert(
+                        "workspaceMount".to_owned(),
+                        json!(format!(
+                            "source=${{localWorkspaceFolder}},target={target},type=bind"
+                        )),
+                    );
+                    definition.insert("workspaceFolder".to_owned(), json!(target));
+                }
+                Mount::Other(spec) => mounts.push(json!(spec)),
+            },
+            _ => {
+                passthrough.push(json!(arg));
+                passthrough.push(json!(value));
+            }
+        }
+    }
+
+    // The dropped command is only reported, so redact rather than refuse.
+    let mut dropped_command = run.command.to_vec();
+    for arg in &mut dropped_command {
+        if contains_secret(arg, secrets) {
+            "<redacted>".clone_into(arg);
+        }
+    }
+    let dockerfile = derived_image.map(|plan| {
+        definition.insert(
+            "build".to_owned(),
+            json!({
+                "dockerfile": "Dockerfile",
+                "options": ["--tag", plan.tag],
+            }),
+        );
+        plan.dockerfile.clone()
+    });
+    if dockerfile.is_none() {
+        definition.insert("image".to_owned(), json!(run.image));
+    }
+
+    definition.insert("forwardPorts".to_owned(), Value::Array(forward_ports));
+    definition.insert("containerEnv".to_owned(), Value::Object(container_env));
+    if !mounts.is_empty() {
+        definition.insert("mounts".to_owned(), Value::Array(mounts));
+    }
+    if !passthrough.is_empty() {
+        definition.insert("runArgs".to_owned(), Value::Array(passthrough));
+    }
+    // Keep the image's own server running, as under `helm serve`.
+    definition.insert("overrideCommand".to_owned(), json!(false));
+    if !post_create.is_empty() {
+        let script = post_create
+            .iter()
+            .map(|command| {
+                command
+                    .iter()
+                    .map(|arg| crate::shell::quote(arg))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect::<Vec<_>>()
+            .join(" && ");
+        definition.insert("postCreateCommand".to_owned(), json!(script));
+    }
+
+    let mut json = serde_json::to_string_pretty(&Value::Object(definition))?;
+    json.push('\n');
+    Ok(Devcontainer {
+        json,
+        dockerfile,
+        dropped_command,
+    })
+}
+
+enum Mount {
+    /// The project directory, mounted at this container path.
+    Workspace(String),
+    /// A `--mount`-style spec for any other volume.
+    Other(String),
+}
+
+/// Converts a `-v source:target[:options]` value into a dev container mount.
+fn mount(volume: &str, project_root: &Path) -> Mount {
+    let mut parts = volume.splitn(3, ':');
+    let source = parts.next().unwrap_or_default();
+    let target = parts.next().unwrap_or_default();
+    let readonly = parts
+        .next()
+        .is_some_and(|options| options.split(',').any(|option| option == "ro"));
+
+    let source_path = Path::new(source);
+    if source_path == project_root && !readonly {
+        return Mount::Workspace(target.to_owned());
+    }
+    let (source, kind) = if let Ok(relative) = source_path.strip_prefix(project_root) {
+        (
+            format!("${{localWorkspaceFolder}}/{}", relative.display()),
+            "bind",
+        )
+    } else if source_path.is_absolute() {
+        (source.to_owned(), "bind")
+    } else {
+        (source.to_owned(), "volume")
+    };
+
+    let mut spec = format!("source={source},target={target},type={kind}");
+    if readonly {
+        spec.push_str(",readonly");
+    }
+    Mount::Other(spec)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::render;
+    use serde_json::Value;
+    use std::path::Path;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| (*value).to_owned()).collect()
+    }
+
+    #[test]
+    fn maps_serve_run_args_onto_devcontainer_fields() {
+        let run_args = args(&[
+            "run",
+            "-d",
+            "--name",
+            "acme-app",
+            "-p",
+            "127.0.0.1:33065:80",
+            "--network",
+            "acme",
+            "--label",
+            "com.helm.managed=true",
+            "-v",
+            "/work/acme:/app",
+            "-v",
+            "/work/acme/storage/keys:/keys:ro",
+            "-v",
+            "acme-cache:/cache",
+            "-e",
+            "APP_URL=https://acme.helm",
+            "dunglas/frankenphp:php8.5",
+        ]);
+        let post_create = vec![args(&["php", "artisan", "key:generate"])];
+
+        let rendered = render(&run_args, None, Path::new("/work/acme"), &post_create, &[])
+            .expect("renders definition");
+        let definition: Value = serde_json::from_str(&rendered.json).expect("valid json");
+
+        assert_eq!(definition["name"], "acme-app");
+        assert_eq!(definition["image"], "dunglas/frankenphp:php8.5");
+        assert_eq!(definition["forwardPorts"], serde_json::json!([80]));
+        assert_eq!(definition["containerEnv"]["APP_URL"], "https://acme.helm");
+        assert_eq!(definition["workspaceFolder"], "/app");
+        assert_eq!(
+            definition["workspaceMount"],
+            "source=${localWorkspaceFolder},target=/app,type=bind"
+        );
+        assert_eq!(
+            definition["mounts"],
+            serde_json::json!([
+                "source=${localWorkspaceFolder}/storage/keys,target=/keys,type=bind,readonly",
+                "source=acme-cache,target=/cache,type=volume",
+            ])
+        );
+        assert_eq!(
+            definition["runArgs"],
+            serde_json::json!(["--network", "acme"])
+        );
+        assert_eq!(definition["postCreateCommand"], "php artisan key:generate");
+        assert_eq!(definition["overrideCommand"], false);
+        assert!(rendered.dockerfile.is_none());
+        assert!(rendered.dropped_command.is_empty());
+    }
+
+    #[test]
+    fn reports_commands_the_definition_cannot_run() {
+        let run_args = args(&["run", "-d", "app:latest", "php", "artisan", "octane:start"]);
+
+        let rendered =
+            render(&run_args, None, Path::new("/work"), &[], &[]).expect("renders definition");
+
+        assert_eq!(rendered.dropped_command, ["php", "artisan", "octane:start"]);
+        assert!(!rendered.json.contains("postCreateCommand"));
+    }
+
+    #[test]
+    fn secrets_become_local_env_references() {
+        let run_args = args(&[
+            "run",
+            "-e",
+            "DB_PASSWORD=hunter2",
+            "-e",
+            "APP_ENV=local",
+            "app:latest",
+        ]);
+        let secrets = ["hunter2".to_owned()];
+
+        let rendered =
+            render(&run_args, None, Path::new("/work"), &[], &secrets).expect("renders definition");
+        let definition: Value = serde_json::from_str(&rendered.json).expect("valid json");
+
+        assert!(!rendered.json.contains("hunter2"), "{}", rendered.json);
+        assert_eq!(
+            definition["containerEnv"]["DB_PASSWORD"],
+            "${localEnv:DB_PASSWORD}"
+        );
+        assert_eq!(definition["containerEnv"]["APP_ENV"], "local");
+
+        let leaking = args(&["run", "--add-host", "hunter2:10.0.0.1", "app:latest"]);
+        assert!(render(&leaking, None, Path::new("/work"), &[], &secrets).is_err());
+    }
+
+    #[test]
+    fn post_create_commands_are_shell_quoted() {
+        let post_create = vec![
+            args(&["php", "artisan", "key:generate"]),
+            args(&["php", "artisan", "tinker", "--execute", "echo 'hi';"]),
+        ];
+
+        let rendered = render(
+            &args(&["run", "app:latest"]),
+            None,
+            Path::new("/work"),
+            &post_create,
+            &[],
+        )
+        .expect("renders definition");
+        let definition: Value = serde_json::from_str(&rendered.json).expect("valid json");
+
+        assert_eq!(
+            definition["postCreateCommand"],
+            "php artisan key:generate && php artisan tinker --execute 'echo '\"'\"'hi'\"'\"';'"
+        );
+    }
+}
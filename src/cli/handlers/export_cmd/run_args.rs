@@ -0,0 +1,80 @@
+//! cli handlers export cmd run args module.
+//!
+//! Splits the `docker run` args Helm builds into flag/value pairs, the image
+//! and its command, and checks values for secrets, for every export format.
+
+use anyhow::{Result, bail};
+
+/// A `docker run` arg list split at the image.
+pub(super) struct RunArgs<'a> {
+    /// Flags in order, each with its value.
+    pub(super) flags: Vec<(&'a str, &'a str)>,
+    pub(super) image: &'a str,
+    /// Args after the image, which override its default command.
+    pub(super) command: &'a [String],
+}
+
+impl<'a> RunArgs<'a> {
+    /// Parses args shaped like Helm's: every flag takes one value.
+    pub(super) fn parse(run_args: &'a [String]) -> Result<Self> {
+        let mut flags = Vec::new();
+        let mut index = 0;
+        while let Some(arg) = run_args.get(index) {
+            index += 1;
+            if arg == "run" || arg == "-d" {
+                continue;
+            }
+            if !arg.starts_with('-') {
+                return Ok(Self {
+                    flags,
+                    image: arg,
+                    command: run_args.get(index..).unwrap_or_default(),
+                });
+            }
+            let Some(value) = run_args.get(index) else {
+                bail!("run flag '{arg}' is missing its value");
+            };
+            index += 1;
+            flags.push((arg.as_str(), value.as_str()));
+        }
+        bail!("run args do not name an image")
+    }
+}
+
+/// Returns whether `value` contains any of the resolved `secrets`.
+pub(super) fn contains_secret(value: &str, secrets: &[String]) -> bool {
+    secrets
+        .iter()
+        .any(|secret| !secret.is_empty() && value.contains(secret.as_str()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RunArgs;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| (*value).to_owned()).collect()
+    }
+
+    #[test]
+    fn parse_splits_flags_image_and_command() {
+        let run_args = args(&[
+            "run",
+            "-d",
+            "--name",
+            "acme-db",
+            "-e",
+            "A=1",
+            "mysql:8.4",
+            "--verbose",
+        ]);
+
+        let run = RunArgs::parse(&run_args).expect("parses");
+
+        assert_eq!(run.flags, [("--name", "acme-db"), ("-e", "A=1")]);
+        assert_eq!(run.image, "mysql:8.4");
+        assert_eq!(run.command, ["--verbose"]);
+        assert!(RunArgs::parse(&args(&["run", "-e", "A=1"])).is_err());
+        assert!(RunArgs::parse(&args(&["run", "--name"])).is_err());
+    }
+}
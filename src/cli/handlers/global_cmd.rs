@@ -0,0 +1,316 @@
+//! cli handlers global cmd module.
+//!
+//! Host-wide views over Helm containers from every project: `ps --global`,
+//! `down --global --project`, and `prune --orphans`.
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::path::{Component, Path, PathBuf};
+
+use super::{log, serialize};
+use crate::docker::{self, ManagedContainer};
+use crate::output::{self, LogLevel, Persistence};
+
+#[derive(Serialize)]
+struct GlobalContainer<'a> {
+    #[serde(flatten)]
+    container: &'a ManagedContainer,
+    orphan: Option<&'static str>,
+}
+
+/// Handles `ps --global`.
+pub(crate) fn handle_ps_global(format: &str) -> Result<()> {
+    let containers = docker::list_managed_containers()?;
+    let rows = containers
+        .iter()
+        .map(|container| GlobalContainer {
+            container,
+            orphan: orphan_reason(container, project_dir_exists),
+        })
+        .collect::<Vec<_>>();
+
+    if format.eq_ignore_ascii_case("json") {
+        return serialize::print_json_pretty(&rows);
+    }
+
+    println!("PROJECT\tENV\tSERVICE\tCONTAINER\tSTATE");
+    for row in &rows {
+        let project = row
+            .container
+            .project_path
+            .as_ref()
+            .map_or_else(|| "-".to_owned(), |path| path.display().to_string());
+        let state = match row.orphan {
+            Some(reason) => format!("{} (orphan: {reason})", row.container.state),
+            None => row.container.state.clone(),
+        };
+        println!(
+            "{project}\t{}\t{}\t{}\t{state}",
+            row.container.runtime_env.as_deref().unwrap_or("-"),
+            row.container.service.as_deref().unwrap_or("-"),
+            row.container.name,
+        );
+    }
+    Ok(())
+}
+
+/// Handles `down --global --project <PATH>`.
+pub(crate) fn handle_down_global(project: &Path, timeout: u64, quiet: bool) -> Result<()> {
+    let root = project_root(project)?;
+    let project_id = docker::project_label_value(&root);
+    let containers = docker::list_managed_containers()?
+        .into_iter()
+        .filter(|container| container.project.as_deref() == Some(project_id.as_str()))
+        .collect::<Vec<_>>();
+
+    if containers.is_empty() {
+        log::info_if_not_quiet(
+            quiet,
+            "down",
+            &format!("No Helm containers found for {}", root.display()),
+        );
+        return Ok(());
+    }
+
+    for container in containers {
+        log::info_if_not_quiet(
+            quiet,
+            container.service.as_deref().unwrap_or(&container.name),
+            &format!("Removing container {}", container.name),
+        );
+        docker::remove_managed_container(&container.name, timeout)
+            .with_context(|| format!("failed to down container {}", container.name))?;
+    }
+    docker::remove_project_pods(&project_id)?;
+    docker::remove_project_networks(&project_id)
+}
+
+/// Handles `prune --orphans`.
+///
+/// Every orphan is listed before anything is removed; running ones are kept
+/// unless `force` is set. On a remote engine, project directories live on
+/// other hosts, so only stale test runtimes count as orphans.
+pub(crate) fn handle_prune_orphans(force: bool, quiet: bool) -> Result<()> {
+    let remote = docker::remote_engine_address();
+    if let Some(address) = &remote {
+        log::info_if_not_quiet(
+            quiet,
+            "prune",
+            &format!(
+                "Engine at {address} is remote; keeping containers whose project directory is not on this host"
+            ),
+        );
+    }
+    let orphans = docker::list_managed_containers()?
+        .into_iter()
+        .filter_map(|container| {
+            orphan_reason(&container, project_dir_exists).map(|reason| (container, reason))
+        })
+        .collect::<Vec<_>>();
+
+    if orphans.is_empty() {
+        log::info_if_not_quiet(quiet, "prune", "No orphaned Helm containers found");
+        return Ok(());
+    }
+
+    for (container, reason) in &orphans {
+        log::info_if_not_quiet(
+            quiet,
+            "prune",
+            &format!(
+                "Orphaned container {} ({reason}, {})",
+                container.name, container.state
+            ),
+        );
+    }
+    let (orphans, running) = split_running(orphans, force);
+    for (container, _) in &running {
+        output::event(
+            "prune",
+            LogLevel::Warn,
+            &format!(
+                "Keeping running container {}; pass --force to remove it",
+                container.name
+            ),
+            Persistence::Persistent,
+        );
+    }
+
+    for (container, reason) in orphans {
+        log::info_if_not_quiet(
+            quiet,
+            "prune",
+            &format!("Removing orphaned container {}", container.name),
+        );
+        docker::remove_managed_container(&container.name, 0)
+            .with_context(|| format!("failed to prune container {}", container.name))?;
+        if let Some(project_id) = container
+            .project
+            .as_deref()
+            .filter(|_| reason == "project directory removed")
+        {
+            docker::remove_project_pods(project_id)?;
+            docker::remove_project_networks(project_id)?;
+        }
+    }
+    Ok(())
+}
+
+/// Resolves `--project` to the absolute root its containers are labelled
+/// with.
+///
+/// A deleted project cannot be canonicalized, so its path is made absolute
+/// and `.`/`..` are folded lexically instead.
+fn project_root(project: &Path) -> Result<PathBuf> {
+    if let Ok(root) = std::fs::canonicalize(project) {
+        return Ok(root);
+    }
+    let absolute = std::path::absolute(project)
+        .with_context(|| format!("failed to resolve {}", project.display()))?;
+    let mut root = PathBuf::new();
+    for component in absolute.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                root.pop();
+            }
+            Component::Prefix(_) | Component::RootDir | Component::Normal(_) => {
+                root.push(component);
+            }
+        }
+    }
+    Ok(root)
+}
+
+/// Returns whether a labelled project directory still exists.
+///
+/// Project directories of a remote engine live on other hosts, so they are
+/// always treated as present.
+fn project_dir_exists(path: &Path) -> bool {
+    docker::remote_engine_address().is_some() || path.is_dir()
+}
+
+/// Separates running orphans from removable ones unless `force` is set.
+fn split_running<T>(
+    orphans: Vec<(ManagedContainer, T)>,
+    force: bool,
+) -> (Vec<(ManagedContainer, T)>, Vec<(ManagedContainer, T)>) {
+    orphans
+        .into_iter()
+        .partition(|(container, _)| force || container.state != "running")
+}
+
+/// Explains why a container no longer belongs to a live project.
+///
+/// Containers created before project labels existed are never orphans.
+fn orphan_reason(
+    container: &ManagedContainer,
+    dir_exists: impl Fn(&Path) -> bool,
+) -> Option<&'static str> {
+    if container
+        .project_path
+        .as_deref()
+        .is_some_and(|path| !dir_exists(path))
+    {
+        return Some("project directory removed");
+    }
+
+    let stale_test_runtime = container
+        .runtime_env
+        .as_deref()
+        .and_then(|env| env.strip_prefix("testing-"))
+        .is_some_and(|run_id| {
+            !run_id.is_empty() && run_id.chars().all(|ch| ch.is_ascii_hexdigit())
+        });
+    if stale_test_runtime && container.state != "running" {
+        return Some("stale test runtime");
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{orphan_reason, project_root, split_running};
+    use crate::docker::ManagedContainer;
+    use std::path::{Path, PathBuf};
+
+    fn container(path: Option<&str>, runtime_env: Option<&str>, state: &str) -> ManagedContainer {
+        ManagedContainer {
+            name: "acme-db".to_owned(),
+            state: state.to_owned(),
+            service: Some("db".to_owned()),
+            kind: Some("database".to_owned()),
+            project: path.map(|_| "abc".to_owned()),
+            project_path: path.map(PathBuf::from),
+            runtime_env: runtime_env.map(str::to_owned),
+        }
+    }
+
+    fn only_live(path: &Path) -> bool {
+        path == Path::new("/work/live")
+    }
+
+    #[test]
+    fn containers_of_removed_projects_are_orphans() {
+        let removed = container(Some("/work/gone"), Some("default"), "running");
+        assert_eq!(
+            orphan_reason(&removed, only_live),
+            Some("project directory removed")
+        );
+        let live = container(Some("/work/live"), Some("default"), "exited");
+        assert_eq!(orphan_reason(&live, only_live), None);
+    }
+
+    #[test]
+    fn stopped_test_runtimes_are_orphans_but_running_ones_are_kept() {
+        let stopped = container(Some("/work/live"), Some("testing-2f1a9c4d"), "exited");
+        assert_eq!(
+            orphan_reason(&stopped, only_live),
+            Some("stale test runtime")
+        );
+        let running = container(Some("/work/live"), Some("testing-2f1a9c4d"), "running");
+        assert_eq!(orphan_reason(&running, only_live), None);
+        let named = container(Some("/work/live"), Some("testing"), "exited");
+        assert_eq!(orphan_reason(&named, only_live), None);
+    }
+
+    #[test]
+    fn deleted_relative_projects_resolve_to_their_absolute_root() -> anyhow::Result<()> {
+        let root = project_root(Path::new("helm-deleted-project/../helm-gone/./app"))?;
+        assert_eq!(root, std::env::current_dir()?.join("helm-gone/app"));
+        Ok(())
+    }
+
+    #[test]
+    fn running_orphans_are_kept_without_force() {
+        let orphans = || {
+            vec![
+                (container(Some("/work/gone"), None, "running"), ()),
+                (container(Some("/work/gone"), None, "exited"), ()),
+            ]
+        };
+
+        let (removed, kept) = split_running(orphans(), false);
+        assert_eq!(removed.len(), 1);
+        assert_eq!(
+            removed.first().map(|(c, ())| c.state.as_str()),
+            Some("exited")
+        );
+        assert_eq!(
+            kept.first().map(|(c, ())| c.state.as_str()),
+            Some("running")
+        );
+
+        let (removed, kept) = split_running(orphans(), true);
+        assert_eq!(removed.len(), 2);
+        assert!(kept.is_empty());
+    }
+
+    #[test]
+    fn unlabelled_containers_are_never_orphans() {
+        assert_eq!(
+            orphan_reason(&container(None, None, "exited"), only_live),
+            None
+        );
+    }
+}
@@ -142,6 +142,8 @@ mod tests {
             domain_strategy: None,
             service: vec![crate::config::preset_preview("postgres")?],
             swarm: Vec::new(),
+            profile: std::collections::BTreeMap::new(),
+            preset: std::collections::BTreeMap::new(),
         };
 
         handle_open(
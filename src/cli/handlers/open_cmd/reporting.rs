@@ -139,6 +139,7 @@ mod tests {
             hook: Vec::new(),
             health_path: None,
             health_statuses: None,
+            health: None,
             localhost_tls,
             octane: false,
             octane_workers: None,
@@ -148,7 +149,21 @@ mod tests {
             env_mapping: None,
             javascript: None,
             container_name: container_name.map(ToOwned::to_owned),
+            memory: None,
+            cpus: None,
+            pids_limit: None,
+            shm_size: None,
+            ulimits: None,
+            tmpfs: None,
+            platform: None,
+            user: None,
+            cap_add: None,
+            restart: None,
+            build: None,
             resolved_container_name: None,
+            resolved_project_root: None,
+            resolved_runtime_env: None,
+            secret_fields: Vec::new(),
         }
     }
 
@@ -163,6 +178,8 @@ mod tests {
                 service("app", Kind::App, Driver::Frankenphp, None, true),
             ],
             swarm: Vec::new(),
+            profile: std::collections::BTreeMap::new(),
+            preset: std::collections::BTreeMap::new(),
         }
     }
 
@@ -255,6 +272,8 @@ mod tests {
             domain_strategy: None,
             service: Vec::new(),
             swarm: Vec::new(),
+            profile: std::collections::BTreeMap::new(),
+            preset: std::collections::BTreeMap::new(),
         };
         assert_eq!(
             service_container_status(&service(
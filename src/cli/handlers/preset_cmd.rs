@@ -8,14 +8,15 @@ use crate::cli::handlers::serialize;
 use crate::config;
 
 /// Handles the `preset list` CLI command.
-pub(crate) fn handle_preset_list() {
-    for name in config::preset_names() {
+pub(crate) fn handle_preset_list(config: &config::Config) -> Result<()> {
+    for name in config::preset_names_with(config)? {
         println!("{name}");
     }
+    Ok(())
 }
 
 /// Handles the `preset show` CLI command.
-pub(crate) fn handle_preset_show(name: &str, format: &str) -> Result<()> {
-    let service = config::preset_preview(name)?;
+pub(crate) fn handle_preset_show(config: &config::Config, name: &str, format: &str) -> Result<()> {
+    let service = config::preset_preview_with(config, name)?;
     serialize::print_pretty(&service, format)
 }
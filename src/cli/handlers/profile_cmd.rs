@@ -8,8 +8,8 @@ use crate::cli::handlers::serialize;
 use crate::{cli, config};
 
 /// Handles the `profile list` CLI command.
-pub(crate) fn handle_profile_list() {
-    for name in cli::support::profile_names() {
+pub(crate) fn handle_profile_list(config: &config::Config) {
+    for name in cli::support::profile_names(config) {
         println!("{name}");
     }
 }
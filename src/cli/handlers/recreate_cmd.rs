@@ -5,7 +5,8 @@
 use anyhow::Result;
 use std::path::Path;
 
-use super::service_scope::for_each_service_in_scope_with_info;
+use super::log;
+use super::service_scope::selected_services_in_scope;
 use crate::{cli, config};
 
 mod random_ports;
@@ -61,16 +62,22 @@ pub(crate) fn handle_recreate(
         );
     }
 
-    for_each_service_in_scope_with_info(
+    let selected = selected_services_in_scope(
         config,
         options.service,
         options.services,
         options.kind,
         options.profile,
+    )?;
+    cli::support::run_services_in_dependency_order(
         options.parallel,
-        options.quiet,
-        "Recreating service",
-        |svc| cli::support::recreate_service(svc, &start_context, options.healthy, options.timeout),
+        &selected,
+        |svc| svc,
+        options.timeout,
+        |svc| {
+            log::info_if_not_quiet(options.quiet, &svc.name, "Recreating service");
+            cli::support::recreate_service(svc, &start_context, options.healthy, options.timeout)
+        },
     )?;
 
     Ok(())
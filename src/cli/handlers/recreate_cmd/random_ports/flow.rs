@@ -25,7 +25,7 @@ where
     cli::support::run_random_ports_flow(
         planned,
         parallel,
-        |runtime| runtime.kind == config::Kind::App,
+        recreate_context.timeout,
         |runtime| runtime_runner(runtime, &recreate_context),
         |runtime| runtime,
         config,
@@ -77,7 +77,9 @@ fn determine_relabel_action(service: &config::ServiceConfig) -> Result<RelabelAc
 
     let managed = docker::inspect_label(&container_name, docker::LABEL_MANAGED)
         .is_some_and(|value| value == docker::VALUE_MANAGED_TRUE);
-    if managed {
+    let owned = docker::inspect_label(&container_name, docker::LABEL_PROJECT)
+        .is_some_and(|value| !value.is_empty() && value != "<no value>");
+    if managed && owned {
         return Ok(RelabelAction::Skip(format!(
             "Skipped relabel because container {container_name} already has Helm labels"
         )));
@@ -118,6 +118,7 @@ mod tests {
             hook: Vec::new(),
             health_path: None,
             health_statuses: None,
+            health: None,
             localhost_tls: true,
             octane: false,
             octane_workers: None,
@@ -127,7 +128,21 @@ mod tests {
             env_mapping: None,
             javascript: None,
             container_name: Some(name.to_owned()),
+            memory: None,
+            cpus: None,
+            pids_limit: None,
+            shm_size: None,
+            ulimits: None,
+            tmpfs: None,
+            platform: None,
+            user: None,
+            cap_add: None,
+            restart: None,
+            build: None,
             resolved_container_name: Some(name.to_owned()),
+            resolved_project_root: None,
+            resolved_runtime_env: None,
+            secret_fields: Vec::new(),
         }
     }
 
@@ -171,6 +186,8 @@ mod tests {
                 service("cache", Kind::Database, Some("redis.helm")),
             ],
             swarm: Vec::new(),
+            profile: std::collections::BTreeMap::new(),
+            preset: std::collections::BTreeMap::new(),
         }
     }
 
@@ -28,16 +28,51 @@ fn render_status_json(services: &[&config::ServiceConfig]) -> Result<()> {
 }
 
 fn build_status_json(service: &config::ServiceConfig) -> serde_json::Value {
-    let status = service
-        .container_name()
-        .ok()
-        .and_then(|name| docker::inspect_status(&name));
-    serde_json::json!({
+    let container_name = service.container_name().ok();
+    let status = container_name.as_deref().and_then(docker::inspect_status);
+    let health = container_name
+        .as_deref()
+        .and_then(docker::inspect_health_status);
+    let restart_state = container_name
+        .as_deref()
+        .and_then(docker::inspect_restart_state);
+    let mut entry = serde_json::json!({
         "name": service.name,
         "kind": cli::support::kind_name(service.kind),
         "driver": cli::support::driver_name(service.driver),
         "image": service.image,
         "port": service.port,
         "status": status.unwrap_or_else(|| "not created".to_owned()),
-    })
+    });
+    if let Some(fields) = entry.as_object_mut() {
+        if let Some(health) = health {
+            fields.insert("health".to_owned(), serde_json::json!(health));
+        }
+        if let Some(restart) = &service.restart {
+            fields.insert("restart_policy".to_owned(), serde_json::json!(restart));
+        }
+        if let Some(state) = restart_state {
+            fields.insert(
+                "restarts".to_owned(),
+                serde_json::json!(state.restart_count),
+            );
+            fields.insert(
+                "last_exit_code".to_owned(),
+                serde_json::json!(state.exit_code),
+            );
+            fields.insert(
+                "crash_looping".to_owned(),
+                serde_json::json!(state.is_crash_looping()),
+            );
+        }
+    }
+    if let (Some((pod, pod_status)), Some(fields)) =
+        (docker::pod_status(service), entry.as_object_mut())
+    {
+        fields.insert(
+            "pod".to_owned(),
+            serde_json::json!({ "name": pod, "status": pod_status }),
+        );
+    }
+    entry
 }
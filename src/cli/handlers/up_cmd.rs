@@ -17,7 +17,7 @@ mod random_ports;
 mod startup;
 
 use data_seed::apply_data_seeds;
-use options::resolve_execution_flags;
+use options::{apply_pod_mode, resolve_execution_flags};
 use post_actions::{PostUpActionsOptions, run_post_up_actions};
 use preflight::{PrepareUpContextOptions, PreparedUpContext, prepare_up_context};
 use random_ports::{RunRandomPortsUpOptions, run_random_ports_up};
@@ -71,12 +71,15 @@ pub(crate) fn handle_up(config: &mut config::Config, options: HandleUpOptions<'_
         },
     )?;
 
-    let execution_flags = resolve_execution_flags(
-        options.wait,
-        options.no_wait,
+    let execution_flags = apply_pod_mode(
+        resolve_execution_flags(
+            options.wait,
+            options.no_wait,
+            options.publish_all,
+            options.no_publish_all,
+        ),
         options.publish_all,
-        options.no_publish_all,
-    );
+    )?;
     let use_wait = execution_flags.use_wait;
     let use_publish_all = execution_flags.use_publish_all;
     if use_publish_all {
@@ -115,6 +118,7 @@ pub(crate) fn handle_up(config: &mut config::Config, options: HandleUpOptions<'_
                 timeout: options.wait_timeout,
                 pull_policy: options.pull_policy,
                 recreate: options.force_recreate,
+                parallel: options.parallel,
                 quiet: options.quiet,
                 workspace_root: &workspace_root,
                 project_dependency_env: &project_dependency_env,
@@ -2,6 +2,8 @@
 
 use anyhow::Result;
 
+use crate::docker;
+
 pub(super) struct ExecutionFlags {
     pub(super) use_wait: bool,
     pub(super) use_publish_all: bool,
@@ -37,6 +39,24 @@ pub(super) fn resolve_execution_flags(
     }
 }
 
+/// Turns the publish-all default off in `podman_pod` mode, where pod ports
+/// are fixed when the pod is created; only an explicit `--publish-all` is
+/// rejected.
+pub(super) fn apply_pod_mode(flags: ExecutionFlags, publish_all: bool) -> Result<ExecutionFlags> {
+    if !docker::pod_mode() {
+        return Ok(flags);
+    }
+    if publish_all {
+        anyhow::bail!(
+            "--publish-all is not supported with podman_pod; pod ports are fixed when the pod is created"
+        );
+    }
+    Ok(ExecutionFlags {
+        use_publish_all: false,
+        ..flags
+    })
+}
+
 /// Returns the default value for publish all enabled.
 const fn default_publish_all_enabled() -> bool {
     true
@@ -50,9 +70,10 @@ const fn default_wait_enabled() -> bool {
 #[cfg(test)]
 mod tests {
     use super::{
-        default_publish_all_enabled, default_wait_enabled, resolve_execution_flags,
+        apply_pod_mode, default_publish_all_enabled, default_wait_enabled, resolve_execution_flags,
         validate_repro_flags,
     };
+    use crate::docker;
 
     #[test]
     fn validate_repro_flags_rejects_conflicting_mode() {
@@ -81,4 +102,17 @@ mod tests {
         let disabled = resolve_execution_flags(false, false, true, true);
         assert!(!disabled.use_publish_all);
     }
+
+    #[test]
+    fn pod_mode_drops_the_publish_all_default() {
+        docker::with_podman_pod(|| {
+            let defaults = resolve_execution_flags(false, false, false, false);
+            let flags = apply_pod_mode(defaults, false).expect("default up runs in pod mode");
+            assert!(!flags.use_publish_all);
+            assert!(flags.use_wait);
+
+            let explicit = resolve_execution_flags(false, false, true, false);
+            assert!(apply_pod_mode(explicit, true).is_err());
+        });
+    }
 }
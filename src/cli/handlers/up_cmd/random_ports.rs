@@ -76,6 +76,7 @@ pub(super) fn run_random_ports_up(
         &planned,
         config,
         options.parallel,
+        options.timeout,
         env_path.as_deref(),
         options.save_ports,
         options.quiet,
@@ -9,6 +9,7 @@ pub(super) fn run_up_flow<F>(
     planned: &[(config::ServiceConfig, bool)],
     config: &mut config::Config,
     parallel: usize,
+    wait_timeout: u64,
     env_path: Option<&Path>,
     save_ports: bool,
     quiet: bool,
@@ -22,7 +23,7 @@ where
     cli::support::run_random_ports_flow(
         planned,
         parallel,
-        |(runtime, _)| runtime.kind == config::Kind::App,
+        wait_timeout,
         |(runtime, uses_random_port)| runtime_runner(runtime, *uses_random_port),
         |(runtime, _)| runtime,
         config,
@@ -5,6 +5,7 @@
 use anyhow::Result;
 use std::collections::HashMap;
 use std::path::Path;
+use std::sync::Mutex;
 
 use crate::cli::handlers::log;
 use crate::{cli, config, docker, env};
@@ -17,6 +18,7 @@ pub(super) struct RunStandardUpOptions<'a> {
     pub(super) timeout: u64,
     pub(super) pull_policy: docker::PullPolicy,
     pub(super) recreate: bool,
+    pub(super) parallel: usize,
     pub(super) quiet: bool,
     pub(super) workspace_root: &'a Path,
     pub(super) project_dependency_env: &'a HashMap<String, String>,
@@ -31,20 +33,27 @@ pub(super) fn run_standard_up(
         cli::support::resolve_up_services(config, options.service, options.kind, options.profile)?;
     let app_env = cli::support::runtime_app_env(config, options.project_dependency_env);
     let start_context = cli::support::ServiceStartContext::new(options.workspace_root, &app_env);
-    for svc in startup_services {
-        start_runtime_service(
-            svc,
-            &start_context,
-            options.recreate,
-            options.pull_policy,
-            options.healthy,
-            options.timeout,
-            options.env_path,
-            options.quiet,
-        )?;
-    }
-
-    Ok(())
+    // Services start concurrently, but each `.env` rewrite must see the
+    // previous one.
+    let env_lock = Mutex::new(options.env_path);
+    cli::support::run_services_in_dependency_order(
+        options.parallel,
+        &startup_services,
+        |svc| svc,
+        options.timeout,
+        |svc| {
+            start_runtime_service(
+                svc,
+                &start_context,
+                options.recreate,
+                options.pull_policy,
+                options.healthy,
+                options.timeout,
+                &env_lock,
+                options.quiet,
+            )
+        },
+    )
 }
 
 fn start_runtime_service(
@@ -54,7 +63,7 @@ fn start_runtime_service(
     pull_policy: docker::PullPolicy,
     wait_healthy: bool,
     health_timeout_secs: u64,
-    env_path: Option<&Path>,
+    env_path: &Mutex<Option<&Path>>,
     quiet: bool,
 ) -> Result<()> {
     log::info_if_not_quiet(quiet, &service.name, "Starting service");
@@ -67,7 +76,10 @@ fn start_runtime_service(
         health_timeout_secs,
         true,
     )?;
-    if let Some(path) = env_path {
+    let env_path = env_path
+        .lock()
+        .map_err(|_| anyhow::anyhow!("failed to lock env file for {}", service.name))?;
+    if let Some(path) = *env_path {
         env::update_env(service, path, true)?;
     }
     Ok(())
@@ -59,7 +59,11 @@ fn update_service(
         "Updating service",
         Persistence::Persistent,
     );
-    docker::pull(service)?;
+    if service.build.is_none() {
+        docker::pull(service)?;
+    } else if build {
+        docker::rebuild_service_image(service)?;
+    }
     cli::support::start_service(
         service,
         start_context,
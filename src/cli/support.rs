@@ -56,7 +56,7 @@ pub(crate) use ensure_sql_service::ensure_sql_service;
 pub(crate) use env_output_path::env_output_path;
 pub(crate) use filter_services::filter_services;
 pub(crate) use for_each_service::{
-    for_each_service, run_selected_services, run_services_with_app_last,
+    for_each_service, run_selected_services, run_services_in_dependency_order,
 };
 pub(crate) use matches_filter::matches_filter;
 pub(crate) use open_in_browser::open_in_browser;
@@ -95,7 +95,7 @@ pub(crate) use selected_services::{selected_services, selected_services_with_fil
 pub(crate) use serve_health::{build_health_url, health_status_accepted};
 pub(crate) use service_labels::{driver_name, kind_name};
 pub(crate) use service_scope::ServiceScope;
-pub(crate) use start_service::{ServiceStartContext, start_service};
+pub(crate) use start_service::{ServiceStartContext, start_service, wait_for_dependency_condition};
 pub(crate) use tail_access_logs::tail_access_logs;
 pub(crate) use workspace_root::workspace_root;
 pub(crate) use workspace_with_project_deps::{
@@ -4,6 +4,7 @@
 
 use anyhow::Result;
 use rayon::prelude::*;
+use std::collections::{HashMap, HashSet};
 
 use crate::config;
 
@@ -24,40 +25,109 @@ pub(crate) fn for_each_service(
     run_selected_services(&selected, parallel, |svc| f(*svc))
 }
 
-pub(crate) fn run_services_with_app_last<S, IsApp, Run>(
+/// Starts services through the dependency scheduler.
+///
+/// Each service starts as soon as the services it depends on have reached
+/// their `depends_on` condition, with at most `parallel` starts in flight.
+/// After `run` starts a service, the scheduler waits (up to `wait_timeout`
+/// seconds) for the strictest condition any dependent in the batch asks for.
+/// App services without `depends_on` keep the historical "apps last"
+/// behaviour by waiting for every non-app service in the batch that does not
+/// itself lead back to an app.
+pub(crate) fn run_services_in_dependency_order<S, FService, Run>(
     parallel: usize,
     services: &[S],
-    is_app: IsApp,
+    service_of: FService,
+    wait_timeout: u64,
     run: Run,
 ) -> Result<()>
 where
     S: Sync,
-    IsApp: Fn(&S) -> bool + Sync,
-    Run: Fn(&S) -> Result<()> + Sync + Send,
+    FService: Fn(&S) -> &config::ServiceConfig + Sync,
+    Run: Fn(&S) -> Result<()> + Sync,
 {
-    crate::parallel::validate_parallelism(parallel)?;
+    let batch: Vec<&config::ServiceConfig> = services.iter().map(&service_of).collect();
+    let infrastructure = infrastructure_names(&batch);
+    let required = required_conditions(&batch);
 
-    if parallel <= 1 {
-        for service in services {
-            run(service)?;
+    crate::parallel::run_dependency_graph(
+        parallel,
+        services,
+        |item| service_of(item).name.as_str(),
+        |item| {
+            let service = service_of(item);
+            match &service.depends_on {
+                Some(_) => service.dependency_names(),
+                None if service.kind == config::Kind::App => infrastructure.clone(),
+                None => Vec::new(),
+            }
+        },
+        |item| {
+            run(item)?;
+            let service = service_of(item);
+            match required.get(service.name.as_str()) {
+                Some(condition) => {
+                    super::wait_for_dependency_condition(service, *condition, wait_timeout)
+                }
+                None => Ok(()),
+            }
+        },
+    )
+}
+
+/// Strictest condition each service must reach for its dependents in the batch.
+fn required_conditions<'a>(
+    batch: &[&'a config::ServiceConfig],
+) -> HashMap<&'a str, config::DependencyCondition> {
+    let mut required: HashMap<&str, config::DependencyCondition> = HashMap::new();
+    for service in batch {
+        for dependency in service.depends_on.iter().flatten() {
+            let Some(target) = batch
+                .iter()
+                .find(|candidate| candidate.name == dependency.service)
+            else {
+                continue;
+            };
+            let entry = required
+                .entry(target.name.as_str())
+                .or_insert(config::DependencyCondition::Started);
+            *entry = (*entry).max(dependency.condition);
         }
-        return Ok(());
     }
+    required
+}
 
-    let (app_services, non_app_services): (Vec<_>, Vec<_>) =
-        services.iter().partition(|service| is_app(service));
-
-    install_in_pool(parallel, || {
-        non_app_services
-            .par_iter()
-            .try_for_each(|service| run(service))
-    })?;
-
-    for service in app_services {
-        run(service)?;
-    }
+/// Non-app services whose `depends_on` closure within the batch has no app.
+fn infrastructure_names(batch: &[&config::ServiceConfig]) -> Vec<String> {
+    let by_name: HashMap<&str, &config::ServiceConfig> = batch
+        .iter()
+        .map(|service| (service.name.as_str(), *service))
+        .collect();
 
-    Ok(())
+    batch
+        .iter()
+        .filter(|service| {
+            let mut stack = vec![**service];
+            let mut seen = HashSet::new();
+            while let Some(current) = stack.pop() {
+                if current.kind == config::Kind::App {
+                    return false;
+                }
+                if !seen.insert(current.name.as_str()) {
+                    continue;
+                }
+                stack.extend(
+                    current
+                        .depends_on
+                        .iter()
+                        .flatten()
+                        .filter_map(|dependency| by_name.get(dependency.service.as_str()).copied()),
+                );
+            }
+            true
+        })
+        .map(|service| service.name.clone())
+        .collect()
 }
 
 fn install_in_pool<T>(parallel: usize, run: impl FnOnce() -> Result<T> + Send) -> Result<T>
@@ -94,39 +164,157 @@ where
 
 #[cfg(test)]
 mod tests {
-    use super::run_services_with_app_last;
-    use std::sync::{Arc, Mutex};
+    use super::{required_conditions, run_services_in_dependency_order};
+    use crate::config::{self, DependencyCondition, ServiceDependency};
+    use std::sync::Mutex;
 
-    #[test]
-    fn run_services_with_app_last_rejects_zero_parallelism() {
-        let services = vec!["app".to_owned(), "cache".to_owned()];
-        let result =
-            run_services_with_app_last(0, &services, |service| service == "app", |_| Ok(()));
-        assert!(result.is_err());
+    /// Parses `name` or `name:condition`.
+    fn parse_dependency(item: &str) -> ServiceDependency {
+        let (name, condition) = item.split_once(':').unwrap_or((item, "started"));
+        let condition = match condition {
+            "healthy" => DependencyCondition::Healthy,
+            "completed" => DependencyCondition::Completed,
+            _ => DependencyCondition::Started,
+        };
+        ServiceDependency::new(name, condition)
     }
 
-    #[test]
-    fn run_services_with_app_last_runs_app_services_last() {
-        let services = vec!["cache".to_owned(), "app".to_owned(), "worker".to_owned()];
-        let run_order = Arc::new(Mutex::new(Vec::new()));
-
-        let run = {
-            let run_order = Arc::clone(&run_order);
-            move |service: &String| {
-                let mut run_order = run_order
+    fn service(
+        name: &str,
+        kind: config::Kind,
+        depends_on: Option<&[&str]>,
+    ) -> config::ServiceConfig {
+        config::ServiceConfig {
+            depends_on: depends_on
+                .map(|items| items.iter().map(|item| parse_dependency(item)).collect()),
+            ..config::ServiceConfig::fixture(name, kind, config::Driver::Redis, "redis:7")
+        }
+    }
+
+    fn run_order(
+        parallel: usize,
+        services: &[config::ServiceConfig],
+    ) -> anyhow::Result<Vec<String>> {
+        let order = Mutex::new(Vec::new());
+        run_services_in_dependency_order(
+            parallel,
+            services,
+            |service| service,
+            30,
+            |service| {
+                order
                     .lock()
-                    .map_err(|_| anyhow::anyhow!("failed to lock run order"))?;
-                run_order.push(service.to_owned());
+                    .map_err(|_| anyhow::anyhow!("failed to lock run order"))?
+                    .push(service.name.clone());
                 Ok(())
-            }
-        };
+            },
+        )?;
+        order
+            .into_inner()
+            .map_err(|_| anyhow::anyhow!("failed to read run order"))
+    }
+
+    #[test]
+    fn run_services_in_dependency_order_rejects_zero_parallelism() {
+        let services = vec![service("app", config::Kind::App, None)];
+        assert!(run_order(0, &services).is_err());
+    }
+
+    #[test]
+    fn run_services_in_dependency_order_runs_apps_without_depends_on_last() -> anyhow::Result<()> {
+        let services = vec![
+            service("app", config::Kind::App, None),
+            service("cache", config::Kind::Cache, None),
+            service("db", config::Kind::Database, None),
+        ];
+        let order = run_order(2, &services)?;
+        assert_eq!(order.last().map(String::as_str), Some("app"));
+        Ok(())
+    }
+
+    #[test]
+    fn run_services_in_dependency_order_lets_infrastructure_depend_on_apps() -> anyhow::Result<()> {
+        let services = vec![
+            service("app", config::Kind::App, None),
+            service("db", config::Kind::Database, None),
+            service("gateway", config::Kind::Cache, Some(&["app"])),
+        ];
+        assert_eq!(run_order(1, &services)?, vec!["db", "app", "gateway"]);
+        Ok(())
+    }
+
+    #[test]
+    fn run_services_in_dependency_order_finds_apps_past_shared_dependencies() -> anyhow::Result<()>
+    {
+        let services = vec![
+            service("app", config::Kind::App, None),
+            service("redis", config::Kind::Cache, None),
+            service("cache1", config::Kind::Cache, Some(&["redis"])),
+            service("cache2", config::Kind::Cache, Some(&["redis"])),
+            service(
+                "sidecar",
+                config::Kind::Cache,
+                Some(&["app", "cache1", "cache2"]),
+            ),
+        ];
+        let order = run_order(1, &services)?;
+        let position = |name: &str| order.iter().position(|entry| entry == name);
+        assert!(position("app") < position("sidecar"), "{order:?}");
+        Ok(())
+    }
+
+    #[test]
+    fn run_services_in_dependency_order_honours_explicit_depends_on() -> anyhow::Result<()> {
+        let services = vec![
+            service("db", config::Kind::Database, None),
+            service("cache", config::Kind::Cache, None),
+            service("app", config::Kind::App, Some(&["db"])),
+        ];
+        assert_eq!(run_order(1, &services)?, vec!["db", "cache", "app"]);
+        Ok(())
+    }
 
-        run_services_with_app_last(2, &services, |service| service == "app", run)
-            .expect("run services");
+    #[test]
+    fn required_conditions_keeps_the_strictest_condition_per_dependency() {
+        let services = vec![
+            service("db", config::Kind::Database, None),
+            service("migrate", config::Kind::App, Some(&["db:healthy"])),
+            service(
+                "horizon",
+                config::Kind::App,
+                Some(&["db", "migrate:completed"]),
+            ),
+            service(
+                "scheduler",
+                config::Kind::App,
+                Some(&["db:healthy", "missing:healthy"]),
+            ),
+        ];
+        let batch: Vec<&config::ServiceConfig> = services.iter().collect();
 
-        let run_order = run_order.lock().expect("run order lock").clone();
-        assert!(run_order.iter().any(|service| service == "cache"));
-        assert!(run_order.iter().any(|service| service == "worker"));
-        assert_eq!(run_order.last(), Some(&"app".to_owned()));
+        let required = required_conditions(&batch);
+
+        assert_eq!(required.get("db"), Some(&DependencyCondition::Healthy));
+        assert_eq!(
+            required.get("migrate"),
+            Some(&DependencyCondition::Completed)
+        );
+        assert_eq!(required.get("horizon"), None);
+        assert_eq!(required.get("missing"), None);
+    }
+
+    #[test]
+    fn run_services_in_dependency_order_waits_for_conditions_in_dry_run() -> anyhow::Result<()> {
+        let mut services = vec![
+            service("db", config::Kind::Database, None),
+            service("migrate", config::Kind::Cache, Some(&["db:healthy"])),
+            service("horizon", config::Kind::App, Some(&["migrate:completed"])),
+        ];
+        for service in &mut services {
+            service.resolved_container_name = Some(format!("helm-{}", service.name));
+        }
+        let order = crate::docker::with_dry_run_lock(|| run_order(2, &services))?;
+        assert_eq!(order, vec!["db", "migrate", "horizon"]);
+        Ok(())
     }
 }
@@ -21,16 +21,21 @@ pub(crate) fn persist_random_runtime_ports<'a, I>(
     quiet: bool,
     config_path: Option<&Path>,
     project_root: Option<&Path>,
-) -> Result<Option<std::path::PathBuf>>
+) -> Result<Option<Vec<std::path::PathBuf>>>
 where
     I: IntoIterator<Item = &'a config::ServiceConfig>,
 {
+    let mut edits = Vec::new();
     for service in services {
         if let Some(path) = env_path {
             env::update_env(service, path, true)?;
         }
         if save_ports {
             config::update_service_port(config_data, &service.name, service.port)?;
+            edits.push(config::ConfigEdit::set(
+                format!("service.{}.port", service.name),
+                i64::from(service.port),
+            ));
         }
     }
 
@@ -38,17 +43,20 @@ where
         return Ok(None);
     }
 
-    let path = config::save_config_with(
-        config_data,
-        config::SaveConfigPathOptions::new(config_path, project_root),
+    let paths = config::edit_config_with(
+        config::EditConfigPathOptions::new(config_path, project_root),
+        &edits,
     )?;
     if !quiet {
         output::event(
             command,
             LogLevel::Success,
-            &format!("Persisted random ports to {}", path.display()),
+            &format!(
+                "Persisted random ports to {}",
+                config::display_paths(&paths)
+            ),
             Persistence::Persistent,
         );
     }
-    Ok(Some(path))
+    Ok(Some(paths))
 }
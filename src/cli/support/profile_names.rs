@@ -2,19 +2,61 @@
 //!
 //! Contains cli support profile names logic used by Helm command workflows.
 
-pub(crate) fn profile_names() -> Vec<&'static str> {
-    vec!["full", "all", "infra", "data", "app", "web", "api"]
+use crate::config;
+
+/// Returns built-in profile names followed by configured `[profile.<name>]` entries.
+pub(crate) fn profile_names(config: &config::Config) -> Vec<String> {
+    let mut names: Vec<String> = config::BUILTIN_PROFILE_NAMES
+        .iter()
+        .map(|name| (*name).to_owned())
+        .collect();
+    for name in config.profile.keys() {
+        if !names.contains(name) {
+            names.push(name.clone());
+        }
+    }
+    names
 }
 
 #[cfg(test)]
 mod tests {
     use super::profile_names;
+    use crate::config::{Config, ProfileConfig, ProjectType};
+
+    fn config() -> Config {
+        Config {
+            schema_version: 1,
+            project_type: ProjectType::Project,
+            container_prefix: None,
+            domain_strategy: None,
+            service: Vec::new(),
+            swarm: Vec::new(),
+            profile: std::collections::BTreeMap::new(),
+            preset: std::collections::BTreeMap::new(),
+        }
+    }
 
     #[test]
     fn profile_names_order_stable() {
         assert_eq!(
-            profile_names(),
+            profile_names(&config()),
             vec!["full", "all", "infra", "data", "app", "web", "api"]
         );
     }
+
+    #[test]
+    fn profile_names_append_configured_profiles_once() {
+        let mut config = config();
+        config
+            .profile
+            .insert("e2e".to_owned(), ProfileConfig::default());
+        config
+            .profile
+            .insert("infra".to_owned(), ProfileConfig::default());
+
+        assert_eq!(
+            profile_names(&config),
+            vec!["full", "all", "infra", "data", "app", "web", "api", "e2e"]
+        );
+    }
 }
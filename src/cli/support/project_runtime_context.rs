@@ -72,6 +72,7 @@ mod tests {
                 hook: Vec::new(),
                 health_path: None,
                 health_statuses: None,
+                health: None,
                 localhost_tls: false,
                 octane: false,
                 octane_workers: None,
@@ -81,9 +82,25 @@ mod tests {
                 env_mapping: None,
                 javascript: None,
                 container_name: Some("db".to_owned()),
+                memory: None,
+                cpus: None,
+                pids_limit: None,
+                shm_size: None,
+                ulimits: None,
+                tmpfs: None,
+                platform: None,
+                user: None,
+                cap_add: None,
+                restart: None,
+                build: None,
                 resolved_container_name: None,
+                resolved_project_root: None,
+                resolved_runtime_env: None,
+                secret_fields: Vec::new(),
             }],
             swarm: Vec::new(),
+            profile: std::collections::BTreeMap::new(),
+            preset: std::collections::BTreeMap::new(),
         }
     }
 
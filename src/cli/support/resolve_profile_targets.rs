@@ -3,14 +3,56 @@
 //! Contains cli support resolve profile targets logic used by Helm command workflows.
 
 use anyhow::Result;
+use std::collections::HashSet;
 
 use crate::config;
 
+use super::profile_names;
+
 pub(crate) fn resolve_profile_targets<'a>(
     config: &'a config::Config,
     profile: &str,
 ) -> Result<Vec<&'a config::ServiceConfig>> {
-    let targets = match profile {
+    let mut selected = HashSet::new();
+    let mut visited = HashSet::new();
+    collect_profile_services(config, profile, &mut visited, &mut selected)?;
+
+    Ok(config
+        .service
+        .iter()
+        .filter(|svc| selected.contains(svc.name.as_str()))
+        .collect())
+}
+
+/// Collects service names selected by one profile, following `include` chains.
+///
+/// Configured `[profile.<name>]` entries take precedence over built-in names.
+fn collect_profile_services<'a>(
+    config: &'a config::Config,
+    profile: &str,
+    visited: &mut HashSet<String>,
+    selected: &mut HashSet<&'a str>,
+) -> Result<()> {
+    if !visited.insert(profile.to_owned()) {
+        return Ok(());
+    }
+
+    if let Some(custom) = config.profile.get(profile) {
+        for svc in &config.service {
+            if custom.services.contains(&svc.name)
+                || custom.kinds.contains(&svc.kind)
+                || custom.drivers.contains(&svc.driver)
+            {
+                selected.insert(svc.name.as_str());
+            }
+        }
+        for included in &custom.include {
+            collect_profile_services(config, included, visited, selected)?;
+        }
+        return Ok(());
+    }
+
+    let targets: Vec<&config::ServiceConfig> = match profile {
         "all" | "full" => config.service.iter().collect(),
         "infra" => config
             .service
@@ -41,18 +83,20 @@ pub(crate) fn resolve_profile_targets<'a>(
         }
         _ => {
             anyhow::bail!(
-                "unknown profile '{profile}'. expected one of: full, all, infra, data, app, web, api"
+                "unknown profile '{profile}'. expected one of: {}",
+                profile_names(config).join(", ")
             )
         }
     };
 
-    Ok(targets)
+    selected.extend(targets.into_iter().map(|svc| svc.name.as_str()));
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::resolve_profile_targets;
-    use crate::config::{Config, Driver, Kind, ServiceConfig};
+    use crate::config::{Config, Driver, Kind, ProfileConfig, ServiceConfig};
 
     fn service(name: &str, kind: Kind) -> ServiceConfig {
         ServiceConfig {
@@ -84,6 +128,7 @@ mod tests {
             hook: Vec::new(),
             health_path: None,
             health_statuses: None,
+            health: None,
             localhost_tls: false,
             octane: false,
             octane_workers: None,
@@ -93,7 +138,21 @@ mod tests {
             env_mapping: None,
             javascript: None,
             container_name: None,
+            memory: None,
+            cpus: None,
+            pids_limit: None,
+            shm_size: None,
+            ulimits: None,
+            tmpfs: None,
+            platform: None,
+            user: None,
+            cap_add: None,
+            restart: None,
+            build: None,
             resolved_container_name: None,
+            resolved_project_root: None,
+            resolved_runtime_env: None,
+            secret_fields: Vec::new(),
         }
     }
 
@@ -109,6 +168,8 @@ mod tests {
                 service("search", Kind::Search),
             ],
             swarm: Vec::new(),
+            profile: std::collections::BTreeMap::new(),
+            preset: std::collections::BTreeMap::new(),
         }
     }
 
@@ -143,4 +204,61 @@ mod tests {
         let config = config();
         assert!(resolve_profile_targets(&config, "invalid").is_err());
     }
+
+    #[test]
+    fn configured_profile_selects_services_kinds_and_drivers() {
+        let mut config = config();
+        config.profile.insert(
+            "backend-only".to_owned(),
+            ProfileConfig {
+                services: vec!["app".to_owned()],
+                kinds: vec![Kind::Search],
+                ..ProfileConfig::default()
+            },
+        );
+
+        let targets = resolve_profile_targets(&config, "backend-only").expect("custom profile");
+        let names: Vec<&str> = targets.iter().map(|svc| svc.name.as_str()).collect();
+        assert_eq!(names, vec!["app", "search"]);
+    }
+
+    #[test]
+    fn configured_profile_merges_included_profiles() {
+        let mut config = config();
+        config.profile.insert(
+            "e2e".to_owned(),
+            ProfileConfig {
+                services: vec!["search".to_owned()],
+                include: vec!["data-core".to_owned(), "app".to_owned()],
+                ..ProfileConfig::default()
+            },
+        );
+        config.profile.insert(
+            "data-core".to_owned(),
+            ProfileConfig {
+                kinds: vec![Kind::Database],
+                ..ProfileConfig::default()
+            },
+        );
+
+        let targets = resolve_profile_targets(&config, "e2e").expect("custom profile");
+        let names: Vec<&str> = targets.iter().map(|svc| svc.name.as_str()).collect();
+        assert_eq!(names, vec!["app", "db", "search"]);
+    }
+
+    #[test]
+    fn configured_profile_overrides_builtin_name() {
+        let mut config = config();
+        config.profile.insert(
+            "data".to_owned(),
+            ProfileConfig {
+                services: vec!["db".to_owned()],
+                ..ProfileConfig::default()
+            },
+        );
+
+        let targets = resolve_profile_targets(&config, "data").expect("custom profile");
+        assert_eq!(targets.len(), 1);
+        assert_eq!(targets[0].name, "db");
+    }
 }
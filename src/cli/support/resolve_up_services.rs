@@ -28,7 +28,7 @@ pub(crate) fn resolve_up_services<'a>(
             let Some(service) = by_name.get(current).copied() else {
                 anyhow::bail!("service '{current}' is missing");
             };
-            Ok(service.depends_on.clone().unwrap_or_default())
+            Ok(service.dependency_names())
         },
         |current| format!("circular dependency detected at service '{current}'"),
     )?;
@@ -79,11 +79,19 @@ mod tests {
             volumes: None,
             env: None,
             command: None,
-            depends_on: depends_on.map(|items| items.into_iter().map(str::to_owned).collect()),
+            depends_on: depends_on.map(|items| {
+                items
+                    .into_iter()
+                    .map(|item| {
+                        config::ServiceDependency::new(item, config::DependencyCondition::Started)
+                    })
+                    .collect()
+            }),
             seed_file: None,
             hook: Vec::new(),
             health_path: None,
             health_statuses: None,
+            health: None,
             localhost_tls: false,
             octane: false,
             octane_workers: None,
@@ -93,7 +101,21 @@ mod tests {
             env_mapping: None,
             javascript: None,
             container_name: None,
+            memory: None,
+            cpus: None,
+            pids_limit: None,
+            shm_size: None,
+            ulimits: None,
+            tmpfs: None,
+            platform: None,
+            user: None,
+            cap_add: None,
+            restart: None,
+            build: None,
             resolved_container_name: None,
+            resolved_project_root: None,
+            resolved_runtime_env: None,
+            secret_fields: Vec::new(),
         }
     }
 
@@ -114,6 +136,8 @@ mod tests {
                 ),
             ],
             swarm: Vec::new(),
+            profile: std::collections::BTreeMap::new(),
+            preset: std::collections::BTreeMap::new(),
         }
     }
 
@@ -29,6 +29,7 @@ pub(crate) fn run_doctor(config: &config::Config, options: RunDoctorOptions<'_>)
     let mut has_error = false;
 
     has_error |= docker::check_runtime_availability();
+    docker::check_remote_engine(config);
     has_error |= ports::check_port_conflicts(config);
     has_error |= app::check_app_services(
         config,
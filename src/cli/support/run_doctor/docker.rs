@@ -5,10 +5,58 @@
 use super::report;
 
 /// Checks selected container runtime availability and reports failures.
+///
+/// Known engine feature gaps are reported as warnings and never fail doctor.
 pub(super) fn check_runtime_availability() -> bool {
-    crate::docker::runtime_diagnostic_checks()
+    let failed = crate::docker::runtime_diagnostic_checks()
         .iter()
-        .any(run_runtime_check)
+        .any(run_runtime_check);
+    for gap in crate::docker::runtime_capability_gaps() {
+        report::warn(gap);
+    }
+    failed
+}
+
+/// Warns about bind mounts a remote engine would resolve on its own host and
+/// loopback ports it publishes on every interface.
+pub(super) fn check_remote_engine(config: &crate::config::Config) {
+    let Some(address) = crate::docker::remote_engine_address() else {
+        return;
+    };
+    report::info(&format!("Container engine runs on remote host {address}"));
+    for (service, source) in local_bind_mounts(config) {
+        report::warn(&format!(
+            "Service '{service}' bind-mounts {source}, which {address} resolves on its own \
+             filesystem; sync the path there or use a named volume"
+        ));
+    }
+    for service in config
+        .service
+        .iter()
+        .filter(|service| crate::docker::widens_loopback_publish(&service.host))
+    {
+        report::warn(&format!(
+            "Service '{}' binds {}:{}, which is published on 0.0.0.0 of {address} and \
+             reachable from its network; set `host` to an interface of {address} to limit it",
+            service.name, service.host, service.port
+        ));
+    }
+}
+
+/// Lists `(service, source)` for volumes whose source is a host path.
+fn local_bind_mounts(config: &crate::config::Config) -> Vec<(&str, &str)> {
+    config
+        .service
+        .iter()
+        .flat_map(|service| {
+            service.volumes.iter().flatten().filter_map(|volume| {
+                let source = volume.split(':').next().unwrap_or_default();
+                source
+                    .starts_with(['/', '.', '~'])
+                    .then_some((service.name.as_str(), source))
+            })
+        })
+        .collect()
 }
 
 fn run_runtime_check(check: &crate::docker::RuntimeDiagnosticCheck) -> bool {
@@ -15,6 +15,10 @@ pub(super) fn info(message: &str) {
     output::event("doctor", LogLevel::Info, message, Persistence::Persistent);
 }
 
+pub(super) fn warn(message: &str) {
+    output::event("doctor", LogLevel::Warn, message, Persistence::Persistent);
+}
+
 pub(super) fn error(message: &str) {
     output::event("doctor", LogLevel::Error, message, Persistence::Persistent);
 }
@@ -5,7 +5,7 @@ use std::path::Path;
 
 use crate::config;
 
-use super::{persist_random_runtime_ports, run_services_with_app_last};
+use super::{persist_random_runtime_ports, run_services_in_dependency_order};
 
 pub(crate) struct RandomPortsPersistenceOptions<'a> {
     pub(crate) env_path: Option<&'a Path>,
@@ -16,10 +16,10 @@ pub(crate) struct RandomPortsPersistenceOptions<'a> {
     pub(crate) project_root: Option<&'a Path>,
 }
 
-pub(crate) fn run_random_ports_flow<'a, T, FIsApp, FRun, FService>(
-    planned: &'a [T],
+pub(crate) fn run_random_ports_flow<T, FRun, FService>(
+    planned: &[T],
     parallel: usize,
-    is_app: FIsApp,
+    wait_timeout: u64,
     runtime_runner: FRun,
     service_from_item: FService,
     config_data: &mut config::Config,
@@ -27,14 +27,19 @@ pub(crate) fn run_random_ports_flow<'a, T, FIsApp, FRun, FService>(
 ) -> Result<()>
 where
     T: Sync,
-    FIsApp: Fn(&T) -> bool + Sync,
-    FRun: Fn(&T) -> Result<()> + Sync + Send,
-    FService: Fn(&'a T) -> &'a config::ServiceConfig,
+    FRun: Fn(&T) -> Result<()> + Sync,
+    FService: Fn(&T) -> &config::ServiceConfig + Sync,
 {
-    run_services_with_app_last(parallel, planned, is_app, runtime_runner)?;
+    run_services_in_dependency_order(
+        parallel,
+        planned,
+        &service_from_item,
+        wait_timeout,
+        runtime_runner,
+    )?;
 
     persist_random_runtime_ports(
-        planned.iter().map(service_from_item),
+        planned.iter().map(&service_from_item),
         config_data,
         persistence.env_path,
         persistence.save_ports,
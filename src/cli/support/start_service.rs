@@ -30,6 +30,9 @@ impl<'a> ServiceStartContext<'a> {
 }
 
 fn wait_strategy_for_service(service: &config::ServiceConfig) -> WaitStrategy {
+    if service.health.is_some() {
+        return WaitStrategy::Container;
+    }
     if service.health_path.is_some() {
         return WaitStrategy::Http;
     }
@@ -62,31 +65,46 @@ pub(crate) fn start_service(
             injected_env: context.app_env,
             allow_rebuild: build,
         })?;
-        if wait_healthy {
-            match wait_strategy_for_service(service) {
-                WaitStrategy::Http => {
-                    serve::wait_until_http_healthy(service, health_timeout_secs, 2, None)?;
-                }
-                WaitStrategy::Container => {
-                    docker::wait_until_healthy(service, health_timeout_secs, 2, None)?;
-                }
-                WaitStrategy::Skip => {}
-            }
-        }
     } else {
         docker::up(service, pull_policy, recreate)?;
-        if wait_healthy {
-            docker::wait_until_healthy(service, health_timeout_secs, 2, None)?;
-        }
+    }
+    if wait_healthy {
+        wait_until_ready(service, health_timeout_secs)?;
     }
 
     Ok(())
 }
 
+/// Blocks until a started service reaches the condition its dependents need.
+pub(crate) fn wait_for_dependency_condition(
+    service: &config::ServiceConfig,
+    condition: config::DependencyCondition,
+    timeout_secs: u64,
+) -> Result<()> {
+    match condition {
+        config::DependencyCondition::Started => Ok(()),
+        config::DependencyCondition::Healthy => wait_until_ready(service, timeout_secs),
+        config::DependencyCondition::Completed => {
+            docker::wait_until_completed(service, timeout_secs)
+        }
+    }
+}
+
+fn wait_until_ready(service: &config::ServiceConfig, timeout_secs: u64) -> Result<()> {
+    if service.kind != config::Kind::App {
+        return docker::wait_until_healthy(service, timeout_secs, 2, None);
+    }
+    match wait_strategy_for_service(service) {
+        WaitStrategy::Http => serve::wait_until_http_healthy(service, timeout_secs, 2, None),
+        WaitStrategy::Container => docker::wait_until_healthy(service, timeout_secs, 2, None),
+        WaitStrategy::Skip => Ok(()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{WaitStrategy, wait_strategy_for_service};
-    use crate::config::{Driver, Kind, ServiceConfig};
+    use crate::config::{Driver, HealthProbeType, Kind, ServiceConfig, ServiceHealth};
 
     #[test]
     fn wait_strategy_uses_http_when_health_path_is_configured() {
@@ -96,6 +114,24 @@ mod tests {
         assert_eq!(wait_strategy_for_service(&service), WaitStrategy::Http);
     }
 
+    #[test]
+    fn wait_strategy_uses_configured_probe_over_health_path() {
+        let mut service = service(Driver::Frankenphp);
+        service.health_path = Some("/up".to_owned());
+        service.health = Some(ServiceHealth {
+            probe: HealthProbeType::Exec,
+            command: Some(vec!["php".to_owned(), "artisan".to_owned()]),
+            path: None,
+            statuses: None,
+            body_contains: None,
+            interval_sec: None,
+            retries: None,
+            start_period_sec: None,
+        });
+
+        assert_eq!(wait_strategy_for_service(&service), WaitStrategy::Container);
+    }
+
     #[test]
     fn wait_strategy_uses_container_check_for_horizon_workers() {
         let service = service(Driver::Horizon);
@@ -145,6 +181,7 @@ mod tests {
             hook: Vec::new(),
             health_path: None,
             health_statuses: None,
+            health: None,
             localhost_tls: false,
             octane: false,
             octane_workers: None,
@@ -154,7 +191,21 @@ mod tests {
             env_mapping: None,
             javascript: None,
             container_name: Some("app".to_owned()),
+            memory: None,
+            cpus: None,
+            pids_limit: None,
+            shm_size: None,
+            ulimits: None,
+            tmpfs: None,
+            platform: None,
+            user: None,
+            cap_add: None,
+            restart: None,
+            build: None,
             resolved_container_name: None,
+            resolved_project_root: None,
+            resolved_runtime_env: None,
+            secret_fields: Vec::new(),
         }
     }
 }
@@ -7,12 +7,17 @@
 #![allow(clippy::match_same_arms)] // Duplicate arms keep driver/preset mapping explicit
 
 mod api;
+mod diagnostics;
 mod domain_names;
 mod expansion;
+mod import;
+mod layers;
+mod migrations;
 mod paths;
 mod presets;
 mod raw;
 mod runtime_env;
+mod schema;
 mod service_methods;
 mod services;
 #[cfg(test)]
@@ -22,19 +27,29 @@ mod validation;
 
 pub(crate) use api::load_raw_config_with;
 pub use api::{
+    ConfigEdit, ConfigOriginPathOptions, EditConfigPathOptions, EngineSettings, InitFromOptions,
     LoadConfigPathOptions, LockfileDiff, MigrateConfigOptions, ProjectRootPathOptions,
-    RawConfigPathOptions, SaveConfigPathOptions, apply_runtime_env, build_image_lock,
-    default_env_file_name, find_service, init_config, load_config, load_config_with,
-    load_container_engine_with, load_lockfile_with, lockfile_diff, migrate_config_with,
-    preferred_sql_client_flavor, preset_names, preset_preview, project_root, project_root_with,
-    resolve_app_service, resolve_service, save_config_with, save_lockfile_with,
-    update_service_host_port, update_service_port, verify_lockfile_with,
+    RawConfigPathOptions, ValidateConfigPathOptions, apply_runtime_env, build_image_lock,
+    config_value_origins, config_value_with, default_env_file_name, display_paths,
+    edit_config_with, find_service, init_config, init_config_from, load_config, load_config_with,
+    load_engine_settings_with, load_lockfile_with, lockfile_diff, migrate_config_with,
+    preferred_sql_client_flavor, preset_names_with, preset_preview_with,
+    preview_config_migration_with, project_root, project_root_with, resolve_app_service,
+    resolve_service, save_lockfile_with, update_service_host_port, update_service_port,
+    validate_config_with, verify_lockfile_with,
 };
+#[cfg(test)]
+pub(crate) use api::{preset_names, preset_preview};
+pub use diagnostics::{ConfigDiagnostic, DiagnosticSeverity};
+pub use import::ImportSource;
 pub(crate) use raw::{RawConfig, RawServiceConfig};
+pub use schema::config_schema;
 pub(crate) use service_methods::network::{
     is_unspecified_port_allocation_host, normalize_host_for_port_allocation,
 };
 pub use types::{
-    Config, ContainerEngine, DomainStrategy, Driver, HookOnError, HookPhase, HookRun, Kind,
-    LockedImage, Lockfile, ProjectType, ServiceConfig, ServiceHook, SwarmGit, SwarmTarget,
+    BUILTIN_PROFILE_NAMES, Config, ContainerEngine, DependencyCondition, DomainStrategy, Driver,
+    HealthProbeType, HookOnError, HookPhase, HookRun, Kind, LockedImage, Lockfile, PresetConfig,
+    ProfileConfig, ProjectType, ServiceBuild, ServiceConfig, ServiceDependency, ServiceHealth,
+    ServiceHook, SwarmGit, SwarmTarget,
 };
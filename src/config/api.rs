@@ -2,31 +2,44 @@
 //!
 //! Contains config api logic used by Helm command workflows.
 
+mod edit;
 mod load_save;
 mod lockfile;
 mod migrate;
+mod origins;
 mod presets;
 mod project;
 mod runtime_env;
 mod services;
 mod sql_client_flavor;
 mod toml_io;
+mod validate;
 
+pub use edit::{
+    ConfigEdit, EditConfigPathOptions, config_value_with, display_paths, edit_config_with,
+};
 pub(crate) use load_save::load_raw_config_with;
 pub use load_save::{
-    LoadConfigPathOptions, RawConfigPathOptions, SaveConfigPathOptions, load_config,
-    load_config_with, load_container_engine_with, save_config_with,
+    EngineSettings, LoadConfigPathOptions, RawConfigPathOptions, load_config, load_config_with,
+    load_engine_settings_with,
 };
 pub use lockfile::{
     LockfileDiff, build_image_lock, load_lockfile_with, lockfile_diff, save_lockfile_with,
     verify_lockfile_with,
 };
-pub use migrate::{MigrateConfigOptions, migrate_config_with};
-pub use presets::{preset_names, preset_preview};
-pub use project::{ProjectRootPathOptions, init_config, project_root, project_root_with};
+pub use migrate::{MigrateConfigOptions, migrate_config_with, preview_config_migration_with};
+pub use origins::{ConfigOriginPathOptions, config_value_origins};
+#[cfg(test)]
+pub(crate) use presets::{preset_names, preset_preview};
+pub use presets::{preset_names_with, preset_preview_with};
+pub use project::{
+    InitFromOptions, ProjectRootPathOptions, init_config, init_config_from, project_root,
+    project_root_with,
+};
 pub use runtime_env::{apply_runtime_env, default_env_file_name};
 pub use services::{
     find_service, resolve_app_service, resolve_service, update_service_host_port,
     update_service_port,
 };
 pub use sql_client_flavor::preferred_sql_client_flavor;
+pub use validate::{ValidateConfigPathOptions, validate_config_with};
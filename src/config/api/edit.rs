@@ -0,0 +1,162 @@
+//! config api edit module.
+//!
+//! Edits `.helm.toml` and its layers in place so comments, ordering, and
+//! formatting survive `helm config set/unset`, `--save-ports`, and
+//! `helm config migrate`.
+
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+use toml_edit::DocumentMut;
+
+use super::super::{RawConfig, expansion};
+use super::project::ProjectRootPathOptions;
+
+mod layers;
+mod path;
+
+use layers::EditLayers;
+
+pub type EditConfigPathOptions<'a> = ProjectRootPathOptions<'a>;
+
+/// One in-place change to a config file.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum ConfigEdit {
+    /// Sets the value at a dotted key path, creating missing tables.
+    Set {
+        /// Dotted key path such as `service.db.port`.
+        key: String,
+        /// New value.
+        value: toml::Value,
+    },
+    /// Removes the value at a dotted key path.
+    Unset {
+        /// Dotted key path such as `service.db.image`.
+        key: String,
+    },
+}
+
+impl ConfigEdit {
+    /// Builds a [`ConfigEdit::Set`].
+    pub fn set(key: impl Into<String>, value: impl Into<toml::Value>) -> Self {
+        Self::Set {
+            key: key.into(),
+            value: value.into(),
+        }
+    }
+
+    /// Builds a [`ConfigEdit::Unset`].
+    pub fn unset(key: impl Into<String>) -> Self {
+        Self::Unset { key: key.into() }
+    }
+}
+
+/// Returns the effective value at a dotted key path, rendered as TOML.
+///
+/// `service.<name>` selects a `[[service]]` entry by the name the loader gives
+/// it, including preset default names; numeric segments index arrays. The
+/// value is read from the last layer (`extends` bases, the config file,
+/// `.helm.local.toml`) that sets it.
+///
+/// # Errors
+///
+/// Returns an error if the config or one of its layers cannot be read or parsed.
+pub fn config_value_with(options: EditConfigPathOptions<'_>, key: &str) -> Result<Option<String>> {
+    let config_path = super::toml_io::resolve_config_path(options)?;
+    let mut layers = EditLayers::load(&config_path)?;
+    let key = layers.canonical_key(key);
+    Ok(layers.get(&key))
+}
+
+/// Applies edits in place and writes back every layer that changed.
+///
+/// Each edit lands in the layer that owns it: the last layer setting the key,
+/// else the last layer defining its `[[service]]` entry, else the config file.
+/// Returns the files written, in edit order.
+///
+/// # Errors
+///
+/// Returns an error if a key path is invalid, a `[[service]]` entry is
+/// missing, or an edited layer no longer parses as a Helm config.
+pub fn edit_config_with(
+    options: EditConfigPathOptions<'_>,
+    edits: &[ConfigEdit],
+) -> Result<Vec<PathBuf>> {
+    let config_path = super::toml_io::resolve_config_path(options)?;
+    let mut layers = EditLayers::load(&config_path)?;
+
+    let mut edited: Vec<usize> = Vec::new();
+    for edit in edits {
+        let (key, owner) = match edit {
+            ConfigEdit::Set { key, .. } => {
+                let key = layers.canonical_key(key);
+                let owner = layers.owner(&key);
+                (key, owner)
+            }
+            ConfigEdit::Unset { key } => {
+                let key = layers.canonical_key(key);
+                let Some(owner) = layers.last_layer_with(&key) else {
+                    anyhow::bail!("'{key}' is not set");
+                };
+                (key, owner)
+            }
+        };
+        let Some((path, document)) = layers.layer_mut(owner) else {
+            anyhow::bail!("no config layer to edit for '{key}'");
+        };
+        match edit {
+            ConfigEdit::Set { value, .. } => path::set(document, &key, value),
+            ConfigEdit::Unset { .. } => path::unset(document, &key),
+        }
+        .with_context(|| format!("failed to edit {}", path.display()))?;
+        if !edited.contains(&owner) {
+            edited.push(owner);
+        }
+    }
+
+    let mut writes = Vec::new();
+    for index in edited {
+        if let Some((path, document)) = layers.layer_mut(index) {
+            let content = document.to_string();
+            check_document(&content, path)?;
+            writes.push((path.to_path_buf(), content));
+        }
+    }
+    for (path, content) in &writes {
+        std::fs::write(path, content)
+            .with_context(|| format!("failed to write config file at {}", path.display()))?;
+    }
+    Ok(writes.into_iter().map(|(path, _)| path).collect())
+}
+
+/// Renders edited file paths for status messages.
+#[must_use]
+pub fn display_paths(paths: &[PathBuf]) -> String {
+    paths
+        .iter()
+        .map(|path| path.display().to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+pub(super) fn read_document(path: &Path) -> Result<DocumentMut> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read config file at {}", path.display()))?;
+    content
+        .parse::<DocumentMut>()
+        .with_context(|| format!("failed to parse TOML config file at {}", path.display()))
+}
+
+/// Rejects edits that would leave a config Helm cannot parse.
+pub(super) fn check_document(content: &str, path: &Path) -> Result<()> {
+    let table = content
+        .parse::<toml::Table>()
+        .with_context(|| format!("edit would produce invalid TOML in {}", path.display()))?;
+    if expansion::has_references(&table) {
+        return Ok(());
+    }
+    toml::Value::Table(table)
+        .try_into::<RawConfig>()
+        .map(drop)
+        .with_context(|| format!("edit would produce an invalid config in {}", path.display()))
+}
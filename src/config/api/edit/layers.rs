@@ -0,0 +1,145 @@
+//! config api edit layers module.
+//!
+//! Routes a dotted key to the config layer that owns it, naming `[[service]]`
+//! entries the way the loader does, so `service.db` finds an unnamed
+//! `preset = "mysql"` entry in an `extends` base or `.helm.local.toml`.
+
+use anyhow::Result;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use toml_edit::DocumentMut;
+
+use super::super::super::{PresetConfig, RawServiceConfig, expansion, layers, presets};
+use super::path;
+
+/// Every layer of a config, parsed for in-place editing.
+pub(super) struct EditLayers {
+    /// Layer files and documents, base layers first.
+    layers: Vec<(PathBuf, DocumentMut)>,
+    /// Index of the config file itself among `layers`.
+    primary: usize,
+    /// Loader-resolved service names mapped to the `name`/`preset` identity
+    /// the entry carries in its files.
+    aliases: HashMap<String, String>,
+}
+
+impl EditLayers {
+    /// Reads `config_path`, its `extends` chain, and the local overlay.
+    pub(super) fn load(config_path: &Path) -> Result<Self> {
+        let document = layers::load_layered_document(config_path)?;
+        let aliases = service_aliases(&document.table);
+        let layers = document
+            .files
+            .into_iter()
+            .map(|path| super::read_document(&path).map(|document| (path, document)))
+            .collect::<Result<Vec<_>>>()?;
+        let primary = layers
+            .iter()
+            .position(|(path, _)| path == config_path)
+            .unwrap_or_default();
+        Ok(Self {
+            layers,
+            primary,
+            aliases,
+        })
+    }
+
+    /// Rewrites `service.<name>` to the identity the layer files use.
+    pub(super) fn canonical_key(&self, key: &str) -> String {
+        let mut segments = key.splitn(3, '.');
+        match (segments.next(), segments.next(), segments.next()) {
+            (Some("service"), Some(name), rest) => match self.aliases.get(name) {
+                Some(identity) => rest.map_or_else(
+                    || format!("service.{identity}"),
+                    |rest| format!("service.{identity}.{rest}"),
+                ),
+                None => key.to_owned(),
+            },
+            _ => key.to_owned(),
+        }
+    }
+
+    /// Returns the effective value of `key`, read from the last layer setting it.
+    pub(super) fn get(&mut self, key: &str) -> Option<String> {
+        self.layers
+            .iter_mut()
+            .rev()
+            .find_map(|(_, document)| path::get(document, key))
+    }
+
+    /// Returns the layer to edit: the last one setting `key`, else the last
+    /// one holding its `[[service]]` entry, else the config file itself.
+    pub(super) fn owner(&mut self, key: &str) -> usize {
+        let entry = service_entry_key(key);
+        self.last_layer_with(key)
+            .or_else(|| entry.and_then(|entry| self.last_layer_with(&entry)))
+            .unwrap_or(self.primary)
+    }
+
+    /// Returns the last layer that sets `key`.
+    pub(super) fn last_layer_with(&mut self, key: &str) -> Option<usize> {
+        self.layers
+            .iter_mut()
+            .rposition(|(_, document)| path::get(document, key).is_some())
+    }
+
+    /// Returns the path and document of one layer.
+    pub(super) fn layer_mut(&mut self, index: usize) -> Option<(&Path, &mut DocumentMut)> {
+        self.layers
+            .get_mut(index)
+            .map(|(path, document)| (path.as_path(), document))
+    }
+}
+
+/// Returns `service.<entry>` for keys inside a `[[service]]` entry.
+fn service_entry_key(key: &str) -> Option<String> {
+    let mut segments = key.splitn(3, '.');
+    match (segments.next(), segments.next(), segments.next()) {
+        (Some("service"), Some(entry), Some(_)) => Some(format!("service.{entry}")),
+        _ => None,
+    }
+}
+
+/// Maps each merged service's resolved name to its `name`/`preset` identity.
+///
+/// Only `name`, `preset`, and `driver` feed the name, so entries with
+/// unresolved references still resolve.
+fn service_aliases(table: &toml::Table) -> HashMap<String, String> {
+    let project_presets = table
+        .get("preset")
+        .cloned()
+        .and_then(|presets| presets.try_into::<BTreeMap<String, PresetConfig>>().ok())
+        .unwrap_or_default();
+    let user_presets = presets::load_user_presets().unwrap_or_default();
+    let catalog = presets::PresetCatalog::new(&project_presets, &user_presets);
+
+    let mut aliases = HashMap::new();
+    let entries = table
+        .get("service")
+        .and_then(toml::Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(toml::Value::as_table);
+    for entry in entries {
+        let Some(identity) = entry
+            .get("name")
+            .or_else(|| entry.get("preset"))
+            .and_then(toml::Value::as_str)
+        else {
+            continue;
+        };
+        let naming = ["name", "preset", "driver"]
+            .iter()
+            .filter_map(|field| Some(((*field).to_owned(), entry.get(*field)?.clone())))
+            .collect::<toml::Table>();
+        let Some(name) = toml::Value::Table(naming)
+            .try_into::<RawServiceConfig>()
+            .ok()
+            .and_then(|raw| expansion::resolve_service_name(raw, &catalog).ok())
+        else {
+            continue;
+        };
+        aliases.entry(name).or_insert_with(|| identity.to_owned());
+    }
+    aliases
+}
@@ -0,0 +1,218 @@
+//! config api edit path module.
+//!
+//! Resolves dotted key paths such as `service.db.env.APP_KEY` inside a
+//! `toml_edit` document and applies get/set/unset at the resolved node.
+
+use anyhow::{Context, Result};
+use toml_edit::{Array, ArrayOfTables, DocumentMut, InlineTable, Item, Table, TableLike, Value};
+
+/// Container reached while walking a key path.
+enum Node<'a> {
+    /// A standard or inline table; `inline_children` marks tables nested in
+    /// `[[array]]` entries, where new sub-tables must be inline or their
+    /// `[header]` would attach to the last entry instead.
+    Table {
+        table: &'a mut dyn TableLike,
+        inline_children: bool,
+    },
+    /// `[[service]]`-style entries, addressed by `name`/`preset` or index.
+    Entries(&'a mut ArrayOfTables),
+    /// Inline array, addressed by index.
+    Array(&'a mut Array),
+}
+
+/// Returns the value at `key` rendered as TOML, if set.
+pub(super) fn get(document: &mut DocumentMut, key: &str) -> Option<String> {
+    let (parent, last) = resolve_parent(document, key, false).ok()?;
+    match parent {
+        Node::Table { table, .. } => table.get(last).map(|item| match item {
+            Item::Value(value) => render_value(value),
+            Item::None | Item::Table(_) | Item::ArrayOfTables(_) => {
+                item.to_string().trim().to_owned()
+            }
+        }),
+        Node::Entries(entries) => entry_index(entries, last)
+            .and_then(|index| entries.get(index))
+            .map(|entry| entry.to_string().trim().to_owned()),
+        Node::Array(array) => last
+            .parse::<usize>()
+            .ok()
+            .and_then(|index| array.get(index))
+            .map(render_value),
+    }
+}
+
+/// Sets `key` to `value`, keeping the surrounding formatting of replaced values.
+pub(super) fn set(document: &mut DocumentMut, key: &str, value: &toml::Value) -> Result<()> {
+    let new_value = value
+        .to_string()
+        .parse::<Value>()
+        .with_context(|| format!("failed to convert value for '{key}'"))?;
+
+    let (parent, last) = resolve_parent(document, key, true)?;
+    match parent {
+        Node::Table { table, .. } => match table.get_mut(last).and_then(Item::as_value_mut) {
+            Some(existing) => replace_keeping_decor(existing, new_value),
+            None => {
+                table.insert(last, Item::Value(new_value));
+            }
+        },
+        Node::Entries(_) => {
+            anyhow::bail!("'{key}' is a whole entry; set one of its keys instead");
+        }
+        Node::Array(array) => {
+            let Some(existing) = array_index(array, last).and_then(|index| array.get_mut(index))
+            else {
+                anyhow::bail!("'{key}' is out of range");
+            };
+            replace_keeping_decor(existing, new_value);
+        }
+    }
+    Ok(())
+}
+
+/// Removes `key`, including whole `[[service]]` entries.
+pub(super) fn unset(document: &mut DocumentMut, key: &str) -> Result<()> {
+    let (parent, last) = resolve_parent(document, key, false)?;
+    let removed = match parent {
+        Node::Table { table, .. } => table.remove(last).is_some(),
+        Node::Entries(entries) => entry_index(entries, last)
+            .map(|index| entries.remove(index))
+            .is_some(),
+        Node::Array(array) => array_index(array, last)
+            .map(|index| array.remove(index))
+            .is_some(),
+    };
+    if !removed {
+        anyhow::bail!("'{key}' is not set");
+    }
+    Ok(())
+}
+
+/// Walks every segment but the last, returning the container and last segment.
+fn resolve_parent<'a, 'k>(
+    document: &'a mut DocumentMut,
+    key: &'k str,
+    create: bool,
+) -> Result<(Node<'a>, &'k str)> {
+    let segments = key.split('.').collect::<Vec<_>>();
+    let Some((last, parents)) = segments.split_last() else {
+        anyhow::bail!("invalid config key '{key}'");
+    };
+    if segments.iter().any(|segment| segment.is_empty()) {
+        anyhow::bail!("invalid config key '{key}'");
+    }
+
+    if parents.first() == Some(&"service") && !document.contains_key("service") {
+        anyhow::bail!("cannot resolve '{key}': the file has no [[service]] entries");
+    }
+
+    let root: &mut Table = document;
+    let mut node = Node::Table {
+        table: root,
+        inline_children: false,
+    };
+    for segment in parents {
+        node = descend(node, segment, create).with_context(|| format!("cannot resolve '{key}'"))?;
+    }
+    Ok((node, last))
+}
+
+fn descend<'a>(node: Node<'a>, segment: &str, create: bool) -> Result<Node<'a>> {
+    match node {
+        Node::Table {
+            table,
+            inline_children,
+        } => {
+            if !table.contains_key(segment) {
+                if !create {
+                    anyhow::bail!("'{segment}' is not set");
+                }
+                table.insert(segment, new_table(inline_children));
+            }
+            let Some(child) = table.get_mut(segment) else {
+                anyhow::bail!("'{segment}' is not set");
+            };
+            node_for(child, inline_children)
+        }
+        Node::Entries(entries) => {
+            let Some(entry) =
+                entry_index(entries, segment).and_then(|index| entries.get_mut(index))
+            else {
+                anyhow::bail!("no entry named '{segment}'");
+            };
+            Ok(Node::Table {
+                table: entry,
+                inline_children: true,
+            })
+        }
+        Node::Array(array) => {
+            let Some(Value::InlineTable(table)) =
+                array_index(array, segment).and_then(|index| array.get_mut(index))
+            else {
+                anyhow::bail!("no table at array element '{segment}'");
+            };
+            Ok(Node::Table {
+                table,
+                inline_children: true,
+            })
+        }
+    }
+}
+
+fn node_for(item: &mut Item, inline_children: bool) -> Result<Node<'_>> {
+    match item {
+        Item::Table(table) => Ok(Node::Table {
+            table,
+            inline_children,
+        }),
+        Item::ArrayOfTables(entries) => Ok(Node::Entries(entries)),
+        Item::Value(Value::InlineTable(table)) => Ok(Node::Table {
+            table,
+            inline_children: true,
+        }),
+        Item::Value(Value::Array(array)) => Ok(Node::Array(array)),
+        Item::None | Item::Value(_) => anyhow::bail!("value is not a table or array"),
+    }
+}
+
+fn new_table(inline: bool) -> Item {
+    if inline {
+        return Item::Value(Value::InlineTable(InlineTable::new()));
+    }
+    let mut table = Table::new();
+    table.set_implicit(true);
+    Item::Table(table)
+}
+
+/// Finds an entry by `name`, then unnamed entries by `preset`, then by index.
+fn entry_index(entries: &ArrayOfTables, segment: &str) -> Option<usize> {
+    let label =
+        |entry: &Table, field: &str| entry.get(field).and_then(Item::as_str).map(str::to_owned);
+    entries
+        .iter()
+        .position(|entry| label(entry, "name").as_deref() == Some(segment))
+        .or_else(|| {
+            entries.iter().position(|entry| {
+                label(entry, "name").is_none() && label(entry, "preset").as_deref() == Some(segment)
+            })
+        })
+        .or_else(|| array_len_index(entries.len(), segment))
+}
+
+fn array_index(array: &Array, segment: &str) -> Option<usize> {
+    array_len_index(array.len(), segment)
+}
+
+fn array_len_index(len: usize, segment: &str) -> Option<usize> {
+    segment.parse::<usize>().ok().filter(|index| *index < len)
+}
+
+fn render_value(value: &Value) -> String {
+    value.clone().decorated("", "").to_string()
+}
+
+fn replace_keeping_decor(existing: &mut Value, mut value: Value) {
+    *value.decor_mut() = existing.decor().clone();
+    *existing = value;
+}
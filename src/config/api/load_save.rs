@@ -4,13 +4,17 @@
 
 use anyhow::{Context, Result};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 
-use super::super::{Config, ContainerEngine, ProjectType, RawConfig, expansion, validation};
+use super::super::{
+    Config, ContainerEngine, ProjectType, RawConfig, expansion, layers, migrations, presets,
+    validation,
+};
 use super::project::ProjectRootPathOptions;
+use crate::output::{self, LogLevel, Persistence};
 
 pub type RawConfigPathOptions<'a> = ProjectRootPathOptions<'a>;
 pub type LoadConfigPathOptions<'a> = ProjectRootPathOptions<'a>;
-pub type SaveConfigPathOptions<'a> = ProjectRootPathOptions<'a>;
 
 /// Loads the configuration file using default discovery.
 ///
@@ -27,39 +31,105 @@ pub fn load_config() -> Result<Config> {
 ///
 /// Returns an error if path resolution, reading, or parsing fails.
 pub fn load_config_with(options: LoadConfigPathOptions<'_>) -> Result<Config> {
-    let raw_config = load_raw_config_with(options)?;
+    let mut raw_config = load_raw_config_with(options)?;
+    raw_config.user_preset = presets::load_user_presets()?;
     let mut config = expansion::expand_raw_config(raw_config)?;
     let project_root = super::project::project_root_with(ProjectRootPathOptions::new(
         options.config_path,
         options.project_root,
     ))?;
 
-    validation::validate_and_resolve_domains(&mut config, &project_root)?;
-    validation::validate_and_resolve_container_names(&mut config)?;
-    validation::validate_swarm_targets(&config)?;
+    validation::validate_config(&mut config, &project_root)?;
+
+    let owner_root = std::fs::canonicalize(&project_root).unwrap_or(project_root);
+    for service in &mut config.service {
+        service.resolved_project_root = Some(owner_root.clone());
+    }
 
     Ok(config)
 }
 
-/// Loads raw config with from persisted or external state.
+/// Loads raw config with its `extends` chain and local overlay merged in.
 pub(crate) fn load_raw_config_with(options: RawConfigPathOptions<'_>) -> Result<RawConfig> {
     let config_path = super::toml_io::resolve_config_path(options)?;
-    let mut raw: RawConfig =
-        super::toml_io::read_toml_file(&config_path, "config file", "TOML config file")?;
+    let mut document = layers::load_layered_document(&config_path)?;
+    if let Some(version) = migrations::migrate_table(&mut document.table)
+        .with_context(|| format!("failed to load {}", describe_layers(&document.files)))?
+    {
+        warn_schema_behind(&config_path, version);
+    }
+    let config_dir = config_path.parent().unwrap_or_else(|| Path::new("."));
+    let secret_fields = expansion::resolve_references(&mut document.table, config_dir)?;
+    let mut raw: RawConfig = toml::Value::Table(document.table)
+        .try_into()
+        .with_context(|| {
+            format!(
+                "failed to parse TOML config file at {}",
+                describe_layers(&document.files)
+            )
+        })?;
+    for (index, fields) in secret_fields {
+        if let Some(service) = raw.service.get_mut(index) {
+            service.secret_fields = fields;
+        }
+    }
     raw.project_type = Some(resolve_project_type(&raw, &config_path)?);
     Ok(raw)
 }
 
-/// Loads the configured container runtime engine from raw config.
+/// Warns once per process that the config was upgraded only in memory.
+fn warn_schema_behind(config_path: &Path, version: u32) {
+    static WARNED: AtomicBool = AtomicBool::new(false);
+    if WARNED.swap(true, Ordering::Relaxed) {
+        return;
+    }
+    output::event(
+        "config",
+        LogLevel::Warn,
+        &format!(
+            "{} uses schema_version {version}; the latest is {}. Run `helm config migrate` to update it",
+            config_path.display(),
+            migrations::CURRENT_SCHEMA_VERSION
+        ),
+        Persistence::Persistent,
+    );
+}
+
+fn describe_layers(files: &[PathBuf]) -> String {
+    files
+        .iter()
+        .map(|path| path.display().to_string())
+        .collect::<Vec<_>>()
+        .join(" + ")
+}
+
+/// Runtime engine settings read before the full config is expanded.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct EngineSettings {
+    /// Configured `container_engine`, if any.
+    pub container_engine: Option<ContainerEngine>,
+    /// Whether `podman_pod = true` is set.
+    pub podman_pod: bool,
+    /// Configured `engine_host` endpoint URL, if any.
+    pub engine_host: Option<String>,
+    /// Configured `engine_context` name, if any.
+    pub engine_context: Option<String>,
+}
+
+/// Loads the configured container runtime engine settings from raw config.
 ///
 /// # Errors
 ///
 /// Returns an error if path resolution, reading, or parsing fails.
-pub fn load_container_engine_with(
-    options: LoadConfigPathOptions<'_>,
-) -> Result<Option<ContainerEngine>> {
+pub fn load_engine_settings_with(options: LoadConfigPathOptions<'_>) -> Result<EngineSettings> {
     let raw = load_raw_config_with(options)?;
-    Ok(raw.container_engine)
+    Ok(EngineSettings {
+        container_engine: raw.container_engine,
+        podman_pod: raw.podman_pod.unwrap_or(false),
+        engine_host: raw.engine_host,
+        engine_context: raw.engine_context,
+    })
 }
 
 fn resolve_project_type(raw: &RawConfig, config_path: &Path) -> Result<ProjectType> {
@@ -120,20 +190,9 @@ fn resolve_project_type(raw: &RawConfig, config_path: &Path) -> Result<ProjectTy
     }
 }
 
-/// Saves configuration back to `.helm.toml`.
-///
-/// # Errors
-///
-/// Returns an error if the config path cannot be resolved or writing fails.
-pub fn save_config_with(config: &Config, options: SaveConfigPathOptions<'_>) -> Result<PathBuf> {
-    let path = super::toml_io::resolve_config_path(options)?;
-    super::toml_io::write_toml_file(&path, config, "config", "config file")?;
-    Ok(path)
-}
-
 #[cfg(test)]
 mod tests {
-    use super::{LoadConfigPathOptions, load_container_engine_with};
+    use super::{LoadConfigPathOptions, load_engine_settings_with};
     use crate::config::ContainerEngine;
     use std::fs;
     use std::path::PathBuf;
@@ -151,7 +210,7 @@ mod tests {
     }
 
     #[test]
-    fn load_container_engine_prefers_configured_value() {
+    fn load_engine_settings_prefers_configured_value() {
         let root = temp_root();
         let config_path = root.join(".helm.toml");
         fs::write(
@@ -160,40 +219,41 @@ mod tests {
         )
         .expect("write config");
 
-        let engine =
-            load_container_engine_with(LoadConfigPathOptions::new(Some(&config_path), None))
+        let settings =
+            load_engine_settings_with(LoadConfigPathOptions::new(Some(&config_path), None))
                 .expect("load engine");
 
-        assert_eq!(engine, Some(ContainerEngine::Podman));
+        assert_eq!(settings.container_engine, Some(ContainerEngine::Podman));
+        assert!(!settings.podman_pod);
     }
 
     #[test]
-    fn load_container_engine_detects_project_type_from_composer_type() {
+    fn load_engine_settings_detects_project_type_from_composer_type() {
         let root = temp_root();
         let config_path = root.join(".helm.toml");
         fs::write(
             &config_path,
-            "schema_version = 1\ncontainer_engine = \"podman\"\nservice = []\n",
+            "schema_version = 1\ncontainer_engine = \"podman\"\npodman_pod = true\nservice = []\n",
         )
         .expect("write config");
         fs::write(root.join("composer.json"), r#"{"type":"library"}"#).expect("write composer");
 
-        let engine =
-            load_container_engine_with(LoadConfigPathOptions::new(Some(&config_path), None))
+        let settings =
+            load_engine_settings_with(LoadConfigPathOptions::new(Some(&config_path), None))
                 .expect("load engine");
 
-        assert_eq!(engine, Some(ContainerEngine::Podman));
+        assert_eq!(settings.container_engine, Some(ContainerEngine::Podman));
+        assert!(settings.podman_pod);
     }
 
     #[test]
-    fn load_container_engine_fails_when_project_type_is_unresolved() {
+    fn load_engine_settings_fails_when_project_type_is_unresolved() {
         let root = temp_root();
         let config_path = root.join(".helm.toml");
         fs::write(&config_path, "schema_version = 1\nservice = []\n").expect("write config");
 
-        let error =
-            load_container_engine_with(LoadConfigPathOptions::new(Some(&config_path), None))
-                .expect_err("project type should be required");
+        let error = load_engine_settings_with(LoadConfigPathOptions::new(Some(&config_path), None))
+            .expect_err("project type should be required");
         assert!(error.to_string().contains("Unable to resolve project_type"));
     }
 }
@@ -1,46 +1,129 @@
 //! config api migrate module.
 //!
-//! Contains config api migrate logic used by Helm command workflows.
+//! Upgrades `.helm.toml` through the registered schema migrations, previewing
+//! the change as a unified diff or writing it after backing up the original.
 
-use anyhow::Result;
-use std::path::PathBuf;
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
 
-use super::super::{expansion, validation};
-use super::load_save::{
-    RawConfigPathOptions, SaveConfigPathOptions, load_raw_config_with, save_config_with,
-};
+use super::super::migrations;
+use super::edit::{check_document, read_document};
+use super::load_save::{LoadConfigPathOptions, load_config_with};
 use super::project::ProjectRootPathOptions;
 
 pub type MigrateConfigOptions<'a> = ProjectRootPathOptions<'a>;
 
-pub fn migrate_config_with(options: MigrateConfigOptions<'_>) -> Result<PathBuf> {
-    let mut raw = load_raw_config_with(RawConfigPathOptions::new(
-        options.config_path,
-        options.project_root,
-    ))?;
-    let version = raw.schema_version.unwrap_or(1);
-    if version > 1 {
-        anyhow::bail!("schema_version '{version}' is newer than this Helm build supports");
+/// Outcome of migrating (or previewing a migration of) one config file.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct ConfigMigration {
+    /// Migrated config file.
+    pub path: PathBuf,
+    /// Schema version before migrating.
+    pub from_version: u32,
+    /// Schema version after migrating.
+    pub to_version: u32,
+    /// Summaries of the migration steps that ran, in order.
+    pub applied: Vec<&'static str>,
+    /// Unified diff between the original and migrated file; empty when unchanged.
+    pub diff: String,
+    /// Copy of the original file, when one was written.
+    pub backup: Option<PathBuf>,
+}
+
+/// Computes the migration for the config file without writing anything.
+///
+/// # Errors
+///
+/// Returns an error if the config cannot be read, declares a newer schema
+/// version, or a migration step fails.
+pub fn preview_config_migration_with(options: MigrateConfigOptions<'_>) -> Result<ConfigMigration> {
+    Ok(plan_migration(options)?.0)
+}
+
+/// Migrates the config file to the latest schema version in place.
+///
+/// The original file is copied to `<file>.v<version>.bak` before writing, and
+/// restored when the migrated config fails to load.
+///
+/// # Errors
+///
+/// Returns an error if the config cannot be migrated, written, or loaded
+/// after migrating.
+pub fn migrate_config_with(options: MigrateConfigOptions<'_>) -> Result<ConfigMigration> {
+    let (mut migration, original, migrated) = plan_migration(options)?;
+    if migration.diff.is_empty() {
+        return Ok(migration);
     }
 
-    raw.schema_version = Some(1);
-    let mut config = expansion::expand_raw_config(raw)?;
-    let project_root = super::project::project_root_with(ProjectRootPathOptions::new(
-        options.config_path,
+    check_document(&migrated, &migration.path)?;
+    let backup = backup_path(&migration.path, migration.from_version);
+    std::fs::write(&backup, &original)
+        .with_context(|| format!("failed to write backup at {}", backup.display()))?;
+    std::fs::write(&migration.path, &migrated).with_context(|| {
+        format!(
+            "failed to write config file at {}",
+            migration.path.display()
+        )
+    })?;
+
+    let loaded = load_config_with(LoadConfigPathOptions::new(
+        Some(&migration.path),
         options.project_root,
-    ))?;
-    validation::validate_and_resolve_domains(&mut config, &project_root)?;
-    validation::validate_and_resolve_container_names(&mut config)?;
-    validation::validate_swarm_targets(&config)?;
-    save_config_with(
-        &config,
-        SaveConfigPathOptions::new(options.config_path, options.project_root),
-    )
+    ));
+    if let Err(error) = loaded {
+        std::fs::write(&migration.path, &original).with_context(|| {
+            format!(
+                "failed to restore {} from {}",
+                migration.path.display(),
+                backup.display()
+            )
+        })?;
+        return Err(error.context("migrated config failed to load; the original was restored"));
+    }
+
+    migration.backup = Some(backup);
+    Ok(migration)
+}
+
+fn plan_migration(options: MigrateConfigOptions<'_>) -> Result<(ConfigMigration, String, String)> {
+    let path = super::toml_io::resolve_config_path(options)?;
+    let original = std::fs::read_to_string(&path)
+        .with_context(|| format!("failed to read config file at {}", path.display()))?;
+    let mut document = read_document(&path)?;
+    let migrated = migrations::migrate_document(&mut document)
+        .with_context(|| format!("failed to migrate {}", path.display()))?;
+    let content = document.to_string();
+
+    let diff = if content == original {
+        String::new()
+    } else {
+        let label = path.display().to_string();
+        similar::TextDiff::from_lines(&original, &content)
+            .unified_diff()
+            .header(&label, &label)
+            .to_string()
+    };
+    let migration = ConfigMigration {
+        path,
+        from_version: migrated.from_version,
+        to_version: migrated.to_version,
+        applied: migrated.applied,
+        diff,
+        backup: None,
+    };
+    Ok((migration, original, content))
+}
+
+fn backup_path(path: &Path, version: u32) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(format!(".v{version}.bak"));
+    path.with_file_name(name)
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{ProjectRootPathOptions, migrate_config_with};
+    use super::{ProjectRootPathOptions, migrate_config_with, preview_config_migration_with};
     use crate::config::{LoadConfigPathOptions, load_config_with};
     use std::fs;
     use std::path::{Path, PathBuf};
@@ -144,4 +227,37 @@ preset = \"laravel\"
 
         Ok(())
     }
+
+    #[test]
+    fn preview_config_migration_with_returns_diff_without_writing() -> anyhow::Result<()> {
+        let root = temp_root();
+        let original = "# shared config\nproject_type = \"project\"\n";
+        let path = write_config(&root, original);
+
+        let preview =
+            preview_config_migration_with(ProjectRootPathOptions::new(Some(&path), None))?;
+
+        assert!(preview.diff.contains("+schema_version = 1"));
+        assert_eq!(preview.backup, None);
+        assert_eq!(fs::read_to_string(&path)?, original);
+        Ok(())
+    }
+
+    #[test]
+    fn migrate_config_with_backs_up_original_and_skips_current_configs() -> anyhow::Result<()> {
+        let root = temp_root();
+        let original = "# shared config\nproject_type = \"project\"\n";
+        let path = write_config(&root, original);
+
+        let migration = migrate_config_with(ProjectRootPathOptions::new(Some(&path), None))?;
+        let backup = migration.backup.expect("backup path");
+        assert_eq!(backup, root.join(".helm.toml.v1.bak"));
+        assert_eq!(fs::read_to_string(&backup)?, original);
+        assert!(fs::read_to_string(&path)?.starts_with("# shared config\n"));
+
+        let again = migrate_config_with(ProjectRootPathOptions::new(Some(&path), None))?;
+        assert!(again.diff.is_empty());
+        assert_eq!(again.backup, None);
+        Ok(())
+    }
 }
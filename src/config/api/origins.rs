@@ -0,0 +1,116 @@
+//! config api origins module.
+//!
+//! Reports which config layer supplied each merged value.
+
+use anyhow::Result;
+use serde::Serialize;
+use std::path::PathBuf;
+
+use super::super::layers;
+use super::project::ProjectRootPathOptions;
+
+pub type ConfigOriginPathOptions<'a> = ProjectRootPathOptions<'a>;
+
+/// One merged config value and the file that set it.
+#[derive(Debug, Clone, Serialize)]
+#[non_exhaustive]
+pub struct ConfigValueOrigin {
+    /// Dotted key path, with `[[service]]` entries keyed by service name.
+    pub key: String,
+    /// Value rendered as inline TOML.
+    pub value: String,
+    /// File that supplied the value.
+    pub source: PathBuf,
+}
+
+/// Lists every merged config value with the file it came from.
+///
+/// # Errors
+///
+/// Returns an error if the config or any layer cannot be read or parsed.
+pub fn config_value_origins(
+    options: ConfigOriginPathOptions<'_>,
+) -> Result<Vec<ConfigValueOrigin>> {
+    let config_path = super::toml_io::resolve_config_path(options)?;
+    let document = layers::load_layered_document(&config_path)?;
+
+    let mut values = Vec::new();
+    for (key, source) in document.origins {
+        let Some(value) = lookup(&document.table, &key) else {
+            continue;
+        };
+        values.push(ConfigValueOrigin {
+            key,
+            value: value.to_string(),
+            source,
+        });
+    }
+    Ok(values)
+}
+
+/// Resolves a dotted origin key, matching array entries by `name`/`preset`.
+fn lookup<'a>(table: &'a toml::Table, key: &str) -> Option<&'a toml::Value> {
+    let mut segments = key.split('.');
+    let mut current = table.get(segments.next()?)?;
+    for segment in segments {
+        current = match current {
+            toml::Value::Table(nested) => nested.get(segment)?,
+            toml::Value::Array(entries) => entries.iter().find(|entry| {
+                entry.as_table().is_some_and(|entry| {
+                    entry
+                        .get("name")
+                        .or_else(|| entry.get("preset"))
+                        .and_then(toml::Value::as_str)
+                        == Some(segment)
+                })
+            })?,
+            _ => return None,
+        };
+    }
+    Some(current)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ConfigOriginPathOptions, config_value_origins};
+    use std::fs;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    #[test]
+    fn config_value_origins_reports_overlay_values() {
+        let root = std::env::temp_dir().join(format!(
+            "helm-config-origins-{}",
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .expect("system clock")
+                .as_nanos()
+        ));
+        fs::create_dir_all(&root).expect("create temp root");
+        let config_path = root.join(".helm.toml");
+        fs::write(
+            &config_path,
+            "container_prefix = \"acme\"\n\n[[service]]\nname = \"db\"\npreset = \"mysql\"\n",
+        )
+        .expect("write config");
+        fs::write(
+            root.join(".helm.local.toml"),
+            "[[service]]\nname = \"db\"\nport = 4306\n",
+        )
+        .expect("write overlay");
+
+        let origins = config_value_origins(ConfigOriginPathOptions::new(Some(&config_path), None))
+            .expect("origins");
+        let port = origins
+            .iter()
+            .find(|origin| origin.key == "service.db.port")
+            .expect("port origin");
+
+        assert_eq!(port.value, "4306");
+        assert_eq!(port.source, root.join(".helm.local.toml"));
+        assert!(
+            origins
+                .iter()
+                .any(|origin| origin.key == "container_prefix" && origin.source == config_path)
+        );
+    }
+}
@@ -2,59 +2,114 @@
 //!
 //! Contains config api presets logic used by Helm command workflows.
 
+use super::super::{Config, RawServiceConfig, ServiceConfig, expansion, presets};
 use anyhow::Result;
 
-use super::super::{RawServiceConfig, ServiceConfig, expansion, presets};
-
 /// Returns all supported preset names.
 #[must_use]
 pub fn preset_names() -> Vec<&'static str> {
     presets::preset_names()
 }
 
-/// Resolves a preset into a default service config preview.
+/// Returns built-in preset names followed by project and user custom presets.
+///
+/// # Errors
+///
+/// Returns an error if a user preset file cannot be read or parsed.
+pub fn preset_names_with(config: &Config) -> Result<Vec<String>> {
+    let user_presets = presets::load_user_presets()?;
+    let catalog = presets::PresetCatalog::new(&config.preset, &user_presets);
+
+    let mut names: Vec<String> = preset_names().into_iter().map(str::to_owned).collect();
+    for name in catalog.names() {
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    Ok(names)
+}
+
+/// Resolves a built-in preset into a default service config preview.
 ///
 /// # Errors
 ///
 /// Returns an error if the preset is unknown.
-pub fn preset_preview(preset: &str) -> Result<ServiceConfig> {
-    expansion::expand_raw_service(RawServiceConfig {
-        preset: Some(preset.to_owned()),
-        name: None,
-        kind: None,
-        driver: None,
-        image: None,
-        host: None,
-        port: None,
-        database: None,
-        username: None,
-        password: None,
-        bucket: None,
-        access_key: None,
-        secret_key: None,
-        api_key: None,
-        region: None,
-        scheme: None,
-        domain: None,
-        domains: None,
-        container_port: None,
-        smtp_port: None,
-        volumes: None,
-        env: None,
-        command: None,
-        depends_on: None,
-        seed_file: None,
-        hook: Vec::new(),
-        health_path: None,
-        health_statuses: None,
-        localhost_tls: None,
-        octane: None,
-        octane_workers: None,
-        octane_max_requests: None,
-        php_extensions: None,
-        trust_container_ca: None,
-        env_mapping: None,
-        javascript: None,
-        container_name: None,
-    })
+#[cfg(test)]
+pub(crate) fn preset_preview(preset: &str) -> Result<ServiceConfig> {
+    let empty = std::collections::BTreeMap::new();
+    preview_with_catalog(preset, &presets::PresetCatalog::new(&empty, &empty))
+}
+
+/// Resolves a built-in or custom preset into a default service config preview.
+///
+/// # Errors
+///
+/// Returns an error if the preset is unknown or its extends chain is invalid.
+pub fn preset_preview_with(config: &Config, preset: &str) -> Result<ServiceConfig> {
+    let user_presets = presets::load_user_presets()?;
+    preview_with_catalog(
+        preset,
+        &presets::PresetCatalog::new(&config.preset, &user_presets),
+    )
+}
+
+fn preview_with_catalog(
+    preset: &str,
+    catalog: &presets::PresetCatalog<'_>,
+) -> Result<ServiceConfig> {
+    expansion::expand_raw_service(
+        RawServiceConfig {
+            preset: Some(preset.to_owned()),
+            name: None,
+            kind: None,
+            driver: None,
+            image: None,
+            host: None,
+            port: None,
+            database: None,
+            username: None,
+            password: None,
+            bucket: None,
+            access_key: None,
+            secret_key: None,
+            api_key: None,
+            region: None,
+            scheme: None,
+            domain: None,
+            domains: None,
+            container_port: None,
+            smtp_port: None,
+            volumes: None,
+            env: None,
+            command: None,
+            depends_on: None,
+            seed_file: None,
+            hook: Vec::new(),
+            health_path: None,
+            health_statuses: None,
+            health: None,
+            localhost_tls: None,
+            octane: None,
+            octane_workers: None,
+            octane_max_requests: None,
+            php_extensions: None,
+            trust_container_ca: None,
+            env_mapping: None,
+            javascript: None,
+            container_name: None,
+            memory: None,
+            cpus: None,
+            pids_limit: None,
+            shm_size: None,
+            ulimits: None,
+            tmpfs: None,
+            platform: None,
+            user: None,
+            cap_add: None,
+            restart: None,
+            build: None,
+            secret_fields: Vec::new(),
+        },
+        catalog,
+    )
 }
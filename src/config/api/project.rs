@@ -5,6 +5,7 @@
 use anyhow::Result;
 use std::path::{Path, PathBuf};
 
+use super::super::import::{self, ImportNote, ImportSource};
 use super::super::paths;
 
 #[derive(Clone, Copy)]
@@ -56,3 +57,54 @@ pub fn project_root_with(options: ProjectRootPathOptions<'_>) -> Result<PathBuf>
 pub fn init_config() -> Result<PathBuf> {
     paths::init_config()
 }
+
+/// Options for [`init_config_from`].
+#[derive(Clone, Copy)]
+#[non_exhaustive]
+pub struct InitFromOptions<'a> {
+    pub source: ImportSource,
+    /// Compose file to read; probes the current directory when unset.
+    pub file: Option<&'a Path>,
+}
+
+impl<'a> InitFromOptions<'a> {
+    pub fn new(source: ImportSource, file: Option<&'a Path>) -> Self {
+        Self { source, file }
+    }
+}
+
+/// Written config plus the migration report from an import.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct InitReport {
+    pub path: PathBuf,
+    /// Compose file the services were read from.
+    pub source_file: PathBuf,
+    pub notes: Vec<ImportNote>,
+}
+
+/// Creates `.helm.toml` in the current directory from a compose or Sail file.
+///
+/// # Errors
+///
+/// Returns an error when `.helm.toml` exists, or the compose file cannot be
+/// found, parsed, or mapped.
+pub fn init_config_from(options: InitFromOptions<'_>) -> Result<InitReport> {
+    let mut imported = None;
+    let path = paths::write_initial_config(|dir, project_name| {
+        let source_file = match options.file {
+            Some(file) => dir.join(file),
+            None => import::find_compose_file(dir)?,
+        };
+        let import::Imported { content, notes } =
+            import::import_compose(options.source, &source_file, project_name)?;
+        imported = Some((source_file, notes));
+        Ok(content)
+    })?;
+    let (source_file, notes) = imported.unwrap_or_default();
+    Ok(InitReport {
+        path,
+        source_file,
+        notes,
+    })
+}
@@ -72,6 +72,7 @@ mod tests {
             hook: Vec::new(),
             health_path: None,
             health_statuses: None,
+            health: None,
             localhost_tls: false,
             octane: false,
             octane_workers: None,
@@ -81,7 +82,21 @@ mod tests {
             env_mapping: None,
             javascript: None,
             container_name: None,
+            memory: None,
+            cpus: None,
+            pids_limit: None,
+            shm_size: None,
+            ulimits: None,
+            tmpfs: None,
+            platform: None,
+            user: None,
+            cap_add: None,
+            restart: None,
+            build: None,
             resolved_container_name: None,
+            resolved_project_root: None,
+            resolved_runtime_env: None,
+            secret_fields: Vec::new(),
         }
     }
 
@@ -94,6 +109,8 @@ mod tests {
             domain_strategy: None,
             service: vec![],
             swarm: vec![],
+            profile: std::collections::BTreeMap::new(),
+            preset: std::collections::BTreeMap::new(),
         };
         assert_eq!(preferred_sql_client_flavor(&config), "mysql");
     }
@@ -107,6 +124,8 @@ mod tests {
             domain_strategy: None,
             service: vec![sql_service("mysql:8.1")],
             swarm: vec![],
+            profile: std::collections::BTreeMap::new(),
+            preset: std::collections::BTreeMap::new(),
         };
         assert_eq!(preferred_sql_client_flavor(&config), "mysql");
     }
@@ -120,6 +139,8 @@ mod tests {
             domain_strategy: None,
             service: vec![sql_service("mariadb:11")],
             swarm: vec![],
+            profile: std::collections::BTreeMap::new(),
+            preset: std::collections::BTreeMap::new(),
         };
         assert_eq!(preferred_sql_client_flavor(&config), "mariadb");
     }
@@ -0,0 +1,22 @@
+//! config api validate module.
+//!
+//! Contains config api validate logic used by Helm command workflows.
+
+use anyhow::Result;
+
+use super::super::{ConfigDiagnostic, diagnostics};
+use super::project::ProjectRootPathOptions;
+
+pub type ValidateConfigPathOptions<'a> = ProjectRootPathOptions<'a>;
+
+/// Validates the config and its layers, returning every problem found.
+///
+/// # Errors
+///
+/// Returns an error only if the config file path cannot be resolved.
+pub fn validate_config_with(
+    options: ValidateConfigPathOptions<'_>,
+) -> Result<Vec<ConfigDiagnostic>> {
+    let config_path = super::toml_io::resolve_config_path(options)?;
+    Ok(diagnostics::collect_diagnostics(&config_path, options))
+}
@@ -0,0 +1,118 @@
+//! config diagnostics module.
+//!
+//! Collects every problem in a config and its layers at once, each with a
+//! file position, a severity, and a stable code for editor and CI tooling.
+
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+use super::ProjectRootPathOptions;
+
+mod checks;
+mod source;
+
+use source::SourceFile;
+
+/// Invalid TOML syntax.
+const CODE_SYNTAX: &str = "HELM001";
+/// Value has the wrong type or is not an allowed variant.
+const CODE_INVALID_VALUE: &str = "HELM002";
+/// Key is not recognized and would be ignored.
+const CODE_UNKNOWN_KEY: &str = "HELM003";
+/// Two services bind the same host port.
+const CODE_PORT_COLLISION: &str = "HELM004";
+/// `depends_on` names a service that does not exist.
+const CODE_UNKNOWN_DEPENDENCY: &str = "HELM005";
+/// Hook run type cannot be used in its phase.
+const CODE_HOOK_MISMATCH: &str = "HELM006";
+/// Config failed a load-time check (layers, references, presets, names, domains, ...).
+const CODE_INVALID_CONFIG: &str = "HELM007";
+
+/// Diagnostic severity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum DiagnosticSeverity {
+    /// The config cannot be used as written.
+    Error,
+    /// The config loads, but part of it is ignored or suspicious.
+    Warning,
+}
+
+/// One config problem located in a source file.
+#[derive(Debug, Clone, Serialize)]
+#[non_exhaustive]
+pub struct ConfigDiagnostic {
+    /// Stable diagnostic code such as `HELM004`.
+    pub code: &'static str,
+    /// Diagnostic severity.
+    pub severity: DiagnosticSeverity,
+    /// Human-readable description.
+    pub message: String,
+    /// File the problem was found in.
+    pub file: PathBuf,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column number.
+    pub column: usize,
+}
+
+impl ConfigDiagnostic {
+    fn error(code: &'static str, message: String, location: (PathBuf, usize, usize)) -> Self {
+        let (file, line, column) = location;
+        Self {
+            code,
+            severity: DiagnosticSeverity::Error,
+            message,
+            file,
+            line,
+            column,
+        }
+    }
+
+    fn warning(code: &'static str, message: String, location: (PathBuf, usize, usize)) -> Self {
+        Self {
+            severity: DiagnosticSeverity::Warning,
+            ..Self::error(code, message, location)
+        }
+    }
+}
+
+/// Validates `config_path` and every layer it merges, collecting all problems.
+pub(super) fn collect_diagnostics(
+    config_path: &Path,
+    options: ProjectRootPathOptions<'_>,
+) -> Vec<ConfigDiagnostic> {
+    let mut diagnostics = Vec::new();
+    let sources = super::layers::layer_paths(config_path)
+        .into_iter()
+        .filter_map(|path| SourceFile::read(&path, &mut diagnostics))
+        .collect::<Vec<_>>();
+    if diagnostics
+        .iter()
+        .any(|diagnostic| diagnostic.severity == DiagnosticSeverity::Error)
+    {
+        return diagnostics;
+    }
+
+    checks::check_config(options, &sources, config_path, &mut diagnostics);
+    diagnostics
+}
+
+/// Finds the most specific recorded position for a dotted key path.
+fn locate(sources: &[SourceFile], key: &str, fallback: &Path) -> (PathBuf, usize, usize) {
+    let mut candidate = key;
+    loop {
+        if let Some(location) = sources
+            .iter()
+            .rev()
+            .find_map(|source| source.locate(candidate))
+        {
+            return location;
+        }
+        match candidate.rsplit_once('.') {
+            Some((parent, _)) => candidate = parent,
+            None => return (fallback.to_path_buf(), 1, 1),
+        }
+    }
+}
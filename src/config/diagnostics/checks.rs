@@ -0,0 +1,158 @@
+//! config diagnostics checks module.
+//!
+//! Runs the load-time validations and cross-service checks on the merged
+//! config, turning each failure into a located diagnostic.
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use super::super::{
+    Config, HookPhase, HookRun, ProjectRootPathOptions, expansion, presets, validation,
+};
+use super::source::SourceFile;
+use super::{
+    CODE_HOOK_MISMATCH, CODE_INVALID_CONFIG, CODE_PORT_COLLISION, CODE_UNKNOWN_DEPENDENCY,
+    ConfigDiagnostic, locate,
+};
+
+/// Loads the merged config step by step, then checks it as a whole.
+pub(super) fn check_config(
+    options: ProjectRootPathOptions<'_>,
+    sources: &[SourceFile],
+    config_path: &Path,
+    diagnostics: &mut Vec<ConfigDiagnostic>,
+) {
+    let mut report = |error: &anyhow::Error| {
+        let location = error.downcast_ref::<validation::KeyedError>().map_or_else(
+            || (config_path.to_path_buf(), 1, 1),
+            |keyed| locate(sources, &keyed.key, config_path),
+        );
+        diagnostics.push(ConfigDiagnostic::error(
+            CODE_INVALID_CONFIG,
+            format!("{error:#}"),
+            location,
+        ));
+    };
+
+    let mut raw = match super::super::load_raw_config_with(options) {
+        Ok(raw) => raw,
+        Err(error) => return report(&error),
+    };
+    match presets::load_user_presets() {
+        Ok(user_presets) => raw.user_preset = user_presets,
+        Err(error) => report(&error),
+    }
+    let mut config = match expansion::expand_raw_config(raw) {
+        Ok(config) => config,
+        Err(error) => return report(&error),
+    };
+
+    let project_root = super::super::project_root_with(ProjectRootPathOptions::new(
+        options.config_path,
+        options.project_root,
+    ))
+    .unwrap_or_else(|_| config_path.parent().unwrap_or(Path::new(".")).to_path_buf());
+    for error in validation::validation_errors(&mut config, &project_root) {
+        report(&error);
+    }
+
+    let mut push = |code: &'static str, key: String, message: String| {
+        diagnostics.push(ConfigDiagnostic::error(
+            code,
+            message,
+            locate(sources, &key, config_path),
+        ));
+    };
+    check_port_collisions(&config, &mut push);
+    check_dependencies(&config, &mut push);
+    check_hooks(&config, &mut push);
+}
+
+/// Reports services that bind the same host port on overlapping addresses.
+fn check_port_collisions(config: &Config, push: &mut impl FnMut(&'static str, String, String)) {
+    let mut bound: HashMap<u16, Vec<(String, &str)>> = HashMap::new();
+    for service in &config.service {
+        let host = service.normalized_host_for_ports();
+        let ports = [
+            ("port", Some(service.port)),
+            ("smtp_port", service.smtp_port),
+        ];
+        for (field, port) in ports {
+            let Some(port) = port.filter(|port| *port != 0) else {
+                continue;
+            };
+            let owners = bound.entry(port).or_default();
+            if let Some((_, owner)) = owners.iter().find(|(owner_host, _)| {
+                *owner_host == host
+                    || super::super::is_unspecified_port_allocation_host(owner_host)
+                    || super::super::is_unspecified_port_allocation_host(&host)
+            }) {
+                push(
+                    CODE_PORT_COLLISION,
+                    format!("service.{}.{field}", service.name),
+                    format!(
+                        "service '{}' {field} {port} collides with service '{owner}' on host {host}",
+                        service.name
+                    ),
+                );
+            }
+            owners.push((host.clone(), service.name.as_str()));
+        }
+    }
+}
+
+/// Reports `depends_on` entries that name no configured service.
+fn check_dependencies(config: &Config, push: &mut impl FnMut(&'static str, String, String)) {
+    let names = config
+        .service
+        .iter()
+        .map(|service| service.name.as_str())
+        .collect::<HashSet<_>>();
+    for service in &config.service {
+        for (index, dependency) in service.depends_on.iter().flatten().enumerate() {
+            let dependency = dependency.service.as_str();
+            if !names.contains(dependency) {
+                push(
+                    CODE_UNKNOWN_DEPENDENCY,
+                    format!("service.{}.depends_on.{index}", service.name),
+                    format!(
+                        "service '{}' depends on unknown service '{dependency}'",
+                        service.name
+                    ),
+                );
+            }
+        }
+    }
+}
+
+/// Reports hooks whose run type cannot work in their phase.
+fn check_hooks(config: &Config, push: &mut impl FnMut(&'static str, String, String)) {
+    for service in &config.service {
+        for hook in &service.hook {
+            let HookRun::Exec { argv } = &hook.run else {
+                continue;
+            };
+            let key = format!("service.{}.hook.{}.run", service.name, hook.name);
+            if hook.phase == HookPhase::PostDown {
+                push(
+                    CODE_HOOK_MISMATCH,
+                    key,
+                    format!(
+                        "hook '{}' on service '{}' uses run.type=exec in post_down, \
+                         but the container is already stopped; use run.type=script",
+                        hook.name, service.name
+                    ),
+                );
+            } else if argv.is_empty() {
+                push(
+                    CODE_HOOK_MISMATCH,
+                    key,
+                    format!(
+                        "hook '{}' on service '{}' uses run.type=exec with an empty argv",
+                        hook.name, service.name
+                    ),
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,238 @@
+//! config diagnostics source module.
+//!
+//! Parses one config layer with spans, reporting syntax errors, invalid
+//! values, and unknown keys, and records key positions for later checks.
+
+use serde_json::Value;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use toml::de::{DeTable, DeValue};
+
+use super::{super::RawConfig, ConfigDiagnostic};
+use super::{CODE_INVALID_CONFIG, CODE_INVALID_VALUE, CODE_SYNTAX, CODE_UNKNOWN_KEY};
+
+/// One parsed config file with the positions of its keys.
+pub(super) struct SourceFile {
+    path: PathBuf,
+    content: String,
+    /// Dotted key paths, with array entries keyed by `name`/`preset`.
+    spans: Vec<(String, Range<usize>)>,
+}
+
+impl SourceFile {
+    /// Reads and checks one layer, pushing any problems into `diagnostics`.
+    pub(super) fn read(path: &Path, diagnostics: &mut Vec<ConfigDiagnostic>) -> Option<Self> {
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(error) => {
+                diagnostics.push(ConfigDiagnostic::error(
+                    CODE_INVALID_CONFIG,
+                    format!("failed to read config file: {error}"),
+                    (path.to_path_buf(), 1, 1),
+                ));
+                return None;
+            }
+        };
+        let mut source = Self {
+            path: path.to_path_buf(),
+            content,
+            spans: Vec::new(),
+        };
+
+        let (table, errors) = DeTable::parse_recoverable(&source.content);
+        for error in &errors {
+            diagnostics.push(ConfigDiagnostic::error(
+                CODE_SYNTAX,
+                error.message().to_owned(),
+                source.position(error.span().map_or(0, |span| span.start)),
+            ));
+        }
+
+        let schema = super::super::config_schema().ok();
+        let mut walker = Walker {
+            definitions: schema.as_ref().and_then(|schema| schema.get("definitions")),
+            spans: Vec::new(),
+            unknown: Vec::new(),
+        };
+        walker.walk_table(table.get_ref(), "", schema.as_ref());
+        for (key, span) in walker.unknown {
+            diagnostics.push(ConfigDiagnostic::warning(
+                CODE_UNKNOWN_KEY,
+                format!("unknown key '{key}' is ignored"),
+                source.position(span.start),
+            ));
+        }
+        source.spans = walker.spans;
+
+        if errors.is_empty() {
+            source.check_values(diagnostics);
+        }
+        Some(source)
+    }
+
+    /// Reports type and variant errors with their span.
+    fn check_values(&self, diagnostics: &mut Vec<ConfigDiagnostic>) {
+        let Ok(mut table) = self.content.parse::<toml::Table>() else {
+            return;
+        };
+        if super::super::expansion::has_references(&table) {
+            // References only type-check once resolved, which loses spans, so
+            // resolve them first and report value errors at the file start.
+            let config_dir = self.path.parent().unwrap_or_else(|| Path::new("."));
+            if super::super::expansion::resolve_references(&mut table, config_dir).is_err() {
+                return;
+            }
+            if let Err(error) = toml::Value::Table(table).try_into::<RawConfig>() {
+                diagnostics.push(ConfigDiagnostic::error(
+                    CODE_INVALID_VALUE,
+                    error.message().to_owned(),
+                    (self.path.clone(), 1, 1),
+                ));
+            }
+            return;
+        }
+
+        if let Err(error) = toml::from_str::<RawConfig>(&self.content) {
+            diagnostics.push(ConfigDiagnostic::error(
+                CODE_INVALID_VALUE,
+                error.message().to_owned(),
+                self.position(error.span().map_or(0, |span| span.start)),
+            ));
+        }
+    }
+
+    /// Returns the file position recorded for an exact dotted key path.
+    pub(super) fn locate(&self, key: &str) -> Option<(PathBuf, usize, usize)> {
+        self.spans
+            .iter()
+            .find(|(path, _)| path == key)
+            .map(|(_, span)| self.position(span.start))
+    }
+
+    /// Converts a byte offset into a 1-based line and column.
+    fn position(&self, offset: usize) -> (PathBuf, usize, usize) {
+        let before = self.content.get(..offset).unwrap_or(&self.content);
+        let line = before.matches('\n').count() + 1;
+        let column = before
+            .rsplit_once('\n')
+            .map_or(before, |(_, tail)| tail)
+            .chars()
+            .count()
+            + 1;
+        (self.path.clone(), line, column)
+    }
+}
+
+/// Walks a parsed document alongside the config JSON Schema.
+struct Walker<'s> {
+    definitions: Option<&'s Value>,
+    spans: Vec<(String, Range<usize>)>,
+    unknown: Vec<(String, Range<usize>)>,
+}
+
+impl<'s> Walker<'s> {
+    fn walk_table(&mut self, table: &DeTable<'_>, path: &str, schema: Option<&'s Value>) {
+        let candidates = self.object_schemas(schema);
+        for (key, value) in table {
+            let key_path = join(path, key.get_ref());
+            self.spans.push((key_path.clone(), key.span()));
+
+            let property = candidates.iter().find_map(|candidate| {
+                candidate
+                    .get("properties")
+                    .and_then(|properties| properties.get(key.get_ref().as_ref()))
+                    .or_else(|| {
+                        candidate
+                            .get("additionalProperties")
+                            .filter(|v| v.is_object())
+                    })
+            });
+            if property.is_none()
+                && candidates
+                    .iter()
+                    .any(|candidate| candidate.get("properties").is_some())
+            {
+                self.unknown.push((key_path.clone(), key.span()));
+            }
+            self.walk_value(value.get_ref(), &key_path, property);
+        }
+    }
+
+    fn walk_value(&mut self, value: &DeValue<'_>, path: &str, schema: Option<&'s Value>) {
+        match value {
+            DeValue::Table(table) => self.walk_table(table, path, schema),
+            DeValue::Array(items) => {
+                let item_schema = self
+                    .object_schemas(schema)
+                    .into_iter()
+                    .find_map(|candidate| candidate.get("items"));
+                for (index, item) in items.iter().enumerate() {
+                    let item_path = join(path, &entry_label(item.get_ref(), index));
+                    self.spans.push((item_path.clone(), item.span()));
+                    self.walk_value(item.get_ref(), &item_path, item_schema);
+                }
+            }
+            DeValue::String(_)
+            | DeValue::Integer(_)
+            | DeValue::Float(_)
+            | DeValue::Boolean(_)
+            | DeValue::Datetime(_) => {}
+        }
+    }
+
+    /// Resolves `$ref`, `anyOf`, and `oneOf` into the concrete schemas they allow.
+    fn object_schemas(&self, schema: Option<&'s Value>) -> Vec<&'s Value> {
+        let mut resolved = Vec::new();
+        let mut pending: Vec<&'s Value> = schema.into_iter().collect();
+        while let Some(current) = pending.pop() {
+            if let Some(reference) = current.get("$ref").and_then(Value::as_str) {
+                let name = reference.trim_start_matches("#/definitions/");
+                pending.extend(self.definitions.and_then(|defs| defs.get(name)));
+                continue;
+            }
+            let variants = ["anyOf", "oneOf"]
+                .iter()
+                .filter_map(|key| current.get(*key).and_then(Value::as_array))
+                .flatten()
+                .collect::<Vec<_>>();
+            if variants.is_empty() {
+                resolved.push(current);
+            } else {
+                pending.extend(variants);
+            }
+        }
+        resolved
+    }
+}
+
+/// Labels an array entry by its `name`/`preset` key, falling back to the index.
+fn entry_label(value: &DeValue<'_>, index: usize) -> String {
+    let DeValue::Table(table) = value else {
+        return index.to_string();
+    };
+    ["name", "preset"]
+        .iter()
+        .find_map(|field| {
+            table
+                .iter()
+                .find(|(key, _)| key.get_ref() == field)
+                .and_then(|(_, value)| match value.get_ref() {
+                    DeValue::String(label) => Some(label.to_string()),
+                    DeValue::Integer(_)
+                    | DeValue::Float(_)
+                    | DeValue::Boolean(_)
+                    | DeValue::Datetime(_)
+                    | DeValue::Array(_)
+                    | DeValue::Table(_) => None,
+                })
+        })
+        .unwrap_or_else(|| index.to_string())
+}
+
+fn join(prefix: &str, key: &str) -> String {
+    if prefix.is_empty() {
+        key.to_owned()
+    } else {
+        format!("{prefix}.{key}")
+    }
+}
@@ -4,22 +4,28 @@
 
 use anyhow::Result;
 
-use super::{Config, ProjectType, RawConfig, RawServiceConfig, ServiceConfig, SwarmTarget};
+use super::{
+    Config, ProjectType, RawConfig, RawServiceConfig, ServiceConfig, SwarmTarget, presets,
+};
 
 mod ports;
+mod references;
 mod service;
 
+pub(super) use references::{has_references, interpolate, read_env_file, resolve_references};
+
 pub(super) fn expand_raw_config(raw: RawConfig) -> Result<Config> {
     let schema_version = raw.schema_version.unwrap_or(1);
-    if schema_version != 1 {
+    if schema_version != super::migrations::CURRENT_SCHEMA_VERSION {
         anyhow::bail!("unsupported schema_version '{schema_version}'; run `helm config migrate`");
     }
     let project_type = raw.project_type.unwrap_or(ProjectType::Project);
+    let catalog = presets::PresetCatalog::new(&raw.preset, &raw.user_preset);
 
     let mut services: Vec<ServiceConfig> = raw
         .service
         .into_iter()
-        .map(expand_raw_service)
+        .map(|service| expand_raw_service(service, &catalog))
         .collect::<Result<Vec<_>>>()?;
 
     ports::assign_missing_ports(&mut services)?;
@@ -57,59 +63,89 @@ pub(super) fn expand_raw_config(raw: RawConfig) -> Result<Config> {
                 })
             })
             .collect::<Result<Vec<_>>>()?,
+        profile: raw.profile,
+        preset: raw.preset,
     })
 }
 
-pub(super) fn expand_raw_service(raw: RawServiceConfig) -> Result<ServiceConfig> {
-    service::expand_raw_service(raw)
+pub(super) fn expand_raw_service(
+    raw: RawServiceConfig,
+    catalog: &presets::PresetCatalog<'_>,
+) -> Result<ServiceConfig> {
+    service::expand_raw_service(presets::apply_custom_presets(raw, catalog)?)
+}
+
+/// Resolves the service name the loader gives `raw`, including custom presets.
+pub(super) fn resolve_service_name(
+    raw: RawServiceConfig,
+    catalog: &presets::PresetCatalog<'_>,
+) -> Result<String> {
+    service::resolve_service_name(&presets::apply_custom_presets(raw, catalog)?)
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{RawServiceConfig, expand_raw_service};
+    use super::{RawServiceConfig, expand_raw_service, presets};
     use crate::config::{Driver, Kind};
+    use std::collections::BTreeMap;
 
     #[test]
     fn expand_raw_service_preserves_octane_worker_settings() -> anyhow::Result<()> {
-        let service = expand_raw_service(RawServiceConfig {
-            preset: None,
-            name: Some("app".to_owned()),
-            kind: Some(Kind::App),
-            driver: Some(Driver::Frankenphp),
-            image: Some("dunglas/frankenphp:php8.5".to_owned()),
-            host: Some("127.0.0.1".to_owned()),
-            port: Some(8080),
-            database: None,
-            username: None,
-            password: None,
-            bucket: None,
-            access_key: None,
-            secret_key: None,
-            api_key: None,
-            region: None,
-            scheme: None,
-            domain: Some("acme.helm".to_owned()),
-            domains: None,
-            container_port: Some(80),
-            smtp_port: None,
-            volumes: None,
-            env: None,
-            command: None,
-            depends_on: None,
-            seed_file: None,
-            hook: Vec::new(),
-            health_path: None,
-            health_statuses: None,
-            localhost_tls: None,
-            octane: Some(true),
-            octane_workers: Some(6),
-            octane_max_requests: Some(500),
-            php_extensions: None,
-            trust_container_ca: None,
-            env_mapping: None,
-            javascript: None,
-            container_name: None,
-        })?;
+        let service = expand_raw_service(
+            RawServiceConfig {
+                preset: None,
+                name: Some("app".to_owned()),
+                kind: Some(Kind::App),
+                driver: Some(Driver::Frankenphp),
+                image: Some("dunglas/frankenphp:php8.5".to_owned()),
+                host: Some("127.0.0.1".to_owned()),
+                port: Some(8080),
+                database: None,
+                username: None,
+                password: None,
+                bucket: None,
+                access_key: None,
+                secret_key: None,
+                api_key: None,
+                region: None,
+                scheme: None,
+                domain: Some("acme.helm".to_owned()),
+                domains: None,
+                container_port: Some(80),
+                smtp_port: None,
+                volumes: None,
+                env: None,
+                command: None,
+                depends_on: None,
+                seed_file: None,
+                hook: Vec::new(),
+                health_path: None,
+                health_statuses: None,
+                health: None,
+                localhost_tls: None,
+                octane: Some(true),
+                octane_workers: Some(6),
+                octane_max_requests: Some(500),
+                php_extensions: None,
+                trust_container_ca: None,
+                env_mapping: None,
+                javascript: None,
+                container_name: None,
+                memory: None,
+                cpus: None,
+                pids_limit: None,
+                shm_size: None,
+                ulimits: None,
+                tmpfs: None,
+                platform: None,
+                user: None,
+                cap_add: None,
+                restart: None,
+                build: None,
+                secret_fields: Vec::new(),
+            },
+            &presets::PresetCatalog::new(&BTreeMap::new(), &BTreeMap::new()),
+        )?;
 
         assert!(service.octane);
         assert_eq!(service.octane_workers, Some(6));
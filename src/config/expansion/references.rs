@@ -0,0 +1,280 @@
+//! config expansion references module.
+//!
+//! Resolves `${VAR}` interpolation and `{ from = "env:..." | "file:..." }`
+//! secret references in `[[service]]` and `[preset.<name>]` values before the
+//! merged config document is deserialized.
+
+use anyhow::{Context, Result};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::config::validation::invalid;
+
+mod dotenv;
+mod interpolate;
+
+pub(in crate::config) use dotenv::read_env_file;
+pub(in crate::config) use interpolate::interpolate;
+
+/// Resolves references in the config document using the process environment,
+/// then the `.env` file in `config_dir`.
+///
+/// Returns, per `[[service]]` index, the field paths that were filled from
+/// secret references so they can be redacted from config output.
+pub(in crate::config) fn resolve_references(
+    table: &mut toml::Table,
+    config_dir: &Path,
+) -> Result<BTreeMap<usize, Vec<String>>> {
+    let dotenv = read_env_file(&config_dir.join(".env"));
+    resolve_references_with(table, &|name| {
+        std::env::var(name)
+            .ok()
+            .or_else(|| dotenv.get(name).cloned())
+    })
+}
+
+/// Returns whether the document uses interpolation or secret references.
+pub(in crate::config) fn has_references(table: &toml::Table) -> bool {
+    ["service", "preset"]
+        .iter()
+        .filter_map(|key| table.get(*key))
+        .any(value_has_references)
+}
+
+fn value_has_references(value: &toml::Value) -> bool {
+    match value {
+        toml::Value::String(text) => text.contains("${"),
+        toml::Value::Array(items) => items.iter().any(value_has_references),
+        toml::Value::Table(table) => {
+            secret_reference(table).is_some() || table.values().any(value_has_references)
+        }
+        _ => false,
+    }
+}
+
+fn resolve_references_with(
+    table: &mut toml::Table,
+    lookup: &dyn Fn(&str) -> Option<String>,
+) -> Result<BTreeMap<usize, Vec<String>>> {
+    if let Some(toml::Value::Table(presets)) = table.get_mut("preset") {
+        for (name, preset) in presets.iter_mut() {
+            let scope = Scope {
+                location: format!("preset '{name}'"),
+                key: format!("preset.{name}"),
+            };
+            let mut secrets = Vec::new();
+            resolve_value(preset, "", &scope, lookup, &mut secrets)?;
+            if let Some(field) = secrets.first() {
+                return Err(invalid(
+                    join(&scope.key, field),
+                    format!(
+                        "{} field '{field}' uses a secret reference; \
+                         secret references are only supported in [[service]] entries",
+                        scope.location
+                    ),
+                ));
+            }
+        }
+    }
+
+    let mut secret_fields = BTreeMap::new();
+    if let Some(toml::Value::Array(services)) = table.get_mut("service") {
+        for (index, service) in services.iter_mut().enumerate() {
+            let label = service_label(service, index);
+            let scope = Scope {
+                location: format!("service '{label}'"),
+                key: format!("service.{label}"),
+            };
+            let mut secrets = Vec::new();
+            resolve_value(service, "", &scope, lookup, &mut secrets)?;
+            if !secrets.is_empty() {
+                secret_fields.insert(index, secrets);
+            }
+        }
+    }
+
+    Ok(secret_fields)
+}
+
+/// Table entry being resolved, named for messages and as a config key.
+struct Scope {
+    location: String,
+    key: String,
+}
+
+fn resolve_value(
+    value: &mut toml::Value,
+    path: &str,
+    scope: &Scope,
+    lookup: &dyn Fn(&str) -> Option<String>,
+    secrets: &mut Vec<String>,
+) -> Result<()> {
+    match value {
+        toml::Value::String(text) => {
+            *text = interpolate(text, &describe(&scope.location, path), lookup)
+                .map_err(|error| invalid(join(&scope.key, path), format!("{error:#}")))?;
+        }
+        toml::Value::Array(items) => {
+            for (index, item) in items.iter_mut().enumerate() {
+                resolve_value(
+                    item,
+                    &join(path, &index.to_string()),
+                    scope,
+                    lookup,
+                    secrets,
+                )?;
+            }
+        }
+        toml::Value::Table(table) => {
+            if let Some(reference) = secret_reference(table) {
+                let resolved = resolve_secret(reference, &describe(&scope.location, path), lookup)
+                    .map_err(|error| invalid(join(&scope.key, path), format!("{error:#}")))?;
+                *value = toml::Value::String(resolved);
+                secrets.push(path.to_owned());
+                return Ok(());
+            }
+            for (key, nested) in table.iter_mut() {
+                resolve_value(nested, &join(path, key), scope, lookup, secrets)?;
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+/// Returns the `from` value of a `{ from = "..." }` inline table.
+fn secret_reference(table: &toml::Table) -> Option<&str> {
+    if table.len() != 1 {
+        return None;
+    }
+    table.get("from").and_then(toml::Value::as_str)
+}
+
+fn resolve_secret(
+    reference: &str,
+    location: &str,
+    lookup: &dyn Fn(&str) -> Option<String>,
+) -> Result<String> {
+    if let Some(name) = reference.strip_prefix("env:") {
+        return lookup(name).ok_or_else(|| {
+            anyhow::anyhow!("environment variable '{name}' referenced in {location} is not set")
+        });
+    }
+    if let Some(path) = reference.strip_prefix("file:") {
+        let path = expand_home(path, lookup);
+        let content = std::fs::read_to_string(&path).with_context(|| {
+            format!(
+                "failed to read secret file {} referenced in {location}",
+                path.display()
+            )
+        })?;
+        return Ok(content.trim_end_matches(['\r', '\n']).to_owned());
+    }
+
+    anyhow::bail!(
+        "unsupported secret reference '{reference}' in {location}; use 'env:<NAME>' or 'file:<PATH>'"
+    )
+}
+
+fn expand_home(path: &str, lookup: &dyn Fn(&str) -> Option<String>) -> PathBuf {
+    match (path.strip_prefix("~/"), lookup("HOME")) {
+        (Some(relative), Some(home)) => PathBuf::from(home).join(relative),
+        _ => PathBuf::from(path),
+    }
+}
+
+fn service_label(service: &toml::Value, index: usize) -> String {
+    service
+        .get("name")
+        .or_else(|| service.get("preset"))
+        .and_then(toml::Value::as_str)
+        .map_or_else(|| format!("#{}", index + 1), str::to_owned)
+}
+
+fn describe(location: &str, path: &str) -> String {
+    format!("{location} field '{path}'")
+}
+
+fn join(prefix: &str, key: &str) -> String {
+    if prefix.is_empty() {
+        key.to_owned()
+    } else {
+        format!("{prefix}.{key}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{has_references, resolve_references_with};
+
+    fn lookup(name: &str) -> Option<String> {
+        match name {
+            "DB_PASS" => Some("from-env".to_owned()),
+            "MYSQL_TAG" => Some("8.4".to_owned()),
+            "HOME" => Some(std::env::temp_dir().display().to_string()),
+            _ => None,
+        }
+    }
+
+    fn parse(content: &str) -> toml::Table {
+        content.parse::<toml::Table>().expect("parse document")
+    }
+
+    #[test]
+    fn resolve_references_interpolates_and_resolves_secrets() {
+        let secret_name = format!("helm-secret-{}", std::process::id());
+        std::fs::write(std::env::temp_dir().join(&secret_name), "from-file\n")
+            .expect("write secret file");
+        let mut table = parse(&format!(
+            r#"
+[[service]]
+name = "db"
+image = "mysql:${{MYSQL_TAG}}"
+password = {{ from = "env:DB_PASS" }}
+env = {{ MINIO_SECRET = {{ from = "file:~/{secret_name}" }}, REGION = "${{REGION:-eu}}" }}
+"#
+        ));
+
+        assert!(has_references(&table));
+        let secrets = resolve_references_with(&mut table, &lookup).expect("resolve references");
+        let service = &table["service"].as_array().expect("services")[0];
+
+        assert_eq!(service["image"].as_str(), Some("mysql:8.4"));
+        assert_eq!(service["password"].as_str(), Some("from-env"));
+        assert_eq!(service["env"]["MINIO_SECRET"].as_str(), Some("from-file"));
+        assert_eq!(service["env"]["REGION"].as_str(), Some("eu"));
+        assert_eq!(
+            secrets.get(&0),
+            Some(&vec!["env.MINIO_SECRET".to_owned(), "password".to_owned()])
+        );
+    }
+
+    #[test]
+    fn resolve_references_rejects_unknown_secret_scheme() {
+        let mut table = parse("[[service]]\nname = \"db\"\npassword = { from = \"vault:db\" }\n");
+        let error = resolve_references_with(&mut table, &lookup).expect_err("unknown scheme");
+        assert!(
+            error
+                .to_string()
+                .contains("unsupported secret reference 'vault:db'")
+        );
+    }
+
+    #[test]
+    fn resolve_references_rejects_secrets_in_presets() {
+        let mut table = parse("[preset.db]\npassword = { from = \"env:DB_PASS\" }\n");
+        let error = resolve_references_with(&mut table, &lookup).expect_err("preset secret");
+        assert!(
+            error
+                .to_string()
+                .contains("only supported in [[service]] entries")
+        );
+    }
+
+    #[test]
+    fn has_references_ignores_plain_documents() {
+        let table = parse("[[service]]\nname = \"db\"\npassword = \"secret\"\n");
+        assert!(!has_references(&table));
+    }
+}
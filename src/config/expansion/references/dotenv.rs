@@ -0,0 +1,31 @@
+//! config expansion references dotenv module.
+//!
+//! Reads `KEY=value` files such as a project `.env` for variable lookups.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Parses `KEY=value` lines; a missing or unreadable file yields no values.
+pub(in crate::config) fn read_env_file(path: &Path) -> HashMap<String, String> {
+    let Ok(text) = std::fs::read_to_string(path) else {
+        return HashMap::new();
+    };
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let line = line.strip_prefix("export ").unwrap_or(line);
+            let (key, value) = line.split_once('=')?;
+            let value = value.trim();
+            let value = ['"', '\'']
+                .iter()
+                .find_map(|quote| {
+                    value
+                        .strip_prefix(*quote)
+                        .and_then(|rest| rest.strip_suffix(*quote))
+                })
+                .unwrap_or(value);
+            Some((key.trim().to_owned(), value.to_owned()))
+        })
+        .collect()
+}
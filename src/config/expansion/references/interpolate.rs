@@ -0,0 +1,117 @@
+//! config expansion references interpolate module.
+//!
+//! Expands `${VAR}` and `${VAR:-default}` placeholders; `$$` escapes a literal `$`.
+
+use anyhow::Result;
+
+/// Interpolates placeholders in `input`, naming `location` in errors.
+pub(in crate::config) fn interpolate(
+    input: &str,
+    location: &str,
+    lookup: &dyn Fn(&str) -> Option<String>,
+) -> Result<String> {
+    if !input.contains('$') {
+        return Ok(input.to_owned());
+    }
+
+    let mut output = String::with_capacity(input.len());
+    let mut rest = input;
+    while let Some(index) = rest.find('$') {
+        let (literal, tail) = rest.split_at(index);
+        output.push_str(literal);
+
+        if let Some(after) = tail.strip_prefix("$$") {
+            output.push('$');
+            rest = after;
+            continue;
+        }
+        let Some(placeholder) = tail.strip_prefix("${") else {
+            output.push('$');
+            rest = tail.get(1..).unwrap_or_default();
+            continue;
+        };
+        let Some(end) = placeholder.find('}') else {
+            anyhow::bail!("unterminated '${{' placeholder in {location}");
+        };
+
+        let expression = placeholder.get(..end).unwrap_or_default();
+        let (name, default) = match expression.split_once(":-") {
+            Some((name, default)) => (name, Some(default)),
+            None => (expression, None),
+        };
+        if name.is_empty()
+            || !name
+                .chars()
+                .all(|ch| ch.is_ascii_alphanumeric() || ch == '_')
+        {
+            anyhow::bail!("invalid variable name '{name}' in {location}");
+        }
+
+        // Only the `:-` form treats an empty value as unset, as in POSIX shells.
+        match (lookup(name), default) {
+            (Some(value), Some(default)) if value.is_empty() => output.push_str(default),
+            (Some(value), _) => output.push_str(&value),
+            (None, Some(default)) => output.push_str(default),
+            (None, None) => {
+                anyhow::bail!("environment variable '{name}' referenced in {location} is not set")
+            }
+        }
+        rest = placeholder.get(end + 1..).unwrap_or_default();
+    }
+    output.push_str(rest);
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::interpolate;
+
+    fn lookup(name: &str) -> Option<String> {
+        match name {
+            "DB_USER" => Some("ci".to_owned()),
+            "EMPTY" => Some(String::new()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn interpolate_replaces_variables_and_defaults() {
+        let value =
+            interpolate("${DB_USER}:${DB_PASS:-secret}", "test", &lookup).expect("interpolate");
+        assert_eq!(value, "ci:secret");
+    }
+
+    #[test]
+    fn interpolate_treats_empty_variables_as_unset() {
+        let value = interpolate("${EMPTY:-fallback}", "test", &lookup).expect("interpolate");
+        assert_eq!(value, "fallback");
+    }
+
+    #[test]
+    fn interpolate_substitutes_empty_variables_without_default() {
+        let value = interpolate("[${EMPTY}]", "test", &lookup).expect("interpolate");
+        assert_eq!(value, "[]");
+    }
+
+    #[test]
+    fn interpolate_keeps_escaped_and_bare_dollars() {
+        let value = interpolate("$$HOME and $PATH", "test", &lookup).expect("interpolate");
+        assert_eq!(value, "$HOME and $PATH");
+    }
+
+    #[test]
+    fn interpolate_rejects_missing_variable_without_default() {
+        let error = interpolate("${MISSING}", "service 'db' field 'password'", &lookup)
+            .expect_err("missing variable should fail");
+        assert_eq!(
+            error.to_string(),
+            "environment variable 'MISSING' referenced in service 'db' field 'password' is not set"
+        );
+    }
+
+    #[test]
+    fn interpolate_rejects_unterminated_placeholder() {
+        assert!(interpolate("${DB_USER", "test", &lookup).is_err());
+    }
+}
@@ -8,13 +8,23 @@ use super::super::{RawServiceConfig, ServiceConfig, presets};
 use conflict::validate_preset_conflicts;
 use fields::{merge_opt_copy, merge_opt_owned, pick_required, value_or_default};
 use hooks::expand_hooks;
-use selection::resolve_name_and_image;
+use selection::{resolve_name, resolve_name_and_image};
 
 mod conflict;
 mod fields;
 mod hooks;
 mod selection;
 
+/// Resolves only the name `expand_raw_service` would give `raw`.
+pub(super) fn resolve_service_name(raw: &RawServiceConfig) -> Result<String> {
+    let defaults = match raw.preset.as_deref() {
+        Some(preset) => Some(presets::preset_defaults(preset)?),
+        None => None,
+    };
+    let driver = value_or_default(raw.driver, defaults.as_ref().map(|d| d.driver), "driver")?;
+    Ok(resolve_name(raw, defaults.as_ref(), driver))
+}
+
 pub(super) fn expand_raw_service(raw: RawServiceConfig) -> Result<ServiceConfig> {
     let preset = raw.preset.as_deref();
     let defaults = match raw.preset.as_deref() {
@@ -48,6 +58,7 @@ pub(super) fn expand_raw_service(raw: RawServiceConfig) -> Result<ServiceConfig>
         kind,
         driver,
         image,
+        build: raw.build,
         host: pick_required(
             raw.host,
             defaults.as_ref().map(|d| d.host),
@@ -89,6 +100,7 @@ pub(super) fn expand_raw_service(raw: RawServiceConfig) -> Result<ServiceConfig>
         health_statuses: raw
             .health_statuses
             .or_else(|| preset.and_then(presets::default_health_statuses_for_preset)),
+        health: raw.health,
         localhost_tls: raw.localhost_tls.unwrap_or(false),
         octane: raw
             .octane
@@ -110,6 +122,19 @@ pub(super) fn expand_raw_service(raw: RawServiceConfig) -> Result<ServiceConfig>
         env_mapping: raw.env_mapping,
         javascript: raw.javascript,
         container_name: raw.container_name,
+        memory: raw.memory,
+        cpus: raw.cpus,
+        pids_limit: raw.pids_limit,
+        shm_size: merge_opt_owned(raw.shm_size, defaults.as_ref().and_then(|d| d.shm_size)),
+        ulimits: raw.ulimits,
+        tmpfs: raw.tmpfs,
+        platform: raw.platform,
+        user: raw.user,
+        cap_add: raw.cap_add,
+        restart: merge_opt_owned(raw.restart, defaults.as_ref().and_then(|d| d.restart)),
         resolved_container_name: None,
+        resolved_project_root: None,
+        resolved_runtime_env: None,
+        secret_fields: raw.secret_fields,
     })
 }
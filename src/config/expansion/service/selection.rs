@@ -13,14 +13,29 @@ pub(super) fn resolve_name_and_image(
     defaults: Option<&presets::PresetDefaults>,
     driver: Driver,
 ) -> Result<(String, String)> {
-    let name = pick_required(
-        raw.name.clone(),
-        defaults.and_then(|d| d.name),
-        format!("{driver:?}").to_lowercase(),
-    );
-    let image = pick_with_default(raw.image.clone(), defaults.map(|d| d.image), || {
+    let name = resolve_name(raw, defaults, driver);
+    // A built service without an image is tagged under a Helm-owned name.
+    let image = raw.image.clone().or_else(|| {
+        raw.build
+            .as_ref()
+            .map(|_| format!("helm/{}", name.to_lowercase()))
+    });
+    let image = pick_with_default(image, defaults.map(|d| d.image), || {
         anyhow!("service '{name}' is missing image")
     })?;
 
     Ok((name, image))
 }
+
+/// Resolves the service name from `name`, the preset default, or the driver.
+pub(super) fn resolve_name(
+    raw: &RawServiceConfig,
+    defaults: Option<&presets::PresetDefaults>,
+    driver: Driver,
+) -> String {
+    pick_required(
+        raw.name.clone(),
+        defaults.and_then(|d| d.name),
+        format!("{driver:?}").to_lowercase(),
+    )
+}
@@ -0,0 +1,94 @@
+//! config import module.
+//!
+//! Converts docker-compose and Laravel Sail files into a starter `.helm.toml`.
+
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+
+mod mapping;
+mod render;
+#[cfg(test)]
+mod tests;
+
+/// File format `helm init --from` reads services from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ImportSource {
+    /// Generic docker-compose file.
+    Compose,
+    /// Laravel Sail `docker-compose.yml`; the Sail runtime becomes the `laravel` preset.
+    Sail,
+}
+
+/// One line of the migration report printed after an import.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ImportNote {
+    /// Compose service the note is about.
+    pub service: String,
+    /// What was mapped, carried over, or dropped.
+    pub message: String,
+}
+
+/// Rendered config plus the migration report.
+pub(in crate::config) struct Imported {
+    pub(in crate::config) content: String,
+    pub(in crate::config) notes: Vec<ImportNote>,
+}
+
+/// Compose file names probed, in order, when no file is given.
+const COMPOSE_FILE_NAMES: [&str; 4] = [
+    "docker-compose.yml",
+    "docker-compose.yaml",
+    "compose.yaml",
+    "compose.yml",
+];
+
+/// Returns the first compose file present in `dir`.
+pub(in crate::config) fn find_compose_file(dir: &Path) -> Result<PathBuf> {
+    COMPOSE_FILE_NAMES
+        .iter()
+        .map(|name| dir.join(name))
+        .find(|path| path.is_file())
+        .with_context(|| {
+            format!(
+                "no compose file found in {} (looked for {}); pass --file",
+                dir.display(),
+                COMPOSE_FILE_NAMES.join(", ")
+            )
+        })
+}
+
+/// Reads `compose_path` and renders the equivalent `.helm.toml`.
+///
+/// `${VAR}` placeholders resolve from the process environment, then from the
+/// `.env` file next to the compose file, like compose itself.
+pub(in crate::config) fn import_compose(
+    source: ImportSource,
+    compose_path: &Path,
+    project_name: &str,
+) -> Result<Imported> {
+    let text = std::fs::read_to_string(compose_path)
+        .with_context(|| format!("failed to read {}", compose_path.display()))?;
+    let document = crate::yaml::parse(&text)
+        .with_context(|| format!("failed to parse {}", compose_path.display()))?;
+    let dotenv = compose_path
+        .parent()
+        .map(|dir| super::expansion::read_env_file(&dir.join(".env")))
+        .unwrap_or_default();
+    let lookup = |name: &str| {
+        std::env::var(name)
+            .ok()
+            .or_else(|| dotenv.get(name).cloned())
+    };
+
+    let plan = mapping::map_services(source, &document, &lookup)?;
+    let file_name = compose_path.file_name().map_or_else(
+        || compose_path.display().to_string(),
+        |name| name.to_string_lossy().into_owned(),
+    );
+    Ok(Imported {
+        content: render::render(project_name, &file_name, &plan.services),
+        notes: plan.notes,
+    })
+}
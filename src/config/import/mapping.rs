@@ -0,0 +1,642 @@
+//! config import mapping module.
+//!
+//! Maps compose services onto Helm presets, falling back to custom drafts.
+
+use anyhow::{Result, bail};
+use std::collections::HashMap;
+
+use super::{ImportNote, ImportSource};
+use crate::config::Driver;
+use crate::config::domain_names::sanitize_project_slug;
+use crate::config::expansion::interpolate;
+use crate::config::presets::preset_defaults;
+use crate::config::service_methods::driver_default_port;
+use crate::yaml::Yaml;
+
+/// Services to render plus the migration report.
+pub(super) struct ImportPlan {
+    pub(super) services: Vec<ImportedService>,
+    pub(super) notes: Vec<ImportNote>,
+}
+
+/// One `[[service]]` entry; `preset: None` marks a custom draft.
+///
+/// `settings` and `env` hold config text with the compose file's `${VAR}`
+/// references intact, so values from `.env` are not baked into the config;
+/// other fields hold resolved values.
+#[derive(Debug, Default)]
+pub(super) struct ImportedService {
+    pub(super) name: String,
+    pub(super) preset: Option<&'static str>,
+    pub(super) image: Option<String>,
+    pub(super) port: Option<u16>,
+    pub(super) container_port: Option<u16>,
+    pub(super) smtp_port: Option<u16>,
+    pub(super) settings: Vec<(&'static str, String)>,
+    pub(super) env: Vec<(String, String)>,
+    pub(super) volumes: Vec<String>,
+    pub(super) command: Vec<String>,
+    pub(super) depends_on: Vec<(String, &'static str)>,
+}
+
+/// How a compose service is carried into Helm.
+#[derive(Clone, Copy)]
+enum Target {
+    /// The Sail runtime container, replaced by the `laravel` preset.
+    SailRuntime,
+    Preset(&'static str),
+    Custom,
+}
+
+/// SMTP port Mailpit and `MailHog` listen on inside the container.
+const MAIL_SMTP_PORT: u16 = 1025;
+
+pub(super) fn map_services(
+    source: ImportSource,
+    document: &Yaml,
+    lookup: &dyn Fn(&str) -> Option<String>,
+) -> Result<ImportPlan> {
+    let Some(Yaml::Map(definitions)) = document.get("services") else {
+        bail!("compose file has no `services` mapping");
+    };
+
+    let mut notes = Vec::new();
+    let mut names: HashMap<&str, (String, Target)> = HashMap::new();
+    let mut taken = Vec::new();
+    for (key, definition) in definitions {
+        let target = classify(source, key, definition, lookup);
+        let base = match target {
+            Target::SailRuntime => "app".to_owned(),
+            Target::Preset(_) | Target::Custom => sanitize_project_slug(key),
+        };
+        let name = unique_name(&base, &taken);
+        taken.push(name.clone());
+        names.insert(key, (name, target));
+    }
+
+    let mut services = Vec::new();
+    for (key, definition) in definitions {
+        let Some((name, target)) = names.get(key.as_str()) else {
+            continue;
+        };
+        let mut context = Resolver {
+            service: key,
+            lookup,
+            notes: &mut notes,
+            references: Vec::new(),
+        };
+        let mut service = match *target {
+            Target::SailRuntime => sail_runtime(&mut context),
+            Target::Preset(preset) => preset_service(&mut context, preset, definition)?,
+            Target::Custom => custom_service(&mut context, definition),
+        };
+        name.clone_into(&mut service.name);
+        if service.name != *key {
+            context.note(format!("renamed to `{}`", service.name));
+        }
+        let is_custom = matches!(target, Target::Custom);
+        service.depends_on = dependencies(&mut context, definition, &names, is_custom);
+        context.note_references();
+        services.push(service);
+    }
+
+    Ok(ImportPlan { services, notes })
+}
+
+/// Tracks the compose service being mapped and collects its report notes.
+struct Resolver<'a> {
+    service: &'a str,
+    lookup: &'a dyn Fn(&str) -> Option<String>,
+    notes: &'a mut Vec<ImportNote>,
+    /// Variables kept as `${VAR}` references in the rendered config.
+    references: Vec<String>,
+}
+
+impl Resolver<'_> {
+    fn note(&mut self, message: String) {
+        self.notes.push(ImportNote {
+            service: self.service.to_owned(),
+            message,
+        });
+    }
+
+    /// Interpolates `raw`, noting and dropping values that cannot resolve.
+    fn resolve(&mut self, raw: &str, field: &str) -> Option<String> {
+        let location = format!("`{field}`");
+        match interpolate(raw, &location, self.lookup) {
+            Ok(value) => Some(value),
+            Err(error) => {
+                self.note(format!("skipped {field}: {error}"));
+                None
+            }
+        }
+    }
+
+    /// Checks that `raw` resolves and returns it unresolved, keeping its
+    /// `${VAR}` references; unresolvable values are noted and dropped.
+    fn keep(&mut self, raw: &str, field: &str) -> Option<String> {
+        self.resolve(raw, field)?;
+        for name in referenced_variables(raw) {
+            if !self.references.iter().any(|known| known == name) {
+                self.references.push(name.to_owned());
+            }
+        }
+        Some(raw.to_owned())
+    }
+
+    fn note_references(&mut self) {
+        if self.references.is_empty() {
+            return;
+        }
+        let names = self
+            .references
+            .iter()
+            .map(|name| format!("`${{{name}}}`"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        self.note(format!(
+            "kept {names} as references; Helm reads them from the environment or the project \
+             `.env` when it loads the config"
+        ));
+    }
+
+    fn scalar(&mut self, definition: &Yaml, field: &str) -> Option<String> {
+        let raw = definition.get(field).and_then(Yaml::as_str)?;
+        self.resolve(raw, field)
+    }
+}
+
+fn classify(
+    source: ImportSource,
+    key: &str,
+    definition: &Yaml,
+    lookup: &dyn Fn(&str) -> Option<String>,
+) -> Target {
+    if source == ImportSource::Sail && is_sail_runtime(key, definition) {
+        return Target::SailRuntime;
+    }
+    definition
+        .get("image")
+        .and_then(Yaml::as_str)
+        .and_then(|image| interpolate(image, "image", lookup).ok())
+        .and_then(|image| preset_for_image(&image))
+        .map_or(Target::Custom, Target::Preset)
+}
+
+/// Sail names its runtime `laravel.test` and builds it from the Sail package.
+fn is_sail_runtime(key: &str, definition: &Yaml) -> bool {
+    key == "laravel.test"
+        || definition.get("build").is_some_and(|build| {
+            build
+                .as_str()
+                .or_else(|| build.get("context").and_then(Yaml::as_str))
+                .is_some_and(|context| context.contains("vendor/laravel/sail"))
+        })
+}
+
+/// Returns the preset for an image reference such as `mysql/mysql-server:8.0`.
+fn preset_for_image(image: &str) -> Option<&'static str> {
+    let reference = image.split('@').next().unwrap_or(image);
+    let repository = match reference.rsplit_once(':') {
+        Some((repository, tag)) if !tag.contains('/') => repository,
+        _ => reference,
+    };
+    let mut segments = repository.rsplit('/');
+    let name = segments.next()?;
+    let namespace = segments.next().unwrap_or_default();
+    let preset = match name {
+        "mysql" | "mysql-server" => "mysql",
+        "mariadb" => "mariadb",
+        "postgres" | "postgis" => "postgres",
+        "mongo" | "mongodb-community-server" => "mongodb",
+        "server" if namespace == "mssql" => "sqlserver",
+        "redis" => "redis",
+        "valkey" => "valkey",
+        "dragonfly" => "dragonfly",
+        "memcached" => "memcached",
+        "minio" => "minio",
+        "garage" => "garage",
+        "rustfs" => "rustfs",
+        "localstack" => "localstack",
+        "meilisearch" => "meilisearch",
+        "typesense" => "typesense",
+        "mailpit" => "mailpit",
+        "mailhog" => "mailhog",
+        "soketi" => "soketi",
+        "rabbitmq" => "rabbitmq",
+        "gotenberg" => "gotenberg",
+        _ if name.starts_with("standalone-") && matches!(namespace, "selenium" | "seleniarm") => {
+            "selenium"
+        }
+        _ => return None,
+    };
+    Some(preset)
+}
+
+fn sail_runtime(context: &mut Resolver<'_>) -> ImportedService {
+    context.note(
+        "mapped to preset `laravel`; the Sail image, ports and environment are replaced by \
+         Helm's runtime and inferred app env"
+            .to_owned(),
+    );
+    ImportedService {
+        preset: Some("laravel"),
+        ..ImportedService::default()
+    }
+}
+
+fn preset_service(
+    context: &mut Resolver<'_>,
+    preset: &'static str,
+    definition: &Yaml,
+) -> Result<ImportedService> {
+    let defaults = preset_defaults(preset)?;
+    context.note(format!("mapped to preset `{preset}`"));
+    let mut service = ImportedService {
+        preset: Some(preset),
+        ..ImportedService::default()
+    };
+    service.image = context
+        .scalar(definition, "image")
+        .filter(|image| image != defaults.image);
+
+    let container_port = defaults
+        .container_port
+        .unwrap_or_else(|| driver_default_port(defaults.driver));
+    let has_smtp = defaults.smtp_port.is_some();
+    for (host, target) in port_mappings(context, definition) {
+        match host {
+            Some(host) if target == container_port && service.port.is_none() => {
+                service.port = Some(host);
+            }
+            Some(host) if has_smtp && target == MAIL_SMTP_PORT && service.smtp_port.is_none() => {
+                service.smtp_port = Some(host);
+            }
+            Some(_) | None => context.note(format!(
+                "port {target} is not published by the `{preset}` preset"
+            )),
+        }
+    }
+
+    let credentials = credential_env(defaults.driver);
+    let managed = managed_env(defaults.driver);
+    let environment = environment(context, definition);
+    for (field, keys) in credentials {
+        if let Some(value) = keys.iter().find_map(|key| {
+            environment
+                .iter()
+                .find(|(name, _)| name == key)
+                .map(|(_, value)| value)
+        }) {
+            service.settings.push((field, value.clone()));
+        }
+    }
+    service.env = environment
+        .into_iter()
+        .filter(|(name, value)| {
+            !value.is_empty()
+                && !managed.contains(&name.as_str())
+                && !credentials
+                    .iter()
+                    .any(|(_, keys)| keys.contains(&name.as_str()))
+        })
+        .collect();
+
+    let volumes = definition
+        .get("volumes")
+        .map(Yaml::items)
+        .unwrap_or_default();
+    for volume in volumes.iter().filter_map(Yaml::as_str) {
+        if let Some(mount) = bind_mount(volume) {
+            context.note(format!(
+                "dropped bind mount `{mount}`; imports do not carry mounts onto presets, add it \
+                 to `volumes` by hand if the service needs it"
+            ));
+        }
+    }
+    let mut dropped: Vec<&str> = ["command", "entrypoint", "healthcheck"]
+        .into_iter()
+        .filter(|field| definition.get(field).is_some())
+        .collect();
+    if volumes
+        .iter()
+        .filter_map(Yaml::as_str)
+        .any(|volume| bind_mount(volume).is_none())
+    {
+        dropped.insert(0, "volumes");
+    }
+    if !dropped.is_empty() {
+        context.note(format!(
+            "not carried over: {} (the preset manages these)",
+            dropped.join(", ")
+        ));
+    }
+    Ok(service)
+}
+
+fn custom_service(context: &mut Resolver<'_>, definition: &Yaml) -> ImportedService {
+    let mut service = ImportedService {
+        image: context.scalar(definition, "image"),
+        ..ImportedService::default()
+    };
+    if service.image.is_none()
+        && let Some(build) = definition.get("build")
+    {
+        let build_context = build
+            .as_str()
+            .or_else(|| build.get("context").and_then(Yaml::as_str))
+            .unwrap_or(".");
+        context.note(format!(
+            "builds from `{build_context}`; set an image Helm can run"
+        ));
+    }
+    if let Some((host, target)) = port_mappings(context, definition).into_iter().next() {
+        service.port = host;
+        service.container_port = Some(target);
+    }
+    service.volumes = definition
+        .get("volumes")
+        .map(Yaml::items)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(Yaml::as_str)
+        .filter_map(|volume| context.resolve(volume, "volumes"))
+        .collect();
+    service.env = environment(context, definition);
+    service.command = match definition.get("command") {
+        Some(Yaml::Scalar(command)) => command.split_whitespace().map(str::to_owned).collect(),
+        Some(Yaml::List(items)) => items
+            .iter()
+            .filter_map(Yaml::as_str)
+            .map(str::to_owned)
+            .collect(),
+        Some(Yaml::Null | Yaml::Map(_)) | None => Vec::new(),
+    };
+    context.note(
+        "no matching preset; added as a commented-out service, set `kind` and `driver` to \
+         enable it"
+            .to_owned(),
+    );
+    service
+}
+
+/// Returns the mount for `./path:/target` style entries, `None` for named
+/// volumes.
+fn bind_mount(volume: &str) -> Option<&str> {
+    let (source, _) = volume.split_once(':')?;
+    (source.starts_with(['/', '.', '~']) || source.contains('/')).then_some(volume)
+}
+
+/// Returns the names of `${VAR}` placeholders in `raw`, skipping `$$`.
+fn referenced_variables(raw: &str) -> Vec<&str> {
+    let mut names = Vec::new();
+    let mut rest = raw;
+    while let Some(index) = rest.find('$') {
+        let tail = rest.get(index..).unwrap_or_default();
+        if let Some(after) = tail.strip_prefix("$$") {
+            rest = after;
+            continue;
+        }
+        let Some(placeholder) = tail.strip_prefix("${") else {
+            rest = tail.get(1..).unwrap_or_default();
+            continue;
+        };
+        let end = placeholder.find('}').unwrap_or(placeholder.len());
+        let expression = placeholder.get(..end).unwrap_or_default();
+        let name = expression
+            .split_once(":-")
+            .map_or(expression, |(name, _)| name);
+        names.push(name);
+        rest = placeholder.get(end..).unwrap_or_default();
+    }
+    names
+}
+
+/// Returns `(host, container)` pairs from short and long port syntax.
+fn port_mappings(context: &mut Resolver<'_>, definition: &Yaml) -> Vec<(Option<u16>, u16)> {
+    let entries = definition.get("ports").map(Yaml::items).unwrap_or_default();
+    let mut mappings = Vec::new();
+    for entry in entries {
+        let parsed = match entry {
+            Yaml::Scalar(raw) => context
+                .resolve(raw, "ports")
+                .and_then(|mapping| short_port(&mapping)),
+            Yaml::Map(_) => {
+                let target = entry.get("target").and_then(Yaml::as_str);
+                let published = entry
+                    .get("published")
+                    .and_then(Yaml::as_str)
+                    .and_then(|raw| context.resolve(raw, "ports"));
+                target.and_then(|target| {
+                    let host = match published {
+                        Some(host) => Some(host.parse().ok()?),
+                        None => None,
+                    };
+                    Some((host, target.parse().ok()?))
+                })
+            }
+            Yaml::Null | Yaml::List(_) => None,
+        };
+        match parsed {
+            Some(mapping) => mappings.push(mapping),
+            None => context.note("skipped a port mapping Helm cannot express".to_owned()),
+        }
+    }
+    mappings
+}
+
+/// Parses `[ip:][host:]container[/protocol]`; ranges are not supported.
+fn short_port(mapping: &str) -> Option<(Option<u16>, u16)> {
+    let mapping = mapping.split('/').next().unwrap_or(mapping);
+    let mut parts = mapping.rsplit(':');
+    let container = parts.next()?.parse().ok()?;
+    let host = match parts.next() {
+        Some(host) => Some(host.parse().ok()?),
+        None => None,
+    };
+    Some((host, container))
+}
+
+/// Reads `environment` in map or `KEY=value` list form, keeping `${VAR}`
+/// references.
+///
+/// Keys listed without a value pass through from the host, as in compose,
+/// and become `${KEY}` references.
+fn environment(context: &mut Resolver<'_>, definition: &Yaml) -> Vec<(String, String)> {
+    let raw: Vec<(String, Option<String>)> = match definition.get("environment") {
+        Some(Yaml::Map(entries)) => entries
+            .iter()
+            .map(|(key, value)| (key.clone(), value.as_str().map(str::to_owned)))
+            .collect(),
+        Some(Yaml::List(items)) => items
+            .iter()
+            .filter_map(Yaml::as_str)
+            .map(|item| match item.split_once('=') {
+                Some((key, value)) => (key.to_owned(), Some(value.to_owned())),
+                None => (item.to_owned(), None),
+            })
+            .collect(),
+        Some(Yaml::Null | Yaml::Scalar(_)) | None => Vec::new(),
+    };
+
+    let mut resolved = Vec::new();
+    for (key, value) in raw {
+        let field = format!("environment.{key}");
+        let value = match value {
+            Some(value) => context.keep(&value, &field),
+            None if (context.lookup)(&key).is_some() => {
+                context.keep(&format!("${{{key}}}"), &field)
+            }
+            None => {
+                context.note(format!("skipped {field}: not set in the environment"));
+                None
+            }
+        };
+        if let Some(value) = value {
+            resolved.push((key, value));
+        }
+    }
+    resolved
+}
+
+fn dependencies(
+    context: &mut Resolver<'_>,
+    definition: &Yaml,
+    names: &HashMap<&str, (String, Target)>,
+    keep_custom: bool,
+) -> Vec<(String, &'static str)> {
+    let declared: Vec<(&str, &'static str)> = match definition.get("depends_on") {
+        Some(Yaml::List(items)) => items
+            .iter()
+            .filter_map(Yaml::as_str)
+            .map(|name| (name, "started"))
+            .collect(),
+        Some(Yaml::Map(entries)) => entries
+            .iter()
+            .map(|(name, options)| {
+                let condition = match options.get("condition").and_then(Yaml::as_str) {
+                    Some("service_healthy") => "healthy",
+                    Some("service_completed_successfully") => "completed",
+                    Some(_) | None => "started",
+                };
+                (name.as_str(), condition)
+            })
+            .collect(),
+        Some(Yaml::Null | Yaml::Scalar(_)) | None => Vec::new(),
+    };
+
+    let mut dependencies = Vec::new();
+    for (key, condition) in declared {
+        match names.get(key) {
+            Some((name, Target::SailRuntime | Target::Preset(_))) => {
+                dependencies.push((name.clone(), condition));
+            }
+            Some((name, Target::Custom)) if keep_custom => {
+                dependencies.push((name.clone(), condition));
+            }
+            Some((_, Target::Custom)) | None => {
+                context.note(format!(
+                    "dropped dependency on `{key}`, which is not an active Helm service"
+                ));
+            }
+        }
+    }
+    dependencies
+}
+
+fn unique_name(base: &str, taken: &[String]) -> String {
+    let mut name = base.to_owned();
+    let mut suffix = 2_u32;
+    while taken.contains(&name) {
+        name = format!("{base}-{suffix}");
+        suffix = suffix.saturating_add(1);
+    }
+    name
+}
+
+/// Env vars Helm fills from service fields, as `(field, keys in priority order)`.
+const fn credential_env(driver: Driver) -> &'static [(&'static str, &'static [&'static str])] {
+    match driver {
+        Driver::Mysql => &[
+            ("database", &["MYSQL_DATABASE", "MARIADB_DATABASE"]),
+            ("username", &["MYSQL_USER", "MARIADB_USER"]),
+            (
+                "password",
+                &[
+                    "MYSQL_PASSWORD",
+                    "MARIADB_PASSWORD",
+                    "MYSQL_ROOT_PASSWORD",
+                    "MARIADB_ROOT_PASSWORD",
+                ],
+            ),
+        ],
+        Driver::Postgres => &[
+            ("database", &["POSTGRES_DB"]),
+            ("username", &["POSTGRES_USER"]),
+            ("password", &["POSTGRES_PASSWORD"]),
+        ],
+        Driver::Mongodb => &[
+            ("database", &["MONGO_INITDB_DATABASE"]),
+            ("username", &["MONGO_INITDB_ROOT_USERNAME"]),
+            ("password", &["MONGO_INITDB_ROOT_PASSWORD"]),
+        ],
+        Driver::Sqlserver => &[("password", &["MSSQL_SA_PASSWORD", "SA_PASSWORD"])],
+        Driver::Minio => &[
+            ("access_key", &["MINIO_ROOT_USER"]),
+            ("secret_key", &["MINIO_ROOT_PASSWORD"]),
+        ],
+        Driver::Meilisearch => &[("api_key", &["MEILI_MASTER_KEY"])],
+        Driver::Typesense => &[("api_key", &["TYPESENSE_API_KEY"])],
+        Driver::Rabbitmq => &[
+            ("username", &["RABBITMQ_DEFAULT_USER"]),
+            ("password", &["RABBITMQ_DEFAULT_PASS"]),
+        ],
+        Driver::Memcached
+        | Driver::Redis
+        | Driver::Valkey
+        | Driver::Dragonfly
+        | Driver::Garage
+        | Driver::Rustfs
+        | Driver::Localstack
+        | Driver::Frankenphp
+        | Driver::Reverb
+        | Driver::Horizon
+        | Driver::Scheduler
+        | Driver::Dusk
+        | Driver::Gotenberg
+        | Driver::Mailhog
+        | Driver::Soketi => &[],
+    }
+}
+
+/// Env vars Helm derives itself and drops on import.
+const fn managed_env(driver: Driver) -> &'static [&'static str] {
+    match driver {
+        Driver::Mysql => &[
+            "MYSQL_ALLOW_EMPTY_PASSWORD",
+            "MARIADB_ALLOW_EMPTY_ROOT_PASSWORD",
+        ],
+        Driver::Postgres => &["PGPASSWORD"],
+        Driver::Sqlserver => &["ACCEPT_EULA"],
+        Driver::Mongodb
+        | Driver::Memcached
+        | Driver::Redis
+        | Driver::Valkey
+        | Driver::Dragonfly
+        | Driver::Minio
+        | Driver::Garage
+        | Driver::Rustfs
+        | Driver::Localstack
+        | Driver::Meilisearch
+        | Driver::Typesense
+        | Driver::Frankenphp
+        | Driver::Reverb
+        | Driver::Horizon
+        | Driver::Scheduler
+        | Driver::Dusk
+        | Driver::Gotenberg
+        | Driver::Mailhog
+        | Driver::Rabbitmq
+        | Driver::Soketi => &[],
+    }
+}
@@ -0,0 +1,120 @@
+//! config import render module.
+//!
+//! Writes imported services as `.helm.toml` content.
+
+use toml_edit::{Array, ArrayOfTables, DocumentMut, InlineTable, Item, Table, Value, value};
+
+use super::mapping::ImportedService;
+use crate::config::domain_names::sanitize_project_slug;
+
+/// Renders preset services as `[[service]]` tables and custom drafts as
+/// commented-out blocks after them.
+pub(super) fn render(project_name: &str, file_name: &str, services: &[ImportedService]) -> String {
+    let mut document = DocumentMut::new();
+    document.insert("schema_version", value(1));
+    document.insert("project_type", value("project"));
+    document.insert("container_engine", value("docker"));
+    document.insert(
+        "container_prefix",
+        value(sanitize_project_slug(project_name)),
+    );
+    document.insert("domain_strategy", value("directory"));
+
+    let mut active = ArrayOfTables::new();
+    for service in services.iter().filter(|service| service.preset.is_some()) {
+        active.push(service_table(service));
+    }
+    if !active.is_empty() {
+        document.insert("service", Item::ArrayOfTables(active));
+    }
+
+    let mut output = format!("# Imported from {file_name} by `helm init --from`.\n");
+    output.push_str(&document.to_string());
+    for service in services.iter().filter(|service| service.preset.is_none()) {
+        let mut draft = DocumentMut::new();
+        let mut tables = ArrayOfTables::new();
+        tables.push(service_table(service));
+        draft.insert("service", Item::ArrayOfTables(tables));
+
+        output.push_str(&format!(
+            "\n# No preset matches `{}`; set kind and driver, then uncomment.\n",
+            service.name
+        ));
+        for line in draft.to_string().lines().filter(|line| !line.is_empty()) {
+            output.push_str("# ");
+            output.push_str(line);
+            output.push('\n');
+        }
+    }
+    output
+}
+
+fn service_table(service: &ImportedService) -> Table {
+    let mut table = Table::new();
+    match service.preset {
+        Some(preset) => {
+            table.insert("preset", value(preset));
+            table.insert("name", value(&service.name));
+        }
+        None => {
+            table.insert("name", value(&service.name));
+            table.insert("kind", value(""));
+            table.insert("driver", value(""));
+        }
+    }
+    if let Some(image) = &service.image {
+        table.insert("image", value(escape(image)));
+    }
+    for (key, port) in [
+        ("port", service.port),
+        ("container_port", service.container_port),
+        ("smtp_port", service.smtp_port),
+    ] {
+        if let Some(port) = port {
+            table.insert(key, value(i64::from(port)));
+        }
+    }
+    for (field, setting) in &service.settings {
+        table.insert(field, value(setting));
+    }
+    if !service.volumes.is_empty() {
+        table.insert("volumes", value(strings(&service.volumes)));
+    }
+    if !service.command.is_empty() {
+        table.insert("command", value(strings(&service.command)));
+    }
+    if !service.depends_on.is_empty() {
+        let dependencies: Array = service
+            .depends_on
+            .iter()
+            .map(|(name, condition)| {
+                if *condition == "started" {
+                    Value::from(name.as_str())
+                } else {
+                    let mut dependency = InlineTable::new();
+                    dependency.insert("service", Value::from(name.as_str()));
+                    dependency.insert("condition", Value::from(*condition));
+                    Value::InlineTable(dependency)
+                }
+            })
+            .collect();
+        table.insert("depends_on", value(dependencies));
+    }
+    if !service.env.is_empty() {
+        let mut env = Table::new();
+        for (key, setting) in &service.env {
+            env.insert(key, value(setting));
+        }
+        table.insert("env", Item::Table(env));
+    }
+    table
+}
+
+fn strings(values: &[String]) -> Array {
+    values.iter().map(|item| escape(item)).collect()
+}
+
+/// Escapes `$` so Helm's own `${VAR}` interpolation keeps resolved values.
+fn escape(text: &str) -> String {
+    text.replace('$', "$$")
+}
@@ -0,0 +1,312 @@
+//! config import tests module.
+//!
+//! Imports Sail and compose fixtures and checks the rendered config loads.
+
+use std::fs;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use super::mapping::map_services;
+use super::{ImportSource, import_compose, render};
+use crate::config::{
+    Config, Driver, Kind, LoadConfigPathOptions, RawConfig, expansion, load_config_with,
+};
+
+const SAIL_COMPOSE: &str = r"
+services:
+    laravel.test:
+        build:
+            context: './vendor/laravel/sail/runtimes/8.4'
+            dockerfile: Dockerfile
+            args:
+                WWWGROUP: '${WWWGROUP}'
+        image: 'sail-8.4/app'
+        extra_hosts:
+            - 'host.docker.internal:host-gateway'
+        ports:
+            - '${APP_PORT:-80}:80'
+            - '${VITE_PORT:-5173}:${VITE_PORT:-5173}'
+        environment:
+            WWWUSER: '${WWWUSER}'
+            LARAVEL_SAIL: 1
+        volumes:
+            - '.:/var/www/html'
+        networks:
+            - sail
+        depends_on:
+            - mysql
+            - redis
+            - meilisearch
+            - mailpit
+            - selenium
+    mysql:
+        image: 'mysql/mysql-server:8.0'
+        ports:
+            - '${FORWARD_DB_PORT:-3306}:3306'
+        environment:
+            MYSQL_ROOT_PASSWORD: '${DB_PASSWORD}'
+            MYSQL_ROOT_HOST: '%'
+            MYSQL_DATABASE: '${DB_DATABASE}'
+            MYSQL_USER: '${DB_USERNAME}'
+            MYSQL_PASSWORD: '${DB_PASSWORD}'
+            MYSQL_ALLOW_EMPTY_PASSWORD: 1
+        volumes:
+            - 'sail-mysql:/var/lib/mysql'
+            - './vendor/laravel/sail/database/mysql/create-testing-database.sh:/docker-entrypoint-initdb.d/10-create-testing-database.sh'
+        networks:
+            - sail
+        healthcheck:
+            test:
+                - CMD
+                - mysqladmin
+                - ping
+                - '-p${DB_PASSWORD}'
+            retries: 3
+            timeout: 5s
+    redis:
+        image: 'redis:alpine'
+        ports:
+            - '${FORWARD_REDIS_PORT:-6379}:6379'
+        volumes:
+            - 'sail-redis:/data'
+    meilisearch:
+        image: 'getmeili/meilisearch:latest'
+        ports:
+            - '${FORWARD_MEILISEARCH_PORT:-7700}:7700'
+        environment:
+            MEILI_NO_ANALYTICS: '${MEILISEARCH_NO_ANALYTICS:-false}'
+    mailpit:
+        image: 'axllent/mailpit:latest'
+        ports:
+            - '${FORWARD_MAILPIT_PORT:-1025}:1025'
+            - '${FORWARD_MAILPIT_DASHBOARD_PORT:-8025}:8025'
+    selenium:
+        image: selenium/standalone-chromium
+        extra_hosts:
+            - 'host.docker.internal:host-gateway'
+        volumes:
+            - '/dev/shm:/dev/shm'
+networks:
+    sail:
+        driver: bridge
+volumes:
+    sail-mysql:
+        driver: local
+    sail-redis:
+        driver: local
+";
+
+fn sail_env(name: &str) -> Option<String> {
+    match name {
+        "DB_DATABASE" => Some("shop".to_owned()),
+        "DB_USERNAME" => Some("sail".to_owned()),
+        "DB_PASSWORD" => Some("password".to_owned()),
+        "FORWARD_DB_PORT" => Some("3307".to_owned()),
+        _ => None,
+    }
+}
+
+fn load(content: &str) -> Config {
+    let raw: RawConfig = toml::from_str(content).expect("rendered config parses");
+    expansion::expand_raw_config(raw).expect("rendered config expands")
+}
+
+#[test]
+fn sail_fixture_maps_onto_presets_and_loads() {
+    let document = crate::yaml::parse(SAIL_COMPOSE).expect("sail compose parses");
+    let plan = map_services(ImportSource::Sail, &document, &sail_env).expect("maps services");
+    let content = render::render("Shop", "docker-compose.yml", &plan.services);
+    let config = load(&content);
+
+    let names: Vec<&str> = config.service.iter().map(|svc| svc.name.as_str()).collect();
+    assert_eq!(
+        names,
+        [
+            "app",
+            "mysql",
+            "redis",
+            "meilisearch",
+            "mailpit",
+            "selenium"
+        ]
+    );
+    assert_eq!(config.container_prefix.as_deref(), Some("shop"));
+
+    let app = config.service.first().expect("app service");
+    assert_eq!(app.kind, Kind::App);
+    assert_eq!(app.driver, Driver::Frankenphp);
+    let dependencies: Vec<&str> = app
+        .depends_on
+        .iter()
+        .flatten()
+        .map(|dependency| dependency.service.as_str())
+        .collect();
+    assert_eq!(
+        dependencies,
+        ["mysql", "redis", "meilisearch", "mailpit", "selenium"]
+    );
+
+    let mysql = config
+        .service
+        .iter()
+        .find(|svc| svc.name == "mysql")
+        .expect("mysql service");
+    assert_eq!(mysql.driver, Driver::Mysql);
+    assert_eq!(mysql.image, "mysql/mysql-server:8.0");
+    assert_eq!(mysql.port, 3307);
+    assert_eq!(mysql.database.as_deref(), Some("${DB_DATABASE}"));
+    assert_eq!(mysql.username.as_deref(), Some("${DB_USERNAME}"));
+    assert_eq!(mysql.password.as_deref(), Some("${DB_PASSWORD}"));
+    assert!(!content.contains("\"password\""), "{content}");
+    let env = mysql.env.as_ref().expect("carried env");
+    assert_eq!(env.get("MYSQL_ROOT_HOST").map(String::as_str), Some("%"));
+    assert!(!env.contains_key("MYSQL_PASSWORD"));
+    assert!(!env.contains_key("MYSQL_ALLOW_EMPTY_PASSWORD"));
+
+    let mailpit = config
+        .service
+        .iter()
+        .find(|svc| svc.name == "mailpit")
+        .expect("mailpit service");
+    assert_eq!(mailpit.port, 8025);
+    assert_eq!(mailpit.smtp_port, Some(1025));
+
+    assert!(plan.notes.iter().any(|note| {
+        note.service == "laravel.test" && note.message.contains("preset `laravel`")
+    }));
+    assert!(
+        plan.notes
+            .iter()
+            .any(|note| note.service == "redis" && note.message.contains("volumes"))
+    );
+    let mysql_notes: Vec<&str> = plan
+        .notes
+        .iter()
+        .filter(|note| note.service == "mysql")
+        .map(|note| note.message.as_str())
+        .collect();
+    assert!(
+        mysql_notes.iter().any(|note| note.starts_with(
+            "dropped bind mount `./vendor/laravel/sail/database/mysql/create-testing-database.sh:"
+        )),
+        "{mysql_notes:?}"
+    );
+    assert!(
+        mysql_notes
+            .iter()
+            .any(|note| note.contains("`${DB_PASSWORD}`") && note.contains("environment")),
+        "{mysql_notes:?}"
+    );
+}
+
+#[test]
+fn unknown_services_become_commented_drafts() {
+    let compose = r#"
+services:
+  web:
+    image: "nginx:1.27"
+    ports: ["8080:80"]
+    environment:
+      - GREETING=$${HOME}
+    depends_on:
+      db:
+        condition: service_healthy
+      worker:
+        condition: service_started
+  db:
+    image: postgres:17
+    environment:
+      POSTGRES_PASSWORD: ${PG_PASSWORD:-secret}
+  worker:
+    build: ./worker
+    command: php artisan queue:work
+    depends_on: [db]
+"#;
+    let document = crate::yaml::parse(compose).expect("compose parses");
+    let plan = map_services(ImportSource::Compose, &document, &|_| None).expect("maps services");
+    let content = render::render("acme", "compose.yaml", &plan.services);
+
+    assert!(content.contains("# name = \"web\""));
+    assert!(content.contains("# image = \"nginx:1.27\""));
+    assert!(
+        content
+            .contains("# depends_on = [{ service = \"db\", condition = \"healthy\" }, \"worker\"]")
+    );
+    assert!(content.contains("# GREETING = \"$${HOME}\""));
+    assert!(content.contains("# command = [\"php\", \"artisan\", \"queue:work\"]"));
+
+    let config = load(&content);
+    assert_eq!(config.service.len(), 1);
+    let db = config.service.first().expect("db service");
+    assert_eq!(db.driver, Driver::Postgres);
+    assert_eq!(db.password.as_deref(), Some("${PG_PASSWORD:-secret}"));
+    assert!(
+        plan.notes
+            .iter()
+            .any(|note| note.service == "worker" && note.message.contains("./worker"))
+    );
+}
+
+#[test]
+fn import_reads_dotenv_next_to_the_compose_file() {
+    let dir = std::env::temp_dir().join(format!(
+        "helm-import-{}",
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("system clock")
+            .as_nanos()
+    ));
+    fs::create_dir_all(&dir).expect("create dir");
+    fs::write(dir.join(".env"), "HELM_IMPORT_TEST_PORT=\"6380\"\n").expect("write env");
+    let compose = dir.join("compose.yaml");
+    fs::write(
+        &compose,
+        "services:\n  cache:\n    image: valkey/valkey:8\n    ports:\n      - \"${HELM_IMPORT_TEST_PORT}:6379\"\n",
+    )
+    .expect("write compose");
+
+    let found = super::find_compose_file(&dir).expect("finds compose.yaml");
+    let imported = import_compose(ImportSource::Compose, &found, "acme").expect("imports");
+    fs::remove_dir_all(&dir).ok();
+
+    let config = load(&imported.content);
+    let cache = config.service.first().expect("cache service");
+    assert_eq!(cache.driver, Driver::Valkey);
+    assert_eq!(cache.port, 6380);
+}
+
+#[test]
+fn imported_sail_config_loads_with_the_project_dotenv() {
+    let dir = std::env::temp_dir().join(format!(
+        "helm-import-sail-{}",
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("system clock")
+            .as_nanos()
+    ));
+    fs::create_dir_all(&dir).expect("create dir");
+    fs::write(
+        dir.join(".env"),
+        "DB_DATABASE=shop\nDB_USERNAME=sail\nDB_PASSWORD=password\nFORWARD_DB_PORT=3307\n",
+    )
+    .expect("write env");
+    let compose = dir.join("docker-compose.yml");
+    fs::write(&compose, SAIL_COMPOSE).expect("write compose");
+
+    let imported = import_compose(ImportSource::Sail, &compose, "shop").expect("imports");
+    let config_path = dir.join(".helm.toml");
+    fs::write(&config_path, &imported.content).expect("write config");
+    let loaded = load_config_with(LoadConfigPathOptions::new(Some(&config_path), None));
+    fs::remove_dir_all(&dir).ok();
+
+    let config = loaded.expect("imported config loads");
+    let mysql = config
+        .service
+        .iter()
+        .find(|svc| svc.name == "mysql")
+        .expect("mysql service");
+    assert_eq!(mysql.database.as_deref(), Some("shop"));
+    assert_eq!(mysql.username.as_deref(), Some("sail"));
+    assert_eq!(mysql.password.as_deref(), Some("password"));
+    assert_eq!(mysql.port, 3307);
+    assert!(!imported.content.contains("\"password\""));
+}
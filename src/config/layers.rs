@@ -0,0 +1,252 @@
+//! config layers module.
+//!
+//! Resolves `extends` chains and the git-ignored `.helm.local.toml` overlay
+//! into one merged TOML document while recording which file set each value.
+
+use anyhow::{Context, Result};
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+
+mod merge;
+
+/// File name of the per-developer overlay merged over the resolved config.
+pub(super) const LOCAL_OVERLAY_FILE_NAME: &str = ".helm.local.toml";
+
+/// Merged config document with per-value source files.
+pub(super) struct LayeredDocument {
+    /// Merged document, with `extends` kept from the primary config file only.
+    pub(super) table: toml::Table,
+    /// Source file for every merged leaf value keyed by dotted path.
+    pub(super) origins: BTreeMap<String, PathBuf>,
+    /// Files that contributed to the document, base layers first.
+    pub(super) files: Vec<PathBuf>,
+}
+
+/// Loads `config_path`, its `extends` chain, and the sibling local overlay.
+pub(super) fn load_layered_document(config_path: &Path) -> Result<LayeredDocument> {
+    let mut chain = Vec::new();
+    collect_extends_chain(config_path, &mut HashSet::new(), &mut chain)?;
+
+    if let Some(overlay_path) = config_path
+        .parent()
+        .map(|parent| parent.join(LOCAL_OVERLAY_FILE_NAME))
+        .filter(|path| path.is_file() && path != config_path)
+    {
+        let overlay = read_table(&overlay_path)?;
+        if overlay.contains_key("extends") {
+            anyhow::bail!(
+                "{} must not set 'extends'; set it in {}",
+                overlay_path.display(),
+                config_path.display()
+            );
+        }
+        chain.push((overlay_path, overlay));
+    }
+
+    let primary_extends = chain
+        .iter()
+        .find(|(path, _)| path == config_path)
+        .and_then(|(_, table)| table.get("extends").cloned());
+
+    let mut document = LayeredDocument {
+        table: toml::Table::new(),
+        origins: BTreeMap::new(),
+        files: Vec::new(),
+    };
+    for (path, mut table) in chain {
+        table.remove("extends");
+        merge::merge_layer(&mut document.table, table, &path, &mut document.origins);
+        document.files.push(path);
+    }
+    if let Some(extends) = primary_extends {
+        document.table.insert("extends".to_owned(), extends);
+    }
+
+    Ok(document)
+}
+
+/// Lists the files a config merges, base layers first, skipping unreadable links.
+///
+/// Unlike [`load_layered_document`] this never fails, so diagnostics can still
+/// inspect every readable layer when the chain itself is broken.
+pub(super) fn layer_paths(config_path: &Path) -> Vec<PathBuf> {
+    let mut paths = Vec::new();
+    let mut visiting = HashSet::new();
+    let mut current = Some(config_path.to_path_buf());
+    while let Some(path) = current.take() {
+        let identity = path.canonicalize().unwrap_or_else(|_| path.clone());
+        if !path.is_file() || !visiting.insert(identity) {
+            break;
+        }
+        current = read_table(&path).ok().and_then(|table| {
+            let relative = table.get("extends")?.as_str()?.to_owned();
+            Some(
+                path.parent()
+                    .map_or_else(|| PathBuf::from(&relative), |parent| parent.join(&relative)),
+            )
+        });
+        paths.insert(0, path);
+    }
+
+    if let Some(overlay_path) = config_path
+        .parent()
+        .map(|parent| parent.join(LOCAL_OVERLAY_FILE_NAME))
+        .filter(|path| path.is_file() && path != config_path)
+    {
+        paths.push(overlay_path);
+    }
+    paths
+}
+
+/// Collects `path` and every file it extends, base files first.
+fn collect_extends_chain(
+    path: &Path,
+    visiting: &mut HashSet<PathBuf>,
+    chain: &mut Vec<(PathBuf, toml::Table)>,
+) -> Result<()> {
+    let identity = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    if !visiting.insert(identity) {
+        anyhow::bail!("circular config extends detected at {}", path.display());
+    }
+
+    let table = read_table(path)?;
+    if let Some(extends) = table.get("extends") {
+        let Some(relative) = extends.as_str() else {
+            anyhow::bail!("'extends' in {} must be a file path string", path.display());
+        };
+        let base_path = path
+            .parent()
+            .map_or_else(|| PathBuf::from(relative), |parent| parent.join(relative));
+        if !base_path.is_file() {
+            anyhow::bail!(
+                "config file {} extends missing file {}",
+                path.display(),
+                base_path.display()
+            );
+        }
+        collect_extends_chain(&base_path, visiting, chain)?;
+    }
+
+    chain.push((path.to_path_buf(), table));
+    Ok(())
+}
+
+fn read_table(path: &Path) -> Result<toml::Table> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read config file at {}", path.display()))?;
+    content
+        .parse::<toml::Table>()
+        .with_context(|| format!("failed to parse TOML config file at {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LOCAL_OVERLAY_FILE_NAME, load_layered_document};
+    use std::fs;
+    use std::path::PathBuf;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn temp_root() -> PathBuf {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("system clock")
+            .as_nanos();
+        let root = std::env::temp_dir().join(format!("helm-config-layers-{nanos}"));
+        fs::create_dir_all(&root).expect("create temp root");
+        root
+    }
+
+    #[test]
+    fn load_layered_document_merges_base_project_and_local_overlay() {
+        let root = temp_root();
+        fs::create_dir_all(root.join("shared")).expect("create shared dir");
+        fs::write(
+            root.join("shared/base.toml"),
+            r#"
+container_prefix = "base"
+
+[[service]]
+preset = "mysql"
+name = "db"
+port = 3306
+
+[[service]]
+preset = "redis"
+"#,
+        )
+        .expect("write base");
+        let config_path = root.join(".helm.toml");
+        fs::write(
+            &config_path,
+            r#"
+extends = "shared/base.toml"
+container_prefix = "acme"
+
+[[service]]
+name = "db"
+image = "mysql:8.4"
+"#,
+        )
+        .expect("write config");
+        fs::write(
+            root.join(LOCAL_OVERLAY_FILE_NAME),
+            "[[service]]\nname = \"db\"\nport = 4306\n",
+        )
+        .expect("write overlay");
+
+        let document = load_layered_document(&config_path).expect("load layered config");
+        let services = document.table["service"].as_array().expect("service array");
+
+        assert_eq!(document.files.len(), 3);
+        assert_eq!(document.table["container_prefix"].as_str(), Some("acme"));
+        assert_eq!(document.table["extends"].as_str(), Some("shared/base.toml"));
+        assert_eq!(services.len(), 2);
+        assert_eq!(services[0]["image"].as_str(), Some("mysql:8.4"));
+        assert_eq!(services[0]["port"].as_integer(), Some(4306));
+        assert_eq!(services[0]["preset"].as_str(), Some("mysql"));
+        assert_eq!(services[1]["preset"].as_str(), Some("redis"));
+        assert_eq!(
+            document.origins.get("service.db.port"),
+            Some(&root.join(LOCAL_OVERLAY_FILE_NAME))
+        );
+        assert_eq!(
+            document.origins.get("service.db.preset"),
+            Some(&root.join("shared/base.toml"))
+        );
+        assert_eq!(document.origins.get("container_prefix"), Some(&config_path));
+    }
+
+    #[test]
+    fn load_layered_document_rejects_extends_cycles() {
+        let root = temp_root();
+        fs::write(root.join("a.toml"), "extends = \"b.toml\"\n").expect("write a");
+        fs::write(root.join("b.toml"), "extends = \"a.toml\"\n").expect("write b");
+
+        let error = load_layered_document(&root.join("a.toml"))
+            .err()
+            .expect("cycle error");
+        assert!(error.to_string().contains("circular config extends"));
+    }
+
+    #[test]
+    fn load_layered_document_rejects_missing_base() {
+        let root = temp_root();
+        let config_path = root.join(".helm.toml");
+        fs::write(&config_path, "extends = \"missing.toml\"\n").expect("write config");
+
+        let error = load_layered_document(&config_path)
+            .err()
+            .expect("missing base error");
+        assert!(error.to_string().contains("extends missing file"));
+    }
+
+    #[test]
+    fn load_layered_document_without_layers_reads_only_the_config() {
+        let root = temp_root();
+        let config_path = root.join(".helm.toml");
+        fs::write(&config_path, "container_prefix = \"acme\"\n").expect("write config");
+
+        let document = load_layered_document(&config_path).expect("load config");
+        assert_eq!(document.files, vec![config_path]);
+    }
+}
@@ -0,0 +1,120 @@
+//! config layers merge module.
+//!
+//! Deep-merges config layers. Tables merge key by key, `[[service]]` and
+//! `[[swarm]]` entries merge by name, and every other value is replaced.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Merges `overlay` into `base`, recording the overlay file for each leaf.
+pub(super) fn merge_layer(
+    base: &mut toml::Table,
+    overlay: toml::Table,
+    source: &Path,
+    origins: &mut BTreeMap<String, PathBuf>,
+) {
+    merge_table(base, overlay, "", source, origins);
+}
+
+fn merge_table(
+    base: &mut toml::Table,
+    overlay: toml::Table,
+    prefix: &str,
+    source: &Path,
+    origins: &mut BTreeMap<String, PathBuf>,
+) {
+    for (key, value) in overlay {
+        let path = join_path(prefix, &key);
+        let keyed_array = prefix.is_empty() && matches!(key.as_str(), "service" | "swarm");
+        match (base.get_mut(&key), value) {
+            (Some(toml::Value::Table(existing)), toml::Value::Table(incoming)) => {
+                merge_table(existing, incoming, &path, source, origins);
+            }
+            (Some(toml::Value::Array(existing)), toml::Value::Array(incoming)) if keyed_array => {
+                merge_keyed_entries(existing, incoming, &path, source, origins);
+            }
+            (_, toml::Value::Array(incoming)) if keyed_array => {
+                let mut entries = Vec::new();
+                merge_keyed_entries(&mut entries, incoming, &path, source, origins);
+                base.insert(key, toml::Value::Array(entries));
+            }
+            (_, incoming) => {
+                record_origins(&incoming, &path, source, origins);
+                base.insert(key, incoming);
+            }
+        }
+    }
+}
+
+/// Merges array-of-table entries that share an identity with an earlier layer's entry.
+fn merge_keyed_entries(
+    existing: &mut Vec<toml::Value>,
+    incoming: Vec<toml::Value>,
+    prefix: &str,
+    source: &Path,
+    origins: &mut BTreeMap<String, PathBuf>,
+) {
+    let previous_layer_len = existing.len();
+    for entry in incoming {
+        let toml::Value::Table(entry) = entry else {
+            existing.push(entry);
+            continue;
+        };
+        let key = entry_key(&entry, existing.len());
+        let entry_prefix = join_path(prefix, &key);
+        let position = existing
+            .iter()
+            .take(previous_layer_len)
+            .position(|candidate| {
+                candidate
+                    .as_table()
+                    .is_some_and(|table| entry_identity(table) == entry_identity(&entry))
+            });
+
+        match position.and_then(|index| existing.get_mut(index)) {
+            Some(toml::Value::Table(current)) if entry_identity(&entry).is_some() => {
+                merge_table(current, entry, &entry_prefix, source, origins);
+            }
+            _ => {
+                let mut merged = toml::Table::new();
+                merge_table(&mut merged, entry, &entry_prefix, source, origins);
+                existing.push(toml::Value::Table(merged));
+            }
+        }
+    }
+}
+
+/// Identity used to match entries across layers: `name`, falling back to `preset`.
+fn entry_identity(entry: &toml::Table) -> Option<&str> {
+    entry
+        .get("name")
+        .or_else(|| entry.get("preset"))
+        .and_then(toml::Value::as_str)
+}
+
+fn entry_key(entry: &toml::Table, index: usize) -> String {
+    entry_identity(entry).map_or_else(|| index.to_string(), str::to_owned)
+}
+
+fn record_origins(
+    value: &toml::Value,
+    path: &str,
+    source: &Path,
+    origins: &mut BTreeMap<String, PathBuf>,
+) {
+    if let toml::Value::Table(table) = value {
+        for (key, nested) in table {
+            record_origins(nested, &join_path(path, key), source, origins);
+        }
+        return;
+    }
+    origins.insert(path.to_owned(), source.to_path_buf());
+}
+
+fn join_path(prefix: &str, key: &str) -> String {
+    if prefix.is_empty() {
+        key.to_owned()
+    } else {
+        format!("{prefix}.{key}")
+    }
+}
@@ -0,0 +1,295 @@
+//! config migrations module.
+//!
+//! Ordered registry of schema migration steps. Each step rewrites the raw
+//! TOML document from one `schema_version` to the next, so renamed or
+//! relocated keys upgrade the same way on disk (`helm config migrate`) and in
+//! memory when an older config is loaded.
+//!
+//! No step is registered yet. To change the schema, bump
+//! [`CURRENT_SCHEMA_VERSION`] and append a [`Migration`] whose `from` is the
+//! previous version; the tests below walk a sample step through both paths.
+
+use anyhow::{Context, Result};
+use toml_edit::{DocumentMut, Item, Value};
+
+/// Schema version written by this Helm build.
+pub(crate) const CURRENT_SCHEMA_VERSION: u32 = 1;
+
+/// One upgrade step from `from` to `from + 1`.
+pub(crate) struct Migration {
+    /// Schema version the step upgrades from.
+    pub from: u32,
+    /// Short description shown by `helm config migrate`.
+    pub summary: &'static str,
+    /// Rewrites the document in place.
+    pub apply: fn(&mut DocumentMut) -> Result<()>,
+}
+
+/// Registered steps, ordered by `from` and ending at [`CURRENT_SCHEMA_VERSION`].
+const MIGRATIONS: &[Migration] = &[];
+
+/// Result of upgrading one document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct MigratedDocument {
+    /// Version the document declared (unversioned files count as 1).
+    pub from_version: u32,
+    /// Version the document now declares.
+    pub to_version: u32,
+    /// Summaries of the steps that ran, in order.
+    pub applied: Vec<&'static str>,
+}
+
+/// Upgrades `document` to [`CURRENT_SCHEMA_VERSION`] and stamps `schema_version`.
+pub(crate) fn migrate_document(document: &mut DocumentMut) -> Result<MigratedDocument> {
+    migrate_document_with(document, MIGRATIONS, CURRENT_SCHEMA_VERSION)
+}
+
+/// Upgrades a merged config table in memory, returning the declared version
+/// when it was behind.
+pub(crate) fn migrate_table(table: &mut toml::Table) -> Result<Option<u32>> {
+    migrate_table_with(table, MIGRATIONS, CURRENT_SCHEMA_VERSION)
+}
+
+fn migrate_table_with(
+    table: &mut toml::Table,
+    steps: &[Migration],
+    target: u32,
+) -> Result<Option<u32>> {
+    let declared = declared_version(
+        table
+            .get("schema_version")
+            .and_then(toml::Value::as_integer),
+    )?;
+    if declared > target {
+        return Err(newer_than_supported(declared));
+    }
+    if declared == target {
+        return Ok(None);
+    }
+
+    let mut document = table
+        .to_string()
+        .parse::<DocumentMut>()
+        .context("failed to prepare config for migration")?;
+    migrate_document_with(&mut document, steps, target)?;
+    *table = document
+        .to_string()
+        .parse::<toml::Table>()
+        .context("migrated config is not valid TOML")?;
+    Ok(Some(declared))
+}
+
+fn migrate_document_with(
+    document: &mut DocumentMut,
+    steps: &[Migration],
+    target: u32,
+) -> Result<MigratedDocument> {
+    let from_version = declared_version(
+        document
+            .get("schema_version")
+            .and_then(Item::as_value)
+            .and_then(Value::as_integer),
+    )?;
+    if from_version > target {
+        return Err(newer_than_supported(from_version));
+    }
+
+    let mut applied = Vec::new();
+    for version in from_version..target {
+        let Some(step) = steps.iter().find(|step| step.from == version) else {
+            anyhow::bail!("no migration registered from schema_version {version}");
+        };
+        (step.apply)(document).with_context(|| {
+            format!(
+                "migration from schema_version {version} failed: {}",
+                step.summary
+            )
+        })?;
+        applied.push(step.summary);
+    }
+
+    match document
+        .get_mut("schema_version")
+        .and_then(Item::as_value_mut)
+    {
+        Some(existing) => {
+            let decor = existing.decor().clone();
+            *existing = Value::from(i64::from(target));
+            *existing.decor_mut() = decor;
+        }
+        None => {
+            document.insert("schema_version", toml_edit::value(i64::from(target)));
+        }
+    }
+
+    Ok(MigratedDocument {
+        from_version,
+        to_version: target,
+        applied,
+    })
+}
+
+fn declared_version(value: Option<i64>) -> Result<u32> {
+    let Some(value) = value else {
+        return Ok(1);
+    };
+    u32::try_from(value)
+        .ok()
+        .filter(|version| *version >= 1)
+        .ok_or_else(|| anyhow::anyhow!("invalid schema_version '{value}'"))
+}
+
+fn newer_than_supported(version: u32) -> anyhow::Error {
+    anyhow::anyhow!(
+        "schema_version '{version}' is newer than this Helm build supports \
+         (latest is {CURRENT_SCHEMA_VERSION}); upgrade Helm"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CURRENT_SCHEMA_VERSION, MIGRATIONS, Migration, migrate_document, migrate_document_with,
+        migrate_table, migrate_table_with,
+    };
+    use toml_edit::DocumentMut;
+
+    fn rename_health_path(document: &mut DocumentMut) -> anyhow::Result<()> {
+        if let Some(entries) = document
+            .get_mut("service")
+            .and_then(toml_edit::Item::as_array_of_tables_mut)
+        {
+            for entry in entries.iter_mut() {
+                if let Some(value) = entry.remove("health_url") {
+                    entry.insert("health_path", value);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn add_marker(document: &mut DocumentMut) -> anyhow::Result<()> {
+        document.insert("marker", toml_edit::value("v3"));
+        Ok(())
+    }
+
+    const STEPS: &[Migration] = &[
+        Migration {
+            from: 1,
+            summary: "rename service.health_url to service.health_path",
+            apply: rename_health_path,
+        },
+        Migration {
+            from: 2,
+            summary: "add marker",
+            apply: add_marker,
+        },
+    ];
+
+    #[test]
+    fn registry_is_contiguous_and_ends_at_current_version() {
+        for (index, step) in MIGRATIONS.iter().enumerate() {
+            assert_eq!(u32::try_from(index).expect("index") + 1, step.from);
+        }
+        let last = MIGRATIONS.last().map_or(1, |step| step.from + 1);
+        assert_eq!(last, CURRENT_SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn migrate_document_with_runs_steps_in_order_and_keeps_comments() -> anyhow::Result<()> {
+        let mut document = "# project config\nschema_version = 1 # pinned\n\n[[service]]\n\
+                            # health check\nhealth_url = \"/up\"\n"
+            .parse::<DocumentMut>()?;
+
+        let migrated = migrate_document_with(&mut document, STEPS, 3)?;
+
+        assert_eq!(migrated.from_version, 1);
+        assert_eq!(migrated.to_version, 3);
+        assert_eq!(
+            migrated.applied,
+            vec![
+                "rename service.health_url to service.health_path",
+                "add marker"
+            ]
+        );
+        let content = document.to_string();
+        assert!(content.contains("# project config"));
+        assert!(content.contains("schema_version = 3 # pinned"));
+        assert!(content.contains("health_path = \"/up\""));
+        assert!(content.contains("marker = \"v3\""));
+        Ok(())
+    }
+
+    #[test]
+    fn migrate_document_with_skips_steps_already_applied() -> anyhow::Result<()> {
+        let mut document = "schema_version = 2\n".parse::<DocumentMut>()?;
+        let migrated = migrate_document_with(&mut document, STEPS, 3)?;
+        assert_eq!(migrated.applied, vec!["add marker"]);
+        Ok(())
+    }
+
+    #[test]
+    fn migrate_document_with_rejects_newer_and_unregistered_versions() -> anyhow::Result<()> {
+        let mut newer = "schema_version = 4\n".parse::<DocumentMut>()?;
+        let error = migrate_document_with(&mut newer, STEPS, 3).expect_err("newer version");
+        assert!(error.to_string().contains("newer than this Helm build"));
+
+        let mut gap = "schema_version = 1\n".parse::<DocumentMut>()?;
+        let error = migrate_document_with(&mut gap, &STEPS[1..], 3).expect_err("missing step");
+        assert!(error.to_string().contains("no migration registered"));
+        Ok(())
+    }
+
+    #[test]
+    fn migrate_document_stamps_unversioned_files() -> anyhow::Result<()> {
+        let mut document = "project_type = \"project\"\n".parse::<DocumentMut>()?;
+        let migrated = migrate_document(&mut document)?;
+        assert_eq!(migrated.from_version, 1);
+        assert!(
+            document
+                .to_string()
+                .contains(&format!("schema_version = {CURRENT_SCHEMA_VERSION}"))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn migrate_table_with_upgrades_older_configs_at_load_time() -> anyhow::Result<()> {
+        let mut table =
+            "[[service]]\nname = \"app\"\nhealth_url = \"/up\"\n".parse::<toml::Table>()?;
+
+        assert_eq!(migrate_table_with(&mut table, STEPS, 3)?, Some(1));
+
+        let service = table
+            .get("service")
+            .and_then(toml::Value::as_array)
+            .and_then(|entries| entries.first())
+            .and_then(toml::Value::as_table)
+            .expect("service entry");
+        assert_eq!(
+            service.get("health_path").and_then(toml::Value::as_str),
+            Some("/up")
+        );
+        assert!(service.get("health_url").is_none());
+        assert_eq!(
+            table
+                .get("schema_version")
+                .and_then(toml::Value::as_integer),
+            Some(3)
+        );
+        assert_eq!(migrate_table_with(&mut table, STEPS, 3)?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn migrate_table_reports_only_configs_that_were_behind() -> anyhow::Result<()> {
+        let mut current =
+            format!("schema_version = {CURRENT_SCHEMA_VERSION}\n").parse::<toml::Table>()?;
+        assert_eq!(migrate_table(&mut current)?, None);
+
+        let mut newer =
+            format!("schema_version = {}\n", CURRENT_SCHEMA_VERSION + 1).parse::<toml::Table>()?;
+        assert!(migrate_table(&mut newer).is_err());
+        Ok(())
+    }
+}
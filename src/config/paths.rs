@@ -35,6 +35,15 @@ pub(super) fn project_root_with(
 }
 
 pub(super) fn init_config() -> Result<PathBuf> {
+    write_initial_config(|_, project_name| Ok(default_config_template(project_name)))
+}
+
+/// Writes a new `.helm.toml` in the current directory.
+///
+/// `render` receives the directory and its name as the project name.
+pub(super) fn write_initial_config(
+    render: impl FnOnce(&Path, &str) -> Result<String>,
+) -> Result<PathBuf> {
     let current_dir = std::env::current_dir().context("failed to get current directory")?;
     let config_path = current_dir.join(".helm.toml");
 
@@ -46,7 +55,8 @@ pub(super) fn init_config() -> Result<PathBuf> {
         .file_name()
         .and_then(std::ffi::OsStr::to_str)
         .unwrap_or("my-app");
-    std::fs::write(&config_path, default_config_template(project_name))
+    let content = render(&current_dir, project_name)?;
+    std::fs::write(&config_path, content)
         .with_context(|| format!("failed to write {}", config_path.display()))?;
 
     Ok(config_path)
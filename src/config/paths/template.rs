@@ -18,7 +18,7 @@ domain_strategy = "directory"
 [[service]]
 preset = "laravel"
 name = "app"
-# depends_on = ["db", "redis"]
+# depends_on = ["redis", {{ service = "db", condition = "healthy" }}]
 #
 # Optional lifecycle hooks:
 # [[service.hook]]
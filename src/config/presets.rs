@@ -8,12 +8,16 @@ use super::{Driver, Kind};
 
 mod app;
 mod cache;
+mod custom;
 mod database;
 mod object_store;
 mod preset_defaults;
 mod search;
+mod user;
 
+pub(in crate::config) use custom::{PresetCatalog, apply_custom_presets};
 pub(in crate::config) use preset_defaults::PresetDefaults;
+pub(in crate::config) use user::load_user_presets;
 
 pub(super) fn preset_defaults(preset: &str) -> Result<PresetDefaults> {
     if let Some(defaults) = database::resolve(preset) {
@@ -50,6 +54,11 @@ pub(super) fn preset_names() -> Vec<&'static str> {
     names
 }
 
+/// Returns whether `preset` names a compiled-in preset.
+pub(super) fn is_builtin_preset(preset: &str) -> bool {
+    preset_names().contains(&preset)
+}
+
 /// Returns the default value for health path for preset.
 pub(super) fn default_health_path_for_preset(preset: &str) -> Option<&'static str> {
     app::default_health_path_for_preset(preset)
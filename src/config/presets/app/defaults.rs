@@ -44,6 +44,7 @@ pub(super) fn dusk() -> PresetDefaults {
     );
     defaults.name = Some("dusk");
     defaults.container_port = Some(4444);
+    defaults.shm_size = Some("2g");
     defaults
 }
 
@@ -55,6 +56,7 @@ pub(super) fn selenium() -> PresetDefaults {
     );
     defaults.name = Some("selenium");
     defaults.container_port = Some(4444);
+    defaults.shm_size = Some("2g");
     defaults
 }
 
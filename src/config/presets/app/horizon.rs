@@ -15,5 +15,6 @@ pub(super) fn defaults() -> PresetDefaults {
         "horizon".to_owned(),
     ]);
     defaults.forced_env = Some(vec![("APP_ENV", "local"), ("QUEUE_CONNECTION", "redis")]);
+    defaults.restart = Some("unless-stopped");
     defaults
 }
@@ -16,5 +16,6 @@ pub(super) fn defaults() -> PresetDefaults {
         "--timeout=90".to_owned(),
     ]);
     defaults.forced_env = Some(vec![("APP_ENV", "local")]);
+    defaults.restart = Some("unless-stopped");
     defaults
 }
@@ -13,5 +13,6 @@ pub(super) fn defaults() -> PresetDefaults {
         "schedule:work".to_owned(),
     ]);
     defaults.forced_env = Some(vec![("APP_ENV", "local")]);
+    defaults.restart = Some("unless-stopped");
     defaults
 }
@@ -0,0 +1,118 @@
+//! config presets custom module.
+//!
+//! Layers custom presets from `.helm.toml` and the user preset directory onto
+//! raw service definitions before built-in preset defaults are applied.
+
+use anyhow::Result;
+use std::collections::{BTreeMap, HashSet};
+
+use super::super::{PresetConfig, RawServiceConfig};
+
+/// Custom preset lookup with project entries taking precedence over user entries.
+pub(in crate::config) struct PresetCatalog<'a> {
+    project: &'a BTreeMap<String, PresetConfig>,
+    user: &'a BTreeMap<String, PresetConfig>,
+}
+
+impl<'a> PresetCatalog<'a> {
+    pub(in crate::config) const fn new(
+        project: &'a BTreeMap<String, PresetConfig>,
+        user: &'a BTreeMap<String, PresetConfig>,
+    ) -> Self {
+        Self { project, user }
+    }
+
+    fn get(&self, name: &str) -> Option<&'a PresetConfig> {
+        self.project.get(name).or_else(|| self.user.get(name))
+    }
+
+    /// Returns custom preset names, project entries first.
+    pub(in crate::config) fn names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.project.keys().cloned().collect();
+        for name in self.user.keys() {
+            if !names.contains(name) {
+                names.push(name.clone());
+            }
+        }
+        names
+    }
+}
+
+/// Applies the custom preset chain named by `raw.preset`.
+///
+/// Fields already set on the service win over custom preset values. After the
+/// chain is applied `raw.preset` names the built-in preset it ends on, or is
+/// cleared when the chain does not extend a built-in preset.
+pub(in crate::config) fn apply_custom_presets(
+    mut raw: RawServiceConfig,
+    catalog: &PresetCatalog<'_>,
+) -> Result<RawServiceConfig> {
+    let mut visited = HashSet::new();
+
+    while let Some(name) = raw.preset.clone() {
+        let custom = match catalog.get(&name) {
+            Some(custom) if visited.insert(name.clone()) => custom,
+            Some(_) if super::is_builtin_preset(&name) => break,
+            Some(_) => anyhow::bail!("preset '{name}' has a circular extends chain"),
+            None => break,
+        };
+
+        overlay_preset(&mut raw, custom);
+        raw.preset.clone_from(&custom.extends);
+        if raw.preset.is_none()
+            && (raw.kind.is_none() || raw.driver.is_none() || raw.image.is_none())
+        {
+            anyhow::bail!(
+                "custom preset '{name}' must set kind, driver and image or extend another preset"
+            );
+        }
+    }
+
+    Ok(raw)
+}
+
+fn overlay_preset(raw: &mut RawServiceConfig, preset: &PresetConfig) {
+    fill(&mut raw.name, preset.name.as_ref());
+    fill_copy(&mut raw.kind, preset.kind);
+    fill_copy(&mut raw.driver, preset.driver);
+    fill(&mut raw.image, preset.image.as_ref());
+    fill(&mut raw.host, preset.host.as_ref());
+    fill_copy(&mut raw.port, preset.port);
+    fill(&mut raw.database, preset.database.as_ref());
+    fill(&mut raw.username, preset.username.as_ref());
+    fill(&mut raw.password, preset.password.as_ref());
+    fill(&mut raw.bucket, preset.bucket.as_ref());
+    fill(&mut raw.access_key, preset.access_key.as_ref());
+    fill(&mut raw.secret_key, preset.secret_key.as_ref());
+    fill(&mut raw.api_key, preset.api_key.as_ref());
+    fill(&mut raw.region, preset.region.as_ref());
+    fill(&mut raw.scheme, preset.scheme.as_ref());
+    fill_copy(&mut raw.container_port, preset.container_port);
+    fill_copy(&mut raw.smtp_port, preset.smtp_port);
+    fill_copy(&mut raw.octane, preset.octane);
+    fill_copy(&mut raw.octane_workers, preset.octane_workers);
+    fill_copy(&mut raw.octane_max_requests, preset.octane_max_requests);
+    fill(&mut raw.php_extensions, preset.php_extensions.as_ref());
+    fill(&mut raw.volumes, preset.volumes.as_ref());
+    fill(&mut raw.command, preset.command.as_ref());
+    fill_copy(&mut raw.trust_container_ca, preset.trust_container_ca);
+
+    if let Some(preset_env) = &preset.env {
+        let env = raw.env.get_or_insert_with(Default::default);
+        for (key, value) in preset_env {
+            env.entry(key.clone()).or_insert_with(|| value.clone());
+        }
+    }
+}
+
+fn fill<T: Clone>(value: &mut Option<T>, default: Option<&T>) {
+    if value.is_none() {
+        *value = default.cloned();
+    }
+}
+
+const fn fill_copy<T: Copy>(value: &mut Option<T>, default: Option<T>) {
+    if value.is_none() {
+        *value = default;
+    }
+}
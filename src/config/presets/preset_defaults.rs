@@ -31,6 +31,8 @@ pub(in crate::config) struct PresetDefaults {
     pub(in crate::config) command: Option<Vec<String>>,
     pub(in crate::config) forced_env: Option<Vec<(&'static str, &'static str)>>,
     pub(in crate::config) trust_container_ca: bool,
+    pub(in crate::config) shm_size: Option<&'static str>,
+    pub(in crate::config) restart: Option<&'static str>,
 }
 
 impl PresetDefaults {
@@ -61,6 +63,8 @@ impl PresetDefaults {
             command: None,
             forced_env: None,
             trust_container_ca: false,
+            shm_size: None,
+            restart: None,
         }
     }
 }
@@ -0,0 +1,114 @@
+//! config presets user module.
+//!
+//! Loads user-level custom presets from `~/.config/helm/presets/*.toml`.
+
+use anyhow::{Context, Result};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use super::super::PresetConfig;
+
+/// Loads user-level presets, returning none when `HOME` is unset.
+pub(in crate::config) fn load_user_presets() -> Result<BTreeMap<String, PresetConfig>> {
+    let Ok(home) = std::env::var("HOME") else {
+        return Ok(BTreeMap::new());
+    };
+    load_presets_from_dir(&user_presets_dir_with_home(&home))
+}
+
+fn user_presets_dir_with_home(home: &str) -> PathBuf {
+    PathBuf::from(home).join(".config/helm/presets")
+}
+
+/// Reads every `<name>.toml` file in `dir` as the preset `<name>`.
+fn load_presets_from_dir(dir: &Path) -> Result<BTreeMap<String, PresetConfig>> {
+    let mut presets = BTreeMap::new();
+    if !dir.is_dir() {
+        return Ok(presets);
+    }
+
+    let entries = std::fs::read_dir(dir)
+        .with_context(|| format!("failed to read preset directory {}", dir.display()))?;
+    for entry in entries {
+        let path = entry
+            .with_context(|| format!("failed to read preset directory {}", dir.display()))?
+            .path();
+        if path.extension().and_then(std::ffi::OsStr::to_str) != Some("toml") {
+            continue;
+        }
+        let Some(name) = path.file_stem().and_then(std::ffi::OsStr::to_str) else {
+            continue;
+        };
+
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("failed to read preset file at {}", path.display()))?;
+        let preset: PresetConfig = toml::from_str(&content)
+            .with_context(|| format!("failed to parse preset file at {}", path.display()))?;
+        presets.insert(name.to_owned(), preset);
+    }
+
+    Ok(presets)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{load_presets_from_dir, user_presets_dir_with_home};
+    use crate::config::Driver;
+    use std::path::PathBuf;
+
+    fn temp_dir() -> PathBuf {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .expect("system clock")
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("helm-user-presets-{nanos}"));
+        std::fs::create_dir_all(&dir).expect("create preset dir");
+        dir
+    }
+
+    #[test]
+    fn user_presets_dir_lives_under_helm_config() {
+        assert_eq!(
+            user_presets_dir_with_home("/home/dev"),
+            PathBuf::from("/home/dev/.config/helm/presets")
+        );
+    }
+
+    #[test]
+    fn load_presets_from_dir_keys_presets_by_file_stem() {
+        let dir = temp_dir();
+        std::fs::write(
+            dir.join("pgvector.toml"),
+            "extends = \"postgres\"\nimage = \"registry.acme.test/pgvector:17\"\n",
+        )
+        .expect("write preset");
+        std::fs::write(dir.join("notes.txt"), "ignored").expect("write note");
+
+        let presets = load_presets_from_dir(&dir).expect("load presets");
+
+        assert_eq!(presets.len(), 1);
+        let pgvector = presets.get("pgvector").expect("pgvector preset");
+        assert_eq!(pgvector.extends.as_deref(), Some("postgres"));
+        assert_eq!(
+            pgvector.image.as_deref(),
+            Some("registry.acme.test/pgvector:17")
+        );
+        assert_eq!(pgvector.driver, None::<Driver>);
+    }
+
+    #[test]
+    fn load_presets_from_dir_returns_empty_for_missing_dir() {
+        let presets =
+            load_presets_from_dir(&temp_dir().join("missing")).expect("missing dir is empty");
+        assert!(presets.is_empty());
+    }
+
+    #[test]
+    fn load_presets_from_dir_reports_parse_failures_with_path() {
+        let dir = temp_dir();
+        std::fs::write(dir.join("broken.toml"), "port = \"not-a-port\"\n").expect("write");
+
+        let error = load_presets_from_dir(&dir).expect_err("invalid preset should fail");
+        assert!(error.to_string().contains("broken.toml"));
+    }
+}
@@ -2,9 +2,11 @@
 //!
 //! Contains config raw logic used by Helm command workflows.
 
+use schemars::JsonSchema;
 use serde::Deserialize;
+use std::collections::BTreeMap;
 
-use super::{ContainerEngine, DomainStrategy, ProjectType};
+use super::{ContainerEngine, DomainStrategy, PresetConfig, ProfileConfig, ProjectType};
 
 mod service;
 mod service_hook;
@@ -16,7 +18,8 @@ pub(crate) use service_hook::{RawHookRun, RawServiceHook};
 pub(crate) use swarm::RawSwarmTarget;
 pub(crate) use swarm_git::RawSwarmGit;
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[schemars(deny_unknown_fields)]
 pub(crate) struct RawConfig {
     #[serde(default)]
     pub schema_version: Option<u32>,
@@ -24,6 +27,15 @@ pub(crate) struct RawConfig {
     pub project_type: Option<ProjectType>,
     #[serde(default)]
     pub container_engine: Option<ContainerEngine>,
+    /// Groups project services into one Podman pod per runtime env.
+    #[serde(default)]
+    pub podman_pod: Option<bool>,
+    /// Remote engine endpoint URL (`ssh://`, `tcp://`, `unix://`).
+    #[serde(default)]
+    pub engine_host: Option<String>,
+    /// Named engine context (Docker context or Podman connection).
+    #[serde(default)]
+    pub engine_context: Option<String>,
     pub container_prefix: Option<String>,
     #[serde(default)]
     pub domain_strategy: Option<DomainStrategy>,
@@ -31,4 +43,11 @@ pub(crate) struct RawConfig {
     pub service: Vec<RawServiceConfig>,
     #[serde(default)]
     pub swarm: Vec<RawSwarmTarget>,
+    #[serde(default)]
+    pub profile: BTreeMap<String, ProfileConfig>,
+    #[serde(default)]
+    pub preset: BTreeMap<String, PresetConfig>,
+    /// User-level presets loaded from the Helm config directory (never parsed from TOML).
+    #[serde(skip)]
+    pub user_preset: BTreeMap<String, PresetConfig>,
 }
@@ -2,14 +2,16 @@
 //!
 //! Contains config raw service logic used by Helm command workflows.
 
+use schemars::JsonSchema;
 use serde::Deserialize;
 use std::collections::HashMap;
 
-use super::super::{Driver, Kind};
+use super::super::{Driver, Kind, ServiceBuild, ServiceDependency, ServiceHealth};
 use super::RawServiceHook;
 use crate::javascript::JavaScriptToolchain;
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[schemars(deny_unknown_fields)]
 pub(crate) struct RawServiceConfig {
     #[serde(default)]
     pub preset: Option<String>,
@@ -22,6 +24,8 @@ pub(crate) struct RawServiceConfig {
     #[serde(default)]
     pub image: Option<String>,
     #[serde(default)]
+    pub build: Option<ServiceBuild>,
+    #[serde(default)]
     pub host: Option<String>,
     #[serde(default)]
     pub port: Option<u16>,
@@ -58,7 +62,7 @@ pub(crate) struct RawServiceConfig {
     #[serde(default)]
     pub command: Option<Vec<String>>,
     #[serde(default)]
-    pub depends_on: Option<Vec<String>>,
+    pub depends_on: Option<Vec<ServiceDependency>>,
     #[serde(default)]
     pub seed_file: Option<String>,
     #[serde(default)]
@@ -68,6 +72,8 @@ pub(crate) struct RawServiceConfig {
     #[serde(default)]
     pub health_statuses: Option<Vec<u16>>,
     #[serde(default)]
+    pub health: Option<ServiceHealth>,
+    #[serde(default)]
     pub localhost_tls: Option<bool>,
     #[serde(default)]
     pub octane: Option<bool>,
@@ -85,4 +91,26 @@ pub(crate) struct RawServiceConfig {
     pub javascript: Option<JavaScriptToolchain>,
     #[serde(default)]
     pub container_name: Option<String>,
+    #[serde(default)]
+    pub memory: Option<String>,
+    #[serde(default)]
+    pub cpus: Option<f64>,
+    #[serde(default)]
+    pub pids_limit: Option<u32>,
+    #[serde(default)]
+    pub shm_size: Option<String>,
+    #[serde(default)]
+    pub ulimits: Option<HashMap<String, String>>,
+    #[serde(default)]
+    pub tmpfs: Option<Vec<String>>,
+    #[serde(default)]
+    pub platform: Option<String>,
+    #[serde(default)]
+    pub user: Option<String>,
+    #[serde(default)]
+    pub cap_add: Option<Vec<String>>,
+    #[serde(default)]
+    pub restart: Option<String>,
+    #[serde(skip)]
+    pub secret_fields: Vec<String>,
 }
@@ -2,11 +2,12 @@
 //!
 //! Contains raw service hook configuration for TOML parsing.
 
+use schemars::JsonSchema;
 use serde::Deserialize;
 
 use super::super::{HookOnError, HookPhase};
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub(crate) struct RawServiceHook {
     #[serde(default)]
     pub name: Option<String>,
@@ -20,7 +21,7 @@ pub(crate) struct RawServiceHook {
     pub timeout_sec: Option<u64>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub(crate) enum RawHookRun {
     Exec { argv: Vec<String> },
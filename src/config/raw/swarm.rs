@@ -2,13 +2,14 @@
 //!
 //! Contains config raw swarm logic used by Helm command workflows.
 
+use schemars::JsonSchema;
 use serde::Deserialize;
 use std::path::PathBuf;
 
 use super::super::types::SwarmInjectEnv;
 use super::RawSwarmGit;
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub(crate) struct RawSwarmTarget {
     pub name: String,
     pub root: PathBuf,
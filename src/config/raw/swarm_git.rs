@@ -2,9 +2,10 @@
 //!
 //! Contains config raw swarm git logic used by Helm command workflows.
 
+use schemars::JsonSchema;
 use serde::Deserialize;
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub(crate) struct RawSwarmGit {
     pub repo: String,
     #[serde(default)]
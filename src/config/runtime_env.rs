@@ -28,6 +28,7 @@ pub(super) fn apply_runtime_env(config: &mut Config, env_name: &str) -> Result<(
             )
         })?;
         service.resolved_container_name = Some(format!("{base_name}{suffix}"));
+        service.resolved_runtime_env = Some(normalized.clone());
         service.port = shift_port(service.port, port_offset, &service.name, "port")?;
         if let Some(smtp_port) = service.smtp_port {
             service.smtp_port = Some(shift_port(
@@ -49,6 +50,9 @@ pub(super) fn runtime_env_config_file_name(runtime_env: Option<&str>) -> Result<
     };
 
     let normalized = normalize_runtime_env_name(env_name)?;
+    if is_default_runtime_env(&normalized) {
+        return Ok(None);
+    }
     Ok(Some(format!(".helm.{normalized}.toml")))
 }
 
@@ -0,0 +1,142 @@
+//! config schema module.
+//!
+//! Generates the `.helm.toml` JSON Schema from the raw serde config types so
+//! editor validation stays in sync with what Helm actually parses.
+
+use schemars::generate::SchemaSettings;
+use serde_json::{Map, Value, json};
+
+use super::RawConfig;
+
+/// Definition name used for `{ from = "env:NAME" }` secret references.
+const SECRET_REFERENCE: &str = "SecretReference";
+
+/// Returns the JSON Schema (draft-07) describing `.helm.toml`.
+///
+/// # Errors
+///
+/// Returns an error if the generated schema cannot be converted to JSON.
+pub fn config_schema() -> anyhow::Result<Value> {
+    let generator = SchemaSettings::draft07().into_generator();
+    let mut schema = serde_json::to_value(generator.into_root_schema_for::<RawConfig>())?;
+
+    if let Some(root) = schema.as_object_mut() {
+        root.insert("title".to_owned(), json!("Helm config (.helm.toml)"));
+        if let Some(Value::Object(properties)) = root.get_mut("properties") {
+            properties.insert(
+                "extends".to_owned(),
+                json!({
+                    "description": "Base config file merged underneath this one, relative to this file.",
+                    "type": "string"
+                }),
+            );
+        }
+        if let Some(Value::Object(definitions)) = root.get_mut("definitions") {
+            if let Some(Value::Object(service)) = definitions.get_mut("RawServiceConfig")
+                && let Some(Value::Object(properties)) = service.get_mut("properties")
+            {
+                allow_secret_references(properties);
+            }
+            definitions.insert(
+                SECRET_REFERENCE.to_owned(),
+                json!({
+                    "description": "Value resolved at load time from `env:<NAME>` or `file:<PATH>`.",
+                    "type": "object",
+                    "properties": {
+                        "from": { "type": "string", "pattern": "^(env|file):.+" }
+                    },
+                    "required": ["from"],
+                    "additionalProperties": false
+                }),
+            );
+        }
+    }
+
+    Ok(schema)
+}
+
+/// Lets every free-form string in a service entry also be a secret reference.
+fn allow_secret_references(properties: &mut Map<String, Value>) {
+    for property in properties.values_mut() {
+        allow_secret_reference(property);
+    }
+}
+
+fn allow_secret_reference(schema: &mut Value) {
+    let Some(object) = schema.as_object_mut() else {
+        return;
+    };
+    for nested in ["items", "additionalProperties"] {
+        if let Some(child) = object.get_mut(nested) {
+            allow_secret_reference(child);
+        }
+    }
+
+    let accepts_string = match object.get("type") {
+        Some(Value::String(kind)) => kind == "string",
+        Some(Value::Array(kinds)) => kinds.iter().any(|kind| kind == "string"),
+        _ => false,
+    };
+    if accepts_string && !object.contains_key("enum") {
+        let original = std::mem::take(object);
+        object.insert(
+            "anyOf".to_owned(),
+            json!([
+                Value::Object(original),
+                { "$ref": format!("#/definitions/{SECRET_REFERENCE}") }
+            ]),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::config_schema;
+    use crate::config::{preset_names, preset_preview};
+
+    fn validation_errors(document: &serde_json::Value) -> Vec<String> {
+        let schema = config_schema().expect("generate schema");
+        let validator = jsonschema::validator_for(&schema).expect("compile schema");
+        validator
+            .iter_errors(document)
+            .map(|error| format!("{}: {error}", error.instance_path()))
+            .collect()
+    }
+
+    #[test]
+    fn config_schema_accepts_every_preset_default() {
+        for preset in preset_names() {
+            let service = preset_preview(preset).expect("resolve preset");
+            let document = serde_json::json!({
+                "project_type": "project",
+                "service": [service],
+            });
+
+            let errors = validation_errors(&document);
+            assert!(errors.is_empty(), "preset '{preset}' failed: {errors:?}");
+        }
+    }
+
+    #[test]
+    fn config_schema_accepts_secret_references_and_extends() {
+        let document = serde_json::json!({
+            "extends": "../shared/.helm.toml",
+            "service": [{
+                "preset": "mysql",
+                "password": { "from": "env:DB_PASS" },
+                "env": { "TOKEN": { "from": "file:~/.secrets/token" } },
+            }],
+        });
+
+        assert_eq!(validation_errors(&document), Vec::<String>::new());
+    }
+
+    #[test]
+    fn config_schema_rejects_unknown_keys_and_enum_values() {
+        let document = serde_json::json!({
+            "service": [{ "preset": "mysql", "driver": "oracle", "imgae": "mysql:8" }],
+        });
+
+        assert_eq!(validation_errors(&document).len(), 2);
+    }
+}
@@ -6,6 +6,12 @@ use super::{Driver, ServiceConfig};
 
 mod connection;
 mod domains;
+#[cfg(test)]
+mod fixtures;
 mod identity;
 pub(crate) mod network;
 mod ports;
+mod runtime_options;
+mod secrets;
+
+pub(in crate::config) use ports::driver_default_port;
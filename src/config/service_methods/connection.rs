@@ -9,7 +9,7 @@ impl ServiceConfig {
     #[must_use]
     pub fn connection_url(&self) -> String {
         let scheme = self.scheme();
-        let host = format_host_for_url(&self.host);
+        let host = format_host_for_url(&self.client_host());
         let port = self.port;
 
         match self.driver {
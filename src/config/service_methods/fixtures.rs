@@ -0,0 +1,68 @@
+//! config service methods fixtures module.
+//!
+//! Shared `ServiceConfig` fixture for unit tests across the crate.
+
+use super::ports::driver_default_port;
+use crate::config::{Driver, Kind, ServiceConfig};
+
+impl ServiceConfig {
+    /// Returns a bare service on `127.0.0.1` and the driver's default port,
+    /// with every optional field unset, for tests to adjust as needed.
+    pub(crate) fn fixture(name: &str, kind: Kind, driver: Driver, image: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            kind,
+            driver,
+            image: image.to_owned(),
+            host: "127.0.0.1".to_owned(),
+            port: driver_default_port(driver),
+            database: None,
+            username: None,
+            password: None,
+            bucket: None,
+            access_key: None,
+            secret_key: None,
+            api_key: None,
+            region: None,
+            scheme: None,
+            domain: None,
+            domains: None,
+            resolved_domain: None,
+            container_port: None,
+            smtp_port: None,
+            volumes: None,
+            env: None,
+            command: None,
+            depends_on: None,
+            seed_file: None,
+            hook: Vec::new(),
+            health_path: None,
+            health_statuses: None,
+            health: None,
+            localhost_tls: false,
+            octane: false,
+            octane_workers: None,
+            octane_max_requests: None,
+            php_extensions: None,
+            trust_container_ca: false,
+            env_mapping: None,
+            javascript: None,
+            container_name: None,
+            memory: None,
+            cpus: None,
+            pids_limit: None,
+            shm_size: None,
+            ulimits: None,
+            tmpfs: None,
+            platform: None,
+            user: None,
+            cap_add: None,
+            restart: None,
+            build: None,
+            resolved_container_name: None,
+            resolved_project_root: None,
+            resolved_runtime_env: None,
+            secret_fields: Vec::new(),
+        }
+    }
+}
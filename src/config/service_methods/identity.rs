@@ -16,6 +16,16 @@ impl ServiceConfig {
             .ok_or_else(|| anyhow!("service '{}' has no resolved container name", self.name))
     }
 
+    /// Returns the names listed in `depends_on`, in declaration order.
+    #[must_use]
+    pub fn dependency_names(&self) -> Vec<String> {
+        self.depends_on
+            .iter()
+            .flatten()
+            .map(|dependency| dependency.service.clone())
+            .collect()
+    }
+
     #[must_use]
     pub fn scheme(&self) -> &str {
         self.scheme.as_deref().unwrap_or("http")
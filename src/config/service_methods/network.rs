@@ -39,6 +39,14 @@ pub(crate) fn is_unspecified_port_allocation_host(host: &str) -> bool {
 }
 
 impl ServiceConfig {
+    /// Returns the host clients on this machine use to reach the published port.
+    ///
+    /// Loopback hosts resolve to the engine host when the engine is remote.
+    #[must_use]
+    pub fn client_host(&self) -> String {
+        crate::docker::reachable_host(&self.host)
+    }
+
     /// Returns whether runtime access should resolve through host-gateway alias.
     #[must_use]
     pub fn uses_host_gateway_alias(&self) -> bool {
@@ -122,6 +130,7 @@ mod tests {
             hook: Vec::new(),
             health_path: None,
             health_statuses: None,
+            health: None,
             localhost_tls: false,
             octane: false,
             octane_workers: None,
@@ -131,7 +140,21 @@ mod tests {
             env_mapping: None,
             javascript: None,
             container_name: None,
+            memory: None,
+            cpus: None,
+            pids_limit: None,
+            shm_size: None,
+            ulimits: None,
+            tmpfs: None,
+            platform: None,
+            user: None,
+            cap_add: None,
+            restart: None,
+            build: None,
             resolved_container_name: None,
+            resolved_project_root: None,
+            resolved_runtime_env: None,
+            secret_fields: Vec::new(),
         };
 
         assert!(service.uses_host_gateway_alias());
@@ -8,30 +8,8 @@ impl ServiceConfig {
     /// Returns the default internal service port for the driver.
     #[must_use]
     pub fn default_port(&self) -> u16 {
-        let driver_port = match self.driver {
-            Driver::Mongodb => 27017,
-            Driver::Memcached => 11211,
-            Driver::Postgres => 5432,
-            Driver::Mysql => 3306,
-            Driver::Sqlserver => 1433,
-            Driver::Redis | Driver::Valkey | Driver::Dragonfly => 6379,
-            Driver::Minio => 9000,
-            Driver::Garage => 3900,
-            Driver::Rustfs => 9000,
-            Driver::Localstack => 4566,
-            Driver::Meilisearch => 7700,
-            Driver::Typesense => 8108,
-            Driver::Frankenphp => 80,
-            Driver::Reverb => 8080,
-            Driver::Horizon => 8000,
-            Driver::Scheduler => 8001,
-            Driver::Dusk => 4444,
-            Driver::Gotenberg => 3000,
-            Driver::Mailhog => 8025,
-            Driver::Rabbitmq => 5672,
-            Driver::Soketi => 6001,
-        };
-        self.container_port.unwrap_or(driver_port)
+        self.container_port
+            .unwrap_or_else(|| driver_default_port(self.driver))
     }
 
     /// Returns true when this is a SQL database service.
@@ -83,3 +61,30 @@ impl ServiceConfig {
         self.default_port()
     }
 }
+
+/// Returns the port a driver's container listens on by default.
+pub(in crate::config) const fn driver_default_port(driver: Driver) -> u16 {
+    match driver {
+        Driver::Mongodb => 27017,
+        Driver::Memcached => 11211,
+        Driver::Postgres => 5432,
+        Driver::Mysql => 3306,
+        Driver::Sqlserver => 1433,
+        Driver::Redis | Driver::Valkey | Driver::Dragonfly => 6379,
+        Driver::Minio => 9000,
+        Driver::Garage => 3900,
+        Driver::Rustfs => 9000,
+        Driver::Localstack => 4566,
+        Driver::Meilisearch => 7700,
+        Driver::Typesense => 8108,
+        Driver::Frankenphp => 80,
+        Driver::Reverb => 8080,
+        Driver::Horizon => 8000,
+        Driver::Scheduler => 8001,
+        Driver::Dusk => 4444,
+        Driver::Gotenberg => 3000,
+        Driver::Mailhog => 8025,
+        Driver::Rabbitmq => 5672,
+        Driver::Soketi => 6001,
+    }
+}
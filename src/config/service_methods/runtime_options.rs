@@ -0,0 +1,101 @@
+//! config service methods runtime options module.
+//!
+//! Contains config service methods runtime options logic used by Helm command workflows.
+
+use super::ServiceConfig;
+
+impl ServiceConfig {
+    /// Returns `run` flags for resource limits and runtime tuning options.
+    ///
+    /// Docker and Podman accept the same spelling for every flag, so both run
+    /// argument builders append this list unchanged.
+    #[must_use]
+    pub fn runtime_option_args(&self) -> Vec<String> {
+        let mut args = Vec::new();
+        let mut push = |flag: &str, value: String| {
+            args.push(flag.to_owned());
+            args.push(value);
+        };
+
+        if let Some(platform) = &self.platform {
+            push("--platform", platform.clone());
+        }
+        if let Some(user) = &self.user {
+            push("--user", user.clone());
+        }
+        if let Some(memory) = &self.memory {
+            push("--memory", memory.clone());
+        }
+        if let Some(cpus) = self.cpus {
+            push("--cpus", cpus.to_string());
+        }
+        if let Some(pids_limit) = self.pids_limit {
+            push("--pids-limit", pids_limit.to_string());
+        }
+        if let Some(shm_size) = &self.shm_size {
+            push("--shm-size", shm_size.clone());
+        }
+        if let Some(ulimits) = &self.ulimits {
+            let mut ulimits = ulimits.iter().collect::<Vec<_>>();
+            ulimits.sort();
+            for (name, value) in ulimits {
+                push("--ulimit", format!("{name}={value}"));
+            }
+        }
+        for mount in self.tmpfs.iter().flatten() {
+            push("--tmpfs", mount.clone());
+        }
+        for capability in self.cap_add.iter().flatten() {
+            push("--cap-add", capability.clone());
+        }
+        if let Some(restart) = &self.restart {
+            push("--restart", restart.clone());
+        }
+        args
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::{Driver, Kind, ServiceConfig};
+    use std::collections::HashMap;
+
+    fn service() -> ServiceConfig {
+        ServiceConfig::fixture(
+            "search",
+            Kind::Search,
+            Driver::Typesense,
+            "typesense/typesense:27.1",
+        )
+    }
+
+    #[test]
+    fn runtime_option_args_is_empty_without_options() {
+        assert!(service().runtime_option_args().is_empty());
+    }
+
+    #[test]
+    fn runtime_option_args_renders_every_option_in_stable_order() {
+        let mut service = service();
+        service.platform = Some("linux/amd64".to_owned());
+        service.user = Some("1000:1000".to_owned());
+        service.memory = Some("1g".to_owned());
+        service.cpus = Some(1.5);
+        service.pids_limit = Some(256);
+        service.shm_size = Some("2g".to_owned());
+        service.ulimits = Some(HashMap::from([
+            ("nproc".to_owned(), "512".to_owned()),
+            ("nofile".to_owned(), "1024:65535".to_owned()),
+        ]));
+        service.tmpfs = Some(vec!["/tmp:size=64m".to_owned()]);
+        service.cap_add = Some(vec!["SYS_PTRACE".to_owned()]);
+        service.restart = Some("unless-stopped".to_owned());
+
+        assert_eq!(
+            service.runtime_option_args().join(" "),
+            "--platform linux/amd64 --user 1000:1000 --memory 1g --cpus 1.5 --pids-limit 256 \
+             --shm-size 2g --ulimit nofile=1024:65535 --ulimit nproc=512 \
+             --tmpfs /tmp:size=64m --cap-add SYS_PTRACE --restart unless-stopped"
+        );
+    }
+}
@@ -0,0 +1,62 @@
+//! config service methods secrets module.
+//!
+//! Contains config service methods secrets logic used by Helm command workflows.
+
+use super::ServiceConfig;
+
+/// Placeholder shown instead of values resolved from secret references.
+const REDACTED: &str = "<redacted>";
+
+impl ServiceConfig {
+    /// Returns a copy with values resolved from secret references redacted.
+    #[must_use]
+    pub fn redacted(&self) -> Self {
+        let mut service = self.clone();
+        for field in &self.secret_fields {
+            service.redact_field(field);
+        }
+        service
+    }
+
+    /// Returns the non-empty values resolved from secret references.
+    #[must_use]
+    pub fn secret_values(&self) -> Vec<String> {
+        let mut service = self.clone();
+        self.secret_fields
+            .iter()
+            .filter_map(|field| service.field_mut(field).cloned())
+            .filter(|value| !value.is_empty())
+            .collect()
+    }
+
+    fn redact_field(&mut self, field: &str) {
+        if let Some(value) = self.field_mut(field) {
+            REDACTED.clone_into(value);
+        }
+    }
+
+    fn field_mut(&mut self, field: &str) -> Option<&mut String> {
+        let (head, tail) = field.split_once('.').unwrap_or((field, ""));
+        match (head, tail) {
+            ("image", "") => Some(&mut self.image),
+            ("host", "") => Some(&mut self.host),
+            ("database", "") => self.database.as_mut(),
+            ("username", "") => self.username.as_mut(),
+            ("password", "") => self.password.as_mut(),
+            ("bucket", "") => self.bucket.as_mut(),
+            ("access_key", "") => self.access_key.as_mut(),
+            ("secret_key", "") => self.secret_key.as_mut(),
+            ("api_key", "") => self.api_key.as_mut(),
+            ("region", "") => self.region.as_mut(),
+            ("env", key) => self.env.as_mut().and_then(|env| env.get_mut(key)),
+            ("volumes", index) => index_into(self.volumes.as_mut(), index),
+            ("command", index) => index_into(self.command.as_mut(), index),
+            _ => None,
+        }
+    }
+}
+
+fn index_into<'a>(items: Option<&'a mut Vec<String>>, index: &str) -> Option<&'a mut String> {
+    let index = index.parse::<usize>().ok()?;
+    items.and_then(|items| items.get_mut(index))
+}
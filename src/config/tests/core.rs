@@ -1,9 +1,16 @@
 use super::*;
 
 mod app_resolution;
+mod dependencies;
 mod domain_strategy;
+mod editing;
+mod health;
 mod helpers;
 mod hooks;
 mod model;
 mod parsing;
+mod profiles;
+mod references;
+mod runtime_options;
 mod service_lookup;
+mod validate;
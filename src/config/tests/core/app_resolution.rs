@@ -14,6 +14,8 @@ fn resolve_app_requires_name_when_multiple_targets() {
             app_service("web2", "two.helm", 8001, Driver::Frankenphp),
         ],
         swarm: vec![],
+        profile: std::collections::BTreeMap::new(),
+        preset: std::collections::BTreeMap::new(),
     };
 
     let result = resolve_app_service(&config, None);
@@ -36,6 +38,8 @@ fn resolve_app_defaults_to_named_app_when_multiple_targets() {
             mailhog,
         ],
         swarm: vec![],
+        profile: std::collections::BTreeMap::new(),
+        preset: std::collections::BTreeMap::new(),
     };
 
     let resolved = resolve_app_service(&config, None).expect("resolved app default");
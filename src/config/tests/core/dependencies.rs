@@ -0,0 +1,98 @@
+use super::*;
+
+fn write_dependency_config(
+    label: &str,
+    depends_on: &str,
+) -> (std::path::PathBuf, std::path::PathBuf) {
+    let nonce = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("clock should be after unix epoch")
+        .as_nanos();
+    let root = std::env::temp_dir().join(format!("helm-config-depends-on-{label}-{nonce}"));
+    std::fs::create_dir_all(&root).expect("create temp config directory");
+
+    let config_path = root.join(".helm.toml");
+    std::fs::write(
+        &config_path,
+        format!(
+            r#"project_type = "project"
+container_prefix = "acme"
+
+[[service]]
+preset = "postgres"
+name = "db"
+
+[[service]]
+preset = "laravel"
+name = "migrate"
+
+[[service]]
+preset = "laravel"
+name = "horizon"
+{depends_on}
+"#
+        ),
+    )
+    .expect("write dependency config");
+    (root, config_path)
+}
+
+#[test]
+fn load_config_with_parses_depends_on_conditions() {
+    let (root, config_path) = write_dependency_config(
+        "parse",
+        r#"depends_on = ["migrate", { service = "db", condition = "healthy" }, { service = "migrate", condition = "completed" }]"#,
+    );
+
+    let config = load_config_with(LoadConfigPathOptions::new(Some(&config_path), None))
+        .expect("load dependency config");
+    let horizon = config
+        .service
+        .iter()
+        .find(|service| service.name == "horizon")
+        .expect("horizon service");
+
+    assert_eq!(
+        horizon.depends_on,
+        Some(vec![
+            ServiceDependency::new("migrate", DependencyCondition::Started),
+            ServiceDependency::new("db", DependencyCondition::Healthy),
+            ServiceDependency::new("migrate", DependencyCondition::Completed),
+        ])
+    );
+    assert_eq!(horizon.dependency_names(), vec!["migrate", "db", "migrate"]);
+
+    std::fs::remove_dir_all(root).expect("cleanup temp config directory");
+}
+
+#[test]
+fn validate_config_with_reports_unknown_long_form_dependency() {
+    let (root, config_path) = write_dependency_config(
+        "unknown",
+        r#"depends_on = [{ service = "redis", condition = "healthy" }]"#,
+    );
+
+    let diagnostics =
+        validate_config_with(ValidateConfigPathOptions::new(Some(&config_path), None))
+            .expect("validate config");
+    let codes: Vec<&str> = diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.code)
+        .collect();
+    assert_eq!(codes, vec!["HELM005"]);
+
+    std::fs::remove_dir_all(root).expect("cleanup temp config directory");
+}
+
+#[test]
+fn load_config_with_rejects_unknown_dependency_condition() {
+    let (root, config_path) = write_dependency_config(
+        "condition",
+        r#"depends_on = [{ service = "db", condition = "ready" }]"#,
+    );
+
+    let result = load_config_with(LoadConfigPathOptions::new(Some(&config_path), None));
+    assert!(result.is_err());
+
+    std::fs::remove_dir_all(root).expect("cleanup temp config directory");
+}
@@ -0,0 +1,199 @@
+use super::*;
+
+const COMMENTED_CONFIG: &str = r#"# Project config
+project_type = "project"
+container_prefix = "acme" # prefix
+
+# Database
+[[service]]
+preset = "mysql"
+name = "db"
+port = 3306 # keep in sync with CI
+depends_on = ["cache"]
+
+[[service]]
+preset = "redis"
+"#;
+
+fn write_config(label: &str) -> (std::path::PathBuf, std::path::PathBuf) {
+    let nonce = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("clock should be after unix epoch")
+        .as_nanos();
+    let root = std::env::temp_dir().join(format!("helm-config-editing-{label}-{nonce}"));
+    std::fs::create_dir_all(&root).expect("create temp config directory");
+    let config_path = root.join(".helm.toml");
+    std::fs::write(&config_path, COMMENTED_CONFIG).expect("write config");
+    (root, config_path)
+}
+
+#[test]
+fn edit_config_with_preserves_comments_and_ordering() {
+    let (root, config_path) = write_config("set");
+
+    edit_config_with(
+        EditConfigPathOptions::new(Some(&config_path), None),
+        &[
+            ConfigEdit::set("service.db.port", 5433),
+            ConfigEdit::set("service.redis.image", "redis:7"),
+            ConfigEdit::set("service.db.env.APP_KEY", "base64:key"),
+            ConfigEdit::unset("service.db.depends_on"),
+        ],
+    )
+    .expect("edit config");
+
+    let content = std::fs::read_to_string(&config_path).expect("read edited config");
+    assert_eq!(
+        content,
+        r#"# Project config
+project_type = "project"
+container_prefix = "acme" # prefix
+
+# Database
+[[service]]
+preset = "mysql"
+name = "db"
+port = 5433 # keep in sync with CI
+env = { APP_KEY = "base64:key" }
+
+[[service]]
+preset = "redis"
+image = "redis:7"
+"#
+    );
+
+    std::fs::remove_dir_all(root).expect("cleanup temp config directory");
+}
+
+#[test]
+fn config_value_with_reads_entries_and_array_elements() {
+    let (root, config_path) = write_config("get");
+    let options = EditConfigPathOptions::new(Some(&config_path), None);
+
+    assert_eq!(
+        config_value_with(options, "service.db.port").expect("get port"),
+        Some("3306".to_owned())
+    );
+    assert_eq!(
+        config_value_with(options, "service.db.depends_on.0").expect("get dependency"),
+        Some("\"cache\"".to_owned())
+    );
+    assert_eq!(
+        config_value_with(options, "service.missing.port").expect("get missing"),
+        None
+    );
+
+    std::fs::remove_dir_all(root).expect("cleanup temp config directory");
+}
+
+#[test]
+fn edit_config_with_rejects_invalid_values_without_writing() {
+    let (root, config_path) = write_config("invalid");
+
+    let error = edit_config_with(
+        EditConfigPathOptions::new(Some(&config_path), None),
+        &[ConfigEdit::set("service.db.port", "not-a-port")],
+    )
+    .expect_err("invalid port should fail");
+    assert!(error.to_string().contains("invalid config"));
+    assert_eq!(
+        std::fs::read_to_string(&config_path).expect("read config"),
+        COMMENTED_CONFIG
+    );
+
+    std::fs::remove_dir_all(root).expect("cleanup temp config directory");
+}
+
+#[test]
+fn edit_config_with_reports_unknown_entries() {
+    let (root, config_path) = write_config("unknown");
+
+    let error = edit_config_with(
+        EditConfigPathOptions::new(Some(&config_path), None),
+        &[ConfigEdit::set("service.missing.port", 1234)],
+    )
+    .expect_err("unknown service should fail");
+    assert!(format!("{error:#}").contains("no entry named 'missing'"));
+
+    std::fs::remove_dir_all(root).expect("cleanup temp config directory");
+}
+
+#[test]
+fn edit_config_with_resolves_preset_default_names() {
+    let (root, config_path) = write_config("preset-name");
+    std::fs::write(&config_path, "[[service]]\npreset = \"mysql\"\n").expect("write config");
+    let options = EditConfigPathOptions::new(Some(&config_path), None);
+
+    edit_config_with(options, &[ConfigEdit::set("service.db.port", 3307)]).expect("edit config");
+
+    assert_eq!(
+        std::fs::read_to_string(&config_path).expect("read config"),
+        "[[service]]\npreset = \"mysql\"\nport = 3307\n"
+    );
+    assert_eq!(
+        config_value_with(options, "service.db.port").expect("get port"),
+        Some("3307".to_owned())
+    );
+
+    std::fs::remove_dir_all(root).expect("cleanup temp config directory");
+}
+
+#[test]
+fn edit_config_with_writes_to_the_layer_owning_the_entry() {
+    let (root, config_path) = write_config("layers");
+    let local_path = root.join(".helm.local.toml");
+    let local = "[[service]]\nname = \"db\"\nport = 4306\n\n[[service]]\npreset = \"meilisearch\"\nname = \"search\"\n";
+    std::fs::write(&local_path, local).expect("write local overlay");
+    let options = EditConfigPathOptions::new(Some(&config_path), None);
+
+    assert_eq!(
+        config_value_with(options, "service.db.port").expect("get port"),
+        Some("4306".to_owned())
+    );
+    let written = edit_config_with(
+        options,
+        &[
+            ConfigEdit::set("service.db.port", 4307),
+            ConfigEdit::set("service.search.port", 7701),
+        ],
+    )
+    .expect("edit config");
+
+    assert_eq!(written, vec![local_path.clone()]);
+    assert_eq!(
+        std::fs::read_to_string(&config_path).expect("read config"),
+        COMMENTED_CONFIG
+    );
+    assert_eq!(
+        std::fs::read_to_string(&local_path).expect("read local overlay"),
+        local
+            .replace("4306", "4307")
+            .replace("name = \"search\"\n", "name = \"search\"\nport = 7701\n")
+    );
+
+    std::fs::remove_dir_all(root).expect("cleanup temp config directory");
+}
+
+#[test]
+fn edit_config_with_targets_the_runtime_env_config() {
+    let (root, config_path) = write_config("runtime-env");
+    let env_path = root.join(".helm.staging.toml");
+    std::fs::write(&env_path, "[[service]]\npreset = \"redis\"\n").expect("write env config");
+
+    edit_config_with(
+        EditConfigPathOptions::new(None, Some(&root)).with_runtime_env(Some("staging")),
+        &[ConfigEdit::set("service.redis.port", 6380)],
+    )
+    .expect("edit config");
+
+    assert_eq!(
+        std::fs::read_to_string(&env_path).expect("read env config"),
+        "[[service]]\npreset = \"redis\"\nport = 6380\n"
+    );
+    assert_eq!(
+        std::fs::read_to_string(&config_path).expect("read config"),
+        COMMENTED_CONFIG
+    );
+
+    std::fs::remove_dir_all(root).expect("cleanup temp config directory");
+}
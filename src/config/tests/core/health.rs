@@ -0,0 +1,84 @@
+use super::*;
+
+fn load_health_config(label: &str, health: &str) -> anyhow::Result<Config> {
+    let nonce = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("clock should be after unix epoch")
+        .as_nanos();
+    let root = std::env::temp_dir().join(format!("helm-config-health-{label}-{nonce}"));
+    std::fs::create_dir_all(&root).expect("create temp config directory");
+
+    let config_path = root.join(".helm.toml");
+    std::fs::write(
+        &config_path,
+        format!(
+            r#"
+                project_type = "project"
+                container_prefix = "acme"
+
+                [[service]]
+                preset = "typesense"
+
+                [service.health]
+                {health}
+            "#
+        ),
+    )
+    .expect("write health config");
+
+    let config = load_config_with(LoadConfigPathOptions::new(Some(&config_path), None));
+    std::fs::remove_dir_all(root).expect("cleanup temp config directory");
+    config
+}
+
+#[test]
+fn load_config_with_parses_service_health_probe() {
+    let config = load_health_config(
+        "parse",
+        r#"
+                type = "http"
+                path = "/health"
+                statuses = [200]
+                body_contains = "\"ok\":true"
+                interval_sec = 5
+                retries = 10
+                start_period_sec = 60
+            "#,
+    )
+    .expect("load health config");
+
+    let health = config.service[0].health.as_ref().expect("health probe");
+    assert_eq!(health.probe, HealthProbeType::Http);
+    assert_eq!(health.path.as_deref(), Some("/health"));
+    assert_eq!(health.statuses, Some(vec![200]));
+    assert_eq!(health.interval_sec, Some(5));
+    assert_eq!(health.retries, Some(10));
+    assert_eq!(health.start_period_sec, Some(60));
+}
+
+#[test]
+fn load_config_with_rejects_mismatched_health_fields() {
+    for (label, health, expected) in [
+        (
+            "exec-command",
+            r#"type = "exec""#,
+            "requires a non-empty command",
+        ),
+        (
+            "tcp-path",
+            "type = \"tcp\"\npath = \"/\"",
+            "health type 'tcp' does not use",
+        ),
+        (
+            "http-path",
+            "type = \"http\"\npath = \"health\"",
+            "must start with '/'",
+        ),
+    ] {
+        let error = load_health_config(label, health).expect_err("invalid probe should fail");
+        assert!(
+            error.to_string().contains(expected),
+            "{label}: unexpected error {error:#}"
+        );
+    }
+}
@@ -29,6 +29,7 @@ pub(super) fn mysql_service(name: &str) -> ServiceConfig {
         hook: Vec::new(),
         health_path: None,
         health_statuses: None,
+        health: None,
         localhost_tls: false,
         octane: false,
         octane_workers: None,
@@ -38,7 +39,21 @@ pub(super) fn mysql_service(name: &str) -> ServiceConfig {
         env_mapping: None,
         javascript: None,
         container_name: None,
+        memory: None,
+        cpus: None,
+        pids_limit: None,
+        shm_size: None,
+        ulimits: None,
+        tmpfs: None,
+        platform: None,
+        user: None,
+        cap_add: None,
+        restart: None,
+        build: None,
         resolved_container_name: Some(format!("test-{name}")),
+        resolved_project_root: None,
+        resolved_runtime_env: None,
+        secret_fields: Vec::new(),
     }
 }
 
@@ -72,6 +87,7 @@ pub(super) fn app_service(name: &str, domain: &str, port: u16, driver: Driver) -
         hook: Vec::new(),
         health_path: None,
         health_statuses: None,
+        health: None,
         localhost_tls: false,
         octane: false,
         octane_workers: None,
@@ -81,6 +97,20 @@ pub(super) fn app_service(name: &str, domain: &str, port: u16, driver: Driver) -
         env_mapping: None,
         javascript: None,
         container_name: None,
+        memory: None,
+        cpus: None,
+        pids_limit: None,
+        shm_size: None,
+        ulimits: None,
+        tmpfs: None,
+        platform: None,
+        user: None,
+        cap_add: None,
+        restart: None,
+        build: None,
         resolved_container_name: None,
+        resolved_project_root: None,
+        resolved_runtime_env: None,
+        secret_fields: Vec::new(),
     }
 }
@@ -51,12 +51,18 @@ fn apply_runtime_env_appends_name_suffix_and_shifts_ports() -> Result<()> {
         domain_strategy: None,
         service: vec![mysql_service("db")],
         swarm: Vec::new(),
+        profile: std::collections::BTreeMap::new(),
+        preset: std::collections::BTreeMap::new(),
     };
 
     apply_runtime_env(&mut config, "test")?;
 
     assert_eq!(config.service[0].container_name()?, "test-db-testing");
     assert_eq!(config.service[0].port, 4306);
+    assert_eq!(
+        config.service[0].resolved_runtime_env.as_deref(),
+        Some("testing")
+    );
     Ok(())
 }
 
@@ -72,6 +78,8 @@ fn apply_runtime_env_testing_namespace_keeps_base_ports() -> Result<()> {
             ..mysql_service("db")
         }],
         swarm: Vec::new(),
+        profile: std::collections::BTreeMap::new(),
+        preset: std::collections::BTreeMap::new(),
     };
 
     apply_runtime_env(&mut config, "testing-2f1a9c4d")?;
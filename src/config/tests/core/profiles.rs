@@ -0,0 +1,122 @@
+use super::*;
+
+fn write_profile_config(label: &str, profiles: &str) -> (std::path::PathBuf, std::path::PathBuf) {
+    let nonce = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("clock should be after unix epoch")
+        .as_nanos();
+    let root = std::env::temp_dir().join(format!("helm-config-profile-{label}-{nonce}"));
+    std::fs::create_dir_all(&root).expect("create temp config directory");
+
+    let config_path = root.join(".helm.toml");
+    std::fs::write(
+        &config_path,
+        format!(
+            r#"
+                project_type = "project"
+                container_prefix = "acme"
+
+                [[service]]
+                preset = "mysql"
+                name = "db"
+
+                [[service]]
+                preset = "redis"
+                name = "cache"
+                {profiles}
+            "#
+        ),
+    )
+    .expect("write profile config");
+
+    (root, config_path)
+}
+
+#[test]
+fn load_config_with_parses_profile_tables() {
+    let (root, config_path) = write_profile_config(
+        "parse",
+        r#"
+                [profile.backend-only]
+                services = ["db"]
+                drivers = ["redis"]
+
+                [profile.e2e]
+                include = ["backend-only", "app"]
+            "#,
+    );
+
+    let config = load_config_with(LoadConfigPathOptions::new(Some(&config_path), None))
+        .expect("load profile config");
+    let backend = config.profile.get("backend-only").expect("backend profile");
+    assert_eq!(backend.services, vec!["db"]);
+    assert_eq!(backend.drivers, vec![Driver::Redis]);
+    assert_eq!(
+        config.profile.get("e2e").expect("e2e profile").include,
+        vec!["backend-only", "app"]
+    );
+
+    std::fs::remove_dir_all(root).expect("cleanup temp config directory");
+}
+
+#[test]
+fn load_config_with_rejects_profile_with_unknown_service() {
+    let (root, config_path) = write_profile_config(
+        "unknown-service",
+        r#"
+                [profile.backend-only]
+                services = ["db", "queue"]
+            "#,
+    );
+
+    let error = load_config_with(LoadConfigPathOptions::new(Some(&config_path), None))
+        .expect_err("expected unknown service error");
+    assert!(
+        error
+            .to_string()
+            .contains("profile 'backend-only' references unknown service 'queue'")
+    );
+
+    std::fs::remove_dir_all(root).expect("cleanup temp config directory");
+}
+
+#[test]
+fn load_config_with_rejects_profile_include_cycles() {
+    let (root, config_path) = write_profile_config(
+        "cycle",
+        r#"
+                [profile.a]
+                include = ["b"]
+
+                [profile.b]
+                include = ["a"]
+            "#,
+    );
+
+    let error = load_config_with(LoadConfigPathOptions::new(Some(&config_path), None))
+        .expect_err("expected include cycle error");
+    assert!(error.to_string().contains("circular profile include"));
+
+    std::fs::remove_dir_all(root).expect("cleanup temp config directory");
+}
+
+#[test]
+fn load_config_with_rejects_unknown_profile_include() {
+    let (root, config_path) = write_profile_config(
+        "unknown-include",
+        r#"
+                [profile.e2e]
+                include = ["missing"]
+            "#,
+    );
+
+    let error = load_config_with(LoadConfigPathOptions::new(Some(&config_path), None))
+        .expect_err("expected unknown include error");
+    assert!(
+        error
+            .to_string()
+            .contains("profile 'e2e' includes unknown profile 'missing'")
+    );
+
+    std::fs::remove_dir_all(root).expect("cleanup temp config directory");
+}
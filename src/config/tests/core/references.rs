@@ -0,0 +1,70 @@
+use super::*;
+
+fn write_reference_config(label: &str) -> (std::path::PathBuf, std::path::PathBuf) {
+    let nonce = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("clock should be after unix epoch")
+        .as_nanos();
+    let root = std::env::temp_dir().join(format!("helm-config-references-{label}-{nonce}"));
+    std::fs::create_dir_all(&root).expect("create temp config directory");
+
+    let secret_path = root.join("db-password");
+    std::fs::write(&secret_path, "s3cret\n").expect("write secret file");
+
+    let config_path = root.join(".helm.toml");
+    std::fs::write(
+        &config_path,
+        format!(
+            r#"
+                project_type = "project"
+                container_prefix = "acme"
+
+                [[service]]
+                preset = "mysql"
+                name = "db"
+                image = "mysql:${{HELM_TEST_UNSET_MYSQL_TAG:-8.4}}"
+                password = {{ from = "file:{}" }}
+            "#,
+            secret_path.display()
+        ),
+    )
+    .expect("write reference config");
+
+    (root, config_path)
+}
+
+#[test]
+fn load_config_with_resolves_interpolation_and_secret_references() {
+    let (root, config_path) = write_reference_config("load");
+
+    let config = load_config_with(LoadConfigPathOptions::new(Some(&config_path), None))
+        .expect("load reference config");
+    let service = config.service.first().expect("db service");
+    assert_eq!(service.image, "mysql:8.4");
+    assert_eq!(service.password.as_deref(), Some("s3cret"));
+    assert_eq!(service.secret_fields, vec!["password"]);
+
+    let redacted = service.redacted();
+    assert_eq!(redacted.password.as_deref(), Some("<redacted>"));
+    assert_eq!(redacted.image, "mysql:8.4");
+
+    std::fs::remove_dir_all(root).expect("cleanup temp config directory");
+}
+
+#[test]
+fn edit_config_with_keeps_references_intact() {
+    let (root, config_path) = write_reference_config("edit");
+
+    edit_config_with(
+        EditConfigPathOptions::new(Some(&config_path), None),
+        &[ConfigEdit::set("service.db.port", 3307)],
+    )
+    .expect("edit referenced config");
+
+    let content = std::fs::read_to_string(&config_path).expect("read edited config");
+    assert!(content.contains("${HELM_TEST_UNSET_MYSQL_TAG:-8.4}"));
+    assert!(content.contains("password = { from = \"file:"));
+    assert!(!content.contains("s3cret"));
+
+    std::fs::remove_dir_all(root).expect("cleanup temp config directory");
+}
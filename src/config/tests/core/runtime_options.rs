@@ -0,0 +1,105 @@
+use super::*;
+
+fn write_runtime_config(label: &str, service: &str) -> (std::path::PathBuf, std::path::PathBuf) {
+    let nonce = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("clock should be after unix epoch")
+        .as_nanos();
+    let root = std::env::temp_dir().join(format!("helm-config-runtime-{label}-{nonce}"));
+    std::fs::create_dir_all(&root).expect("create temp config directory");
+
+    let config_path = root.join(".helm.toml");
+    std::fs::write(
+        &config_path,
+        format!(
+            r#"
+                project_type = "project"
+                container_prefix = "acme"
+
+                [[service]]
+                preset = "selenium"
+
+                [[service]]
+                preset = "typesense"
+                {service}
+            "#
+        ),
+    )
+    .expect("write runtime options config");
+
+    (root, config_path)
+}
+
+#[test]
+fn load_config_with_parses_runtime_options_and_preset_shm_default() {
+    let (root, config_path) = write_runtime_config(
+        "parse",
+        r#"
+                memory = "1g"
+                cpus = 1.5
+                pids_limit = 512
+                ulimits = { nofile = "1024:65535" }
+                tmpfs = ["/tmp:size=64m"]
+                platform = "linux/amd64"
+                user = "1000:1000"
+                cap_add = ["SYS_NICE"]
+                restart = "on-failure:5"
+            "#,
+    );
+
+    let config = load_config_with(LoadConfigPathOptions::new(Some(&config_path), None))
+        .expect("load runtime options config");
+    assert_eq!(config.service[0].shm_size.as_deref(), Some("2g"));
+    let search = &config.service[1];
+    assert_eq!(search.memory.as_deref(), Some("1g"));
+    assert_eq!(search.cpus, Some(1.5));
+    assert_eq!(search.pids_limit, Some(512));
+    assert_eq!(search.shm_size, None);
+    assert_eq!(
+        search
+            .ulimits
+            .as_ref()
+            .and_then(|ulimits| ulimits.get("nofile"))
+            .map(String::as_str),
+        Some("1024:65535")
+    );
+    assert_eq!(search.platform.as_deref(), Some("linux/amd64"));
+    assert_eq!(search.restart.as_deref(), Some("on-failure:5"));
+
+    std::fs::remove_dir_all(root).expect("cleanup temp config directory");
+}
+
+#[test]
+fn load_config_with_rejects_invalid_runtime_options() {
+    for (label, service, expected) in [
+        ("memory", r#"memory = "lots""#, "invalid memory 'lots'"),
+        ("cpus", "cpus = 0.0", "cpus must be greater than 0"),
+        (
+            "ulimit",
+            r#"ulimits = { files = "10" }"#,
+            "unknown ulimit 'files'",
+        ),
+        ("tmpfs", r#"tmpfs = ["tmp"]"#, "must be an absolute path"),
+        (
+            "platform",
+            r#"platform = "amd64""#,
+            "must look like `os/arch`",
+        ),
+        (
+            "restart",
+            r#"restart = "always""#,
+            "restart 'always' must be",
+        ),
+    ] {
+        let (root, config_path) = write_runtime_config(label, service);
+
+        let error = load_config_with(LoadConfigPathOptions::new(Some(&config_path), None))
+            .expect_err("invalid runtime option should fail");
+        assert!(
+            error.to_string().contains(expected),
+            "{label}: unexpected error {error:#}"
+        );
+
+        std::fs::remove_dir_all(root).expect("cleanup temp config directory");
+    }
+}
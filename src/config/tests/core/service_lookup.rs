@@ -9,6 +9,8 @@ fn find_service_success() {
         domain_strategy: None,
         service: vec![mysql_service("db1"), mysql_service("db2")],
         swarm: vec![],
+        profile: std::collections::BTreeMap::new(),
+        preset: std::collections::BTreeMap::new(),
     };
 
     let result = find_service(&config, "db2");
@@ -25,6 +27,8 @@ fn find_service_not_found() {
         domain_strategy: None,
         service: vec![mysql_service("db1")],
         swarm: vec![],
+        profile: std::collections::BTreeMap::new(),
+        preset: std::collections::BTreeMap::new(),
     };
 
     let result = find_service(&config, "nonexistent");
@@ -43,6 +47,8 @@ fn find_service_empty_list() {
         domain_strategy: None,
         service: vec![],
         swarm: vec![],
+        profile: std::collections::BTreeMap::new(),
+        preset: std::collections::BTreeMap::new(),
     };
 
     let result = find_service(&config, "any");
@@ -0,0 +1,147 @@
+use super::*;
+
+fn validate(label: &str, content: &str) -> (std::path::PathBuf, Vec<ConfigDiagnostic>) {
+    let nonce = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("clock should be after unix epoch")
+        .as_nanos();
+    let root = std::env::temp_dir().join(format!("helm-config-validate-{label}-{nonce}"));
+    std::fs::create_dir_all(&root).expect("create temp config directory");
+    let config_path = root.join(".helm.toml");
+    std::fs::write(&config_path, content).expect("write config");
+
+    let diagnostics =
+        validate_config_with(ValidateConfigPathOptions::new(Some(&config_path), None))
+            .expect("validate config");
+    (root, diagnostics)
+}
+
+fn summary(diagnostics: &[ConfigDiagnostic]) -> Vec<(&'static str, usize, usize)> {
+    diagnostics
+        .iter()
+        .map(|diagnostic| (diagnostic.code, diagnostic.line, diagnostic.column))
+        .collect()
+}
+
+#[test]
+fn validate_config_with_reports_every_problem_with_positions() {
+    let (root, diagnostics) = validate(
+        "all",
+        r#"project_type = "project"
+container_prefix = "acme"
+
+[[service]]
+preset = "mysql"
+name = "db"
+port = 3306
+imgae = "mysql:8"
+
+[[service]]
+preset = "postgres"
+name = "pg"
+port = 3306
+depends_on = ["db", "cache"]
+
+[[service.hook]]
+name = "cleanup"
+phase = "post_down"
+run = { type = "exec", argv = ["rm", "-rf", "/tmp/cache"] }
+"#,
+    );
+
+    assert_eq!(
+        summary(&diagnostics),
+        vec![
+            ("HELM003", 8, 1),
+            ("HELM004", 13, 1),
+            ("HELM005", 14, 21),
+            ("HELM006", 19, 1),
+        ]
+    );
+    assert_eq!(
+        diagnostics.first().map(|diagnostic| diagnostic.severity),
+        Some(DiagnosticSeverity::Warning)
+    );
+
+    std::fs::remove_dir_all(root).expect("cleanup temp config directory");
+}
+
+#[test]
+fn validate_config_with_reports_syntax_errors() {
+    let (root, diagnostics) = validate("syntax", "project_type = \"project\"\n[[service]\n");
+
+    assert_eq!(summary(&diagnostics), vec![("HELM001", 2, 11)]);
+
+    std::fs::remove_dir_all(root).expect("cleanup temp config directory");
+}
+
+#[test]
+fn validate_config_with_reports_invalid_values() {
+    let (root, diagnostics) = validate(
+        "value",
+        "project_type = \"project\"\n[[service]]\npreset = \"mysql\"\nport = \"abc\"\n",
+    );
+
+    assert_eq!(summary(&diagnostics), vec![("HELM002", 4, 8)]);
+
+    std::fs::remove_dir_all(root).expect("cleanup temp config directory");
+}
+
+#[test]
+fn validate_config_with_locates_load_time_errors_at_their_key() {
+    let (root, diagnostics) = validate(
+        "load",
+        r#"project_type = "project"
+container_prefix = "acme"
+
+[[service]]
+preset = "mysql"
+name = "db"
+cpus = 0.0
+
+[profile.ci]
+services = ["db", "cache"]
+"#,
+    );
+
+    assert_eq!(
+        summary(&diagnostics),
+        vec![("HELM007", 10, 19), ("HELM007", 7, 1)]
+    );
+
+    std::fs::remove_dir_all(root).expect("cleanup temp config directory");
+}
+
+#[test]
+fn validate_config_with_locates_unresolved_references_at_their_field() {
+    let (root, diagnostics) = validate(
+        "references",
+        r#"project_type = "project"
+container_prefix = "acme"
+
+[[service]]
+preset = "mysql"
+name = "db"
+password = "${HELM_VALIDATE_UNSET_PASSWORD}"
+"#,
+    );
+
+    assert_eq!(summary(&diagnostics), vec![("HELM007", 7, 1)]);
+
+    std::fs::remove_dir_all(root).expect("cleanup temp config directory");
+}
+
+#[test]
+fn validate_config_with_accepts_clean_config() {
+    let (root, diagnostics) = validate(
+        "clean",
+        "project_type = \"project\"\ncontainer_prefix = \"acme\"\n\n[[service]]\npreset = \"mysql\"\nname = \"db\"\n",
+    );
+
+    assert!(
+        diagnostics.is_empty(),
+        "unexpected diagnostics: {diagnostics:?}"
+    );
+
+    std::fs::remove_dir_all(root).expect("cleanup temp config directory");
+}
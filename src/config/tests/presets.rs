@@ -1,6 +1,7 @@
 use super::*;
 
 mod aliases;
+mod custom;
 mod defaults;
 mod names;
 mod overrides;
@@ -0,0 +1,150 @@
+use super::*;
+
+#[test]
+fn custom_preset_extends_builtin_and_overrides_fields() {
+    let toml = r#"
+            container_prefix = "acme-api"
+
+            [preset.pgvector]
+            extends = "postgres"
+            image = "registry.acme.test/postgres-pgvector:17"
+            database = "acme"
+
+            [[service]]
+            preset = "pgvector"
+            password = "override"
+        "#;
+
+    let raw: RawConfig = toml::from_str(toml).expect("raw config parse");
+    let config = expansion::expand_raw_config(raw).expect("expand custom preset config");
+    let db = config.service.first().expect("db service");
+
+    assert_eq!(db.name, "db");
+    assert_eq!(db.driver, Driver::Postgres);
+    assert_eq!(db.image, "registry.acme.test/postgres-pgvector:17");
+    assert_eq!(db.database.as_deref(), Some("acme"));
+    assert_eq!(db.username.as_deref(), Some("laravel"));
+    assert_eq!(db.password.as_deref(), Some("override"));
+    assert!(config.preset.contains_key("pgvector"));
+}
+
+#[test]
+fn custom_preset_can_shadow_builtin_name() {
+    let toml = r#"
+            container_prefix = "acme-api"
+
+            [preset.redis]
+            extends = "redis"
+            image = "registry.acme.test/redis:8"
+
+            [[service]]
+            preset = "redis"
+        "#;
+
+    let raw: RawConfig = toml::from_str(toml).expect("raw config parse");
+    let config = expansion::expand_raw_config(raw).expect("expand shadowed preset config");
+    let cache = config.service.first().expect("cache service");
+
+    assert_eq!(cache.driver, Driver::Redis);
+    assert_eq!(cache.image, "registry.acme.test/redis:8");
+}
+
+#[test]
+fn custom_preset_chain_merges_env_with_service_precedence() {
+    let toml = r#"
+            container_prefix = "acme-api"
+
+            [preset.sidecar]
+            kind = "app"
+            driver = "gotenberg"
+            image = "registry.acme.test/sidecar:1"
+            env = { LOG_LEVEL = "info", REGION = "eu" }
+
+            [preset.sidecar-debug]
+            extends = "sidecar"
+            env = { LOG_LEVEL = "debug" }
+
+            [[service]]
+            preset = "sidecar-debug"
+            name = "sidecar"
+            env = { REGION = "us" }
+        "#;
+
+    let raw: RawConfig = toml::from_str(toml).expect("raw config parse");
+    let config = expansion::expand_raw_config(raw).expect("expand chained preset config");
+    let sidecar = config.service.first().expect("sidecar service");
+    let env = sidecar.env.as_ref().expect("merged env");
+
+    assert_eq!(sidecar.image, "registry.acme.test/sidecar:1");
+    assert_eq!(env.get("LOG_LEVEL").map(String::as_str), Some("debug"));
+    assert_eq!(env.get("REGION").map(String::as_str), Some("us"));
+}
+
+#[test]
+fn custom_preset_without_extends_requires_kind_driver_and_image() {
+    let toml = r#"
+            container_prefix = "acme-api"
+
+            [preset.sidecar]
+            image = "registry.acme.test/sidecar:1"
+
+            [[service]]
+            preset = "sidecar"
+        "#;
+
+    let raw: RawConfig = toml::from_str(toml).expect("raw config parse");
+    let error = expansion::expand_raw_config(raw).expect_err("incomplete preset should fail");
+    assert!(
+        error
+            .to_string()
+            .contains("custom preset 'sidecar' must set kind, driver and image")
+    );
+}
+
+#[test]
+fn custom_preset_rejects_circular_extends() {
+    let toml = r#"
+            container_prefix = "acme-api"
+
+            [preset.a]
+            extends = "b"
+
+            [preset.b]
+            extends = "a"
+
+            [[service]]
+            preset = "a"
+        "#;
+
+    let raw: RawConfig = toml::from_str(toml).expect("raw config parse");
+    let error = expansion::expand_raw_config(raw).expect_err("cycle should fail");
+    assert!(error.to_string().contains("circular extends chain"));
+}
+
+#[test]
+fn project_custom_preset_takes_precedence_over_user_preset() {
+    let toml = r#"
+            container_prefix = "acme-api"
+
+            [preset.cache]
+            extends = "valkey"
+
+            [[service]]
+            preset = "cache"
+        "#;
+
+    let mut raw: RawConfig = toml::from_str(toml).expect("raw config parse");
+    raw.user_preset.insert(
+        "cache".to_owned(),
+        PresetConfig {
+            extends: Some("redis".to_owned()),
+            ..PresetConfig::default()
+        },
+    );
+    let config = expansion::expand_raw_config(raw).expect("expand preset config");
+
+    assert_eq!(
+        config.service.first().expect("cache service").driver,
+        Driver::Valkey
+    );
+}
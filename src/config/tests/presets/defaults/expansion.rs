@@ -207,6 +207,7 @@ fn queue_worker_preset_sets_runtime_command_and_defaults() {
         worker.env.as_ref().and_then(|env| env.get("APP_ENV")),
         Some(&"local".to_owned())
     );
+    assert_eq!(worker.restart.as_deref(), Some("unless-stopped"));
 }
 
 #[test]
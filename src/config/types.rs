@@ -5,7 +5,12 @@
 mod config_root;
 mod enums;
 mod lockfile;
+mod preset;
+mod profile;
 mod service;
+mod service_build;
+mod service_dependency;
+mod service_health;
 mod service_hook;
 mod swarm;
 mod swarm_git;
@@ -13,7 +18,12 @@ mod swarm_git;
 pub use config_root::Config;
 pub use enums::{ContainerEngine, DomainStrategy, Driver, Kind, ProjectType};
 pub use lockfile::{LockedImage, Lockfile};
+pub use preset::PresetConfig;
+pub use profile::{BUILTIN_PROFILE_NAMES, ProfileConfig};
 pub use service::ServiceConfig;
+pub use service_build::ServiceBuild;
+pub use service_dependency::{DependencyCondition, ServiceDependency};
+pub use service_health::{HealthProbeType, ServiceHealth};
 pub use service_hook::{HookOnError, HookPhase, HookRun, ServiceHook};
 pub(crate) use swarm::SwarmInjectEnv;
 pub use swarm::SwarmTarget;
@@ -3,8 +3,9 @@
 //! Contains config types config root logic used by Helm command workflows.
 
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
-use super::{DomainStrategy, ProjectType, ServiceConfig, SwarmTarget};
+use super::{DomainStrategy, PresetConfig, ProfileConfig, ProjectType, ServiceConfig, SwarmTarget};
 
 /// Root configuration structure.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -26,6 +27,12 @@ pub struct Config {
     /// Optional swarm workspace targets.
     #[serde(default)]
     pub swarm: Vec<SwarmTarget>,
+    /// User-defined service profiles keyed by profile name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub profile: BTreeMap<String, ProfileConfig>,
+    /// Project-level custom presets keyed by preset name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub preset: BTreeMap<String, PresetConfig>,
 }
 
 /// Returns the default value for schema version.
@@ -3,10 +3,11 @@
 //! Contains config types enums logic used by Helm command workflows.
 
 use clap::ValueEnum;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Helm project archetype.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ValueEnum, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 #[non_exhaustive]
 pub enum ProjectType {
@@ -17,7 +18,7 @@ pub enum ProjectType {
 }
 
 /// Service category.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ValueEnum, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum Kind {
@@ -34,7 +35,7 @@ pub enum Kind {
 }
 
 /// Service driver/backend.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ValueEnum, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 #[non_exhaustive]
 pub enum Driver {
@@ -87,7 +88,7 @@ pub enum Driver {
 }
 
 /// Container runtime engine.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ValueEnum, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 #[non_exhaustive]
 pub enum ContainerEngine {
@@ -95,6 +96,8 @@ pub enum ContainerEngine {
     Docker,
     /// Podman engine.
     Podman,
+    /// containerd through `nerdctl` (Rancher Desktop, Lima).
+    Nerdctl,
 }
 
 impl Default for ContainerEngine {
@@ -104,7 +107,7 @@ impl Default for ContainerEngine {
 }
 
 /// Strategy for generating default app domains.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ValueEnum, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 #[non_exhaustive]
 pub enum DomainStrategy {
@@ -120,6 +123,7 @@ impl ContainerEngine {
         match self {
             Self::Docker => "docker",
             Self::Podman => "podman",
+            Self::Nerdctl => "nerdctl",
         }
     }
 
@@ -128,13 +132,14 @@ impl ContainerEngine {
         match self {
             Self::Docker => "host.docker.internal",
             Self::Podman => "host.containers.internal",
+            Self::Nerdctl => "host.docker.internal",
         }
     }
 
     #[must_use]
     pub const fn host_gateway_mapping(self) -> Option<&'static str> {
         match self {
-            Self::Docker => Some("host.docker.internal:host-gateway"),
+            Self::Docker | Self::Nerdctl => Some("host.docker.internal:host-gateway"),
             Self::Podman => None,
         }
     }
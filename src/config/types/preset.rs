@@ -0,0 +1,96 @@
+//! config types preset module.
+//!
+//! Contains config types preset logic used by Helm command workflows.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use super::{Driver, Kind};
+
+/// Custom preset declared as `[preset.<name>]` or in the user preset directory.
+///
+/// Every field is optional so a preset can layer on top of the preset named
+/// by `extends`; services using the preset still override any field.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[non_exhaustive]
+pub struct PresetConfig {
+    /// Built-in or custom preset this preset builds on.
+    #[serde(default)]
+    pub extends: Option<String>,
+    /// Default service name.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Service kind.
+    #[serde(default)]
+    pub kind: Option<Kind>,
+    /// Backend driver.
+    #[serde(default)]
+    pub driver: Option<Driver>,
+    /// Docker image.
+    #[serde(default)]
+    pub image: Option<String>,
+    /// Host bind address.
+    #[serde(default)]
+    pub host: Option<String>,
+    /// Host port.
+    #[serde(default)]
+    pub port: Option<u16>,
+    /// Database name for SQL services.
+    #[serde(default)]
+    pub database: Option<String>,
+    /// Username for services with auth.
+    #[serde(default)]
+    pub username: Option<String>,
+    /// Password for services with auth.
+    #[serde(default)]
+    pub password: Option<String>,
+    /// Object store bucket.
+    #[serde(default)]
+    pub bucket: Option<String>,
+    /// Access key for object store.
+    #[serde(default)]
+    pub access_key: Option<String>,
+    /// Secret key for object store.
+    #[serde(default)]
+    pub secret_key: Option<String>,
+    /// API key for search services.
+    #[serde(default)]
+    pub api_key: Option<String>,
+    /// Region for object store.
+    #[serde(default)]
+    pub region: Option<String>,
+    /// URL scheme override (`http`, `https`).
+    #[serde(default)]
+    pub scheme: Option<String>,
+    /// Internal container port override.
+    #[serde(default)]
+    pub container_port: Option<u16>,
+    /// Host SMTP port for mail testing services.
+    #[serde(default)]
+    pub smtp_port: Option<u16>,
+    /// Run app services with Laravel Octane.
+    #[serde(default)]
+    pub octane: Option<bool>,
+    /// Octane worker count.
+    #[serde(default)]
+    pub octane_workers: Option<u16>,
+    /// Octane max requests.
+    #[serde(default)]
+    pub octane_max_requests: Option<u32>,
+    /// PHP extensions for derived serve images.
+    #[serde(default)]
+    pub php_extensions: Option<Vec<String>>,
+    /// Docker volume mounts.
+    #[serde(default)]
+    pub volumes: Option<Vec<String>>,
+    /// Container command and args.
+    #[serde(default)]
+    pub command: Option<Vec<String>>,
+    /// Default container environment; service `env` keys take precedence.
+    #[serde(default)]
+    pub env: Option<HashMap<String, String>>,
+    /// Trust inner container Caddy CA in local system trust store.
+    #[serde(default)]
+    pub trust_container_ca: Option<bool>,
+}
@@ -0,0 +1,29 @@
+//! config types profile module.
+//!
+//! Contains config types profile logic used by Helm command workflows.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{Driver, Kind};
+
+/// Profile names resolved without any `[profile.<name>]` config entry.
+pub const BUILTIN_PROFILE_NAMES: [&str; 7] = ["full", "all", "infra", "data", "app", "web", "api"];
+
+/// User-defined service selection declared as `[profile.<name>]`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[non_exhaustive]
+pub struct ProfileConfig {
+    /// Service names selected explicitly.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub services: Vec<String>,
+    /// Service kinds selected as a whole.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub kinds: Vec<Kind>,
+    /// Service drivers selected as a whole.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub drivers: Vec<Driver>,
+    /// Other built-in or configured profiles merged into this one.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub include: Vec<String>,
+}
@@ -4,8 +4,9 @@
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::PathBuf;
 
-use super::{Driver, Kind, ServiceHook};
+use super::{Driver, Kind, ServiceBuild, ServiceDependency, ServiceHealth, ServiceHook};
 use crate::javascript::JavaScriptToolchain;
 
 /// Configuration for a single service instance.
@@ -20,6 +21,9 @@ pub struct ServiceConfig {
     pub driver: Driver,
     /// Docker image.
     pub image: String,
+    /// Image build that replaces pulling `image`.
+    #[serde(default)]
+    pub build: Option<ServiceBuild>,
     /// Host bind address.
     pub host: String,
     /// Host port.
@@ -66,9 +70,10 @@ pub struct ServiceConfig {
     /// Optional command and args for the container entrypoint.
     #[serde(default)]
     pub command: Option<Vec<String>>,
-    /// Optional service dependencies that should be started first.
+    /// Optional service dependencies and the condition each must reach
+    /// before this service starts.
     #[serde(default)]
-    pub depends_on: Option<Vec<String>>,
+    pub depends_on: Option<Vec<ServiceDependency>>,
     /// Optional SQL seed file applied when running `up --with-data`.
     #[serde(default)]
     pub seed_file: Option<String>,
@@ -81,6 +86,9 @@ pub struct ServiceConfig {
     /// Optional accepted health status codes.
     #[serde(default)]
     pub health_statuses: Option<Vec<u16>>,
+    /// Readiness probe overriding the driver's built-in health check.
+    #[serde(default)]
+    pub health: Option<ServiceHealth>,
     /// Serve app directly via <https://localhost>:<port> without host Caddy routing.
     #[serde(default)]
     pub localhost_tls: bool,
@@ -108,7 +116,46 @@ pub struct ServiceConfig {
     /// Explicit docker container name for this service.
     #[serde(default)]
     pub container_name: Option<String>,
+    /// Memory limit such as `512m` or `2g` (`--memory`).
+    #[serde(default)]
+    pub memory: Option<String>,
+    /// CPU limit such as `1.5` (`--cpus`).
+    #[serde(default)]
+    pub cpus: Option<f64>,
+    /// Maximum number of processes (`--pids-limit`).
+    #[serde(default)]
+    pub pids_limit: Option<u32>,
+    /// Size of `/dev/shm` such as `2g` (`--shm-size`).
+    #[serde(default)]
+    pub shm_size: Option<String>,
+    /// Ulimits by name, as `soft` or `soft:hard` (`--ulimit`).
+    #[serde(default)]
+    pub ulimits: Option<HashMap<String, String>>,
+    /// Tmpfs mounts such as `/tmp` or `/tmp:size=64m` (`--tmpfs`).
+    #[serde(default)]
+    pub tmpfs: Option<Vec<String>>,
+    /// Image platform such as `linux/amd64` (`--platform`).
+    #[serde(default)]
+    pub platform: Option<String>,
+    /// Container user as `name`, `uid`, or `uid:gid` (`--user`).
+    #[serde(default)]
+    pub user: Option<String>,
+    /// Linux capabilities to add such as `SYS_PTRACE` (`--cap-add`).
+    #[serde(default)]
+    pub cap_add: Option<Vec<String>>,
+    /// Restart policy: `no`, `on-failure[:N]`, or `unless-stopped` (`--restart`).
+    #[serde(default)]
+    pub restart: Option<String>,
     /// Resolved container name at runtime (not serialized).
     #[serde(skip)]
     pub resolved_container_name: Option<String>,
+    /// Workspace root the service was loaded from (not serialized).
+    #[serde(skip)]
+    pub resolved_project_root: Option<PathBuf>,
+    /// Runtime env applied with `--env` (not serialized).
+    #[serde(skip)]
+    pub resolved_runtime_env: Option<String>,
+    /// Field paths filled from secret references (redacted in config output).
+    #[serde(skip)]
+    pub secret_fields: Vec<String>,
 }
@@ -0,0 +1,43 @@
+//! config types service build module.
+//!
+//! Contains the `[service.build]` image build configuration.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Image Helm builds for a service instead of pulling `image`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+#[non_exhaustive]
+pub struct ServiceBuild {
+    /// Build context directory, relative to the project root (default `.`).
+    #[serde(default)]
+    pub context: Option<String>,
+    /// Dockerfile path, relative to the context (default `Dockerfile`).
+    #[serde(default)]
+    pub dockerfile: Option<String>,
+    /// Build args passed as `--build-arg KEY=value`.
+    #[serde(default)]
+    pub args: Option<HashMap<String, String>>,
+    /// Multi-stage target to build (`--target`).
+    #[serde(default)]
+    pub target: Option<String>,
+    /// BuildKit secrets such as `id=npm,src=~/.npmrc` (`--secret`).
+    #[serde(default)]
+    pub secrets: Option<Vec<String>>,
+}
+
+impl ServiceBuild {
+    /// Returns the context directory as configured.
+    #[must_use]
+    pub fn context_dir(&self) -> &str {
+        self.context.as_deref().unwrap_or(".")
+    }
+
+    /// Returns the Dockerfile path relative to the context.
+    #[must_use]
+    pub fn dockerfile_path(&self) -> &str {
+        self.dockerfile.as_deref().unwrap_or("Dockerfile")
+    }
+}
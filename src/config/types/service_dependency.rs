@@ -0,0 +1,145 @@
+//! config types service dependency module.
+//!
+//! Contains the `depends_on` entry types and their short/long TOML forms.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// What a dependent waits for before it starts.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum DependencyCondition {
+    /// The dependency's container has been started.
+    #[default]
+    Started,
+    /// The dependency passed its health check.
+    Healthy,
+    /// The dependency ran to completion and exited with code 0.
+    Completed,
+}
+
+impl DependencyCondition {
+    /// Returns the config spelling of the condition.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Started => "started",
+            Self::Healthy => "healthy",
+            Self::Completed => "completed",
+        }
+    }
+}
+
+/// One `depends_on` entry.
+///
+/// Accepts either a bare service name (`"db"`, condition `started`) or a
+/// table (`{ service = "db", condition = "healthy" }`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(from = "DependencySpec", into = "DependencySpec")]
+#[non_exhaustive]
+pub struct ServiceDependency {
+    /// Name of the service depended on.
+    pub service: String,
+    /// Condition the dependency must reach first.
+    pub condition: DependencyCondition,
+}
+
+impl ServiceDependency {
+    /// Creates a dependency entry.
+    #[must_use]
+    pub fn new(service: impl Into<String>, condition: DependencyCondition) -> Self {
+        Self {
+            service: service.into(),
+            condition,
+        }
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum DependencySpec {
+    Name(String),
+    Detailed(DetailedDependency),
+}
+
+#[derive(Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+struct DetailedDependency {
+    service: String,
+    #[serde(default)]
+    condition: DependencyCondition,
+}
+
+impl From<DependencySpec> for ServiceDependency {
+    fn from(spec: DependencySpec) -> Self {
+        match spec {
+            DependencySpec::Name(service) => Self::new(service, DependencyCondition::Started),
+            DependencySpec::Detailed(detailed) => Self::new(detailed.service, detailed.condition),
+        }
+    }
+}
+
+impl From<ServiceDependency> for DependencySpec {
+    fn from(dependency: ServiceDependency) -> Self {
+        match dependency.condition {
+            DependencyCondition::Started => Self::Name(dependency.service),
+            DependencyCondition::Healthy | DependencyCondition::Completed => {
+                Self::Detailed(DetailedDependency {
+                    service: dependency.service,
+                    condition: dependency.condition,
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DependencyCondition, ServiceDependency};
+
+    #[derive(serde::Deserialize, serde::Serialize)]
+    struct Wrapper {
+        depends_on: Vec<ServiceDependency>,
+    }
+
+    #[test]
+    fn depends_on_accepts_short_and_long_forms() -> anyhow::Result<()> {
+        let wrapper: Wrapper = toml::from_str(
+            r#"depends_on = ["cache", { service = "db", condition = "healthy" }, { service = "migrate", condition = "completed" }, { service = "mail" }]"#,
+        )?;
+        assert_eq!(
+            wrapper.depends_on,
+            vec![
+                ServiceDependency::new("cache", DependencyCondition::Started),
+                ServiceDependency::new("db", DependencyCondition::Healthy),
+                ServiceDependency::new("migrate", DependencyCondition::Completed),
+                ServiceDependency::new("mail", DependencyCondition::Started),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn depends_on_serializes_started_entries_in_short_form() -> anyhow::Result<()> {
+        let wrapper = Wrapper {
+            depends_on: vec![
+                ServiceDependency::new("cache", DependencyCondition::Started),
+                ServiceDependency::new("db", DependencyCondition::Healthy),
+            ],
+        };
+        assert_eq!(
+            toml::to_string(&wrapper)?,
+            "depends_on = [\"cache\", { service = \"db\", condition = \"healthy\" }]\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn depends_on_rejects_unknown_conditions() {
+        let result =
+            toml::from_str::<Wrapper>(r#"depends_on = [{ service = "db", condition = "ready" }]"#);
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,49 @@
+//! config types service health module.
+//!
+//! Contains the `[service.health]` readiness probe configuration.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// How a configured readiness probe checks a service.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthProbeType {
+    /// Runs `command` inside the container; exit code 0 means ready.
+    Exec,
+    /// Requests `path` on the published port.
+    Http,
+    /// Opens a TCP connection to the published port.
+    Tcp,
+}
+
+/// Readiness probe that replaces the driver's built-in health check.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+#[non_exhaustive]
+pub struct ServiceHealth {
+    /// Probe type.
+    #[serde(rename = "type")]
+    pub probe: HealthProbeType,
+    /// Command argv for `exec` probes.
+    #[serde(default)]
+    pub command: Option<Vec<String>>,
+    /// Request path for `http` probes (default `/`).
+    #[serde(default)]
+    pub path: Option<String>,
+    /// Accepted HTTP statuses (default any 2xx or 3xx).
+    #[serde(default)]
+    pub statuses: Option<Vec<u16>>,
+    /// Substring the HTTP response body must contain.
+    #[serde(default)]
+    pub body_contains: Option<String>,
+    /// Seconds between probe attempts.
+    #[serde(default)]
+    pub interval_sec: Option<u64>,
+    /// Failed attempts allowed after the start period before giving up.
+    #[serde(default)]
+    pub retries: Option<u32>,
+    /// Seconds after start during which failures do not count as retries.
+    #[serde(default)]
+    pub start_period_sec: Option<u64>,
+}
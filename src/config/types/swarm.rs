@@ -2,6 +2,7 @@
 //!
 //! Contains config types swarm logic used by Helm command workflows.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
@@ -24,7 +25,7 @@ pub struct SwarmTarget {
     pub git: Option<SwarmGit>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub(crate) struct SwarmInjectEnv {
     /// Environment variable name to inject.
     pub env: String,
@@ -3,27 +3,68 @@
 //! Contains config validation logic used by Helm command workflows.
 
 use anyhow::Result;
+use std::fmt;
+use std::path::Path;
 
 use super::Config;
 
+mod build;
 mod container_names;
 mod domains;
+mod health;
+mod profiles;
+mod runtime_options;
 mod swarm;
 
-/// Validates and resolves container names and reports actionable failures.
-pub(super) fn validate_and_resolve_container_names(config: &mut Config) -> Result<()> {
-    container_names::validate_and_resolve_container_names(config)
+type Validator = fn(&mut Config, &Path) -> Result<()>;
+
+/// Load-time validations in the order they run; domains and container names
+/// are resolved in place before the checks that read them.
+const VALIDATORS: [Validator; 7] = [
+    domains::validate_and_resolve_domains,
+    |config, _| container_names::validate_and_resolve_container_names(config),
+    |config, _| swarm::validate_swarm_targets(config),
+    |config, _| profiles::validate_profiles(config),
+    |config, _| runtime_options::validate_runtime_options(config),
+    |config, _| health::validate_health_probes(config),
+    |config, _| build::validate_service_builds(config),
+];
+
+/// Validation failure naming the dotted config key it is about, such as
+/// `service.db.cpus` or `profile.ci.services`.
+#[derive(Debug)]
+pub(super) struct KeyedError {
+    pub(super) key: String,
+    message: String,
+}
+
+impl fmt::Display for KeyedError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for KeyedError {}
+
+/// Returns a validation error about `key`.
+pub(super) fn invalid(key: impl Into<String>, message: impl Into<String>) -> anyhow::Error {
+    anyhow::Error::new(KeyedError {
+        key: key.into(),
+        message: message.into(),
+    })
 }
 
-/// Resolves generated app domains from project-level config when needed.
-pub(super) fn validate_and_resolve_domains(
-    config: &mut Config,
-    project_root: &std::path::Path,
-) -> Result<()> {
-    domains::validate_and_resolve_domains(config, project_root)
+/// Runs every validation in order, stopping at the first failure.
+pub(super) fn validate_config(config: &mut Config, project_root: &Path) -> Result<()> {
+    VALIDATORS
+        .iter()
+        .try_for_each(|validate| validate(config, project_root))
 }
 
-/// Validates swarm targets and reports actionable failures.
-pub(super) fn validate_swarm_targets(config: &Config) -> Result<()> {
-    swarm::validate_swarm_targets(config)
+/// Runs every validation in order and returns all failures.
+pub(super) fn validation_errors(config: &mut Config, project_root: &Path) -> Vec<anyhow::Error> {
+    VALIDATORS
+        .iter()
+        .filter_map(|validate| validate(config, project_root).err())
+        .collect()
 }
@@ -0,0 +1,99 @@
+//! config validation build module.
+//!
+//! Checks `[service.build]` tables before they are rendered into
+//! `docker build` flags.
+
+use anyhow::Result;
+
+use super::invalid;
+use crate::config::{Config, ServiceBuild};
+
+/// Validates configured image builds and reports actionable failures.
+pub(super) fn validate_service_builds(config: &Config) -> Result<()> {
+    for service in &config.service {
+        if let Some(build) = &service.build {
+            validate_build(&service.name, build)?;
+        }
+    }
+    Ok(())
+}
+
+fn validate_build(name: &str, build: &ServiceBuild) -> Result<()> {
+    for (field, value) in [
+        ("context", &build.context),
+        ("dockerfile", &build.dockerfile),
+        ("target", &build.target),
+    ] {
+        if value
+            .as_deref()
+            .is_some_and(|value| value.trim().is_empty())
+        {
+            return Err(invalid(
+                format!("service.{name}.build.{field}"),
+                format!("service '{name}' build {field} must not be empty"),
+            ));
+        }
+    }
+    for key in build.args.iter().flat_map(|args| args.keys()) {
+        if key.is_empty() || key.contains('=') || key.chars().any(char::is_whitespace) {
+            return Err(invalid(
+                format!("service.{name}.build.args"),
+                format!("service '{name}' has invalid build arg name '{key}'"),
+            ));
+        }
+    }
+    for secret in build.secrets.iter().flatten() {
+        let has_id = secret
+            .split(',')
+            .any(|part| part.strip_prefix("id=").is_some_and(|id| !id.is_empty()));
+        if !has_id {
+            return Err(invalid(
+                format!("service.{name}.build.secrets"),
+                format!(
+                    "service '{name}' build secret '{secret}' must name an id, such as \
+                     `id=npm,src=~/.npmrc`"
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate_build;
+    use crate::config::ServiceBuild;
+    use std::collections::HashMap;
+
+    fn build() -> ServiceBuild {
+        ServiceBuild {
+            context: Some("docker/sidecar".to_owned()),
+            dockerfile: None,
+            args: Some(HashMap::from([("VERSION".to_owned(), "1".to_owned())])),
+            target: Some("runtime".to_owned()),
+            secrets: Some(vec!["id=npm,src=.npmrc".to_owned()]),
+        }
+    }
+
+    #[test]
+    fn accepts_complete_builds() {
+        assert!(validate_build("sidecar", &build()).is_ok());
+    }
+
+    #[test]
+    fn rejects_secrets_without_ids_and_bad_arg_names() {
+        let mut secret = build();
+        secret.secrets = Some(vec!["src=.npmrc".to_owned()]);
+        let error = validate_build("sidecar", &secret).expect_err("secret without id");
+        assert!(error.to_string().contains("must name an id"));
+
+        let mut arg = build();
+        arg.args = Some(HashMap::from([("A=B".to_owned(), "1".to_owned())]));
+        let error = validate_build("sidecar", &arg).expect_err("invalid arg name");
+        assert!(error.to_string().contains("invalid build arg name"));
+
+        let mut target = build();
+        target.target = Some(" ".to_owned());
+        assert!(validate_build("sidecar", &target).is_err());
+    }
+}
@@ -5,6 +5,7 @@
 use anyhow::Result;
 use std::collections::HashSet;
 
+use super::invalid;
 use crate::config::Config;
 
 /// Validates and resolves container names and reports actionable failures.
@@ -41,18 +42,24 @@ pub(super) fn validate_and_resolve_container_names(config: &mut Config) -> Resul
         };
 
         if !resolved_names.insert(resolved.clone()) {
-            anyhow::bail!("duplicate container name resolved: '{resolved}'");
+            return Err(invalid(
+                format!("service.{}.container_name", service.name),
+                format!("duplicate container name resolved: '{resolved}'"),
+            ));
         }
 
         service.resolved_container_name = Some(resolved);
     }
 
     if !missing.is_empty() {
-        anyhow::bail!(
-            "missing container naming strategy: set `container_prefix` or \
-             `container_name` on each service (missing: {})",
-            missing.join(", ")
-        );
+        return Err(invalid(
+            "container_prefix",
+            format!(
+                "missing container naming strategy: set `container_prefix` or \
+                 `container_name` on each service (missing: {})",
+                missing.join(", ")
+            ),
+        ));
     }
 
     Ok(())
@@ -93,6 +100,7 @@ mod tests {
             hook: Vec::new(),
             health_path: None,
             health_statuses: None,
+            health: None,
             localhost_tls: false,
             octane: false,
             octane_workers: None,
@@ -102,7 +110,21 @@ mod tests {
             env_mapping: None,
             javascript: None,
             container_name: explicit_container_name.map(ToOwned::to_owned),
+            memory: None,
+            cpus: None,
+            pids_limit: None,
+            shm_size: None,
+            ulimits: None,
+            tmpfs: None,
+            platform: None,
+            user: None,
+            cap_add: None,
+            restart: None,
+            build: None,
             resolved_container_name: None,
+            resolved_project_root: None,
+            resolved_runtime_env: None,
+            secret_fields: Vec::new(),
         }
     }
 
@@ -115,6 +137,8 @@ mod tests {
             domain_strategy: None,
             service: vec![app_service("api", "127.0.0.1", None)],
             swarm: Vec::new(),
+            profile: std::collections::BTreeMap::new(),
+            preset: std::collections::BTreeMap::new(),
         };
         let missing = validate_and_resolve_container_names(&mut config);
         assert!(missing.is_err());
@@ -132,6 +156,8 @@ mod tests {
                 app_service("web", "127.0.0.1", None),
             ],
             swarm: Vec::new(),
+            profile: std::collections::BTreeMap::new(),
+            preset: std::collections::BTreeMap::new(),
         };
 
         validate_and_resolve_container_names(&mut config).expect("resolve names");
@@ -157,6 +183,8 @@ mod tests {
                 app_service("web", "127.0.0.1", Some("api")),
             ],
             swarm: Vec::new(),
+            profile: std::collections::BTreeMap::new(),
+            preset: std::collections::BTreeMap::new(),
         };
 
         let duplicated = validate_and_resolve_container_names(&mut config);
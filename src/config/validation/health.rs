@@ -0,0 +1,76 @@
+//! config validation health module.
+//!
+//! Checks that each `[service.health]` probe only sets fields its type uses.
+
+use anyhow::Result;
+
+use super::invalid;
+use crate::config::{Config, HealthProbeType, ServiceHealth};
+
+/// Validates configured health probes and reports actionable failures.
+pub(super) fn validate_health_probes(config: &Config) -> Result<()> {
+    for service in &config.service {
+        if let Some(health) = &service.health {
+            validate_probe(&service.name, health)?;
+        }
+    }
+    Ok(())
+}
+
+fn validate_probe(name: &str, health: &ServiceHealth) -> Result<()> {
+    let has_http_fields =
+        health.path.is_some() || health.statuses.is_some() || health.body_contains.is_some();
+    match health.probe {
+        HealthProbeType::Exec => {
+            if health.command.as_ref().is_none_or(Vec::is_empty) {
+                return Err(invalid(
+                    format!("service.{name}.health.command"),
+                    format!("service '{name}' health type 'exec' requires a non-empty command"),
+                ));
+            }
+            if has_http_fields {
+                return Err(invalid(
+                    format!("service.{name}.health.type"),
+                    format!(
+                        "service '{name}' health type 'exec' does not use path, statuses, or \
+                         body_contains"
+                    ),
+                ));
+            }
+        }
+        HealthProbeType::Http => {
+            if health.command.is_some() {
+                return Err(invalid(
+                    format!("service.{name}.health.command"),
+                    format!("service '{name}' health type 'http' does not use command"),
+                ));
+            }
+            if let Some(path) = &health.path
+                && !path.starts_with('/')
+            {
+                return Err(invalid(
+                    format!("service.{name}.health.path"),
+                    format!("service '{name}' health path '{path}' must start with '/'"),
+                ));
+            }
+        }
+        HealthProbeType::Tcp => {
+            if health.command.is_some() || has_http_fields {
+                return Err(invalid(
+                    format!("service.{name}.health.type"),
+                    format!(
+                        "service '{name}' health type 'tcp' does not use command, path, \
+                         statuses, or body_contains"
+                    ),
+                ));
+            }
+        }
+    }
+    if health.interval_sec == Some(0) {
+        return Err(invalid(
+            format!("service.{name}.health.interval_sec"),
+            format!("service '{name}' health interval_sec must be greater than 0"),
+        ));
+    }
+    Ok(())
+}
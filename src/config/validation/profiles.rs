@@ -0,0 +1,84 @@
+//! config validation profiles module.
+//!
+//! Contains config validation profiles logic used by Helm command workflows.
+
+use anyhow::Result;
+use std::cell::RefCell;
+use std::collections::HashSet;
+
+use super::invalid;
+use crate::config::{BUILTIN_PROFILE_NAMES, Config};
+
+/// Validates user-defined profiles and reports actionable failures.
+pub(super) fn validate_profiles(config: &Config) -> Result<()> {
+    if config.profile.is_empty() {
+        return Ok(());
+    }
+
+    let service_names: HashSet<&str> = config
+        .service
+        .iter()
+        .map(|service| service.name.as_str())
+        .collect();
+
+    for (name, profile) in &config.profile {
+        if name.trim().is_empty() {
+            return Err(invalid("profile", "profile name must not be empty"));
+        }
+        for (index, service) in profile.services.iter().enumerate() {
+            if !service_names.contains(service.as_str()) {
+                return Err(invalid(
+                    format!("profile.{name}.services.{index}"),
+                    format!("profile '{name}' references unknown service '{service}'"),
+                ));
+            }
+        }
+        for included in &profile.include {
+            if included == name {
+                return Err(invalid(
+                    format!("profile.{name}.include"),
+                    format!("profile '{name}' cannot include itself"),
+                ));
+            }
+            if !config.profile.contains_key(included)
+                && !BUILTIN_PROFILE_NAMES.contains(&included.as_str())
+            {
+                return Err(invalid(
+                    format!("profile.{name}.include"),
+                    format!("profile '{name}' includes unknown profile '{included}'"),
+                ));
+            }
+        }
+    }
+
+    let roots: Vec<String> = config.profile.keys().cloned().collect();
+    let cycle_at = RefCell::new(String::new());
+    crate::dependency_order::order_dependency_names(
+        &roots,
+        |current| {
+            Ok(config
+                .profile
+                .get(current)
+                .map_or_else(Vec::new, |profile| {
+                    profile
+                        .include
+                        .iter()
+                        .filter(|included| config.profile.contains_key(included.as_str()))
+                        .cloned()
+                        .collect()
+                }))
+        },
+        |current| {
+            current.clone_into(&mut cycle_at.borrow_mut());
+            format!("circular profile include detected at profile '{current}'")
+        },
+    )
+    .map_err(|error| {
+        invalid(
+            format!("profile.{}.include", cycle_at.borrow()),
+            format!("{error:#}"),
+        )
+    })?;
+
+    Ok(())
+}
@@ -0,0 +1,221 @@
+//! config validation runtime options module.
+//!
+//! Checks per-service resource limits and runtime tuning values before they
+//! are rendered into `docker run` / `podman run` flags.
+
+use anyhow::Result;
+
+use super::invalid;
+use crate::config::{Config, ServiceConfig};
+
+const ULIMIT_NAMES: &[&str] = &[
+    "core",
+    "cpu",
+    "data",
+    "fsize",
+    "locks",
+    "memlock",
+    "msgqueue",
+    "nice",
+    "nofile",
+    "nproc",
+    "rss",
+    "rtprio",
+    "rttime",
+    "sigpending",
+    "stack",
+];
+
+/// Validates resource limits and runtime options on every service.
+pub(super) fn validate_runtime_options(config: &Config) -> Result<()> {
+    for service in &config.service {
+        validate_service(service)?;
+    }
+    Ok(())
+}
+
+fn validate_service(service: &ServiceConfig) -> Result<()> {
+    let name = &service.name;
+    for (field, value) in [("memory", &service.memory), ("shm_size", &service.shm_size)] {
+        if let Some(value) = value
+            && !is_byte_size(value)
+        {
+            return Err(invalid(
+                format!("service.{name}.{field}"),
+                format!(
+                    "service '{name}' has invalid {field} '{value}'; use a size such as 512m or 2g"
+                ),
+            ));
+        }
+    }
+    if let Some(cpus) = service.cpus
+        && !(cpus.is_finite() && cpus > 0.0)
+    {
+        return Err(invalid(
+            format!("service.{name}.cpus"),
+            format!("service '{name}' cpus must be greater than 0"),
+        ));
+    }
+    if service.pids_limit == Some(0) {
+        return Err(invalid(
+            format!("service.{name}.pids_limit"),
+            format!("service '{name}' pids_limit must be greater than 0"),
+        ));
+    }
+
+    for (limit, value) in service.ulimits.iter().flatten() {
+        if !ULIMIT_NAMES.contains(&limit.as_str()) {
+            return Err(invalid(
+                format!("service.{name}.ulimits.{limit}"),
+                format!(
+                    "service '{name}' has unknown ulimit '{limit}'; expected one of: {}",
+                    ULIMIT_NAMES.join(", ")
+                ),
+            ));
+        }
+        if !is_ulimit_value(value) {
+            return Err(invalid(
+                format!("service.{name}.ulimits.{limit}"),
+                format!(
+                    "service '{name}' ulimit '{limit}' must be `soft` or `soft:hard` \
+                     with soft <= hard, got '{value}'"
+                ),
+            ));
+        }
+    }
+
+    for mount in service.tmpfs.iter().flatten() {
+        if !mount.starts_with('/') {
+            return Err(invalid(
+                format!("service.{name}.tmpfs"),
+                format!("service '{name}' tmpfs mount '{mount}' must be an absolute path"),
+            ));
+        }
+    }
+
+    if let Some(platform) = &service.platform {
+        let segments = platform.split('/').collect::<Vec<_>>();
+        if !(2..=3).contains(&segments.len()) || segments.iter().any(|part| part.is_empty()) {
+            return Err(invalid(
+                format!("service.{name}.platform"),
+                format!(
+                    "service '{name}' platform '{platform}' must look like `os/arch` or \
+                     `os/arch/variant`"
+                ),
+            ));
+        }
+    }
+
+    if let Some(user) = &service.user {
+        let parts = user.split(':').collect::<Vec<_>>();
+        if parts.len() > 2
+            || parts.iter().any(|part| part.is_empty())
+            || user.chars().any(char::is_whitespace)
+        {
+            return Err(invalid(
+                format!("service.{name}.user"),
+                format!("service '{name}' user '{user}' must be `name`, `uid`, or `uid:gid`"),
+            ));
+        }
+    }
+
+    for capability in service.cap_add.iter().flatten() {
+        if capability.is_empty()
+            || !capability
+                .chars()
+                .all(|ch| ch.is_ascii_alphanumeric() || ch == '_')
+        {
+            return Err(invalid(
+                format!("service.{name}.cap_add"),
+                format!("service '{name}' has invalid cap_add entry '{capability}'"),
+            ));
+        }
+    }
+
+    if let Some(restart) = &service.restart
+        && !is_restart_policy(restart)
+    {
+        return Err(invalid(
+            format!("service.{name}.restart"),
+            format!(
+                "service '{name}' restart '{restart}' must be `no`, `on-failure[:N]`, or \
+                 `unless-stopped`"
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+fn is_restart_policy(value: &str) -> bool {
+    match value.split_once(':') {
+        None => matches!(value, "no" | "on-failure" | "unless-stopped"),
+        Some(("on-failure", retries)) => retries.parse::<u32>().is_ok_and(|retries| retries > 0),
+        Some(_) => false,
+    }
+}
+
+/// Accepts sizes the engines parse, such as `512m`, `2g`, `1.5GB`, or `1024`.
+fn is_byte_size(value: &str) -> bool {
+    let digits_end = value
+        .find(|ch: char| !(ch.is_ascii_digit() || ch == '.'))
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(digits_end);
+    let Ok(number) = number.parse::<f64>() else {
+        return false;
+    };
+    if number <= 0.0 {
+        return false;
+    }
+
+    let unit = unit.to_ascii_lowercase();
+    let unit = unit.strip_suffix('b').unwrap_or(&unit);
+    let unit = unit.strip_suffix('i').unwrap_or(unit);
+    matches!(unit, "" | "k" | "m" | "g" | "t" | "p")
+}
+
+fn is_ulimit_value(value: &str) -> bool {
+    let parse = |part: &str| part.parse::<i64>().ok().filter(|limit| *limit >= -1);
+    match value.split_once(':') {
+        None => parse(value).is_some(),
+        Some((soft, hard)) => match (parse(soft), parse(hard)) {
+            (Some(soft), Some(hard)) => hard == -1 || (soft != -1 && soft <= hard),
+            _ => false,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_byte_size, is_restart_policy, is_ulimit_value};
+
+    #[test]
+    fn byte_sizes_accept_engine_units() {
+        for value in ["512m", "2g", "2G", "1.5GB", "64mib", "1024", "1k"] {
+            assert!(is_byte_size(value), "{value} should be valid");
+        }
+        for value in ["", "0", "m", "2x", "-1g", "2 g", "1.2.3g"] {
+            assert!(!is_byte_size(value), "{value} should be invalid");
+        }
+    }
+
+    #[test]
+    fn ulimit_values_require_soft_not_above_hard() {
+        for value in ["1024", "1024:2048", "-1", "1024:-1"] {
+            assert!(is_ulimit_value(value), "{value} should be valid");
+        }
+        for value in ["", "a", "2048:1024", "-1:1024", "1:2:3", "-2"] {
+            assert!(!is_ulimit_value(value), "{value} should be invalid");
+        }
+    }
+
+    #[test]
+    fn restart_policies_allow_bounded_on_failure() {
+        for value in ["no", "on-failure", "on-failure:5", "unless-stopped"] {
+            assert!(is_restart_policy(value), "{value} should be valid");
+        }
+        for value in ["", "always", "on-failure:0", "on-failure:x", "no:1"] {
+            assert!(!is_restart_policy(value), "{value} should be invalid");
+        }
+    }
+}
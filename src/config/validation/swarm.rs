@@ -5,6 +5,7 @@
 use anyhow::Result;
 use std::collections::HashSet;
 
+use super::invalid;
 use crate::config::Config;
 
 /// Validates swarm targets and reports actionable failures.
@@ -18,13 +19,19 @@ pub(super) fn validate_swarm_targets(config: &Config) -> Result<()> {
     for target in &config.swarm {
         let normalized = target.name.trim();
         if normalized.is_empty() {
-            anyhow::bail!("swarm target name must not be empty");
+            return Err(invalid("swarm", "swarm target name must not be empty"));
         }
         if target.root.as_os_str().is_empty() {
-            anyhow::bail!("swarm target '{normalized}' must define a non-empty root");
+            return Err(invalid(
+                format!("swarm.{normalized}.root"),
+                format!("swarm target '{normalized}' must define a non-empty root"),
+            ));
         }
         if !names.insert(normalized.to_owned()) {
-            anyhow::bail!("duplicate swarm target name: '{normalized}'");
+            return Err(invalid(
+                format!("swarm.{normalized}"),
+                format!("duplicate swarm target name: '{normalized}'"),
+            ));
         }
     }
 
@@ -33,13 +40,22 @@ pub(super) fn validate_swarm_targets(config: &Config) -> Result<()> {
         for dependency in &target.depends_on {
             let dep = dependency.trim();
             if dep.is_empty() {
-                anyhow::bail!("swarm target '{normalized}' has an empty dependency name");
+                return Err(invalid(
+                    format!("swarm.{normalized}.depends_on"),
+                    format!("swarm target '{normalized}' has an empty dependency name"),
+                ));
             }
             if dep == normalized {
-                anyhow::bail!("swarm target '{normalized}' cannot depend on itself");
+                return Err(invalid(
+                    format!("swarm.{normalized}.depends_on"),
+                    format!("swarm target '{normalized}' cannot depend on itself"),
+                ));
             }
             if !names.contains(dep) {
-                anyhow::bail!("swarm target '{normalized}' depends on unknown target '{dep}'");
+                return Err(invalid(
+                    format!("swarm.{normalized}.depends_on"),
+                    format!("swarm target '{normalized}' depends on unknown target '{dep}'"),
+                ));
             }
         }
 
@@ -47,25 +63,40 @@ pub(super) fn validate_swarm_targets(config: &Config) -> Result<()> {
         for inject in &target.inject_env {
             let env = inject.env.trim();
             if env.is_empty() {
-                anyhow::bail!("swarm target '{normalized}' has an empty inject_env.env");
+                return Err(invalid(
+                    format!("swarm.{normalized}.inject_env"),
+                    format!("swarm target '{normalized}' has an empty inject_env.env"),
+                ));
             }
             if !env_names.insert(env.to_owned()) {
-                anyhow::bail!(
-                    "swarm target '{normalized}' has duplicate inject env variable '{env}'"
-                );
+                return Err(invalid(
+                    format!("swarm.{normalized}.inject_env"),
+                    format!(
+                        "swarm target '{normalized}' has duplicate inject env variable '{env}'"
+                    ),
+                ));
             }
 
             let from = inject.from.trim();
             if from.is_empty() {
-                anyhow::bail!("swarm target '{normalized}' has an empty inject_env.from");
+                return Err(invalid(
+                    format!("swarm.{normalized}.inject_env"),
+                    format!("swarm target '{normalized}' has an empty inject_env.from"),
+                ));
             }
             if !names.contains(from) {
-                anyhow::bail!("swarm target '{normalized}' injects from unknown target '{from}'");
+                return Err(invalid(
+                    format!("swarm.{normalized}.inject_env"),
+                    format!("swarm target '{normalized}' injects from unknown target '{from}'"),
+                ));
             }
 
             let value = inject.value.trim();
             if value.is_empty() {
-                anyhow::bail!("swarm target '{normalized}' has an empty inject_env.value");
+                return Err(invalid(
+                    format!("swarm.{normalized}.inject_env"),
+                    format!("swarm target '{normalized}' has an empty inject_env.value"),
+                ));
             }
             if let Some(token) = value.strip_prefix(':')
                 && !matches!(
@@ -73,20 +104,30 @@ pub(super) fn validate_swarm_targets(config: &Config) -> Result<()> {
                     "domain" | "host" | "port" | "scheme" | "base_url" | "url"
                 )
             {
-                anyhow::bail!(
-                    "swarm target '{normalized}' inject_env for '{env}' uses unsupported token '{value}'"
-                );
+                return Err(invalid(
+                    format!("swarm.{normalized}.inject_env"),
+                    format!(
+                        "swarm target '{normalized}' inject_env for '{env}' uses unsupported \
+                         token '{value}'"
+                    ),
+                ));
             }
         }
 
         if let Some(git) = &target.git {
             if git.repo.trim().is_empty() {
-                anyhow::bail!("swarm target '{normalized}' has an empty git.repo");
+                return Err(invalid(
+                    format!("swarm.{normalized}.git.repo"),
+                    format!("swarm target '{normalized}' has an empty git.repo"),
+                ));
             }
             if let Some(branch) = &git.branch
                 && branch.trim().is_empty()
             {
-                anyhow::bail!("swarm target '{normalized}' has an empty git.branch");
+                return Err(invalid(
+                    format!("swarm.{normalized}.git.branch"),
+                    format!("swarm target '{normalized}' has an empty git.branch"),
+                ));
             }
         }
     }
@@ -4,6 +4,8 @@ use anyhow::Result;
 use std::collections::HashSet;
 
 /// Topologically orders selected names by dependency relation.
+///
+/// Cycle errors append the offending path, e.g. `(a -> b -> a)`.
 pub(crate) fn order_dependency_names<FDep, FCycle>(
     selected_roots: &[String],
     dependencies_for: FDep,
@@ -14,14 +16,14 @@ where
     FCycle: Fn(&str) -> String,
 {
     let mut ordered = Vec::new();
-    let mut visiting = HashSet::new();
+    let mut visiting = Vec::new();
     let mut visited = HashSet::new();
 
     fn visit<FDep, FCycle>(
         current: &str,
         dependencies_for: &FDep,
         cycle_error: &FCycle,
-        visiting: &mut HashSet<String>,
+        visiting: &mut Vec<String>,
         visited: &mut HashSet<String>,
         ordered: &mut Vec<String>,
     ) -> Result<()>
@@ -32,9 +34,17 @@ where
         if visited.contains(current) {
             return Ok(());
         }
-        if !visiting.insert(current.to_owned()) {
-            anyhow::bail!("{}", cycle_error(current));
+        if let Some(start) = visiting.iter().position(|name| name == current) {
+            let path = visiting
+                .iter()
+                .skip(start)
+                .map(String::as_str)
+                .chain(std::iter::once(current))
+                .collect::<Vec<_>>()
+                .join(" -> ");
+            anyhow::bail!("{} ({path})", cycle_error(current));
         }
+        visiting.push(current.to_owned());
 
         for dependency in dependencies_for(current)? {
             visit(
@@ -47,7 +57,7 @@ where
             )?;
         }
 
-        visiting.remove(current);
+        visiting.pop();
         visited.insert(current.to_owned());
         ordered.push(current.to_owned());
         Ok(())
@@ -66,3 +76,39 @@ where
 
     Ok(ordered)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::order_dependency_names;
+    use std::collections::HashMap;
+
+    fn order(edges: &[(&str, &[&str])], roots: &[&str]) -> anyhow::Result<Vec<String>> {
+        let graph: HashMap<&str, Vec<String>> = edges
+            .iter()
+            .map(|(name, deps)| (*name, deps.iter().map(|dep| (*dep).to_owned()).collect()))
+            .collect();
+        let roots: Vec<String> = roots.iter().map(|root| (*root).to_owned()).collect();
+        order_dependency_names(
+            &roots,
+            |current| Ok(graph.get(current).cloned().unwrap_or_default()),
+            |current| format!("cycle at '{current}'"),
+        )
+    }
+
+    #[test]
+    fn order_dependency_names_places_dependencies_first() -> anyhow::Result<()> {
+        let ordered = order(&[("app", &["db", "cache"]), ("db", &[])], &["app"])?;
+        assert_eq!(ordered, vec!["db", "cache", "app"]);
+        Ok(())
+    }
+
+    #[test]
+    fn order_dependency_names_reports_the_cycle_path() {
+        let error = order(
+            &[("app", &["db"]), ("db", &["queue"]), ("queue", &["db"])],
+            &["app"],
+        )
+        .expect_err("cycle");
+        assert_eq!(error.to_string(), "cycle at 'db' (db -> queue -> db)");
+    }
+}
@@ -5,7 +5,8 @@
 use colored::Colorize;
 
 use super::about_style::{AboutRow, print_section, print_section_with_title};
-use crate::{config::ServiceConfig, docker};
+use crate::config::{DependencyCondition, ServiceConfig};
+use crate::docker;
 
 /// Print a Laravel-style status overview for services.
 pub fn print_status(services: &[&ServiceConfig]) {
@@ -34,6 +35,9 @@ fn print_service_section(service: &ServiceConfig) {
         docker::inspect_status(&container_name).unwrap_or_else(|| "not created".to_owned());
     let status_value = render_status_value(&raw_status);
 
+    let health = docker::inspect_health_status(&container_name);
+    let restart_state = docker::inspect_restart_state(&container_name);
+    let stopped = matches!(raw_status.as_str(), "exited" | "dead");
     let published_port =
         docker::inspect_host_port_binding(&container_name, service.resolved_container_port())
             .map(|(host, port)| format!("{host}:{port}"))
@@ -53,6 +57,30 @@ fn print_service_section(service: &ServiceConfig) {
         AboutRow::colored("Status", raw_status, status_value),
     ];
 
+    if let Some(health) = health {
+        let health_value = render_status_value(&health);
+        rows.push(AboutRow::colored("Health", health, health_value));
+    }
+
+    if let Some(restart) = &service.restart {
+        rows.push(AboutRow::plain("Restart Policy", restart.as_str()));
+    }
+    if let Some(state) = restart_state {
+        rows.push(restarts_row(state));
+        if state.restart_count > 0 || stopped {
+            rows.push(AboutRow::plain(
+                "Last Exit Code",
+                state.exit_code.to_string(),
+            ));
+        }
+    }
+
+    if let Some((pod, pod_status)) = docker::pod_status(service) {
+        let pod_value = render_status_value(&pod_status);
+        rows.push(AboutRow::plain("Pod", pod));
+        rows.push(AboutRow::colored("Pod Status", pod_status, pod_value));
+    }
+
     let domain_urls = service.resolved_domain_urls();
     if !domain_urls.is_empty() {
         rows.push(AboutRow::plain("Domain", domain_urls.join(", ")));
@@ -63,6 +91,15 @@ fn print_service_section(service: &ServiceConfig) {
     }
 
     if let Some(dependencies) = &service.depends_on {
+        let dependencies = dependencies
+            .iter()
+            .map(|dependency| match dependency.condition {
+                DependencyCondition::Started => dependency.service.clone(),
+                DependencyCondition::Healthy | DependencyCondition::Completed => {
+                    format!("{} ({})", dependency.service, dependency.condition.as_str())
+                }
+            })
+            .collect::<Vec<_>>();
         rows.push(AboutRow::plain("Depends On", dependencies.join(", ")));
     }
 
@@ -80,13 +117,26 @@ fn print_service_section(service: &ServiceConfig) {
     print_section_with_title(&service.name, &rendered_title, &rows);
 }
 
+/// Renders the restart count, flagged red when the container crash-loops.
+fn restarts_row(state: docker::RestartState) -> AboutRow<'static> {
+    let count = state.restart_count.to_string();
+    if state.is_crash_looping() {
+        let flagged = format!("{count} (crash-looping)");
+        let rendered = flagged.red().bold().to_string();
+        return AboutRow::colored("Restarts", flagged, rendered);
+    }
+    AboutRow::plain("Restarts", count)
+}
+
 /// Renders status value for command execution.
 fn render_status_value(status: &str) -> String {
     let normalized = status.trim().to_ascii_lowercase();
     match normalized.as_str() {
-        "running" => "running".green().bold().to_string(),
-        "exited" | "dead" => normalized.red().bold().to_string(),
-        "created" | "restarting" => normalized.yellow().bold().to_string(),
+        "running" | "healthy" => normalized.green().bold().to_string(),
+        "exited" | "dead" | "unhealthy" => normalized.red().bold().to_string(),
+        "created" | "restarting" | "degraded" | "starting" => {
+            normalized.yellow().bold().to_string()
+        }
         "paused" => normalized.yellow().bold().to_string(),
         "dry-run" => normalized.cyan().bold().to_string(),
         "not created" => "not created".red().bold().to_string(),
@@ -118,6 +168,7 @@ mod tests {
         let paused = render_status_value("paused");
         let dry_run = render_status_value("dry-run");
         let custom = render_status_value("custom");
+        let unhealthy = render_status_value("unhealthy");
 
         assert!(running.contains("running"));
         assert!(exited.contains("exited"));
@@ -127,6 +178,7 @@ mod tests {
         assert!(paused.contains("paused"));
         assert!(dry_run.contains("dry-run"));
         assert!(custom.contains("custom"));
+        assert!(unhealthy.contains("unhealthy"));
     }
 
     #[test]
@@ -5,6 +5,7 @@
 #![allow(clippy::match_same_arms)] // Explicit driver branches document intent
 #![allow(clippy::redundant_iter_cloned)] // Thread spawn paths need owned service values
 
+use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Mutex, OnceLock};
 
@@ -12,10 +13,13 @@ use crate::config::ContainerEngine;
 use crate::output::{self, LogLevel, Persistence};
 
 static DRY_RUN: AtomicBool = AtomicBool::new(false);
+static PODMAN_POD: AtomicBool = AtomicBool::new(false);
 static CONTAINER_ENGINE: OnceLock<Mutex<ContainerEngine>> = OnceLock::new();
 #[cfg(test)]
 static TEST_DOCKER_COMMAND: OnceLock<Mutex<Option<String>>> = OnceLock::new();
 #[cfg(test)]
+static TEST_API_SOCKET: OnceLock<Mutex<Option<PathBuf>>> = OnceLock::new();
+#[cfg(test)]
 static TEST_RUNTIME_SCOPE_LOCK: OnceLock<Mutex<()>> = OnceLock::new();
 
 /// Pull behavior used by `up`.
@@ -60,6 +64,19 @@ pub(crate) fn container_engine() -> ContainerEngine {
         .unwrap_or_else(|err| err.into_inner())
 }
 
+/// Enables or disables `podman_pod` grouping for project services.
+pub(crate) fn set_podman_pod(enabled: bool) {
+    PODMAN_POD.store(enabled, Ordering::SeqCst);
+}
+
+/// Returns true when services join a per-project pod instead of a network.
+///
+/// The flag is ignored for engines without pod support.
+#[must_use]
+pub(crate) fn pod_mode() -> bool {
+    PODMAN_POD.load(Ordering::SeqCst) && active_engine_adapter().supports_pods()
+}
+
 #[must_use]
 pub(crate) fn host_gateway_alias() -> &'static str {
     active_engine_adapter().host_gateway_alias()
@@ -85,6 +102,21 @@ pub(crate) fn runtime_log_source_key() -> &'static str {
     active_engine_adapter().log_source_key()
 }
 
+#[must_use]
+pub(crate) fn runtime_supports_container_network() -> bool {
+    active_engine_adapter().supports_container_network()
+}
+
+#[must_use]
+pub(crate) fn runtime_supports_healthchecks() -> bool {
+    active_engine_adapter().supports_healthchecks()
+}
+
+#[must_use]
+pub(crate) fn runtime_capability_gaps() -> &'static [&'static str] {
+    active_engine_adapter().capability_gaps()
+}
+
 #[cfg(test)]
 pub(crate) fn with_dry_run_lock<R>(test: impl FnOnce() -> R) -> R {
     with_dry_run_state(true, test)
@@ -110,6 +142,20 @@ pub(crate) fn with_container_engine<R>(engine: ContainerEngine, test: impl FnOnc
     })
 }
 
+#[cfg(test)]
+pub(crate) fn with_podman_pod<R>(test: impl FnOnce() -> R) -> R {
+    with_container_engine(ContainerEngine::Podman, || {
+        let previous = PODMAN_POD.swap(true, Ordering::SeqCst);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(test));
+        PODMAN_POD.store(previous, Ordering::SeqCst);
+
+        match result {
+            Ok(result) => result,
+            Err(err) => std::panic::resume_unwind(err),
+        }
+    })
+}
+
 #[cfg(test)]
 pub(crate) fn with_dry_run_state<R>(enabled: bool, test: impl FnOnce() -> R) -> R {
     with_test_runtime_state(|| {
@@ -221,6 +267,63 @@ fn with_test_runtime_state<R>(test: impl FnOnce() -> R) -> R {
     }
 }
 
+/// Environment variable that selects the engine transport (`api` or `cli`).
+pub(crate) const ENGINE_TRANSPORT_ENV: &str = "HELM_ENGINE_TRANSPORT";
+
+/// Returns the engine API socket when the native client should be used.
+#[cfg(not(test))]
+pub(crate) fn engine_api_socket() -> Option<PathBuf> {
+    resolve_api_socket(
+        std::env::var(ENGINE_TRANSPORT_ENV).ok().as_deref(),
+        remote::endpoint_api_socket().unwrap_or_else(|| active_engine_adapter().api_socket()),
+    )
+}
+
+/// Tests only reach a socket installed with `with_engine_api_socket`, never
+/// the host daemon.
+#[cfg(test)]
+pub(crate) fn engine_api_socket() -> Option<PathBuf> {
+    let socket = TEST_API_SOCKET
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+        .clone();
+    resolve_api_socket(
+        std::env::var(ENGINE_TRANSPORT_ENV).ok().as_deref(),
+        remote::endpoint_api_socket().unwrap_or(socket),
+    )
+}
+
+fn resolve_api_socket(transport: Option<&str>, socket: Option<PathBuf>) -> Option<PathBuf> {
+    if transport.is_some_and(|value| value.eq_ignore_ascii_case("cli")) {
+        return None;
+    }
+    socket.filter(|path| path.exists())
+}
+
+#[cfg(test)]
+pub(crate) fn with_engine_api_socket<F, T>(socket: &std::path::Path, test: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    with_test_runtime_state(|| {
+        let previous = TEST_API_SOCKET
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .replace(socket.to_path_buf());
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(test));
+        *TEST_API_SOCKET
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap_or_else(|err| err.into_inner()) = previous;
+        match result {
+            Ok(result) => result,
+            Err(err) => std::panic::resume_unwind(err),
+        }
+    })
+}
+
 pub(crate) fn docker_command() -> String {
     #[cfg(test)]
     {
@@ -235,7 +338,10 @@ pub(crate) fn docker_command() -> String {
     active_engine_adapter().command_binary().to_owned()
 }
 
+mod api;
+mod build;
 mod cmd;
+mod drift;
 mod engine;
 mod exec;
 mod failure_message;
@@ -246,43 +352,89 @@ mod inspect;
 mod labels;
 mod logs;
 mod manage;
+mod managed;
+mod network;
 mod ops;
+mod pod;
 mod policy;
+mod remote;
 mod scheduler;
 mod up;
 
+pub(crate) use build::{built_image_outdated, rebuild_service_image, with_built_image};
 pub(crate) use cmd::{
     docker_arg_refs, ensure_docker_output_success, run_docker_output, run_docker_output_owned,
     run_docker_status, run_docker_status_owned, spawn_docker_stdin_stderr_piped,
     spawn_docker_stdout_stderr_piped,
 };
+pub(crate) use drift::{DriftState, FieldChange, desired_state_hash, drift_state, field_changes};
 pub(crate) use engine::RuntimeDiagnosticCheck;
 pub(crate) use exec::build_exec_args;
 pub use exec::{exec_command, exec_interactive, exec_piped};
 pub(crate) use failure_message::command_failed_in_container;
-pub use health::wait_until_healthy;
-pub(crate) use image_inspect::{docker_image_exists, docker_image_repo_digest};
+pub(crate) use health::append_health_args;
+pub use health::{wait_until_completed, wait_until_healthy};
+pub(crate) use image_inspect::{docker_image_exists, docker_image_id, docker_image_repo_digest};
 pub(crate) use image_pull::docker_pull;
 pub use inspect::{
-    inspect_env, inspect_host_port_binding, inspect_json, inspect_label, inspect_status,
+    RestartState, inspect_env, inspect_exit_code, inspect_health_status, inspect_host_port_binding,
+    inspect_json, inspect_label, inspect_restart_state, inspect_status,
 };
 pub(crate) use labels::{
-    LABEL_CONTAINER, LABEL_KIND, LABEL_MANAGED, LABEL_SERVICE, VALUE_MANAGED_TRUE, kind_label_value,
+    LABEL_CONFIG_HASH, LABEL_CONTAINER, LABEL_KIND, LABEL_MANAGED, LABEL_PROJECT,
+    LABEL_PROJECT_PATH, LABEL_RUNTIME_ENV, LABEL_SERVICE, VALUE_DEFAULT_RUNTIME_ENV,
+    VALUE_MANAGED_TRUE, append_labels, project_label_value,
 };
 pub use logs::{LogsOptions, logs, logs_many, logs_prefixed};
 pub use manage::{down, pull, recreate, restart, rm, stop};
+pub(crate) use managed::{ManagedContainer, list_managed_containers, remove_managed_container};
+pub(crate) use network::{
+    append_network_args, ensure_service_network, project_network_name, remove_project_networks,
+    remove_unused_service_networks,
+};
 pub use ops::{
     CpOptions, PruneOptions, StatsOptions, attach, cp, events, inspect_container, kill, pause,
     port, port_output, prune, prune_stopped_container, stats, top, unpause, wait,
 };
+pub(crate) use pod::{
+    apply_pod_args, ensure_service_pod, pod_status, register_pod_services, remove_project_pods,
+    remove_service_pods,
+};
 pub(crate) use policy::{DockerPolicyOverrides, set_policy_overrides};
+pub(crate) use remote::{
+    EngineEndpoint, apply_engine_endpoint, publish_host, reachable_host, remote_engine_address,
+    set_engine_endpoint, widens_loopback_publish,
+};
 pub(crate) use scheduler::{DockerOpClass, with_scheduled_docker_op};
+pub(crate) use up::service_run_args;
 pub use up::up;
 
 #[cfg(test)]
 mod tests {
     use crate::config::ContainerEngine;
     use crate::docker::{host_gateway_alias, is_dry_run, with_dry_run_lock};
+    use std::path::PathBuf;
+
+    #[test]
+    fn resolve_api_socket_honours_cli_transport_and_missing_sockets() {
+        let existing = std::env::temp_dir();
+        assert_eq!(
+            super::resolve_api_socket(None, Some(existing.clone())),
+            Some(existing.clone())
+        );
+        assert_eq!(
+            super::resolve_api_socket(Some("CLI"), Some(existing.clone())),
+            None
+        );
+        assert_eq!(
+            super::resolve_api_socket(Some("api"), Some(existing.clone())),
+            Some(existing)
+        );
+        assert_eq!(
+            super::resolve_api_socket(None, Some(PathBuf::from("/nonexistent/helm.sock"))),
+            None
+        );
+    }
 
     #[test]
     fn with_dry_run_lock_sets_dry_run_inside_closure() {
@@ -343,4 +495,24 @@ mod tests {
             assert_eq!(super::runtime_log_source_key(), "podman");
         });
     }
+
+    #[test]
+    fn container_engine_switches_command_and_gaps_to_nerdctl() {
+        super::with_container_engine(ContainerEngine::Nerdctl, || {
+            assert_eq!(super::docker_command(), "nerdctl");
+            assert_eq!(host_gateway_alias(), "host.docker.internal");
+            assert_eq!(
+                super::runtime_command_text(&["ps".to_owned()]),
+                "nerdctl ps"
+            );
+            assert_eq!(
+                super::runtime_diagnostic_checks()[1].success_message,
+                "containerd reachable"
+            );
+            assert_eq!(super::runtime_log_source_key(), "nerdctl");
+            assert!(!super::runtime_supports_container_network());
+            assert!(!super::runtime_supports_healthchecks());
+            assert!(!super::runtime_capability_gaps().is_empty());
+        });
+    }
 }
@@ -0,0 +1,25 @@
+//! docker api module.
+//!
+//! Native client for the engine HTTP API over its unix socket. Every call
+//! returns `None` when no socket is reachable so callers fall back to the CLI.
+
+use std::io;
+use std::path::Path;
+
+mod containers;
+mod frames;
+mod streams;
+#[cfg(test)]
+mod tests;
+mod transport;
+
+pub(crate) use containers::{exec_output, inspect_container, start_container, stop_container};
+pub(crate) use frames::{LineBuffer, OutputStream};
+pub(crate) use streams::{events, logs};
+
+/// Runs `request` against the engine socket, or returns `None` when the
+/// socket is disabled, missing, or the connection fails.
+fn call<T>(request: impl FnOnce(&Path) -> io::Result<T>) -> Option<T> {
+    let socket = super::engine_api_socket()?;
+    request(&socket).ok()
+}
@@ -0,0 +1,123 @@
+//! docker api containers module.
+//!
+//! Container inspect, start/stop and exec calls over the engine API.
+
+use anyhow::Result;
+use serde_json::{Value, json};
+use std::io;
+use std::path::Path;
+
+use super::call;
+use super::frames::{OutputStream, read_frames};
+use super::transport::{encode_query, request, stream};
+
+/// Captured result of a non-interactive exec.
+#[derive(Debug, Default)]
+pub(crate) struct ExecOutput {
+    /// Exit code, or `None` when the exec could not be started.
+    pub(crate) exit_code: Option<i64>,
+    pub(crate) stdout: Vec<u8>,
+    pub(crate) stderr: Vec<u8>,
+}
+
+impl ExecOutput {
+    pub(crate) const fn success(&self) -> bool {
+        matches!(self.exit_code, Some(0))
+    }
+}
+
+/// Returns the inspect document for a container, `Some(None)` when it does
+/// not exist.
+pub(crate) fn inspect_container(name: &str) -> Option<Option<Value>> {
+    call(|socket| inspect_with(socket, name))
+}
+
+/// Starts a container; an already running container counts as started.
+pub(crate) fn start_container(name: &str) -> Option<Result<()>> {
+    call(|socket| {
+        let response = request(
+            socket,
+            "POST",
+            &format!("/containers/{}/start", encode_query(name)),
+            None,
+        )?;
+        Ok(match response.status {
+            204 | 304 => Ok(()),
+            _ => Err(anyhow::anyhow!(response.error_message())),
+        })
+    })
+}
+
+/// Stops a container, returning whether it was running.
+pub(crate) fn stop_container(name: &str, timeout: u64) -> Option<bool> {
+    call(|socket| {
+        let response = request(
+            socket,
+            "POST",
+            &format!("/containers/{}/stop?t={timeout}", encode_query(name)),
+            None,
+        )?;
+        Ok(response.status == 204)
+    })
+}
+
+/// Runs a command in a container and captures its output.
+pub(crate) fn exec_output(name: &str, command: &[&str]) -> Option<ExecOutput> {
+    call(|socket| exec_with(socket, name, command))
+}
+
+pub(super) fn inspect_with(socket: &Path, name: &str) -> io::Result<Option<Value>> {
+    let response = request(
+        socket,
+        "GET",
+        &format!("/containers/{}/json", encode_query(name)),
+        None,
+    )?;
+    Ok(response.is_success().then(|| response.json()).flatten())
+}
+
+fn exec_with(socket: &Path, name: &str, command: &[&str]) -> io::Result<ExecOutput> {
+    let created = request(
+        socket,
+        "POST",
+        &format!("/containers/{}/exec", encode_query(name)),
+        Some(&json!({
+            "AttachStdout": true,
+            "AttachStderr": true,
+            "Cmd": command,
+        })),
+    )?;
+    let Some(exec_id) = created
+        .is_success()
+        .then(|| created.json())
+        .flatten()
+        .and_then(|body| body.get("Id")?.as_str().map(str::to_owned))
+    else {
+        return Ok(ExecOutput {
+            exit_code: None,
+            stdout: Vec::new(),
+            stderr: created.error_message().into_bytes(),
+        });
+    };
+
+    let started = stream(
+        socket,
+        "POST",
+        &format!("/exec/{exec_id}/start"),
+        Some(&json!({ "Detach": false, "Tty": false })),
+    )?;
+    let mut output = ExecOutput::default();
+    read_frames(started.body, true, |stream, bytes| {
+        match stream {
+            OutputStream::Stdout => output.stdout.extend_from_slice(bytes),
+            OutputStream::Stderr => output.stderr.extend_from_slice(bytes),
+        }
+        Ok(())
+    })?;
+
+    let inspected = request(socket, "GET", &format!("/exec/{exec_id}/json"), None)?;
+    output.exit_code = inspected
+        .json()
+        .and_then(|body| body.get("ExitCode")?.as_i64());
+    Ok(output)
+}
@@ -0,0 +1,134 @@
+//! docker api frames module.
+//!
+//! Splits the engine's multiplexed stdout/stderr stream into frames.
+
+use std::io::{self, Read};
+
+/// Output stream a frame belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum OutputStream {
+    Stdout,
+    Stderr,
+}
+
+/// Reads frames until EOF, handing each payload to `sink`.
+///
+/// Containers started with a TTY send raw bytes instead of frames; pass
+/// `multiplexed = false` for those.
+pub(super) fn read_frames(
+    mut reader: impl Read,
+    multiplexed: bool,
+    mut sink: impl FnMut(OutputStream, &[u8]) -> io::Result<()>,
+) -> io::Result<()> {
+    let mut buffer = vec![0_u8; 8192];
+    if !multiplexed {
+        loop {
+            let read = reader.read(&mut buffer)?;
+            if read == 0 {
+                return Ok(());
+            }
+            sink(OutputStream::Stdout, buffer.get(..read).unwrap_or_default())?;
+        }
+    }
+
+    let mut header = [0_u8; 8];
+    loop {
+        match reader.read_exact(&mut header) {
+            Ok(()) => {}
+            Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => return Ok(()),
+            Err(err) => return Err(err),
+        }
+        let [kind, _, _, _, a, b, c, d] = header;
+        let stream = if kind == 2 {
+            OutputStream::Stderr
+        } else {
+            OutputStream::Stdout
+        };
+        let mut remaining = usize::try_from(u32::from_be_bytes([a, b, c, d])).unwrap_or(0);
+        while remaining > 0 {
+            let want = remaining.min(buffer.len());
+            let chunk = buffer.get_mut(..want).unwrap_or_default();
+            reader.read_exact(chunk)?;
+            sink(stream, chunk)?;
+            remaining = remaining.saturating_sub(want);
+        }
+    }
+}
+
+/// Buffers partial lines so callers can prefix complete ones.
+#[derive(Default)]
+pub(crate) struct LineBuffer {
+    pending: Vec<u8>,
+}
+
+impl LineBuffer {
+    pub(crate) fn push(&mut self, bytes: &[u8], mut line: impl FnMut(&str)) {
+        self.pending.extend_from_slice(bytes);
+        while let Some(end) = self.pending.iter().position(|byte| *byte == b'\n') {
+            let rest = self.pending.split_off(end.saturating_add(1));
+            let complete = std::mem::replace(&mut self.pending, rest);
+            line(String::from_utf8_lossy(&complete).trim_end_matches(['\r', '\n']));
+        }
+    }
+
+    pub(crate) fn finish(self, mut line: impl FnMut(&str)) {
+        if !self.pending.is_empty() {
+            line(&String::from_utf8_lossy(&self.pending));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LineBuffer, OutputStream, read_frames};
+
+    fn frame(kind: u8, payload: &str) -> Vec<u8> {
+        let mut bytes = vec![kind, 0, 0, 0];
+        bytes.extend_from_slice(&u32::try_from(payload.len()).expect("len").to_be_bytes());
+        bytes.extend_from_slice(payload.as_bytes());
+        bytes
+    }
+
+    #[test]
+    fn read_frames_splits_stdout_and_stderr() {
+        let mut raw = frame(1, "out\n");
+        raw.extend(frame(2, "err\n"));
+        let mut seen = Vec::new();
+        read_frames(raw.as_slice(), true, |stream, bytes| {
+            seen.push((stream, String::from_utf8_lossy(bytes).into_owned()));
+            Ok(())
+        })
+        .expect("frames");
+
+        assert_eq!(
+            seen,
+            vec![
+                (OutputStream::Stdout, "out\n".to_owned()),
+                (OutputStream::Stderr, "err\n".to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn read_frames_passes_tty_output_through() {
+        let mut seen = String::new();
+        read_frames(b"plain".as_slice(), false, |stream, bytes| {
+            assert_eq!(stream, OutputStream::Stdout, "tty output is stdout");
+            seen.push_str(&String::from_utf8_lossy(bytes));
+            Ok(())
+        })
+        .expect("raw");
+        assert_eq!(seen, "plain");
+    }
+
+    #[test]
+    fn line_buffer_joins_lines_split_across_frames() {
+        let mut lines = Vec::new();
+        let mut buffer = LineBuffer::default();
+        buffer.push(b"first li", |line| lines.push(line.to_owned()));
+        buffer.push(b"ne\r\nsecond\nthi", |line| lines.push(line.to_owned()));
+        buffer.finish(|line| lines.push(line.to_owned()));
+
+        assert_eq!(lines, vec!["first line", "second", "thi"]);
+    }
+}
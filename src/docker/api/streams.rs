@@ -0,0 +1,244 @@
+//! docker api streams module.
+//!
+//! Streams container logs and engine events over the engine API.
+
+use anyhow::Result;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+
+use super::call;
+use super::containers::inspect_with;
+use super::frames::{OutputStream, read_frames};
+use super::transport::{StreamResponse, encode_query, stream};
+use crate::docker::LogsOptions;
+
+/// Streams a container's logs into `sink`.
+///
+/// Returns `None` when the CLI must handle the request, e.g. for relative
+/// `--since 10m` values the API does not accept.
+pub(crate) fn logs(
+    name: &str,
+    options: &LogsOptions,
+    sink: impl FnMut(OutputStream, &[u8]) -> io::Result<()>,
+) -> Option<Result<()>> {
+    if !is_unix_timestamp(options.since.as_deref()) || !is_unix_timestamp(options.until.as_deref())
+    {
+        return None;
+    }
+
+    let opened = call(|socket| {
+        let Some(inspect) = inspect_with(socket, name)? else {
+            return Ok(None);
+        };
+        let tty = inspect
+            .pointer("/Config/Tty")
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+        let response = stream(socket, "GET", &logs_path(name, options), None)?;
+        Ok(Some((response, tty)))
+    })?;
+
+    let failed = || anyhow::anyhow!("Failed to get logs for container '{name}'");
+    Some(match opened {
+        Some((StreamResponse { status: 200, body }, tty)) => {
+            read_frames(body, !tty, sink).map_err(|err| failed().context(err))
+        }
+        Some(_) | None => Err(failed()),
+    })
+}
+
+/// Streams engine events to `out`, one line per event.
+///
+/// Custom `--format` templates and relative times are left to the CLI.
+pub(crate) fn events(
+    since: Option<&str>,
+    until: Option<&str>,
+    format: Option<&str>,
+    filters: &[String],
+    out: &mut impl Write,
+) -> Option<Result<()>> {
+    if format.is_some() || !is_unix_timestamp(since) || !is_unix_timestamp(until) {
+        return None;
+    }
+    let path = events_path(since, until, filters)?;
+
+    let response = call(|socket| stream(socket, "GET", &path, None))?;
+    if response.status != 200 {
+        return Some(Err(anyhow::anyhow!(
+            "Failed to read events (status {})",
+            response.status
+        )));
+    }
+
+    let events = serde_json::Deserializer::from_reader(response.body).into_iter::<Value>();
+    for event in events {
+        let written = event
+            .map_err(anyhow::Error::from)
+            .and_then(|event| Ok(writeln!(out, "{}", render_event(&event))?));
+        if let Err(err) = written {
+            return Some(Err(err));
+        }
+    }
+    Some(Ok(()))
+}
+
+fn logs_path(name: &str, options: &LogsOptions) -> String {
+    let mut path = format!("/containers/{}/logs?stdout=1&stderr=1", encode_query(name));
+    if options.follow {
+        path.push_str("&follow=1");
+    }
+    if options.timestamps {
+        path.push_str("&timestamps=1");
+    }
+    if let Some(tail) = options.tail {
+        path.push_str(&format!("&tail={tail}"));
+    }
+    if let Some(since) = &options.since {
+        path.push_str(&format!("&since={}", encode_query(since)));
+    }
+    if let Some(until) = &options.until {
+        path.push_str(&format!("&until={}", encode_query(until)));
+    }
+    path
+}
+
+/// Builds the events URL, or `None` for filters the CLI should reject.
+fn events_path(since: Option<&str>, until: Option<&str>, filters: &[String]) -> Option<String> {
+    let mut grouped = BTreeMap::<&str, Vec<&str>>::new();
+    for filter in filters {
+        let (key, value) = filter.split_once('=')?;
+        grouped.entry(key).or_default().push(value);
+    }
+
+    let mut query = Vec::new();
+    if let Some(since) = since {
+        query.push(format!("since={}", encode_query(since)));
+    }
+    if let Some(until) = until {
+        query.push(format!("until={}", encode_query(until)));
+    }
+    if !grouped.is_empty() {
+        let encoded = serde_json::to_string(&grouped).ok()?;
+        query.push(format!("filters={}", encode_query(&encoded)));
+    }
+
+    if query.is_empty() {
+        Some("/events".to_owned())
+    } else {
+        Some(format!("/events?{}", query.join("&")))
+    }
+}
+
+/// Renders an event the way `docker events` prints it by default.
+fn render_event(event: &Value) -> String {
+    let time = event
+        .get("timeNano")
+        .and_then(Value::as_i64)
+        .and_then(|nanos| time::OffsetDateTime::from_unix_timestamp_nanos(i128::from(nanos)).ok())
+        .and_then(|time| {
+            time.format(&time::format_description::well_known::Rfc3339)
+                .ok()
+        })
+        .unwrap_or_default();
+    let text = |pointer: &str| {
+        event
+            .pointer(pointer)
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+    };
+    let attributes = event
+        .pointer("/Actor/Attributes")
+        .and_then(Value::as_object)
+        .map(|attributes| {
+            let mut pairs = attributes
+                .iter()
+                .map(|(key, value)| format!("{key}={}", value.as_str().unwrap_or_default()))
+                .collect::<Vec<_>>();
+            pairs.sort();
+            pairs.join(", ")
+        })
+        .unwrap_or_default();
+
+    let mut line = format!(
+        "{time} {} {} {}",
+        text("/Type"),
+        text("/Action"),
+        text("/Actor/ID")
+    );
+    if !attributes.is_empty() {
+        line.push_str(&format!(" ({attributes})"));
+    }
+    line
+}
+
+fn is_unix_timestamp(value: Option<&str>) -> bool {
+    value.is_none_or(|value| {
+        let (seconds, fraction) = value.split_once('.').unwrap_or((value, "0"));
+        !seconds.is_empty()
+            && seconds.chars().all(|ch| ch.is_ascii_digit())
+            && fraction.chars().all(|ch| ch.is_ascii_digit())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{events_path, is_unix_timestamp, logs_path, render_event};
+    use crate::docker::LogsOptions;
+
+    #[test]
+    fn logs_path_maps_cli_options_to_query() {
+        let path = logs_path(
+            "acme-db",
+            &LogsOptions {
+                follow: true,
+                tail: Some(50),
+                since: Some("1700000000".to_owned()),
+                until: None,
+                timestamps: true,
+                prefix: false,
+            },
+        );
+        assert_eq!(
+            path,
+            "/containers/acme-db/logs?stdout=1&stderr=1&follow=1&timestamps=1&tail=50&since=1700000000"
+        );
+    }
+
+    #[test]
+    fn events_path_groups_filters_by_key() {
+        let filters = vec![
+            "label=com.helm.managed=true".to_owned(),
+            "type=container".to_owned(),
+            "label=com.helm.service=db".to_owned(),
+        ];
+        let path = events_path(Some("1700000000"), None, &filters).expect("path");
+        assert!(path.starts_with("/events?since=1700000000&filters=%7B%22label%22"));
+        assert!(path.contains("com.helm.service%3Ddb"));
+        assert_eq!(events_path(None, None, &[]).as_deref(), Some("/events"));
+        assert_eq!(events_path(None, None, &["bogus".to_owned()]), None);
+    }
+
+    #[test]
+    fn render_event_matches_cli_default_layout() {
+        let event = serde_json::json!({
+            "Type": "container",
+            "Action": "start",
+            "Actor": { "ID": "abc123", "Attributes": { "name": "acme-db", "image": "mysql:8.4" } },
+            "timeNano": 1_700_000_000_000_000_000_i64
+        });
+        assert_eq!(
+            render_event(&event),
+            "2023-11-14T22:13:20Z container start abc123 (image=mysql:8.4, name=acme-db)"
+        );
+    }
+
+    #[test]
+    fn relative_times_are_left_to_the_cli() {
+        assert!(is_unix_timestamp(None));
+        assert!(is_unix_timestamp(Some("1700000000")));
+        assert!(is_unix_timestamp(Some("1700000000.5")));
+        assert!(!is_unix_timestamp(Some("10m")));
+        assert!(!is_unix_timestamp(Some("2024-01-01T00:00:00Z")));
+    }
+}
@@ -0,0 +1,361 @@
+//! docker api tests module.
+//!
+//! Exercises the native client against a fake engine on a local unix socket.
+
+#![cfg(unix)]
+
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::os::unix::net::UnixListener;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::docker::{self, LogsOptions};
+
+/// One canned reply, matched against the start of `METHOD PATH`.
+struct Reply {
+    request: &'static str,
+    response: Vec<u8>,
+}
+
+fn reply(request: &'static str, status: &str, body: &str) -> Reply {
+    Reply {
+        request,
+        response: format!(
+            "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{body}",
+            body.len()
+        )
+        .into_bytes(),
+    }
+}
+
+fn raw_reply(request: &'static str, head: &str, body: Vec<u8>) -> Reply {
+    let mut response = head.as_bytes().to_vec();
+    response.extend(body);
+    Reply { request, response }
+}
+
+fn frame(kind: u8, payload: &str) -> Vec<u8> {
+    let mut bytes = vec![kind, 0, 0, 0];
+    bytes.extend_from_slice(&u32::try_from(payload.len()).expect("len").to_be_bytes());
+    bytes.extend_from_slice(payload.as_bytes());
+    bytes
+}
+
+struct FakeEngine {
+    dir: PathBuf,
+    socket: PathBuf,
+    requests: Arc<Mutex<Vec<String>>>,
+    server: Option<JoinHandle<()>>,
+}
+
+impl FakeEngine {
+    /// Serves each reply on its own connection, in order.
+    fn start(replies: Vec<Reply>) -> Self {
+        let dir = std::env::temp_dir().join(format!(
+            "helm-api-{}",
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .expect("time")
+                .as_nanos()
+        ));
+        fs::create_dir_all(&dir).expect("socket dir");
+        let socket = dir.join("engine.sock");
+        let listener = UnixListener::bind(&socket).expect("bind fake engine");
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let seen = Arc::clone(&requests);
+
+        let server = thread::spawn(move || {
+            for reply in replies {
+                let (stream, _) = listener.accept().expect("accept");
+                let mut reader = BufReader::new(stream);
+                let request = read_request(&mut reader);
+                assert!(
+                    request.starts_with(reply.request),
+                    "expected `{}`, got `{request}`",
+                    reply.request
+                );
+                seen.lock().expect("requests").push(request);
+                let mut stream = reader.into_inner();
+                stream.write_all(&reply.response).expect("write reply");
+            }
+        });
+
+        Self {
+            dir,
+            socket,
+            requests,
+            server: Some(server),
+        }
+    }
+
+    fn run<T>(&self, test: impl FnOnce() -> T) -> T {
+        docker::with_engine_api_socket(&self.socket, test)
+    }
+
+    fn finish(mut self) -> Vec<String> {
+        if let Some(server) = self.server.take() {
+            server.join().expect("fake engine thread");
+        }
+        self.requests.lock().expect("requests").clone()
+    }
+}
+
+impl Drop for FakeEngine {
+    fn drop(&mut self) {
+        fs::remove_dir_all(&self.dir).ok();
+    }
+}
+
+/// Reads `METHOD PATH` plus any JSON body.
+fn read_request(reader: &mut impl BufRead) -> String {
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).expect("request line");
+    let mut length = 0;
+    loop {
+        let mut header = String::new();
+        reader.read_line(&mut header).expect("header");
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some(value) = header.strip_prefix("Content-Length: ") {
+            length = value.parse().expect("content length");
+        }
+    }
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).expect("body");
+
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default();
+    let path = parts.next().unwrap_or_default();
+    let body = String::from_utf8_lossy(&body);
+    if body.is_empty() {
+        format!("{method} {path}")
+    } else {
+        format!("{method} {path} {body}")
+    }
+}
+
+#[test]
+fn inspect_helpers_read_from_one_api_document() {
+    let document = r#"{"State":{"Status":"running","ExitCode":0},"Config":{"Env":["A=1"],"Labels":{"com.helm.managed":"true"}}}"#;
+    let engine = FakeEngine::start(vec![
+        reply("GET /containers/acme-db/json", "200 OK", document),
+        reply("GET /containers/acme-db/json", "200 OK", document),
+        reply("GET /containers/acme-db/json", "200 OK", document),
+        reply(
+            "GET /containers/missing/json",
+            "404 Not Found",
+            r#"{"message":"No such container: missing"}"#,
+        ),
+    ]);
+
+    let (status, label, absent, missing) = engine.run(|| {
+        (
+            docker::inspect_status("acme-db"),
+            docker::inspect_label("acme-db", "com.helm.managed"),
+            docker::inspect_label("acme-db", "com.helm.project"),
+            docker::inspect_status("missing"),
+        )
+    });
+
+    assert_eq!(status.as_deref(), Some("running"));
+    assert_eq!(label.as_deref(), Some("true"));
+    assert_eq!(absent.as_deref(), Some(""));
+    assert_eq!(missing, None);
+    assert_eq!(engine.finish().len(), 4);
+}
+
+#[test]
+fn exec_output_creates_starts_and_inspects_an_exec() {
+    let mut stream = frame(1, "PONG\n");
+    stream.extend(frame(2, "warning\n"));
+    let engine = FakeEngine::start(vec![
+        reply(
+            "POST /containers/acme-redis/exec",
+            "201 Created",
+            r#"{"Id":"e1"}"#,
+        ),
+        raw_reply(
+            "POST /exec/e1/start",
+            "HTTP/1.1 200 OK\r\nContent-Type: application/vnd.docker.multiplexed-stream\r\n\r\n",
+            stream,
+        ),
+        reply(
+            "GET /exec/e1/json",
+            "200 OK",
+            r#"{"ExitCode":0,"Running":false}"#,
+        ),
+    ]);
+
+    let output = engine
+        .run(|| super::exec_output("acme-redis", &["redis-cli", "PING"]))
+        .expect("api reachable");
+    let requests = engine.finish();
+
+    assert!(output.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "PONG\n");
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "warning\n");
+    assert!(
+        requests
+            .first()
+            .is_some_and(|request| request.contains(r#""Cmd":["redis-cli","PING"]"#)),
+        "exec create carries the command: {requests:?}"
+    );
+}
+
+#[test]
+fn exec_output_reports_containers_that_are_not_running() {
+    let engine = FakeEngine::start(vec![reply(
+        "POST /containers/acme-db/exec",
+        "409 Conflict",
+        r#"{"message":"container is not running"}"#,
+    )]);
+
+    let output = engine
+        .run(|| super::exec_output("acme-db", &["true"]))
+        .expect("api reachable");
+    engine.finish();
+
+    assert!(!output.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "container is not running"
+    );
+}
+
+#[test]
+fn start_and_stop_treat_304_as_already_in_state() {
+    let engine = FakeEngine::start(vec![
+        reply("POST /containers/acme-db/start", "304 Not Modified", ""),
+        reply("POST /containers/acme-db/stop?t=5", "304 Not Modified", ""),
+        reply("POST /containers/acme-db/stop?t=5", "204 No Content", ""),
+        reply(
+            "POST /containers/gone/start",
+            "404 Not Found",
+            r#"{"message":"No such container: gone"}"#,
+        ),
+    ]);
+
+    let (started, skipped, stopped, failed) = engine.run(|| {
+        (
+            super::start_container("acme-db"),
+            super::stop_container("acme-db", 5),
+            super::stop_container("acme-db", 5),
+            super::start_container("gone"),
+        )
+    });
+    engine.finish();
+
+    assert!(matches!(started, Some(Ok(()))));
+    assert_eq!(skipped, Some(false));
+    assert_eq!(stopped, Some(true));
+    let error = failed.and_then(Result::err).expect("start failure");
+    assert_eq!(error.to_string(), "No such container: gone");
+}
+
+#[test]
+fn logs_stream_chunked_multiplexed_frames() {
+    let frames = [frame(1, "booted\n"), frame(2, "slow query\n")].concat();
+    let mut chunked = format!("{:x}\r\n", frames.len()).into_bytes();
+    chunked.extend(&frames);
+    chunked.extend(b"\r\n0\r\n\r\n");
+    let engine = FakeEngine::start(vec![
+        reply(
+            "GET /containers/acme-db/json",
+            "200 OK",
+            r#"{"Config":{"Tty":false}}"#,
+        ),
+        raw_reply(
+            "GET /containers/acme-db/logs?stdout=1&stderr=1&tail=10",
+            "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n",
+            chunked,
+        ),
+    ]);
+
+    let mut lines = Vec::new();
+    let result = engine.run(|| {
+        super::logs(
+            "acme-db",
+            &LogsOptions {
+                follow: false,
+                tail: Some(10),
+                since: None,
+                until: None,
+                timestamps: false,
+                prefix: false,
+            },
+            |stream, bytes| {
+                lines.push((stream, String::from_utf8_lossy(bytes).into_owned()));
+                Ok(())
+            },
+        )
+    });
+    engine.finish();
+
+    assert!(matches!(result, Some(Ok(()))));
+    assert_eq!(
+        lines,
+        vec![
+            (super::OutputStream::Stdout, "booted\n".to_owned()),
+            (super::OutputStream::Stderr, "slow query\n".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn events_render_one_line_per_streamed_event() {
+    let body = concat!(
+        r#"{"Type":"container","Action":"start","Actor":{"ID":"c1","Attributes":{"name":"acme-db"}},"timeNano":1700000000000000000}"#,
+        "\n",
+        r#"{"Type":"container","Action":"die","Actor":{"ID":"c1","Attributes":{}},"timeNano":1700000001000000000}"#,
+        "\n"
+    );
+    let engine = FakeEngine::start(vec![reply(
+        "GET /events?since=1700000000&until=1700000002",
+        "200 OK",
+        body,
+    )]);
+
+    let mut out = Vec::new();
+    let result =
+        engine.run(|| super::events(Some("1700000000"), Some("1700000002"), None, &[], &mut out));
+    engine.finish();
+
+    assert!(matches!(result, Some(Ok(()))));
+    assert_eq!(
+        String::from_utf8_lossy(&out),
+        "2023-11-14T22:13:20Z container start c1 (name=acme-db)\n\
+         2023-11-14T22:13:21Z container die c1\n"
+    );
+}
+
+#[test]
+fn unreachable_socket_falls_back_to_the_cli() {
+    let dir = std::env::temp_dir().join(format!(
+        "helm-api-fallback-{}",
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("time")
+            .as_nanos()
+    ));
+    fs::create_dir_all(&dir).expect("dir");
+    let binary = dir.join("docker");
+    fs::write(&binary, "#!/bin/sh\nprintf 'exited'\n").expect("fake docker");
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&binary, fs::Permissions::from_mode(0o755)).expect("chmod");
+    }
+
+    let status = docker::with_engine_api_socket(Path::new("/nonexistent/helm.sock"), || {
+        docker::with_docker_command(&binary.to_string_lossy(), || {
+            docker::inspect_status("acme-db")
+        })
+    });
+    fs::remove_dir_all(&dir).ok();
+
+    assert_eq!(status.as_deref(), Some("exited"));
+}
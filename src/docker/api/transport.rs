@@ -0,0 +1,276 @@
+//! docker api transport module.
+//!
+//! Minimal HTTP/1.1 client over the engine's unix socket.
+
+use std::collections::HashMap;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::path::Path;
+use std::time::Duration;
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// A response whose body has not been read yet.
+pub(super) struct StreamResponse {
+    pub(super) status: u16,
+    pub(super) body: Box<dyn BufRead + Send>,
+}
+
+/// A fully read response.
+pub(super) struct ApiResponse {
+    pub(super) status: u16,
+    pub(super) body: Vec<u8>,
+}
+
+impl ApiResponse {
+    pub(super) const fn is_success(&self) -> bool {
+        self.status >= 200 && self.status < 300
+    }
+
+    pub(super) fn json(&self) -> Option<serde_json::Value> {
+        serde_json::from_slice(&self.body).ok()
+    }
+
+    /// Returns the engine's `message` field, or the raw body.
+    pub(super) fn error_message(&self) -> String {
+        self.json()
+            .and_then(|body| body.get("message")?.as_str().map(str::to_owned))
+            .unwrap_or_else(|| String::from_utf8_lossy(&self.body).trim().to_owned())
+    }
+}
+
+/// Sends a request and reads the whole response body.
+pub(super) fn request(
+    socket: &Path,
+    method: &str,
+    path: &str,
+    body: Option<&serde_json::Value>,
+) -> io::Result<ApiResponse> {
+    let mut response = send(socket, method, path, body, Some(REQUEST_TIMEOUT))?;
+    let mut body = Vec::new();
+    response.body.read_to_end(&mut body)?;
+    Ok(ApiResponse {
+        status: response.status,
+        body,
+    })
+}
+
+/// Sends a request and returns the body as a stream, without a read timeout.
+pub(super) fn stream(
+    socket: &Path,
+    method: &str,
+    path: &str,
+    body: Option<&serde_json::Value>,
+) -> io::Result<StreamResponse> {
+    send(socket, method, path, body, None)
+}
+
+fn send(
+    socket: &Path,
+    method: &str,
+    path: &str,
+    body: Option<&serde_json::Value>,
+    timeout: Option<Duration>,
+) -> io::Result<StreamResponse> {
+    let mut connection = connect(socket, timeout)?;
+    let payload = body.map(serde_json::Value::to_string).unwrap_or_default();
+    let mut head = format!("{method} {path} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n");
+    if body.is_some() {
+        head.push_str("Content-Type: application/json\r\n");
+    }
+    head.push_str(&format!("Content-Length: {}\r\n\r\n", payload.len()));
+    connection.write_all(head.as_bytes())?;
+    connection.write_all(payload.as_bytes())?;
+    connection.flush()?;
+
+    read_response(BufReader::new(connection))
+}
+
+fn read_response<R: BufRead + Send + 'static>(mut reader: R) -> io::Result<StreamResponse> {
+    let mut status_line = String::new();
+    reader.read_line(&mut status_line)?;
+    let status = status_line
+        .split_whitespace()
+        .nth(1)
+        .and_then(|code| code.parse::<u16>().ok())
+        .ok_or_else(|| invalid(format!("malformed status line: {}", status_line.trim())))?;
+
+    let mut headers = HashMap::new();
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            break;
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            headers.insert(name.trim().to_ascii_lowercase(), value.trim().to_owned());
+        }
+    }
+
+    let chunked = headers
+        .get("transfer-encoding")
+        .is_some_and(|value| value.eq_ignore_ascii_case("chunked"));
+    let body: Box<dyn BufRead + Send> = if chunked {
+        Box::new(BufReader::new(ChunkedReader::new(reader)))
+    } else if let Some(length) = headers
+        .get("content-length")
+        .and_then(|value| value.parse::<u64>().ok())
+    {
+        Box::new(reader.take(length))
+    } else {
+        // Hijacked exec/attach streams run until the engine closes the socket.
+        Box::new(reader)
+    };
+
+    Ok(StreamResponse { status, body })
+}
+
+/// Decodes a `Transfer-Encoding: chunked` body.
+struct ChunkedReader<R> {
+    inner: R,
+    remaining: u64,
+    done: bool,
+}
+
+impl<R: BufRead> ChunkedReader<R> {
+    const fn new(inner: R) -> Self {
+        Self {
+            inner,
+            remaining: 0,
+            done: false,
+        }
+    }
+
+    fn next_chunk(&mut self) -> io::Result<()> {
+        let mut line = String::new();
+        self.inner.read_line(&mut line)?;
+        let size = line.trim().split(';').next().unwrap_or_default();
+        self.remaining = u64::from_str_radix(size, 16)
+            .map_err(|_| invalid(format!("malformed chunk size: {}", line.trim())))?;
+        if self.remaining == 0 {
+            self.done = true;
+        }
+        Ok(())
+    }
+}
+
+impl<R: BufRead> Read for ChunkedReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.done {
+            return Ok(0);
+        }
+        if self.remaining == 0 {
+            self.next_chunk()?;
+            if self.done {
+                return Ok(0);
+            }
+        }
+
+        let limit = usize::try_from(self.remaining).unwrap_or(usize::MAX);
+        let want = buf.len().min(limit);
+        let read = self.inner.read(buf.get_mut(..want).unwrap_or_default())?;
+        if read == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "chunked body ended early",
+            ));
+        }
+        self.remaining = self.remaining.saturating_sub(read as u64);
+        if self.remaining == 0 {
+            let mut crlf = String::new();
+            self.inner.read_line(&mut crlf)?;
+        }
+        Ok(read)
+    }
+}
+
+fn invalid(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+trait Connection: Read + Write + Send {}
+
+impl<T: Read + Write + Send> Connection for T {}
+
+#[cfg(unix)]
+fn connect(socket: &Path, timeout: Option<Duration>) -> io::Result<Box<dyn Connection>> {
+    let stream = std::os::unix::net::UnixStream::connect(socket)?;
+    stream.set_read_timeout(timeout)?;
+    stream.set_write_timeout(Some(REQUEST_TIMEOUT))?;
+    Ok(Box::new(stream))
+}
+
+#[cfg(not(unix))]
+fn connect(_socket: &Path, _timeout: Option<Duration>) -> io::Result<Box<dyn Connection>> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "engine API sockets require a unix platform",
+    ))
+}
+
+/// Percent-encodes a query string component.
+pub(super) fn encode_query(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b'~') {
+            encoded.push(char::from(byte));
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{encode_query, read_response};
+    use std::io::{Cursor, Read};
+
+    fn body_of(raw: &'static str) -> (u16, String) {
+        let mut response = read_response(Cursor::new(raw.as_bytes())).expect("response");
+        let mut body = String::new();
+        response.body.read_to_string(&mut body).expect("body");
+        (response.status, body)
+    }
+
+    #[test]
+    fn reads_content_length_bodies() {
+        let (status, body) = body_of("HTTP/1.1 200 OK\r\nContent-Length: 5\r\n\r\nhello trailing");
+        assert_eq!(status, 200);
+        assert_eq!(body, "hello");
+    }
+
+    #[test]
+    fn decodes_chunked_bodies() {
+        let (status, body) = body_of(
+            "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n4\r\nhelm\r\n6;ext=1\r\n ready\r\n0\r\n\r\n",
+        );
+        assert_eq!(status, 200);
+        assert_eq!(body, "helm ready");
+    }
+
+    #[test]
+    fn reads_hijacked_streams_to_eof() {
+        let (status, body) = body_of(
+            "HTTP/1.1 200 OK\r\nContent-Type: application/vnd.docker.raw-stream\r\n\r\nraw",
+        );
+        assert_eq!(status, 200);
+        assert_eq!(body, "raw");
+    }
+
+    #[test]
+    fn rejects_malformed_status_lines() {
+        assert!(read_response(Cursor::new(b"garbage\r\n\r\n".as_slice())).is_err());
+    }
+
+    #[test]
+    fn encode_query_escapes_reserved_characters() {
+        assert_eq!(
+            encode_query(r#"{"label":["a=b"]}"#),
+            "%7B%22label%22%3A%5B%22a%3Db%22%5D%7D"
+        );
+        assert_eq!(encode_query("acme-db_1.x"), "acme-db_1.x");
+    }
+}
@@ -0,0 +1,363 @@
+//! docker build module.
+//!
+//! Builds `[service.build]` images through the build scheduler slot. Tags
+//! carry a context signature, so an unchanged build is reused and a changed
+//! one gets a new tag that `up` recreates the container for.
+
+mod dockerignore;
+
+use anyhow::{Context, Result};
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use dockerignore::DockerIgnore;
+
+use crate::config::{ServiceBuild, ServiceConfig};
+use crate::output::{self, LogLevel, Persistence};
+
+use super::drift::fnv1a64;
+use super::{
+    DockerOpClass, docker_image_id, inspect_json, is_dry_run, print_docker_command,
+    run_docker_status_owned, runtime_command_error_context, with_scheduled_docker_op,
+};
+
+/// Build inputs resolved against the project root.
+struct BuildPlan<'a> {
+    build: &'a ServiceBuild,
+    context: PathBuf,
+    dockerfile: PathBuf,
+    tag: String,
+}
+
+/// Returns the service with `image` set to its built tag.
+///
+/// The image is built when no image exists for the current signature;
+/// services without `[service.build]` are returned unchanged.
+pub(crate) fn with_built_image(service: &ServiceConfig) -> Result<Cow<'_, ServiceConfig>> {
+    let Some(plan) = build_plan(service)? else {
+        return Ok(Cow::Borrowed(service));
+    };
+    if is_dry_run() || !super::up::inspect_image_exists(&plan.tag)? {
+        run_build(service, &plan, false)?;
+    }
+    let mut built = service.clone();
+    built.image = plan.tag;
+    Ok(Cow::Owned(built))
+}
+
+/// Rebuilds the service image with `--pull` so base image updates land even
+/// when the signature is unchanged.
+pub(crate) fn rebuild_service_image(service: &ServiceConfig) -> Result<()> {
+    match build_plan(service)? {
+        Some(plan) => run_build(service, &plan, true),
+        None => Ok(()),
+    }
+}
+
+/// Returns true when the container runs an image other than the one the
+/// built tag points at.
+///
+/// Image IDs are compared rather than tags, so a `--pull` rebuild that moves
+/// the same tag to a new image still recreates the container.
+pub(crate) fn built_image_outdated(service: &ServiceConfig, container_name: &str) -> bool {
+    if service.build.is_none() {
+        return false;
+    }
+    let Some(running) = inspect_json(container_name)
+        .and_then(|inspect| inspect.pointer("/Image")?.as_str().map(str::to_owned))
+    else {
+        return false;
+    };
+    let Ok(Some(built)) = docker_image_id(
+        &service.image,
+        &runtime_command_error_context("image inspect"),
+    ) else {
+        return false;
+    };
+    !same_image_id(&running, &built)
+}
+
+/// Compares image IDs, ignoring the `sha256:` prefix Podman leaves off.
+fn same_image_id(left: &str, right: &str) -> bool {
+    let trim = |id: &str| id.trim().trim_start_matches("sha256:").to_owned();
+    trim(left) == trim(right)
+}
+
+fn build_plan(service: &ServiceConfig) -> Result<Option<BuildPlan<'_>>> {
+    let Some(build) = &service.build else {
+        return Ok(None);
+    };
+    let project_root = service
+        .resolved_project_root
+        .clone()
+        .unwrap_or_else(|| PathBuf::from("."));
+    let context = project_root.join(build.context_dir());
+    if !context.is_dir() {
+        anyhow::bail!(
+            "service '{}' build context {} is not a directory",
+            service.name,
+            context.display()
+        );
+    }
+    let dockerfile = context.join(build.dockerfile_path());
+    let signature = build_signature(build, &context, &dockerfile)?;
+    Ok(Some(BuildPlan {
+        build,
+        tag: built_image_tag(&service.image, &signature),
+        context,
+        dockerfile,
+    }))
+}
+
+fn run_build(service: &ServiceConfig, plan: &BuildPlan<'_>, pull: bool) -> Result<()> {
+    let args = build_args(plan, pull);
+    if is_dry_run() {
+        output::event(
+            &service.name,
+            LogLevel::Info,
+            &format!("[dry-run] Build image {}", plan.tag),
+            Persistence::Transient,
+        );
+        print_docker_command(&args);
+        return Ok(());
+    }
+
+    output::event(
+        &service.name,
+        LogLevel::Info,
+        &format!("Waiting for Docker build slot for image {}", plan.tag),
+        Persistence::Persistent,
+    );
+    let status =
+        with_scheduled_docker_op(DockerOpClass::Build, "docker-build-service-image", || {
+            output::event(
+                &service.name,
+                LogLevel::Info,
+                &format!("Building image {}", plan.tag),
+                Persistence::Persistent,
+            );
+            run_docker_status_owned(&args, &runtime_command_error_context("build"))
+        })?;
+    if !status.success() {
+        anyhow::bail!("failed to build image {} for '{}'", plan.tag, service.name);
+    }
+
+    output::event(
+        &service.name,
+        LogLevel::Success,
+        &format!("Built image {}", plan.tag),
+        Persistence::Persistent,
+    );
+    Ok(())
+}
+
+fn build_args(plan: &BuildPlan<'_>, pull: bool) -> Vec<String> {
+    let mut args = vec![
+        "build".to_owned(),
+        "-t".to_owned(),
+        plan.tag.clone(),
+        "-f".to_owned(),
+        plan.dockerfile.to_string_lossy().into_owned(),
+    ];
+    if pull {
+        args.push("--pull".to_owned());
+    }
+    for (key, value) in sorted_args(plan.build) {
+        args.push("--build-arg".to_owned());
+        args.push(format!("{key}={value}"));
+    }
+    if let Some(target) = &plan.build.target {
+        args.push("--target".to_owned());
+        args.push(target.clone());
+    }
+    for secret in plan.build.secrets.iter().flatten() {
+        args.push("--secret".to_owned());
+        args.push(expand_secret_source(secret));
+    }
+    args.push(plan.context.to_string_lossy().into_owned());
+    args
+}
+
+fn sorted_args(build: &ServiceBuild) -> Vec<(&String, &String)> {
+    let mut args = build.args.iter().flatten().collect::<Vec<_>>();
+    args.sort();
+    args
+}
+
+/// Expands a leading `~/` in a secret's `src=`, which the engines take
+/// literally.
+fn expand_secret_source(secret: &str) -> String {
+    let Ok(home) = std::env::var("HOME") else {
+        return secret.to_owned();
+    };
+    secret
+        .split(',')
+        .map(|part| match part.strip_prefix("src=~/") {
+            Some(path) => format!("src={home}/{path}"),
+            None => part.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Derives the cache signature from the Dockerfile, build options, and the
+/// size and modification time of every context file `.dockerignore` keeps.
+///
+/// File contents are not read, so large contexts stay cheap on every `up`;
+/// touching a file without changing it can only cause an extra rebuild.
+fn build_signature(build: &ServiceBuild, context: &Path, dockerfile: &Path) -> Result<String> {
+    let content = std::fs::read(dockerfile)
+        .with_context(|| format!("failed to read {}", dockerfile.display()))?;
+    let mut manifest = format!("dockerfile {:016x}\n", fnv1a64(&content));
+    for (key, value) in sorted_args(build) {
+        manifest.push_str(&format!("arg {key}={value}\n"));
+    }
+    if let Some(target) = &build.target {
+        manifest.push_str(&format!("target {target}\n"));
+    }
+    for secret in build.secrets.iter().flatten() {
+        manifest.push_str(&format!("secret {secret}\n"));
+    }
+
+    let ignore = DockerIgnore::load(context);
+    let mut files = Vec::new();
+    collect_context_files(context, context, &ignore, &mut files)?;
+    files.sort();
+    for file in files {
+        manifest.push_str(&format!("file {file}\n"));
+    }
+    Ok(format!(
+        "build-fnv1a64-v2:{:016x}",
+        fnv1a64(manifest.as_bytes())
+    ))
+}
+
+/// Collects `<path> <size> <mtime>` entries for the files the build sends.
+fn collect_context_files(
+    context: &Path,
+    dir: &Path,
+    ignore: &DockerIgnore,
+    files: &mut Vec<String>,
+) -> Result<()> {
+    let entries =
+        std::fs::read_dir(dir).with_context(|| format!("failed to read {}", dir.display()))?;
+    for entry in entries {
+        let path = entry?.path();
+        let relative = path
+            .strip_prefix(context)
+            .unwrap_or(&path)
+            .to_string_lossy()
+            .replace('\\', "/");
+        if relative == ".git" {
+            continue;
+        }
+        let metadata = std::fs::symlink_metadata(&path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        if metadata.is_dir() {
+            if !ignore.skips_dir(&relative) {
+                collect_context_files(context, &path, ignore, files)?;
+            }
+        } else if metadata.is_file() && !ignore.is_ignored(&relative) {
+            let modified = metadata
+                .modified()
+                .ok()
+                .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+                .map_or(0, |elapsed| elapsed.as_nanos());
+            files.push(format!("{relative} {} {modified}", metadata.len()));
+        }
+    }
+    Ok(())
+}
+
+/// Tags the build under the service's image repository with the signature
+/// digest, such as `helm/sidecar:helm-1f2e3d4c5b6a7988`.
+fn built_image_tag(image: &str, signature: &str) -> String {
+    let image = image.split('@').next().unwrap_or(image);
+    let name_start = image.rfind('/').map_or(0, |index| index + 1);
+    let repository = match image.get(name_start..).and_then(|name| name.rfind(':')) {
+        Some(colon) => image.get(..name_start + colon).unwrap_or(image),
+        None => image,
+    };
+    format!("{repository}:helm-{:016x}", fnv1a64(signature.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{build_signature, built_image_tag, same_image_id};
+    use crate::config::ServiceBuild;
+    use std::collections::HashMap;
+
+    fn temp_context(label: &str) -> std::path::PathBuf {
+        let nonce = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_nanos());
+        let root = std::env::temp_dir().join(format!("helm-build-{label}-{nonce}"));
+        std::fs::create_dir_all(root.join("src")).expect("create context");
+        std::fs::write(root.join("Dockerfile"), "FROM alpine\nCOPY src /src\n")
+            .expect("write dockerfile");
+        std::fs::write(root.join("src/main.sh"), "echo one\n").expect("write source");
+        root
+    }
+
+    fn build() -> ServiceBuild {
+        ServiceBuild {
+            context: None,
+            dockerfile: None,
+            args: Some(HashMap::from([("VERSION".to_owned(), "1".to_owned())])),
+            target: None,
+            secrets: None,
+        }
+    }
+
+    #[test]
+    fn signature_tracks_context_files_and_build_args() {
+        let root = temp_context("signature");
+        let dockerfile = root.join("Dockerfile");
+        let first = build_signature(&build(), &root, &dockerfile).expect("signature");
+        assert_eq!(
+            build_signature(&build(), &root, &dockerfile).expect("signature"),
+            first
+        );
+
+        std::fs::write(root.join("src/main.sh"), "echo two words\n").expect("edit source");
+        let edited = build_signature(&build(), &root, &dockerfile).expect("signature");
+        assert_ne!(edited, first);
+
+        let mut other_args = build();
+        other_args.args = Some(HashMap::from([("VERSION".to_owned(), "2".to_owned())]));
+        assert_ne!(
+            build_signature(&other_args, &root, &dockerfile).expect("signature"),
+            edited
+        );
+
+        std::fs::write(root.join(".dockerignore"), "logs/\n**/*.tmp\n").expect("write ignore");
+        let ignored = build_signature(&build(), &root, &dockerfile).expect("signature");
+        std::fs::create_dir_all(root.join("logs")).expect("create logs");
+        std::fs::write(root.join("logs/run.log"), "noise\n").expect("write log");
+        std::fs::write(root.join("src/scratch.tmp"), "noise\n").expect("write scratch");
+        assert_eq!(
+            build_signature(&build(), &root, &dockerfile).expect("signature"),
+            ignored
+        );
+
+        std::fs::remove_dir_all(root).expect("cleanup context");
+    }
+
+    #[test]
+    fn image_ids_compare_across_engine_formats() {
+        assert!(same_image_id("sha256:abc123", "abc123\n"));
+        assert!(!same_image_id("sha256:abc123", "sha256:def456"));
+    }
+
+    #[test]
+    fn built_tags_replace_the_image_tag_with_the_signature() {
+        let tag = built_image_tag("registry:5000/acme/sidecar:dev", "sig");
+        assert!(tag.starts_with("registry:5000/acme/sidecar:helm-"), "{tag}");
+        assert!(built_image_tag("helm/sidecar", "sig").starts_with("helm/sidecar:helm-"));
+        assert_ne!(
+            built_image_tag("helm/sidecar", "a"),
+            built_image_tag("helm/sidecar", "b")
+        );
+    }
+}
@@ -0,0 +1,166 @@
+//! docker build dockerignore module.
+//!
+//! Matches context paths against `.dockerignore` rules the way the engines
+//! do: `*`, `?`, `[...]` and `**` globs, matches on parent directories, and
+//! `!` exceptions where the last matching rule wins.
+
+use std::path::Path;
+
+/// Parsed `.dockerignore` rules for one build context.
+pub(super) struct DockerIgnore {
+    rules: Vec<Rule>,
+}
+
+struct Rule {
+    segments: Vec<Vec<char>>,
+    exception: bool,
+}
+
+impl DockerIgnore {
+    /// Reads `.dockerignore` from the context; a missing file ignores nothing.
+    pub(super) fn load(context: &Path) -> Self {
+        Self::parse(&std::fs::read_to_string(context.join(".dockerignore")).unwrap_or_default())
+    }
+
+    fn parse(content: &str) -> Self {
+        let rules = content
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| {
+                let (exception, pattern) = match line.strip_prefix('!') {
+                    Some(pattern) => (true, pattern.trim()),
+                    None => (false, line),
+                };
+                let segments = pattern
+                    .split('/')
+                    .filter(|segment| !segment.is_empty() && *segment != ".")
+                    .map(|segment| segment.chars().collect())
+                    .collect::<Vec<_>>();
+                (!segments.is_empty()).then_some(Rule {
+                    segments,
+                    exception,
+                })
+            })
+            .collect();
+        Self { rules }
+    }
+
+    /// Returns true when the context-relative path is left out of the build.
+    pub(super) fn is_ignored(&self, relative: &str) -> bool {
+        let path = relative.split('/').collect::<Vec<_>>();
+        self.rules
+            .iter()
+            .rev()
+            .find(|rule| rule.matches(&path))
+            .is_some_and(|rule| !rule.exception)
+    }
+
+    /// Returns true when nothing below an ignored directory can be
+    /// re-included, so the walk can skip it.
+    pub(super) fn skips_dir(&self, relative: &str) -> bool {
+        self.is_ignored(relative) && !self.rules.iter().any(|rule| rule.exception)
+    }
+}
+
+impl Rule {
+    /// Matches the path itself or any of its parent directories.
+    fn matches(&self, path: &[&str]) -> bool {
+        (1..=path.len()).any(|len| {
+            path.get(..len)
+                .is_some_and(|prefix| match_segments(&self.segments, prefix))
+        })
+    }
+}
+
+fn match_segments(pattern: &[Vec<char>], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((first, rest)) if first.as_slice() == ['*', '*'] => {
+            (0..=path.len()).any(|skip| match_segments(rest, path.get(skip..).unwrap_or_default()))
+        }
+        Some((first, rest)) => path.split_first().is_some_and(|(segment, remaining)| {
+            glob(first, &segment.chars().collect::<Vec<_>>()) && match_segments(rest, remaining)
+        }),
+    }
+}
+
+/// Matches one path segment against `*`, `?`, `[...]` and `\` escapes.
+fn glob(pattern: &[char], text: &[char]) -> bool {
+    match pattern.split_first() {
+        None => text.is_empty(),
+        Some(('*', rest)) => {
+            (0..=text.len()).any(|skip| glob(rest, text.get(skip..).unwrap_or_default()))
+        }
+        Some(('?', rest)) => text
+            .split_first()
+            .is_some_and(|(_, remaining)| glob(rest, remaining)),
+        Some(('[', rest)) => {
+            let end = rest
+                .iter()
+                .skip(1)
+                .position(|ch| *ch == ']')
+                .map(|index| index + 1);
+            match (end, text.split_first()) {
+                (Some(end), Some((ch, remaining))) => {
+                    class_matches(rest.get(..end).unwrap_or_default(), *ch)
+                        && glob(rest.get(end + 1..).unwrap_or_default(), remaining)
+                }
+                (None, Some(('[', remaining))) => glob(rest, remaining),
+                _ => false,
+            }
+        }
+        Some(('\\', [literal, rest @ ..])) | Some((literal, rest)) => text
+            .split_first()
+            .is_some_and(|(ch, remaining)| ch == literal && glob(rest, remaining)),
+    }
+}
+
+fn class_matches(class: &[char], ch: char) -> bool {
+    let (negated, mut items) = match class.split_first() {
+        Some(('^' | '!', rest)) => (true, rest),
+        _ => (false, class),
+    };
+    let mut matched = false;
+    while let Some((first, rest)) = items.split_first() {
+        match rest {
+            ['-', last, remaining @ ..] => {
+                matched |= (*first..=*last).contains(&ch);
+                items = remaining;
+            }
+            _ => {
+                matched |= *first == ch;
+                items = rest;
+            }
+        }
+    }
+    matched != negated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DockerIgnore;
+
+    #[test]
+    fn rules_follow_engine_glob_and_exception_semantics() {
+        let ignore = DockerIgnore::parse(
+            "# deps\n/vendor\nnode_modules/\n**/*.log\n!keep.log\nsrc/cache-[0-9]\n",
+        );
+
+        assert!(ignore.is_ignored("vendor"));
+        assert!(ignore.is_ignored("vendor/laravel/framework/composer.json"));
+        assert!(ignore.is_ignored("node_modules/.bin/vite"));
+        assert!(ignore.is_ignored("storage/logs/laravel.log"));
+        assert!(ignore.is_ignored("debug.log"));
+        assert!(ignore.is_ignored("src/cache-7/blob"));
+        assert!(!ignore.is_ignored("keep.log"));
+        assert!(!ignore.is_ignored("src/cache-x"));
+        assert!(!ignore.is_ignored("src/vendor"));
+        assert!(!ignore.is_ignored("app/Http/Kernel.php"));
+        assert!(!ignore.skips_dir("vendor"));
+
+        let plain = DockerIgnore::parse("vendor\n");
+        assert!(plain.skips_dir("vendor"));
+        assert!(!plain.skips_dir("app"));
+    }
+}
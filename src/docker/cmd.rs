@@ -8,9 +8,16 @@ pub(crate) fn docker_arg_refs(args: &[String]) -> Vec<&str> {
     args.iter().map(String::as_str).collect()
 }
 
+/// Builds a runtime CLI command aimed at the configured engine endpoint.
+fn runtime_command() -> Command {
+    let mut command = Command::new(crate::docker::docker_command());
+    crate::docker::apply_engine_endpoint(&mut command);
+    command
+}
+
 /// Runs `docker` with args and captures output.
 pub(crate) fn run_docker_output(args: &[&str], context: &str) -> Result<Output> {
-    Command::new(crate::docker::docker_command())
+    runtime_command()
         .args(args)
         .output()
         .with_context(|| context.to_owned())
@@ -24,7 +31,7 @@ pub(crate) fn run_docker_output_owned(args: &[String], context: &str) -> Result<
 
 /// Runs `docker` with args and waits for exit status.
 pub(crate) fn run_docker_status(args: &[&str], context: &str) -> Result<ExitStatus> {
-    Command::new(crate::docker::docker_command())
+    runtime_command()
         .args(args)
         .status()
         .with_context(|| context.to_owned())
@@ -38,7 +45,7 @@ pub(crate) fn run_docker_status_owned(args: &[String], context: &str) -> Result<
 
 /// Spawns `docker` with stdin/stderr piped.
 pub(crate) fn spawn_docker_stdin_stderr_piped(args: &[&str], context: &str) -> Result<Child> {
-    Command::new(crate::docker::docker_command())
+    runtime_command()
         .args(args)
         .stdin(Stdio::piped())
         .stderr(Stdio::piped())
@@ -48,7 +55,7 @@ pub(crate) fn spawn_docker_stdin_stderr_piped(args: &[&str], context: &str) -> R
 
 /// Spawns `docker` with stdout/stderr piped.
 pub(crate) fn spawn_docker_stdout_stderr_piped(args: &[&str], context: &str) -> Result<Child> {
-    Command::new(crate::docker::docker_command())
+    runtime_command()
         .args(args)
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
@@ -0,0 +1,279 @@
+//! docker drift module.
+//!
+//! Computes the desired-state hash stamped on managed containers and compares
+//! it with the label on an existing container.
+
+mod fields;
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+use crate::config::{Driver, Kind, ServiceConfig};
+use crate::javascript::JavaScriptToolchain;
+
+use super::{LABEL_CONFIG_HASH, inspect_label};
+
+pub(crate) use fields::{FieldChange, field_changes};
+
+/// How an existing container relates to the resolved service config.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DriftState {
+    /// The container carries the current desired-state hash.
+    InSync,
+    /// The container was created from a different config.
+    Drifted,
+    /// The container predates hash labels, so drift cannot be detected.
+    Unlabeled,
+    /// No container exists for the service.
+    Missing,
+}
+
+impl DriftState {
+    pub(crate) const fn as_str(self) -> &'static str {
+        match self {
+            Self::InSync => "in-sync",
+            Self::Drifted => "drifted",
+            Self::Unlabeled => "unlabeled",
+            Self::Missing => "missing",
+        }
+    }
+}
+
+/// Fields that shape the container Helm creates for a service.
+///
+/// Maps are collected into `BTreeMap`s so the serialized form, and therefore
+/// the hash, does not depend on `HashMap` iteration order.
+#[derive(Serialize)]
+struct DesiredState<'a> {
+    kind: Kind,
+    driver: Driver,
+    /// Built images are tagged per content signature and compared by `up`
+    /// directly, so only pulled images take part.
+    image: Option<&'a str>,
+    host: &'a str,
+    port: u16,
+    container_port: Option<u16>,
+    smtp_port: Option<u16>,
+    database: Option<&'a str>,
+    username: Option<&'a str>,
+    password: Option<&'a str>,
+    bucket: Option<&'a str>,
+    access_key: Option<&'a str>,
+    secret_key: Option<&'a str>,
+    api_key: Option<&'a str>,
+    region: Option<&'a str>,
+    volumes: Option<&'a [String]>,
+    env: Option<BTreeMap<&'a str, &'a str>>,
+    command: Option<&'a [String]>,
+    localhost_tls: bool,
+    octane: bool,
+    octane_workers: Option<u16>,
+    octane_max_requests: Option<u32>,
+    php_extensions: Option<&'a [String]>,
+    trust_container_ca: bool,
+    javascript: Option<&'a JavaScriptToolchain>,
+    runtime_options: Vec<String>,
+    /// Native `--health-*` flags, which the engine fixes at create time.
+    health: Vec<String>,
+    network: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pod: Option<String>,
+}
+
+impl<'a> DesiredState<'a> {
+    fn from_service(service: &'a ServiceConfig) -> Self {
+        Self {
+            kind: service.kind,
+            driver: service.driver,
+            image: service.build.is_none().then_some(service.image.as_str()),
+            host: &service.host,
+            port: service.port,
+            container_port: service.container_port,
+            smtp_port: service.smtp_port,
+            database: service.database.as_deref(),
+            username: service.username.as_deref(),
+            password: service.password.as_deref(),
+            bucket: service.bucket.as_deref(),
+            access_key: service.access_key.as_deref(),
+            secret_key: service.secret_key.as_deref(),
+            api_key: service.api_key.as_deref(),
+            region: service.region.as_deref(),
+            volumes: service.volumes.as_deref(),
+            env: service.env.as_ref().map(|env| {
+                env.iter()
+                    .map(|(key, value)| (key.as_str(), value.as_str()))
+                    .collect()
+            }),
+            command: service.command.as_deref(),
+            localhost_tls: service.localhost_tls,
+            octane: service.octane,
+            octane_workers: service.octane_workers,
+            octane_max_requests: service.octane_max_requests,
+            php_extensions: service.php_extensions.as_deref(),
+            trust_container_ca: service.trust_container_ca,
+            javascript: service.javascript.as_ref(),
+            runtime_options: service.runtime_option_args(),
+            health: {
+                let mut args = Vec::new();
+                super::append_health_args(&mut args, service);
+                args
+            },
+            network: super::project_network_name(service),
+            pod: super::pod_mode()
+                .then(|| super::project_network_name(service))
+                .flatten(),
+        }
+    }
+}
+
+/// Returns the desired-state hash for a resolved service.
+///
+/// Only fields that change the created container take part, so editing hooks,
+/// seed files, host-only readiness checks or `depends_on` does not force a
+/// recreate. Probes that render native `--health-*` flags do.
+///
+/// The hash lands in a label anyone with `inspect` access can read, so values
+/// resolved from secret references are hashed redacted.
+pub(crate) fn desired_state_hash(service: &ServiceConfig) -> Result<String> {
+    let redacted = service.redacted();
+    let state = DesiredState::from_service(&redacted);
+    let encoded = serde_json::to_vec(&state)
+        .with_context(|| format!("failed to encode desired state for '{}'", service.name))?;
+    Ok(format!("{:016x}", fnv1a64(&encoded)))
+}
+
+/// Classifies an existing container against the service's desired state.
+pub(crate) fn drift_state(service: &ServiceConfig, container_name: &str) -> Result<DriftState> {
+    if super::inspect_status(container_name).is_none() {
+        return Ok(DriftState::Missing);
+    }
+    Ok(classify(
+        inspect_label(container_name, LABEL_CONFIG_HASH).as_deref(),
+        &desired_state_hash(service)?,
+    ))
+}
+
+fn classify(label: Option<&str>, desired: &str) -> DriftState {
+    match label.map(str::trim) {
+        None | Some("" | "<no value>") => DriftState::Unlabeled,
+        Some(current) if current == desired => DriftState::InSync,
+        Some(_) => DriftState::Drifted,
+    }
+}
+
+pub(super) fn fnv1a64(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let mut hash = OFFSET_BASIS;
+    for byte in bytes {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(PRIME);
+    }
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DriftState, classify, desired_state_hash};
+    use crate::config::{
+        ContainerEngine, Driver, HealthProbeType, Kind, ServiceConfig, ServiceHealth,
+    };
+    use std::collections::HashMap;
+
+    fn service() -> ServiceConfig {
+        ServiceConfig {
+            database: Some("app".to_owned()),
+            username: Some("root".to_owned()),
+            password: Some("secret".to_owned()),
+            ..ServiceConfig::fixture("db", Kind::Database, Driver::Mysql, "mysql:8.0")
+        }
+    }
+
+    #[test]
+    fn hash_is_stable_across_env_insertion_order() {
+        let mut first = service();
+        first.env = Some(HashMap::from([
+            ("A".to_owned(), "1".to_owned()),
+            ("B".to_owned(), "2".to_owned()),
+        ]));
+        let mut second = service();
+        second.env = Some(HashMap::from([
+            ("B".to_owned(), "2".to_owned()),
+            ("A".to_owned(), "1".to_owned()),
+        ]));
+
+        assert_eq!(
+            desired_state_hash(&first).expect("hash"),
+            desired_state_hash(&second).expect("hash")
+        );
+        assert_eq!(desired_state_hash(&first).expect("hash").len(), 16);
+    }
+
+    #[test]
+    fn hash_changes_with_container_shaping_fields_only() {
+        let baseline = desired_state_hash(&service()).expect("hash");
+
+        let mut image = service();
+        image.image = "mysql:8.4".to_owned();
+        assert_ne!(desired_state_hash(&image).expect("hash"), baseline);
+
+        let mut memory = service();
+        memory.memory = Some("1g".to_owned());
+        assert_ne!(desired_state_hash(&memory).expect("hash"), baseline);
+
+        let mut host_only = service();
+        host_only.health_path = Some("/up".to_owned());
+        host_only.seed_file = Some("seed.sql".to_owned());
+        assert_eq!(desired_state_hash(&host_only).expect("hash"), baseline);
+    }
+
+    #[test]
+    fn hash_ignores_values_resolved_from_secrets() {
+        let mut first = service();
+        first.secret_fields = vec!["password".to_owned(), "env.API_TOKEN".to_owned()];
+        first.env = Some(HashMap::from([(
+            "API_TOKEN".to_owned(),
+            "tok-1".to_owned(),
+        )]));
+        let mut second = first.clone();
+        second.password = Some("rotated".to_owned());
+        second.env = Some(HashMap::from([(
+            "API_TOKEN".to_owned(),
+            "tok-2".to_owned(),
+        )]));
+
+        assert_eq!(
+            desired_state_hash(&first).expect("hash"),
+            desired_state_hash(&second).expect("hash")
+        );
+    }
+
+    #[test]
+    fn hash_changes_with_native_health_flags() {
+        crate::docker::with_container_engine(ContainerEngine::Docker, || {
+            let baseline = desired_state_hash(&service()).expect("hash");
+            let mut health = service();
+            health.health = Some(ServiceHealth {
+                probe: HealthProbeType::Tcp,
+                command: None,
+                path: None,
+                statuses: None,
+                body_contains: None,
+                interval_sec: Some(10),
+                retries: None,
+                start_period_sec: None,
+            });
+            assert_ne!(desired_state_hash(&health).expect("hash"), baseline);
+        });
+    }
+
+    #[test]
+    fn classify_treats_missing_labels_as_unlabeled() {
+        assert_eq!(classify(None, "abc"), DriftState::Unlabeled);
+        assert_eq!(classify(Some("<no value>"), "abc"), DriftState::Unlabeled);
+        assert_eq!(classify(Some("abc\n"), "abc"), DriftState::InSync);
+        assert_eq!(classify(Some("def"), "abc"), DriftState::Drifted);
+    }
+}
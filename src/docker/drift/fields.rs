@@ -0,0 +1,301 @@
+//! docker drift fields module.
+//!
+//! Lists the config fields whose running value differs from the resolved
+//! service, for `helm diff`.
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::config::{Kind, ServiceConfig};
+
+/// One field whose running value differs from the config.
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub(crate) struct FieldChange {
+    pub(crate) field: String,
+    pub(crate) running: Option<String>,
+    pub(crate) config: Option<String>,
+}
+
+/// Lists fields whose running value differs from the config.
+///
+/// Only values Helm sets from config are compared; engine defaults and
+/// image-provided env are ignored.
+pub(crate) fn field_changes(service: &ServiceConfig, inspect: &Value) -> Vec<FieldChange> {
+    let mut changes = Vec::new();
+    let mut compare = |field: String, running: Option<String>, config: Option<String>| {
+        if running != config {
+            changes.push(FieldChange {
+                field,
+                running,
+                config,
+            });
+        }
+    };
+
+    // App containers run a derived runtime image, so the configured image is
+    // only comparable for infrastructure services.
+    if service.kind != Kind::App {
+        compare(
+            "image".to_owned(),
+            string_at(inspect, "/Config/Image"),
+            Some(service.image.clone()),
+        );
+    }
+
+    if let Some(command) = &service.command {
+        let running = inspect
+            .pointer("/Config/Cmd")
+            .and_then(Value::as_array)
+            .map(|values| {
+                values
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .map(str::to_owned)
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        if !running.ends_with(command) {
+            compare(
+                "command".to_owned(),
+                Some(running.join(" ")),
+                Some(command.join(" ")),
+            );
+        }
+    }
+
+    let running_env = running_env(inspect);
+    if let Some(env) = &service.env {
+        let mut keys = env.keys().collect::<Vec<_>>();
+        keys.sort();
+        for key in keys {
+            compare(
+                format!("env.{key}"),
+                running_env
+                    .iter()
+                    .find(|(name, _)| name == key)
+                    .map(|(_, value)| value.clone()),
+                env.get(key).cloned(),
+            );
+        }
+    }
+
+    // Pod members publish nothing themselves; the pod owns the bindings.
+    if !super::super::pod_mode() {
+        let port_key = format!(
+            "/HostConfig/PortBindings/{}~1tcp",
+            service.resolved_container_port()
+        );
+        let binding = inspect
+            .pointer(&port_key)
+            .and_then(Value::as_array)
+            .and_then(|bindings| bindings.first());
+        compare(
+            "port".to_owned(),
+            binding.and_then(|binding| {
+                let port = binding.get("HostPort").and_then(Value::as_str)?;
+                let host = binding.get("HostIp").and_then(Value::as_str).unwrap_or("");
+                Some(format!("{host}:{port}"))
+            }),
+            Some(format!(
+                "{}:{}",
+                super::super::publish_host(&service.host),
+                service.port
+            )),
+        );
+    }
+
+    let mounts = inspect
+        .get("Mounts")
+        .and_then(Value::as_array)
+        .map(|mounts| {
+            mounts
+                .iter()
+                .filter_map(|mount| mount.get("Destination").and_then(Value::as_str))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    for volume in service.volumes.iter().flatten() {
+        let Some(destination) = volume.split(':').nth(1) else {
+            continue;
+        };
+        if !mounts.contains(&destination) {
+            compare(format!("volume.{destination}"), None, Some(volume.clone()));
+        }
+    }
+
+    if let Some(user) = &service.user {
+        compare(
+            "user".to_owned(),
+            string_at(inspect, "/Config/User").filter(|value| !value.is_empty()),
+            Some(user.clone()),
+        );
+    }
+
+    redact_secrets(service, &mut changes);
+    changes
+}
+
+/// Hides values resolved from secret references on both sides of a change;
+/// the comparison itself still uses the real values.
+fn redact_secrets(service: &ServiceConfig, changes: &mut [FieldChange]) {
+    let redacted = service.redacted();
+    for change in changes {
+        if !service.secret_fields.contains(&change.field) {
+            continue;
+        }
+        let placeholder = match change.field.split_once('.') {
+            Some(("env", key)) => redacted.env.as_ref().and_then(|env| env.get(key)).cloned(),
+            Some(_) => None,
+            None if change.field == "image" => Some(redacted.image.clone()),
+            None => None,
+        };
+        change.running = change.running.as_ref().and(placeholder.clone());
+        change.config = placeholder;
+    }
+}
+
+fn string_at(inspect: &Value, pointer: &str) -> Option<String> {
+    inspect
+        .pointer(pointer)
+        .and_then(Value::as_str)
+        .map(str::to_owned)
+}
+
+fn running_env(inspect: &Value) -> Vec<(String, String)> {
+    inspect
+        .pointer("/Config/Env")
+        .and_then(Value::as_array)
+        .map(|entries| {
+            entries
+                .iter()
+                .filter_map(Value::as_str)
+                .filter_map(|entry| entry.split_once('='))
+                .map(|(key, value)| (key.to_owned(), value.to_owned()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FieldChange, field_changes};
+    use crate::config::{Driver, Kind, ServiceConfig};
+    use std::collections::HashMap;
+
+    fn service() -> ServiceConfig {
+        ServiceConfig {
+            port: 33060,
+            database: Some("app".to_owned()),
+            username: Some("root".to_owned()),
+            password: Some("secret".to_owned()),
+            volumes: Some(vec!["mysql-data:/var/lib/mysql".to_owned()]),
+            env: Some(HashMap::from([
+                ("TZ".to_owned(), "UTC".to_owned()),
+                ("MYSQL_LOG".to_owned(), "1".to_owned()),
+            ])),
+            ..ServiceConfig::fixture("db", Kind::Database, Driver::Mysql, "mysql:8.4")
+        }
+    }
+
+    /// Compares with the runtime state locked so pod mode stays off.
+    fn outside_pod(service: &ServiceConfig, inspect: &serde_json::Value) -> Vec<FieldChange> {
+        crate::docker::with_test_runtime_lock(|| field_changes(service, inspect))
+    }
+
+    #[test]
+    fn field_changes_reports_only_fields_that_differ() {
+        let inspect = serde_json::json!({
+            "Config": {
+                "Image": "mysql:8.0",
+                "Env": ["TZ=UTC", "MYSQL_LOG=0", "PATH=/usr/bin"]
+            },
+            "HostConfig": {
+                "PortBindings": {
+                    "3306/tcp": [{ "HostIp": "127.0.0.1", "HostPort": "33060" }]
+                }
+            },
+            "Mounts": []
+        });
+
+        let changes = outside_pod(&service(), &inspect);
+
+        assert_eq!(
+            changes,
+            vec![
+                FieldChange {
+                    field: "image".to_owned(),
+                    running: Some("mysql:8.0".to_owned()),
+                    config: Some("mysql:8.4".to_owned()),
+                },
+                FieldChange {
+                    field: "env.MYSQL_LOG".to_owned(),
+                    running: Some("0".to_owned()),
+                    config: Some("1".to_owned()),
+                },
+                FieldChange {
+                    field: "volume./var/lib/mysql".to_owned(),
+                    running: None,
+                    config: Some("mysql-data:/var/lib/mysql".to_owned()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn field_changes_is_empty_for_matching_container() {
+        let inspect = serde_json::json!({
+            "Config": {
+                "Image": "mysql:8.4",
+                "Env": ["TZ=UTC", "MYSQL_LOG=1"]
+            },
+            "HostConfig": {
+                "PortBindings": {
+                    "3306/tcp": [{ "HostIp": "127.0.0.1", "HostPort": "33060" }]
+                }
+            },
+            "Mounts": [{ "Destination": "/var/lib/mysql" }]
+        });
+
+        assert!(outside_pod(&service(), &inspect).is_empty());
+    }
+
+    #[test]
+    fn pod_members_skip_port_bindings() {
+        crate::docker::with_podman_pod(|| {
+            let inspect = serde_json::json!({
+                "Config": { "Image": "mysql:8.4", "Env": ["TZ=UTC", "MYSQL_LOG=1"] },
+                "HostConfig": { "PortBindings": {} },
+                "Mounts": [{ "Destination": "/var/lib/mysql" }]
+            });
+            assert!(field_changes(&service(), &inspect).is_empty());
+        });
+    }
+
+    #[test]
+    fn field_changes_redacts_secret_env_values() {
+        let mut service = service();
+        service.env = Some(HashMap::from([(
+            "API_TOKEN".to_owned(),
+            "hunter2".to_owned(),
+        )]));
+        service.secret_fields = vec!["env.API_TOKEN".to_owned()];
+        let inspect = serde_json::json!({
+            "Config": { "Image": "mysql:8.4", "Env": ["API_TOKEN=old-token"] },
+            "HostConfig": {
+                "PortBindings": {
+                    "3306/tcp": [{ "HostIp": "127.0.0.1", "HostPort": "33060" }]
+                }
+            },
+            "Mounts": [{ "Destination": "/var/lib/mysql" }]
+        });
+
+        assert_eq!(
+            outside_pod(&service, &inspect),
+            vec![FieldChange {
+                field: "env.API_TOKEN".to_owned(),
+                running: Some("<redacted>".to_owned()),
+                config: Some("<redacted>".to_owned()),
+            }]
+        );
+    }
+}
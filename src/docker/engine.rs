@@ -1,5 +1,7 @@
 //! Container runtime engine adapter metadata.
 
+use std::path::{Path, PathBuf};
+
 use crate::config::ContainerEngine;
 
 /// Runtime health check specification used by doctor checks.
@@ -27,17 +29,38 @@ pub(crate) trait RuntimeEngineAdapter {
     fn event_source_label(&self) -> &'static str;
     /// Returns log source key used by output event channels.
     fn log_source_key(&self) -> &'static str;
+    /// Returns the engine API unix socket, if the engine is local.
+    fn api_socket(&self) -> Option<PathBuf>;
+    /// Returns true when the engine can group containers into pods.
+    fn supports_pods(&self) -> bool;
+    /// Returns true when `run --network container:<name>` is supported.
+    fn supports_container_network(&self) -> bool;
+    /// Returns true when `run --health-cmd` healthchecks are supported.
+    fn supports_healthchecks(&self) -> bool;
+    /// Returns known feature gaps reported by `helm doctor`.
+    fn capability_gaps(&self) -> &'static [&'static str];
+    /// Returns the variable the CLI reads its endpoint URL from, if remote
+    /// endpoints are supported.
+    fn host_env_var(&self) -> Option<&'static str>;
+    /// Returns the variable the CLI reads a named context from.
+    fn context_env_var(&self) -> Option<&'static str>;
+    /// Returns args that print the endpoint URL of a named context.
+    fn context_endpoint_args(&self, name: &str) -> Option<Vec<String>>;
+    /// Returns the context selected in the CLI's own config, if any.
+    fn current_context(&self) -> Option<String>;
 }
 
 pub(super) fn adapter_for(kind: ContainerEngine) -> &'static dyn RuntimeEngineAdapter {
     match kind {
         ContainerEngine::Docker => &DockerEngineAdapter,
         ContainerEngine::Podman => &PodmanEngineAdapter,
+        ContainerEngine::Nerdctl => &NerdctlEngineAdapter,
     }
 }
 
 struct DockerEngineAdapter;
 struct PodmanEngineAdapter;
+struct NerdctlEngineAdapter;
 
 const DOCKER_DIAGNOSTICS: [RuntimeDiagnosticCheck; 2] = [
     RuntimeDiagnosticCheck {
@@ -69,6 +92,30 @@ const PODMAN_DIAGNOSTICS: [RuntimeDiagnosticCheck; 2] = [
     },
 ];
 
+const NERDCTL_DIAGNOSTICS: [RuntimeDiagnosticCheck; 2] = [
+    RuntimeDiagnosticCheck {
+        arg: "--version",
+        success_message: "nerdctl CLI available",
+        failed_output_prefix: "nerdctl unavailable",
+        failed_exec_prefix: "nerdctl unavailable",
+    },
+    RuntimeDiagnosticCheck {
+        arg: "info",
+        success_message: "containerd reachable",
+        failed_output_prefix: "containerd not reachable",
+        failed_exec_prefix: "nerdctl info failed",
+    },
+];
+
+const NERDCTL_CAPABILITY_GAPS: [&str; 5] = [
+    "nerdctl has no engine API socket; every runtime call uses the CLI",
+    "nerdctl cannot join another container's network (`--network container:`); \
+     object-store buckets are bootstrapped through the host gateway instead",
+    "nerdctl has no pods; `podman_pod` is ignored",
+    "nerdctl runs no engine healthchecks; readiness is probed from the host",
+    "nerdctl cannot target a remote engine; `engine_host` and `engine_context` are unsupported",
+];
+
 impl RuntimeEngineAdapter for DockerEngineAdapter {
     fn kind(&self) -> ContainerEngine {
         ContainerEngine::Docker
@@ -97,6 +144,52 @@ impl RuntimeEngineAdapter for DockerEngineAdapter {
     fn log_source_key(&self) -> &'static str {
         "docker"
     }
+
+    fn api_socket(&self) -> Option<PathBuf> {
+        unix_socket_from_env("DOCKER_HOST")
+            .unwrap_or_else(|| Some(PathBuf::from("/var/run/docker.sock")))
+    }
+
+    fn supports_pods(&self) -> bool {
+        false
+    }
+
+    fn supports_container_network(&self) -> bool {
+        true
+    }
+
+    fn supports_healthchecks(&self) -> bool {
+        true
+    }
+
+    fn capability_gaps(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    fn host_env_var(&self) -> Option<&'static str> {
+        Some("DOCKER_HOST")
+    }
+
+    fn context_env_var(&self) -> Option<&'static str> {
+        Some("DOCKER_CONTEXT")
+    }
+
+    fn context_endpoint_args(&self, name: &str) -> Option<Vec<String>> {
+        Some(vec![
+            "context".to_owned(),
+            "inspect".to_owned(),
+            name.to_owned(),
+            "--format".to_owned(),
+            "{{.Endpoints.docker.Host}}".to_owned(),
+        ])
+    }
+
+    fn current_context(&self) -> Option<String> {
+        let config_dir = std::env::var_os("DOCKER_CONFIG")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".docker")))?;
+        docker_current_context(&config_dir)
+    }
 }
 
 impl RuntimeEngineAdapter for PodmanEngineAdapter {
@@ -127,12 +220,207 @@ impl RuntimeEngineAdapter for PodmanEngineAdapter {
     fn log_source_key(&self) -> &'static str {
         "podman"
     }
+
+    fn api_socket(&self) -> Option<PathBuf> {
+        unix_socket_from_env("CONTAINER_HOST").unwrap_or_else(|| {
+            let rootless = std::env::var_os("XDG_RUNTIME_DIR")
+                .map(|dir| PathBuf::from(dir).join("podman/podman.sock"))
+                .filter(|socket| socket.exists());
+            Some(rootless.unwrap_or_else(|| PathBuf::from("/run/podman/podman.sock")))
+        })
+    }
+
+    fn supports_pods(&self) -> bool {
+        true
+    }
+
+    fn supports_container_network(&self) -> bool {
+        true
+    }
+
+    fn supports_healthchecks(&self) -> bool {
+        true
+    }
+
+    fn capability_gaps(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    fn host_env_var(&self) -> Option<&'static str> {
+        Some("CONTAINER_HOST")
+    }
+
+    fn context_env_var(&self) -> Option<&'static str> {
+        Some("CONTAINER_CONNECTION")
+    }
+
+    fn context_endpoint_args(&self, name: &str) -> Option<Vec<String>> {
+        Some(vec![
+            "system".to_owned(),
+            "connection".to_owned(),
+            "list".to_owned(),
+            "--format".to_owned(),
+            format!("{{{{if eq .Name \"{name}\"}}}}{{{{.URI}}}}{{{{end}}}}"),
+        ])
+    }
+
+    fn current_context(&self) -> Option<String> {
+        None
+    }
+}
+
+impl RuntimeEngineAdapter for NerdctlEngineAdapter {
+    fn kind(&self) -> ContainerEngine {
+        ContainerEngine::Nerdctl
+    }
+
+    fn command_binary(&self) -> &'static str {
+        self.kind().command_binary()
+    }
+
+    fn host_gateway_alias(&self) -> &'static str {
+        self.kind().host_gateway_alias()
+    }
+
+    fn host_gateway_mapping(&self) -> Option<&'static str> {
+        self.kind().host_gateway_mapping()
+    }
+
+    fn diagnostics(&self) -> &'static [RuntimeDiagnosticCheck] {
+        &NERDCTL_DIAGNOSTICS
+    }
+
+    fn event_source_label(&self) -> &'static str {
+        "containerd (nerdctl)"
+    }
+
+    fn log_source_key(&self) -> &'static str {
+        "nerdctl"
+    }
+
+    fn api_socket(&self) -> Option<PathBuf> {
+        None
+    }
+
+    fn supports_pods(&self) -> bool {
+        false
+    }
+
+    fn supports_container_network(&self) -> bool {
+        false
+    }
+
+    fn supports_healthchecks(&self) -> bool {
+        false
+    }
+
+    fn capability_gaps(&self) -> &'static [&'static str] {
+        &NERDCTL_CAPABILITY_GAPS
+    }
+
+    fn host_env_var(&self) -> Option<&'static str> {
+        None
+    }
+
+    fn context_env_var(&self) -> Option<&'static str> {
+        None
+    }
+
+    fn context_endpoint_args(&self, _name: &str) -> Option<Vec<String>> {
+        None
+    }
+
+    fn current_context(&self) -> Option<String> {
+        None
+    }
+}
+
+/// Reads the `docker context use` selection from the CLI config directory.
+fn docker_current_context(config_dir: &Path) -> Option<String> {
+    let config = std::fs::read_to_string(config_dir.join("config.json")).ok()?;
+    let config: serde_json::Value = serde_json::from_str(&config).ok()?;
+    config
+        .get("currentContext")?
+        .as_str()
+        .filter(|name| !name.is_empty() && *name != "default")
+        .map(str::to_owned)
+}
+
+/// Reads a `unix://` socket from a host variable.
+///
+/// Returns `None` when the variable is unset, and `Some(None)` when it points
+/// at a non-unix endpoint the native client cannot reach.
+fn unix_socket_from_env(variable: &str) -> Option<Option<PathBuf>> {
+    let value = std::env::var(variable)
+        .ok()
+        .filter(|value| !value.is_empty())?;
+    Some(parse_unix_socket(&value))
+}
+
+fn parse_unix_socket(value: &str) -> Option<PathBuf> {
+    value.strip_prefix("unix://").map(PathBuf::from)
 }
 
 #[cfg(test)]
 mod tests {
-    use super::adapter_for;
+    use super::{adapter_for, docker_current_context, parse_unix_socket, unix_socket_from_env};
     use crate::config::ContainerEngine;
+    use std::path::PathBuf;
+
+    #[test]
+    fn docker_adapter_defaults_to_the_standard_socket() {
+        if std::env::var_os("DOCKER_HOST").is_some() || std::env::var_os("CONTAINER_HOST").is_some()
+        {
+            return;
+        }
+        assert_eq!(
+            adapter_for(ContainerEngine::Docker).api_socket(),
+            Some(PathBuf::from("/var/run/docker.sock"))
+        );
+        assert!(
+            adapter_for(ContainerEngine::Podman).api_socket().is_some(),
+            "podman falls back to a rootful or rootless socket"
+        );
+        assert_eq!(unix_socket_from_env("HELM_TEST_UNSET_ENGINE_HOST"), None);
+    }
+
+    #[test]
+    fn docker_current_context_ignores_the_default_context() {
+        let dir = std::env::temp_dir().join(format!(
+            "helm-docker-config-{}",
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .expect("system clock")
+                .as_nanos()
+        ));
+        std::fs::create_dir_all(&dir).expect("create config dir");
+        assert_eq!(docker_current_context(&dir), None);
+
+        std::fs::write(dir.join("config.json"), r#"{"currentContext":"default"}"#)
+            .expect("write config");
+        assert_eq!(docker_current_context(&dir), None);
+
+        std::fs::write(dir.join("config.json"), r#"{"currentContext":"build-box"}"#)
+            .expect("write config");
+        assert_eq!(docker_current_context(&dir), Some("build-box".to_owned()));
+        std::fs::remove_dir_all(&dir).ok();
+
+        assert_eq!(adapter_for(ContainerEngine::Podman).current_context(), None);
+        assert_eq!(
+            adapter_for(ContainerEngine::Nerdctl).current_context(),
+            None
+        );
+    }
+
+    #[test]
+    fn parse_unix_socket_accepts_only_unix_endpoints() {
+        assert_eq!(
+            parse_unix_socket("unix:///run/user/1000/docker.sock"),
+            Some(PathBuf::from("/run/user/1000/docker.sock"))
+        );
+        assert_eq!(parse_unix_socket("tcp://10.0.0.5:2376"), None);
+        assert_eq!(parse_unix_socket("ssh://builder"), None);
+    }
 
     #[test]
     fn docker_adapter_exposes_expected_runtime_contract() {
@@ -146,6 +434,7 @@ mod tests {
         assert_eq!(adapter.diagnostics().len(), 2);
         assert_eq!(adapter.event_source_label(), "Docker daemon");
         assert_eq!(adapter.log_source_key(), "docker");
+        assert!(!adapter.supports_pods());
     }
 
     #[test]
@@ -157,5 +446,31 @@ mod tests {
         assert_eq!(adapter.diagnostics().len(), 2);
         assert_eq!(adapter.event_source_label(), "Podman runtime");
         assert_eq!(adapter.log_source_key(), "podman");
+        assert!(adapter.supports_pods());
+    }
+
+    #[test]
+    fn nerdctl_adapter_exposes_expected_runtime_contract() {
+        let adapter = adapter_for(ContainerEngine::Nerdctl);
+        assert_eq!(adapter.command_binary(), "nerdctl");
+        assert_eq!(adapter.host_gateway_alias(), "host.docker.internal");
+        assert_eq!(
+            adapter.host_gateway_mapping(),
+            Some("host.docker.internal:host-gateway")
+        );
+        assert_eq!(adapter.diagnostics().len(), 2);
+        assert_eq!(adapter.event_source_label(), "containerd (nerdctl)");
+        assert_eq!(adapter.log_source_key(), "nerdctl");
+        assert_eq!(adapter.api_socket(), None);
+        assert!(!adapter.supports_pods());
+        assert!(!adapter.supports_container_network());
+        assert!(!adapter.supports_healthchecks());
+        assert_eq!(adapter.capability_gaps().len(), 5);
+        assert_eq!(adapter.host_env_var(), None);
+        assert!(
+            adapter_for(ContainerEngine::Docker)
+                .capability_gaps()
+                .is_empty()
+        );
     }
 }
@@ -7,13 +7,24 @@ use anyhow::Result;
 use crate::config::ServiceConfig;
 use crate::output::{self, LogLevel, Persistence};
 
-use super::{inspect_status, is_dry_run};
+use super::{inspect_health_status, inspect_json, inspect_status, is_dry_run};
 use checks::check_service_health;
+pub use completion::wait_until_completed;
+pub(crate) use native::append_health_args;
 
 mod checks;
+mod completion;
 mod http;
+mod native;
+mod probe;
 
 /// Waits for until healthy to reach a ready state.
+///
+/// Containers created with an engine healthcheck are read from
+/// `State.Health.Status`; others are probed from the host.
+/// A `[service.health]` probe replaces the driver check, and its interval
+/// and retries take precedence over the caller's defaults. Failures during
+/// its start period do not count as retries and extend the timeout.
 pub fn wait_until_healthy(
     service: &ServiceConfig,
     timeout: u64,
@@ -21,13 +32,26 @@ pub fn wait_until_healthy(
     retries: Option<u32>,
 ) -> Result<()> {
     let container_name = service.container_name()?;
+    let health = service.health.as_ref();
+    let interval = health
+        .and_then(|health| health.interval_sec)
+        .unwrap_or(interval);
+    let retries = health.and_then(|health| health.retries).or(retries);
+    let start_period = std::time::Duration::from_secs(
+        health
+            .and_then(|health| health.start_period_sec)
+            .unwrap_or(0),
+    );
 
     if is_dry_run() {
+        let probe = health
+            .map(|health| format!(" probe={}", probe::describe_probe(health)))
+            .unwrap_or_default();
         output::event(
             &service.name,
             LogLevel::Info,
             &format!(
-                "[dry-run] Wait for service health (timeout={}s interval={}s retries={retries:?})",
+                "[dry-run] Wait for service health (timeout={}s interval={}s retries={retries:?}{probe})",
                 timeout, interval
             ),
             Persistence::Transient,
@@ -36,7 +60,7 @@ pub fn wait_until_healthy(
     }
 
     let start = std::time::Instant::now();
-    let timeout_dur = std::time::Duration::from_secs(timeout);
+    let timeout_dur = std::time::Duration::from_secs(timeout) + start_period;
     let sleep_secs = interval.max(1);
 
     match inspect_status(&container_name) {
@@ -47,18 +71,34 @@ pub fn wait_until_healthy(
         None => anyhow::bail!("Container '{container_name}' does not exist"),
     }
 
+    let engine_health = inspect_health_status(&container_name).is_some();
+    let waiting = match health {
+        _ if engine_health => "Waiting for engine healthcheck".to_owned(),
+        Some(health) => format!(
+            "Waiting for health probe ({})",
+            probe::describe_probe(health)
+        ),
+        None => "Waiting for service to accept connections".to_owned(),
+    };
     output::event(
         &service.name,
         LogLevel::Info,
-        "Waiting for service to accept connections",
+        &waiting,
         Persistence::Persistent,
     );
 
     let mut attempts: u32 = 0;
     loop {
-        attempts = attempts.saturating_add(1);
+        if start.elapsed() >= start_period {
+            attempts = attempts.saturating_add(1);
+        }
 
-        match check_service_health(service, &container_name) {
+        let ready = if engine_health {
+            check_engine_health(&container_name)
+        } else {
+            check_service_health(service, &container_name)
+        };
+        match ready {
             Ok(true) => {
                 output::event(
                     &service.name,
@@ -100,3 +140,31 @@ pub fn wait_until_healthy(
         std::thread::sleep(std::time::Duration::from_secs(sleep_secs));
     }
 }
+
+/// Reads one engine healthcheck state; `unhealthy` carries the last probe
+/// output so retries report why.
+fn check_engine_health(container_name: &str) -> Result<bool> {
+    match inspect_health_status(container_name).as_deref() {
+        Some("healthy") => Ok(true),
+        Some("unhealthy") => {
+            let output = last_health_output(container_name)
+                .map(|output| format!(": {output}"))
+                .unwrap_or_default();
+            anyhow::bail!("engine healthcheck reports unhealthy{output}")
+        }
+        _ => Ok(false),
+    }
+}
+
+fn last_health_output(container_name: &str) -> Option<String> {
+    let inspect = inspect_json(container_name)?;
+    let output = inspect
+        .pointer("/State/Health/Log")?
+        .as_array()?
+        .last()?
+        .get("Output")?
+        .as_str()?
+        .trim()
+        .to_owned();
+    (!output.is_empty()).then_some(output)
+}
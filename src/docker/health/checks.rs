@@ -11,7 +11,13 @@ use crate::config::{Driver, ServiceConfig};
 use super::http::http_status_code;
 
 /// Checks service health and reports actionable failures.
+///
+/// A configured `[service.health]` probe replaces the driver default.
 pub(super) fn check_service_health(service: &ServiceConfig, container_name: &str) -> Result<bool> {
+    if let Some(health) = &service.health {
+        return super::probe::check_configured_health(service, health, container_name);
+    }
+
     match service.driver {
         Driver::Mongodb => docker_exec_succeeds(
             &[
@@ -58,12 +64,12 @@ pub(super) fn check_service_health(service: &ServiceConfig, container_name: &str
 }
 
 fn health_check_tcp(service: &ServiceConfig) -> Result<bool> {
-    let address = format!("{}:{}", service.host, service.port);
+    let address = format!("{}:{}", service.client_host(), service.port);
     Ok(TcpStream::connect(address).is_ok())
 }
 
 fn health_check_http(service: &ServiceConfig, path: &str) -> Result<bool> {
-    let status_code = http_status_code(&service.host, service.port, path)?;
+    let status_code = http_status_code(&service.client_host(), service.port, path)?;
     Ok(status_code < 500)
 }
 
@@ -107,7 +113,16 @@ fn health_check_scheduler(container_name: &str) -> Result<bool> {
     )
 }
 
+/// Runs an `exec` health command, over the engine API when available.
+///
+/// Health polling runs these every second, so avoiding a CLI process per
+/// probe matters.
 fn docker_exec_succeeds(args: &[&str], context: &str) -> Result<bool> {
+    if let Some(["exec", container_name, command @ ..]) = Some(args)
+        && let Some(output) = crate::docker::api::exec_output(container_name, command)
+    {
+        return Ok(output.success());
+    }
     crate::docker::run_docker_output(args, context).map(|output| output.status.success())
 }
 
@@ -119,7 +134,7 @@ fn docker_exec_succeeds_owned(args: &[String], context: &str) -> Result<bool> {
 #[cfg(test)]
 mod tests {
     use super::check_service_health;
-    use crate::config::{Driver, Kind, ServiceConfig};
+    use crate::config::{Driver, HealthProbeType, Kind, ServiceConfig, ServiceHealth};
     use crate::docker;
     use std::env;
     use std::fs;
@@ -158,6 +173,7 @@ mod tests {
             hook: Vec::new(),
             health_path: None,
             health_statuses: None,
+            health: None,
             localhost_tls: false,
             octane: false,
             octane_workers: None,
@@ -167,7 +183,21 @@ mod tests {
             env_mapping: None,
             javascript: None,
             container_name: Some("app".to_owned()),
+            memory: None,
+            cpus: None,
+            pids_limit: None,
+            shm_size: None,
+            ulimits: None,
+            tmpfs: None,
+            platform: None,
+            user: None,
+            cap_add: None,
+            restart: None,
+            build: None,
             resolved_container_name: None,
+            resolved_project_root: None,
+            resolved_runtime_env: None,
+            secret_fields: Vec::new(),
         }
     }
 
@@ -317,4 +347,70 @@ mod tests {
         let service = service(Driver::Sqlserver, 0);
         assert!(!check_service_health(&service, "app-container").expect("unavailable"));
     }
+
+    fn probe(probe: HealthProbeType) -> ServiceHealth {
+        ServiceHealth {
+            probe,
+            command: None,
+            path: None,
+            statuses: None,
+            body_contains: None,
+            interval_sec: None,
+            retries: None,
+            start_period_sec: None,
+        }
+    }
+
+    #[test]
+    fn configured_exec_probe_replaces_driver_check() {
+        let mut service = service(Driver::Postgres, 5432);
+        let mut health = probe(HealthProbeType::Exec);
+        health.command = Some(vec![
+            "test".to_owned(),
+            "-f".to_owned(),
+            "/ready".to_owned(),
+        ]);
+        service.health = Some(health);
+
+        with_fake_docker(
+            r#"[ "$3" = "test" ] && [ "$5" = "/ready" ] && exit 0; exit 1"#,
+            || {
+                assert!(check_service_health(&service, "app-container").expect("exec probe"));
+            },
+        );
+    }
+
+    #[test]
+    fn configured_http_probe_checks_statuses_and_body() {
+        let response = "HTTP/1.1 503 Service Unavailable\r\n\r\nindexing";
+        with_http_server(response, 2, |port| {
+            let mut service = service(Driver::Typesense, port);
+            let mut health = probe(HealthProbeType::Http);
+            health.path = Some("/health".to_owned());
+            service.health = Some(health.clone());
+            assert!(!check_service_health(&service, "app-container").expect("default statuses"));
+
+            health.statuses = Some(vec![503]);
+            health.body_contains = Some("ready".to_owned());
+            service.health = Some(health);
+            assert!(!check_service_health(&service, "app-container").expect("body mismatch"));
+        });
+
+        with_http_server("HTTP/1.1 200 OK\r\n\r\n{\"ok\":true}", 1, |port| {
+            let mut service = service(Driver::Typesense, port);
+            let mut health = probe(HealthProbeType::Http);
+            health.body_contains = Some("\"ok\":true".to_owned());
+            service.health = Some(health);
+            assert!(check_service_health(&service, "app-container").expect("body match"));
+        });
+    }
+
+    #[test]
+    fn configured_tcp_probe_replaces_http_driver_check() {
+        with_http_server("garbage", 1, |port| {
+            let mut service = service(Driver::Meilisearch, port);
+            service.health = Some(probe(HealthProbeType::Tcp));
+            assert!(check_service_health(&service, "app-container").expect("tcp probe"));
+        });
+    }
 }
@@ -0,0 +1,70 @@
+//! docker health completion module.
+//!
+//! Waits for one-shot services that dependents gate on with
+//! `condition = "completed"`.
+
+use anyhow::Result;
+use std::time::{Duration, Instant};
+
+use crate::config::ServiceConfig;
+use crate::docker::{inspect_exit_code, inspect_status, is_dry_run};
+use crate::output::{self, LogLevel, Persistence};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Waits until the service container exits, failing unless it exits with 0.
+pub fn wait_until_completed(service: &ServiceConfig, timeout: u64) -> Result<()> {
+    let container_name = service.container_name()?;
+    if is_dry_run() {
+        output::event(
+            &service.name,
+            LogLevel::Info,
+            &format!("[dry-run] Wait for service to complete (timeout={timeout}s)"),
+            Persistence::Transient,
+        );
+        return Ok(());
+    }
+
+    output::event(
+        &service.name,
+        LogLevel::Info,
+        "Waiting for service to complete",
+        Persistence::Persistent,
+    );
+    let start = Instant::now();
+    loop {
+        match inspect_status(&container_name).as_deref() {
+            Some("exited" | "dead") => {
+                return match inspect_exit_code(&container_name) {
+                    Some(0) => {
+                        output::event(
+                            &service.name,
+                            LogLevel::Success,
+                            "Service completed",
+                            Persistence::Persistent,
+                        );
+                        Ok(())
+                    }
+                    Some(code) => anyhow::bail!(
+                        "Service '{}' exited with code {code} instead of completing successfully",
+                        service.name
+                    ),
+                    None => anyhow::bail!(
+                        "Service '{}' exited but its exit code could not be read",
+                        service.name
+                    ),
+                };
+            }
+            Some(_) => {}
+            None => anyhow::bail!("Container '{container_name}' does not exist"),
+        }
+
+        if start.elapsed() >= Duration::from_secs(timeout) {
+            anyhow::bail!(
+                "Timed out after {timeout}s waiting for '{}' to complete",
+                service.name
+            );
+        }
+        std::thread::sleep(POLL_INTERVAL);
+    }
+}
@@ -3,10 +3,38 @@
 //! Contains docker health http logic used by Helm command workflows.
 
 use anyhow::{Context, Result};
-use std::io::{BufRead, BufReader, Write};
+use std::io::{BufRead, BufReader, Read, Write};
 use std::net::{TcpStream, ToSocketAddrs};
 
+/// Largest response body read for `body_contains` probes.
+const MAX_BODY_BYTES: u64 = 64 * 1024;
+
 pub(super) fn http_status_code(host: &str, port: u16, path: &str) -> Result<u16> {
+    let mut reader = send_request(host, port, path)?;
+    read_status_code(&mut reader)
+}
+
+/// Returns the status code and (truncated) body of a `GET` request.
+pub(super) fn http_status_and_body(host: &str, port: u16, path: &str) -> Result<(u16, String)> {
+    let mut reader = send_request(host, port, path)?;
+    let code = read_status_code(&mut reader)?;
+
+    let mut response = Vec::new();
+    reader
+        .take(MAX_BODY_BYTES)
+        .read_to_end(&mut response)
+        .context("failed reading health check response body")?;
+    // The status line's CRLF was consumed, so restore it before finding the
+    // blank line that ends the headers.
+    let response = format!("\r\n{}", String::from_utf8_lossy(&response));
+    let body = response
+        .split_once("\r\n\r\n")
+        .map_or("", |(_, body)| body)
+        .to_owned();
+    Ok((code, body))
+}
+
+fn send_request(host: &str, port: u16, path: &str) -> Result<BufReader<TcpStream>> {
     let mut addrs = (host, port)
         .to_socket_addrs()
         .with_context(|| format!("failed resolving {host}:{port}"))?;
@@ -29,25 +57,26 @@ pub(super) fn http_status_code(host: &str, port: u16, path: &str) -> Result<u16>
         .write_all(request.as_bytes())
         .context("failed writing health check request")?;
 
-    let mut reader = BufReader::new(stream);
+    Ok(BufReader::new(stream))
+}
+
+fn read_status_code(reader: &mut BufReader<TcpStream>) -> Result<u16> {
     let mut status_line = String::new();
     reader
         .read_line(&mut status_line)
         .context("failed reading health check response")?;
 
-    let code = status_line
+    status_line
         .split_whitespace()
         .nth(1)
         .ok_or_else(|| anyhow::anyhow!("invalid HTTP status line: {status_line}"))?
         .parse::<u16>()
-        .context("failed parsing HTTP status code")?;
-
-    Ok(code)
+        .context("failed parsing HTTP status code")
 }
 
 #[cfg(test)]
 mod tests {
-    use super::http_status_code;
+    use super::{http_status_and_body, http_status_code};
     use std::io::{Read, Write};
     use std::net::TcpListener;
     use std::time::{Duration, Instant};
@@ -102,6 +131,21 @@ mod tests {
         });
     }
 
+    #[test]
+    fn reads_status_code_and_body() {
+        with_http_server(
+            "HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\n\r\nindex ready",
+            1,
+            |port| {
+                let (status, body) =
+                    http_status_and_body("127.0.0.1", port, "/").expect("status and body");
+
+                assert_eq!(status, 200);
+                assert_eq!(body, "index ready");
+            },
+        );
+    }
+
     #[test]
     fn rejects_invalid_status_line() {
         with_http_server("INVALID\r\n", 1, |port| {
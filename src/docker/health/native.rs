@@ -0,0 +1,267 @@
+//! docker health native module.
+//!
+//! Renders the readiness probe as engine healthcheck flags for `docker run`,
+//! so `docker ps`, `helm ps`, and readiness waits share one health state.
+
+use crate::config::{Driver, HealthProbeType, ServiceConfig, ServiceHealth};
+
+const DEFAULT_INTERVAL_SECS: u64 = 2;
+const DEFAULT_RETRIES: u32 = 3;
+
+/// Appends `--health-*` flags for the service's probe.
+///
+/// Nothing is added when the engine has no healthchecks or the probe cannot
+/// run inside the container; readiness then falls back to host polling.
+pub(crate) fn append_health_args(args: &mut Vec<String>, service: &ServiceConfig) {
+    if !crate::docker::runtime_supports_healthchecks() {
+        return;
+    }
+    let Some(command) = health_command(service) else {
+        return;
+    };
+    let health = service.health.as_ref();
+    let interval = health
+        .and_then(|health| health.interval_sec)
+        .unwrap_or(DEFAULT_INTERVAL_SECS);
+    let retries = health
+        .and_then(|health| health.retries)
+        .unwrap_or(DEFAULT_RETRIES);
+
+    args.extend([
+        "--health-cmd".to_owned(),
+        command,
+        "--health-interval".to_owned(),
+        format!("{interval}s"),
+        "--health-retries".to_owned(),
+        retries.to_string(),
+    ]);
+    if let Some(start_period) = health.and_then(|health| health.start_period_sec) {
+        args.push("--health-start-period".to_owned());
+        args.push(format!("{start_period}s"));
+    }
+}
+
+/// Returns the in-container shell command for the service's probe.
+fn health_command(service: &ServiceConfig) -> Option<String> {
+    if let Some(health) = &service.health {
+        return configured_command(service, health);
+    }
+
+    let port = service.resolved_container_port();
+    let command = match service.driver {
+        Driver::Mongodb => "mongosh --quiet --eval 'db.adminCommand({ ping: 1 })'".to_owned(),
+        // Probing over TCP skips the socket-only server the postgres and mysql
+        // entrypoints run while initializing.
+        Driver::Postgres => format!(
+            "pg_isready -h 127.0.0.1 -U {}",
+            crate::shell::quote(service.username.as_deref().unwrap_or("postgres"))
+        ),
+        // The password comes from the container env so it never shows up in
+        // `docker inspect` or exported healthchecks.
+        Driver::Mysql => format!(
+            "MYSQL_PWD=\"$MYSQL_PASSWORD\" mysqladmin ping -h 127.0.0.1 -u {} --silent",
+            crate::shell::quote(service.username.as_deref().unwrap_or("root"))
+        ),
+        Driver::Redis | Driver::Valkey | Driver::Dragonfly => "redis-cli PING".to_owned(),
+        Driver::Minio => "mc ready local".to_owned(),
+        Driver::Rabbitmq => "rabbitmq-diagnostics -q ping".to_owned(),
+        Driver::Horizon => "php artisan horizon:status".to_owned(),
+        Driver::Scheduler => "php artisan schedule:list".to_owned(),
+        Driver::Sqlserver | Driver::Memcached => tcp_command(port),
+        Driver::Meilisearch | Driver::Typesense | Driver::Gotenberg => {
+            http_command(port, "/health", "^[1-4]")
+        }
+        Driver::Dusk => http_command(port, "/wd/hub/status", "^[1-4]"),
+        Driver::Rustfs
+        | Driver::Localstack
+        | Driver::Frankenphp
+        | Driver::Reverb
+        | Driver::Mailhog
+        | Driver::Soketi => http_command(port, "/", "^[1-4]"),
+        // The garage image ships without a shell.
+        Driver::Garage => return None,
+    };
+    Some(command)
+}
+
+/// Body matching has no portable in-container form, so those probes stay
+/// host-side.
+fn configured_command(service: &ServiceConfig, health: &ServiceHealth) -> Option<String> {
+    let port = service.resolved_container_port();
+    match health.probe {
+        HealthProbeType::Exec => {
+            let command = health.command.as_ref().filter(|cmd| !cmd.is_empty())?;
+            Some(
+                command
+                    .iter()
+                    .map(|part| crate::shell::quote(part))
+                    .collect::<Vec<_>>()
+                    .join(" "),
+            )
+        }
+        HealthProbeType::Http if health.body_contains.is_some() => None,
+        HealthProbeType::Http => {
+            let pattern = health.statuses.as_ref().map_or_else(
+                || "^[23]".to_owned(),
+                |statuses| {
+                    let statuses = statuses
+                        .iter()
+                        .map(u16::to_string)
+                        .collect::<Vec<_>>()
+                        .join("|");
+                    format!("^({statuses})$")
+                },
+            );
+            Some(http_command(
+                port,
+                health.path.as_deref().unwrap_or("/"),
+                &pattern,
+            ))
+        }
+        HealthProbeType::Tcp => Some(tcp_command(port)),
+    }
+}
+
+/// Checks the status code with `curl`, or any 2xx with `wget` when the image
+/// has no `curl`.
+fn http_command(port: u16, path: &str, status_pattern: &str) -> String {
+    let url = crate::shell::quote(&format!("http://127.0.0.1:{port}{path}"));
+    format!(
+        "if command -v curl >/dev/null 2>&1; then \
+         curl -s -o /dev/null -w '%{{http_code}}' {url} | grep -qE '{status_pattern}'; \
+         else wget -q -O /dev/null {url}; fi"
+    )
+}
+
+fn tcp_command(port: u16) -> String {
+    format!(
+        "if command -v nc >/dev/null 2>&1; then nc -z 127.0.0.1 {port}; \
+         else bash -c '</dev/tcp/127.0.0.1/{port}'; fi"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{append_health_args, health_command};
+    use crate::config::{
+        ContainerEngine, Driver, HealthProbeType, Kind, ServiceConfig, ServiceHealth,
+    };
+
+    fn service(driver: Driver) -> ServiceConfig {
+        ServiceConfig {
+            port: 15432,
+            container_name: Some("app".to_owned()),
+            ..ServiceConfig::fixture("db", Kind::Database, driver, "php")
+        }
+    }
+
+    fn probe(probe: HealthProbeType) -> ServiceHealth {
+        ServiceHealth {
+            probe,
+            command: None,
+            path: None,
+            statuses: None,
+            body_contains: None,
+            interval_sec: None,
+            retries: None,
+            start_period_sec: None,
+        }
+    }
+
+    #[test]
+    fn driver_probes_render_health_flags() {
+        crate::docker::with_container_engine(ContainerEngine::Docker, || {
+            let mut args = Vec::new();
+            append_health_args(&mut args, &service(Driver::Postgres));
+            assert_eq!(
+                args,
+                [
+                    "--health-cmd",
+                    "pg_isready -h 127.0.0.1 -U postgres",
+                    "--health-interval",
+                    "2s",
+                    "--health-retries",
+                    "3",
+                ]
+            );
+        });
+    }
+
+    #[test]
+    fn configured_probe_sets_timing_and_quotes_exec_args() {
+        crate::docker::with_container_engine(ContainerEngine::Docker, || {
+            let mut service = service(Driver::Mysql);
+            let mut health = probe(HealthProbeType::Exec);
+            health.command = Some(vec![
+                "sh".to_owned(),
+                "-c".to_owned(),
+                "test -f /ready".to_owned(),
+            ]);
+            health.interval_sec = Some(5);
+            health.retries = Some(10);
+            health.start_period_sec = Some(30);
+            service.health = Some(health);
+
+            let mut args = Vec::new();
+            append_health_args(&mut args, &service);
+            assert_eq!(
+                args,
+                [
+                    "--health-cmd",
+                    "sh -c 'test -f /ready'",
+                    "--health-interval",
+                    "5s",
+                    "--health-retries",
+                    "10",
+                    "--health-start-period",
+                    "30s",
+                ]
+            );
+        });
+    }
+
+    #[test]
+    fn mysql_probe_reads_the_password_from_the_container_env() {
+        let mut service = service(Driver::Mysql);
+        service.password = Some("hunter2".to_owned());
+
+        let command = health_command(&service).unwrap_or_default();
+        assert!(!command.contains("hunter2"), "{command}");
+        assert!(
+            command.starts_with("MYSQL_PWD=\"$MYSQL_PASSWORD\" mysqladmin ping"),
+            "{command}"
+        );
+    }
+
+    #[test]
+    fn http_probes_match_configured_statuses() {
+        let mut service = service(Driver::Typesense);
+        let mut health = probe(HealthProbeType::Http);
+        health.path = Some("/health".to_owned());
+        health.statuses = Some(vec![200, 503]);
+        service.health = Some(health);
+
+        let command = health_command(&service).unwrap_or_default();
+        assert!(
+            command.contains("http://127.0.0.1:8108/health"),
+            "{command}"
+        );
+        assert!(command.contains("grep -qE '^(200|503)$'"), "{command}");
+    }
+
+    #[test]
+    fn host_only_probes_and_engines_add_no_flags() {
+        let mut service = service(Driver::Typesense);
+        let mut health = probe(HealthProbeType::Http);
+        health.body_contains = Some("ok".to_owned());
+        service.health = Some(health);
+        assert_eq!(health_command(&service), None);
+        assert_eq!(health_command(&self::service(Driver::Garage)), None);
+
+        crate::docker::with_container_engine(ContainerEngine::Nerdctl, || {
+            let mut args = Vec::new();
+            append_health_args(&mut args, &self::service(Driver::Postgres));
+            assert!(args.is_empty());
+        });
+    }
+}
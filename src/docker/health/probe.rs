@@ -0,0 +1,80 @@
+//! docker health probe module.
+//!
+//! Runs the readiness probe configured in `[service.health]`.
+
+use anyhow::Result;
+use std::net::TcpStream;
+
+use crate::config::{HealthProbeType, ServiceConfig, ServiceHealth};
+
+use super::http::{http_status_and_body, http_status_code};
+
+/// Runs one attempt of a configured probe.
+pub(super) fn check_configured_health(
+    service: &ServiceConfig,
+    health: &ServiceHealth,
+    container_name: &str,
+) -> Result<bool> {
+    match health.probe {
+        HealthProbeType::Exec => {
+            let command = health
+                .command
+                .iter()
+                .flatten()
+                .map(String::as_str)
+                .collect::<Vec<_>>();
+            if let Some(output) = crate::docker::api::exec_output(container_name, &command) {
+                return Ok(output.success());
+            }
+            let mut args = vec!["exec".to_owned(), container_name.to_owned()];
+            args.extend(health.command.iter().flatten().cloned());
+            let arg_refs = crate::docker::docker_arg_refs(&args);
+            crate::docker::run_docker_output(&arg_refs, "configured health check command failed")
+                .map(|output| output.status.success())
+        }
+        HealthProbeType::Http => check_http(service, health),
+        HealthProbeType::Tcp => {
+            let address = format!("{}:{}", service.client_host(), service.port);
+            Ok(TcpStream::connect(address).is_ok())
+        }
+    }
+}
+
+fn check_http(service: &ServiceConfig, health: &ServiceHealth) -> Result<bool> {
+    let path = health.path.as_deref().unwrap_or("/");
+    let (status, body) = match &health.body_contains {
+        Some(_) => http_status_and_body(&service.client_host(), service.port, path)?,
+        None => (
+            http_status_code(&service.client_host(), service.port, path)?,
+            String::new(),
+        ),
+    };
+
+    let status_ok = match &health.statuses {
+        Some(statuses) => statuses.contains(&status),
+        None => (200..400).contains(&status),
+    };
+    let body_ok = health
+        .body_contains
+        .as_deref()
+        .is_none_or(|needle| body.contains(needle));
+    Ok(status_ok && body_ok)
+}
+
+/// Describes the probe for progress and dry-run output.
+pub(super) fn describe_probe(health: &ServiceHealth) -> String {
+    match health.probe {
+        HealthProbeType::Exec => format!(
+            "exec `{}`",
+            health
+                .command
+                .iter()
+                .flatten()
+                .cloned()
+                .collect::<Vec<_>>()
+                .join(" ")
+        ),
+        HealthProbeType::Http => format!("http {}", health.path.as_deref().unwrap_or("/")),
+        HealthProbeType::Tcp => "tcp".to_owned(),
+    }
+}
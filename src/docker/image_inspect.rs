@@ -12,16 +12,16 @@ pub(crate) fn docker_image_exists(image: &str, context: &str) -> Result<bool> {
 
 /// Returns repo digest from `docker image inspect --format` when available.
 pub(crate) fn docker_image_repo_digest(image: &str, context: &str) -> Result<Option<String>> {
-    let output = run_docker_output(
-        &[
-            "image",
-            "inspect",
-            "--format",
-            "{{index .RepoDigests 0}}",
-            image,
-        ],
-        context,
-    )?;
+    inspect_image_field(image, "{{index .RepoDigests 0}}", context)
+}
+
+/// Returns the local image ID `<image>` currently points at.
+pub(crate) fn docker_image_id(image: &str, context: &str) -> Result<Option<String>> {
+    inspect_image_field(image, "{{.Id}}", context)
+}
+
+fn inspect_image_field(image: &str, format: &str, context: &str) -> Result<Option<String>> {
+    let output = run_docker_output(&["image", "inspect", "--format", format, image], context)?;
     if !output.status.success() {
         return Ok(None);
     }
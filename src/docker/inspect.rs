@@ -5,10 +5,10 @@
 use std::collections::HashMap;
 use std::process::Output;
 
-use super::is_dry_run;
+use super::{api, is_dry_run};
 use command::{docker_inspect, docker_inspect_format};
 use label::inspect_label as inspect_label_value;
-use parse::extract_host_port_binding_from_inspect;
+use parse::{extract_host_port_binding_from_inspect, restart_state_from_inspect};
 
 mod command;
 mod label;
@@ -16,21 +16,114 @@ mod parse;
 #[cfg(test)]
 mod tests;
 
+/// Restarts after which a failing container counts as crash-looping.
+const CRASH_LOOP_RESTARTS: u32 = 3;
+
+/// Restart history the engine records for a container.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct RestartState {
+    /// Times the restart policy has restarted the container.
+    pub restart_count: u32,
+    /// Exit code of the last run.
+    pub exit_code: i32,
+    /// Whether the engine is waiting to restart the container.
+    pub restarting: bool,
+}
+
+impl RestartState {
+    /// Returns true when the container keeps failing and being restarted.
+    #[must_use]
+    pub const fn is_crash_looping(&self) -> bool {
+        self.restart_count >= CRASH_LOOP_RESTARTS && (self.restarting || self.exit_code != 0)
+    }
+}
+
 #[must_use]
 pub fn inspect_status(container_name: &str) -> Option<String> {
     if is_dry_run() {
         return Some("dry-run".to_owned());
     }
+    if let Some(inspect) = api::inspect_container(container_name) {
+        return string_field(inspect.as_ref()?, "/State/Status");
+    }
 
     let stdout = successful_stdout(docker_inspect_format(container_name, "{{.State.Status}}")?)?;
     Some(stdout.trim().to_owned())
 }
 
+/// Returns the engine healthcheck state (`starting`, `healthy`, or
+/// `unhealthy`), or `None` when the container has no healthcheck.
+#[must_use]
+pub fn inspect_health_status(container_name: &str) -> Option<String> {
+    if is_dry_run() {
+        return None;
+    }
+    if let Some(inspect) = api::inspect_container(container_name) {
+        return string_field(inspect.as_ref()?, "/State/Health/Status")
+            .filter(|status| !status.is_empty());
+    }
+
+    let stdout = successful_stdout(docker_inspect_format(
+        container_name,
+        "{{if .State.Health}}{{.State.Health.Status}}{{end}}",
+    )?)?;
+    Some(stdout.trim().to_owned()).filter(|status| !status.is_empty())
+}
+
+/// Returns the restart count and last exit code of a container.
+#[must_use]
+pub fn inspect_restart_state(container_name: &str) -> Option<RestartState> {
+    if is_dry_run() {
+        return None;
+    }
+    let inspect = match api::inspect_container(container_name) {
+        Some(inspect) => inspect?,
+        None => {
+            let payload = successful_stdout(docker_inspect(container_name)?)?;
+            let parsed: serde_json::Value = serde_json::from_str(&payload).ok()?;
+            parsed.as_array()?.first()?.clone()
+        }
+    };
+    restart_state_from_inspect(&inspect)
+}
+
+/// Returns the exit code recorded for a container, if it has one.
+#[must_use]
+pub fn inspect_exit_code(container_name: &str) -> Option<i32> {
+    if is_dry_run() {
+        return Some(0);
+    }
+    if let Some(inspect) = api::inspect_container(container_name) {
+        return inspect?
+            .pointer("/State/ExitCode")?
+            .as_i64()
+            .and_then(|code| i32::try_from(code).ok());
+    }
+
+    let stdout = successful_stdout(docker_inspect_format(
+        container_name,
+        "{{.State.ExitCode}}",
+    )?)?;
+    stdout.trim().parse().ok()
+}
+
 #[must_use]
 pub fn inspect_env(container_name: &str) -> Option<HashMap<String, String>> {
     if is_dry_run() {
         return Some(HashMap::new());
     }
+    if let Some(inspect) = api::inspect_container(container_name) {
+        let entries = inspect?.pointer("/Config/Env")?.as_array()?.clone();
+        return Some(
+            entries
+                .iter()
+                .filter_map(serde_json::Value::as_str)
+                .filter_map(|entry| entry.split_once('='))
+                .map(|(key, value)| (key.to_owned(), value.to_owned()))
+                .collect(),
+        );
+    }
 
     let stdout = successful_stdout(docker_inspect_format(
         container_name,
@@ -55,6 +148,10 @@ pub fn inspect_host_port_binding(
     if is_dry_run() {
         return None;
     }
+    if let Some(inspect) = api::inspect_container(container_name) {
+        let payload = serde_json::Value::Array(vec![inspect?]).to_string();
+        return extract_host_port_binding_from_inspect(&payload, container_port);
+    }
 
     let payload = successful_stdout(docker_inspect(container_name)?)?;
     extract_host_port_binding_from_inspect(&payload, container_port)
@@ -70,12 +167,22 @@ pub fn inspect_json(container_name: &str) -> Option<serde_json::Value> {
     if is_dry_run() {
         return Some(serde_json::json!({}));
     }
+    if let Some(inspect) = api::inspect_container(container_name) {
+        return inspect;
+    }
 
     let payload = successful_stdout(docker_inspect(container_name)?)?;
     let parsed: serde_json::Value = serde_json::from_str(&payload).ok()?;
     parsed.as_array()?.first().cloned()
 }
 
+fn string_field(inspect: &serde_json::Value, pointer: &str) -> Option<String> {
+    inspect
+        .pointer(pointer)?
+        .as_str()
+        .map(|value| value.trim().to_owned())
+}
+
 fn successful_stdout(output: Output) -> Option<String> {
     output
         .status
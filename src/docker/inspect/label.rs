@@ -11,6 +11,18 @@ pub(super) fn inspect_label(container_name: &str, key: &str) -> Option<String> {
     if is_dry_run() {
         return Some(String::new());
     }
+    if let Some(inspect) = crate::docker::api::inspect_container(container_name) {
+        // Missing labels render as an empty string, matching `index` in the
+        // CLI template.
+        let labels = inspect?.get("Config")?.get("Labels").cloned();
+        return Some(
+            labels
+                .as_ref()
+                .and_then(|labels| labels.get(key)?.as_str())
+                .unwrap_or_default()
+                .to_owned(),
+        );
+    }
 
     let template = format!("{{{{index .Config.Labels \"{key}\"}}}}");
     let output = docker_inspect_format(container_name, &template)?;
@@ -4,6 +4,8 @@
 
 use serde_json::Value;
 
+use super::RestartState;
+
 pub(super) fn extract_host_port_binding_from_inspect(
     payload: &str,
     container_port: u16,
@@ -21,3 +23,20 @@ pub(super) fn extract_host_port_binding_from_inspect(
     let port = binding.get("HostPort")?.as_str()?.parse::<u16>().ok()?;
     Some((host, port))
 }
+
+pub(super) fn restart_state_from_inspect(inspect: &Value) -> Option<RestartState> {
+    let restart_count = inspect.get("RestartCount")?.as_u64()?;
+    let state = inspect.get("State")?;
+    Some(RestartState {
+        restart_count: u32::try_from(restart_count).unwrap_or(u32::MAX),
+        exit_code: state
+            .get("ExitCode")
+            .and_then(Value::as_i64)
+            .and_then(|code| i32::try_from(code).ok())
+            .unwrap_or_default(),
+        restarting: state
+            .get("Restarting")
+            .and_then(Value::as_bool)
+            .unwrap_or_default(),
+    })
+}
@@ -2,7 +2,8 @@
 //!
 //! Contains docker inspect tests logic used by Helm command workflows.
 
-use super::parse::extract_host_port_binding_from_inspect;
+use super::RestartState;
+use super::parse::{extract_host_port_binding_from_inspect, restart_state_from_inspect};
 
 #[test]
 fn extracts_runtime_binding_from_docker_inspect_json() {
@@ -37,3 +38,30 @@ fn returns_none_when_binding_missing() {
     let binding = extract_host_port_binding_from_inspect(payload, 3306);
     assert_eq!(binding, None);
 }
+
+#[test]
+fn reads_restart_state_and_flags_crash_loops() {
+    let inspect = serde_json::json!({
+        "RestartCount": 4,
+        "State": { "Status": "restarting", "Restarting": true, "ExitCode": 1 }
+    });
+
+    let state = restart_state_from_inspect(&inspect);
+    assert_eq!(
+        state,
+        Some(RestartState {
+            restart_count: 4,
+            exit_code: 1,
+            restarting: true,
+        })
+    );
+    assert!(state.is_some_and(|state| state.is_crash_looping()));
+
+    let settled = RestartState {
+        restart_count: 4,
+        exit_code: 0,
+        restarting: false,
+    };
+    assert!(!settled.is_crash_looping());
+    assert_eq!(restart_state_from_inspect(&serde_json::json!({})), None);
+}
@@ -2,13 +2,23 @@
 //!
 //! Contains Helm Docker label keys and values used by Helm command workflows.
 
-use crate::config::Kind;
+use anyhow::Result;
+use std::path::Path;
+
+use crate::config::{Kind, ServiceConfig};
+
+use super::desired_state_hash;
 
 pub(crate) const LABEL_MANAGED: &str = "com.helm.managed";
 pub(crate) const LABEL_SERVICE: &str = "com.helm.service";
 pub(crate) const LABEL_KIND: &str = "com.helm.kind";
 pub(crate) const LABEL_CONTAINER: &str = "com.helm.container";
+pub(crate) const LABEL_CONFIG_HASH: &str = "com.helm.config-hash";
+pub(crate) const LABEL_PROJECT: &str = "com.helm.project";
+pub(crate) const LABEL_PROJECT_PATH: &str = "com.helm.project.path";
+pub(crate) const LABEL_RUNTIME_ENV: &str = "com.helm.runtime_env";
 pub(crate) const VALUE_MANAGED_TRUE: &str = "true";
+pub(crate) const VALUE_DEFAULT_RUNTIME_ENV: &str = "default";
 
 pub(crate) fn kind_label_value(kind: Kind) -> &'static str {
     match kind {
@@ -19,3 +29,90 @@ pub(crate) fn kind_label_value(kind: Kind) -> &'static str {
         Kind::App => "app",
     }
 }
+
+/// Returns the `com.helm.project` value identifying a workspace root.
+pub(crate) fn project_label_value(project_root: &Path) -> String {
+    format!(
+        "{:016x}",
+        super::drift::fnv1a64(project_root.to_string_lossy().as_bytes())
+    )
+}
+
+/// Appends the Helm ownership labels to `run` args.
+pub(crate) fn append_labels(
+    args: &mut Vec<String>,
+    service: &ServiceConfig,
+    container_name: &str,
+) -> Result<()> {
+    let mut push = |label: String| {
+        args.push("--label".to_owned());
+        args.push(label);
+    };
+
+    push(format!("{LABEL_MANAGED}={VALUE_MANAGED_TRUE}"));
+    push(format!("{LABEL_SERVICE}={}", service.name));
+    push(format!("{LABEL_KIND}={}", kind_label_value(service.kind)));
+    push(format!("{LABEL_CONTAINER}={container_name}"));
+    push(format!(
+        "{LABEL_CONFIG_HASH}={}",
+        desired_state_hash(service)?
+    ));
+    if let Some(root) = &service.resolved_project_root {
+        push(format!("{LABEL_PROJECT}={}", project_label_value(root)));
+        push(format!("{LABEL_PROJECT_PATH}={}", root.display()));
+    }
+    push(format!(
+        "{LABEL_RUNTIME_ENV}={}",
+        service
+            .resolved_runtime_env
+            .as_deref()
+            .unwrap_or(VALUE_DEFAULT_RUNTIME_ENV)
+    ));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{append_labels, project_label_value};
+    use crate::config::{Driver, Kind, ServiceConfig};
+    use std::path::PathBuf;
+
+    #[test]
+    fn appends_helm_labels_for_container_ownership() {
+        let service = ServiceConfig {
+            database: Some("app".to_owned()),
+            username: Some("root".to_owned()),
+            password: Some("secret".to_owned()),
+            resolved_container_name: Some("acme-db".to_owned()),
+            resolved_project_root: Some(PathBuf::from("/work/acme")),
+            ..ServiceConfig::fixture("db", Kind::Database, Driver::Mysql, "mysql:8.0")
+        };
+        let mut args = Vec::new();
+
+        append_labels(&mut args, &service, "acme-db").expect("labels");
+
+        let rendered = args.join(" ");
+        assert!(rendered.contains("--label com.helm.managed=true"));
+        assert!(rendered.contains("--label com.helm.service=db"));
+        assert!(rendered.contains("--label com.helm.kind=database"));
+        assert!(rendered.contains("--label com.helm.container=acme-db"));
+        assert!(rendered.contains(&format!(
+            "--label com.helm.config-hash={}",
+            crate::docker::desired_state_hash(&service).expect("hash")
+        )));
+        assert!(rendered.contains(&format!(
+            "--label com.helm.project={}",
+            project_label_value(&PathBuf::from("/work/acme"))
+        )));
+        assert!(rendered.contains("--label com.helm.project.path=/work/acme"));
+        assert!(rendered.contains("--label com.helm.runtime_env=default"));
+    }
+
+    #[test]
+    fn project_label_value_is_stable_per_path() {
+        let first = project_label_value(&PathBuf::from("/work/acme"));
+        assert_eq!(first, project_label_value(&PathBuf::from("/work/acme")));
+        assert_ne!(first, project_label_value(&PathBuf::from("/work/other")));
+        assert_eq!(first.len(), 16);
+    }
+}
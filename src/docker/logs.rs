@@ -3,9 +3,11 @@
 //! Contains docker logs logic used by Helm command workflows.
 
 use anyhow::Result;
+use std::io::Write;
 
 use crate::config::ServiceConfig;
 
+use super::api::{LineBuffer, OutputStream};
 use super::{is_dry_run, print_docker_command};
 use args::build_logs_args;
 use run::run_docker_status;
@@ -28,13 +30,22 @@ pub struct LogsOptions {
 
 pub fn logs(service: &ServiceConfig, options: LogsOptions) -> Result<()> {
     let container_name = service.container_name()?;
-    let args = build_logs_args(&container_name, options);
+    let args = build_logs_args(&container_name, options.clone());
 
     if is_dry_run() {
         print_docker_command(&args);
         return Ok(());
     }
 
+    if let Some(streamed) =
+        super::api::logs(&container_name, &options, |stream, bytes| match stream {
+            OutputStream::Stdout => std::io::stdout().write_all(bytes),
+            OutputStream::Stderr => std::io::stderr().write_all(bytes),
+        })
+    {
+        return streamed;
+    }
+
     let status = run_docker_status(&args, &super::runtime_command_error_context("logs"))?;
 
     if !status.success() {
@@ -46,13 +57,28 @@ pub fn logs(service: &ServiceConfig, options: LogsOptions) -> Result<()> {
 
 pub fn logs_prefixed(service: &ServiceConfig, options: LogsOptions) -> Result<()> {
     let container_name = service.container_name()?;
-    let args = build_logs_args(&container_name, options);
+    let args = build_logs_args(&container_name, options.clone());
 
     if is_dry_run() {
         print_docker_command(&args);
         return Ok(());
     }
 
+    let mut stdout = LineBuffer::default();
+    let mut stderr = LineBuffer::default();
+    let print = |line: &str| println!("[{}] {line}", service.name);
+    if let Some(streamed) = super::api::logs(&container_name, &options, |stream, bytes| {
+        match stream {
+            OutputStream::Stdout => stdout.push(bytes, print),
+            OutputStream::Stderr => stderr.push(bytes, print),
+        }
+        Ok(())
+    }) {
+        stdout.finish(print);
+        stderr.finish(print);
+        return streamed;
+    }
+
     stream_logs_with_prefix(&args, &service.name, &container_name)
 }
 
@@ -24,12 +24,17 @@ pub(super) fn stop(service: &ServiceConfig, timeout: u64) -> Result<()> {
         return Ok(());
     }
 
-    let output = docker_output(
-        &["stop", "--time", &timeout.to_string(), &container_name],
-        &crate::docker::runtime_command_error_context("stop"),
-    )?;
-
-    if output.status.success() {
+    let stopped = match crate::docker::api::stop_container(&container_name, timeout) {
+        Some(stopped) => stopped,
+        None => docker_output(
+            &["stop", "--time", &timeout.to_string(), &container_name],
+            &crate::docker::runtime_command_error_context("stop"),
+        )?
+        .status
+        .success(),
+    };
+
+    if stopped {
         output::event(
             &service.name,
             LogLevel::Success,
@@ -11,7 +11,18 @@ use crate::output::{self, LogLevel, Persistence};
 use super::super::{is_dry_run, print_docker_command};
 
 /// Pulls pull as part of the docker manage image ops workflow.
+///
+/// Services with `[service.build]` have no registry image and are skipped.
 pub(super) fn pull(service: &ServiceConfig) -> Result<()> {
+    if service.build.is_some() {
+        output::event(
+            &service.name,
+            LogLevel::Info,
+            "Skipping pull for locally built image",
+            Persistence::Persistent,
+        );
+        return Ok(());
+    }
     output::event(
         &service.name,
         LogLevel::Info,
@@ -0,0 +1,147 @@
+//! docker managed module.
+//!
+//! Lists and removes Helm-managed containers across every project on the host.
+
+use anyhow::Result;
+use serde::Serialize;
+use std::path::PathBuf;
+
+use super::{
+    LABEL_KIND, LABEL_MANAGED, LABEL_PROJECT, LABEL_PROJECT_PATH, LABEL_RUNTIME_ENV, LABEL_SERVICE,
+    VALUE_MANAGED_TRUE, ensure_docker_output_success, is_dry_run, print_docker_command,
+    run_docker_output_owned, runtime_command_error_context,
+};
+
+/// One Helm-managed container found on the host.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(crate) struct ManagedContainer {
+    pub(crate) name: String,
+    pub(crate) state: String,
+    pub(crate) service: Option<String>,
+    pub(crate) kind: Option<String>,
+    pub(crate) project: Option<String>,
+    pub(crate) project_path: Option<PathBuf>,
+    pub(crate) runtime_env: Option<String>,
+}
+
+/// Lists every container carrying `com.helm.managed=true`.
+pub(crate) fn list_managed_containers() -> Result<Vec<ManagedContainer>> {
+    let args = list_args();
+    if is_dry_run() {
+        print_docker_command(&args);
+        return Ok(Vec::new());
+    }
+
+    let output = run_docker_output_owned(&args, &runtime_command_error_context("ps"))?;
+    let output = ensure_docker_output_success(output, "Failed to list Helm containers")?;
+    Ok(parse_listing(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Force-removes a managed container, stopping it first within `timeout`.
+pub(crate) fn remove_managed_container(name: &str, timeout: u64) -> Result<()> {
+    let stop_args = vec![
+        "stop".to_owned(),
+        "--time".to_owned(),
+        timeout.to_string(),
+        name.to_owned(),
+    ];
+    let remove_args = vec!["rm".to_owned(), "-f".to_owned(), name.to_owned()];
+    if is_dry_run() {
+        print_docker_command(&stop_args);
+        print_docker_command(&remove_args);
+        return Ok(());
+    }
+
+    if super::api::stop_container(name, timeout).is_none() {
+        drop(run_docker_output_owned(
+            &stop_args,
+            &runtime_command_error_context("stop"),
+        ));
+    }
+    let output = run_docker_output_owned(&remove_args, &runtime_command_error_context("rm"))?;
+    ensure_docker_output_success(output, &format!("Failed to remove container {name}"))?;
+    Ok(())
+}
+
+fn list_args() -> Vec<String> {
+    let columns = [
+        "{{.Names}}".to_owned(),
+        "{{.State}}".to_owned(),
+        label_column(LABEL_SERVICE),
+        label_column(LABEL_KIND),
+        label_column(LABEL_PROJECT),
+        label_column(LABEL_PROJECT_PATH),
+        label_column(LABEL_RUNTIME_ENV),
+    ];
+    vec![
+        "ps".to_owned(),
+        "-a".to_owned(),
+        "--filter".to_owned(),
+        format!("label={LABEL_MANAGED}={VALUE_MANAGED_TRUE}"),
+        "--format".to_owned(),
+        columns.join("\t"),
+    ]
+}
+
+fn label_column(key: &str) -> String {
+    format!("{{{{.Label \"{key}\"}}}}")
+}
+
+fn parse_listing(stdout: &str) -> Vec<ManagedContainer> {
+    stdout
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split('\t').map(str::trim);
+            let name = fields.next().filter(|name| !name.is_empty())?.to_owned();
+            let state = fields.next().unwrap_or_default().to_owned();
+            let mut label = || {
+                fields
+                    .next()
+                    .filter(|value| !value.is_empty() && *value != "<no value>")
+                    .map(str::to_owned)
+            };
+            Some(ManagedContainer {
+                name,
+                state,
+                service: label(),
+                kind: label(),
+                project: label(),
+                project_path: label().map(PathBuf::from),
+                runtime_env: label(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{list_args, parse_listing};
+    use std::path::PathBuf;
+
+    #[test]
+    fn list_args_filter_managed_containers_and_read_labels() {
+        let rendered = list_args().join(" ");
+        assert!(rendered.starts_with("ps -a --filter label=com.helm.managed=true --format"));
+        assert!(rendered.contains("{{.Label \"com.helm.project.path\"}}"));
+        assert!(rendered.contains("{{.Label \"com.helm.runtime_env\"}}"));
+    }
+
+    #[test]
+    fn parse_listing_reads_labelled_and_legacy_rows() {
+        let containers = parse_listing(
+            "acme-db\trunning\tdb\tdatabase\tabc123\t/work/acme\tdefault\n\
+             old-cache\texited\tcache\tcache\t\t<no value>\t\n\n",
+        );
+
+        assert_eq!(containers.len(), 2);
+        let first = containers.first().expect("first row");
+        assert_eq!(first.name, "acme-db");
+        assert_eq!(first.project.as_deref(), Some("abc123"));
+        assert_eq!(first.project_path, Some(PathBuf::from("/work/acme")));
+        assert_eq!(first.runtime_env.as_deref(), Some("default"));
+        let legacy = containers.get(1).expect("second row");
+        assert_eq!(legacy.state, "exited");
+        assert_eq!(legacy.project, None);
+        assert_eq!(legacy.project_path, None);
+    }
+}
@@ -0,0 +1,326 @@
+//! docker network module.
+//!
+//! Creates the per-project user-defined network services join so they can
+//! reach each other by service name.
+
+use anyhow::Result;
+use std::collections::{BTreeSet, HashSet};
+use std::path::Path;
+use std::sync::{Mutex, OnceLock};
+
+use crate::config::ServiceConfig;
+
+use super::{
+    LABEL_MANAGED, LABEL_PROJECT, LABEL_PROJECT_PATH, LABEL_RUNTIME_ENV, VALUE_DEFAULT_RUNTIME_ENV,
+    VALUE_MANAGED_TRUE, ensure_docker_output_success, is_dry_run, print_docker_command,
+    project_label_value, run_docker_output_owned, runtime_command_error_context,
+};
+
+static ENSURED_NETWORKS: OnceLock<Mutex<HashSet<String>>> = OnceLock::new();
+
+/// Returns the project network a service joins.
+///
+/// Services resolved without a project root (ad-hoc configs, tests) keep the
+/// engine's default network.
+pub(crate) fn project_network_name(service: &ServiceConfig) -> Option<String> {
+    let root = service.resolved_project_root.as_deref()?;
+    let project_id = project_label_value(root);
+    let mut name = format!(
+        "helm-{}-{}",
+        project_slug(root),
+        project_id.get(..8).unwrap_or(&project_id)
+    );
+    if let Some(runtime_env) = &service.resolved_runtime_env {
+        name.push('-');
+        name.push_str(runtime_env);
+    }
+    Some(name)
+}
+
+/// Appends `--network` and the service-name alias to `run` args.
+pub(crate) fn append_network_args(args: &mut Vec<String>, service: &ServiceConfig) {
+    if let Some(network) = project_network_name(service) {
+        args.push("--network".to_owned());
+        args.push(network);
+        args.push("--network-alias".to_owned());
+        args.push(service.name.clone());
+    }
+}
+
+/// Creates the service's project network if it does not exist yet.
+///
+/// In `podman_pod` mode the project pod takes the network's place.
+pub(crate) fn ensure_service_network(service: &ServiceConfig) -> Result<()> {
+    if super::pod_mode() {
+        return super::ensure_service_pod(service);
+    }
+    let Some(network) = project_network_name(service) else {
+        return Ok(());
+    };
+    let mut ensured = ENSURED_NETWORKS
+        .get_or_init(|| Mutex::new(HashSet::new()))
+        .lock()
+        .unwrap_or_else(|err| err.into_inner());
+    if ensured.contains(&network) {
+        return Ok(());
+    }
+
+    let inspect_args = vec!["network".to_owned(), "inspect".to_owned(), network.clone()];
+    let create_args = create_args(&network, service);
+    if is_dry_run() {
+        print_docker_command(&inspect_args);
+        print_docker_command(&create_args);
+        return Ok(());
+    }
+
+    let inspect = run_docker_output_owned(
+        &inspect_args,
+        &runtime_command_error_context("network inspect"),
+    )?;
+    if !inspect.status.success() {
+        let output = run_docker_output_owned(
+            &create_args,
+            &runtime_command_error_context("network create"),
+        )?;
+        // Another Helm process may have created it between inspect and create.
+        if !String::from_utf8_lossy(&output.stderr).contains("already exists") {
+            ensure_docker_output_success(output, &format!("Failed to create network {network}"))?;
+        }
+    }
+    ensured.insert(network);
+    Ok(())
+}
+
+/// Removes the project networks of `services` once no container, running or
+/// stopped, is attached to them anymore.
+///
+/// `down` calls this after removing its containers, so the last member to go
+/// takes the network with it while partial downs leave it in place.
+pub(crate) fn remove_unused_service_networks(services: &[&ServiceConfig]) -> Result<()> {
+    if super::pod_mode() {
+        return Ok(());
+    }
+    let networks = services
+        .iter()
+        .filter_map(|service| project_network_name(service))
+        .collect::<BTreeSet<_>>();
+    for network in networks {
+        remove_network_if_unused(&network)?;
+    }
+    Ok(())
+}
+
+fn remove_network_if_unused(network: &str) -> Result<()> {
+    let list_args = member_list_args(network);
+    let remove_args = vec!["network".to_owned(), "rm".to_owned(), network.to_owned()];
+    if is_dry_run() {
+        print_docker_command(&list_args);
+        print_docker_command(&remove_args);
+        return Ok(());
+    }
+
+    let members = run_docker_output_owned(&list_args, &runtime_command_error_context("ps"))?;
+    // A failed listing means the engine cannot filter by network; keep it.
+    if !members.status.success() || !String::from_utf8_lossy(&members.stdout).trim().is_empty() {
+        return Ok(());
+    }
+    forget_network(network);
+    drop(run_docker_output_owned(
+        &remove_args,
+        &runtime_command_error_context("network rm"),
+    ));
+    Ok(())
+}
+
+fn member_list_args(network: &str) -> Vec<String> {
+    vec![
+        "ps".to_owned(),
+        "--all".to_owned(),
+        "--filter".to_owned(),
+        format!("network={network}"),
+        "--format".to_owned(),
+        "{{.Names}}".to_owned(),
+    ]
+}
+
+/// Drops a removed network from the per-process cache so the next `up`
+/// creates it again.
+fn forget_network(network: &str) {
+    ENSURED_NETWORKS
+        .get_or_init(|| Mutex::new(HashSet::new()))
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+        .remove(network);
+}
+
+/// Removes every Helm network created for a project.
+///
+/// Networks still in use by containers outside Helm are left in place.
+pub(crate) fn remove_project_networks(project_id: &str) -> Result<()> {
+    let list_args = vec![
+        "network".to_owned(),
+        "ls".to_owned(),
+        "--filter".to_owned(),
+        format!("label={LABEL_PROJECT}={project_id}"),
+        "--format".to_owned(),
+        "{{.Name}}".to_owned(),
+    ];
+    if is_dry_run() {
+        print_docker_command(&list_args);
+        return Ok(());
+    }
+
+    let output = run_docker_output_owned(&list_args, &runtime_command_error_context("network ls"))?;
+    let output = ensure_docker_output_success(output, "Failed to list Helm networks")?;
+    for network in String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+    {
+        forget_network(network);
+        drop(run_docker_output_owned(
+            &["network".to_owned(), "rm".to_owned(), network.to_owned()],
+            &runtime_command_error_context("network rm"),
+        ));
+    }
+    Ok(())
+}
+
+fn create_args(network: &str, service: &ServiceConfig) -> Vec<String> {
+    let mut args = vec![
+        "network".to_owned(),
+        "create".to_owned(),
+        "--label".to_owned(),
+        format!("{LABEL_MANAGED}={VALUE_MANAGED_TRUE}"),
+    ];
+    if let Some(root) = &service.resolved_project_root {
+        args.push("--label".to_owned());
+        args.push(format!("{LABEL_PROJECT}={}", project_label_value(root)));
+        args.push("--label".to_owned());
+        args.push(format!("{LABEL_PROJECT_PATH}={}", root.display()));
+    }
+    args.push("--label".to_owned());
+    args.push(format!(
+        "{LABEL_RUNTIME_ENV}={}",
+        service
+            .resolved_runtime_env
+            .as_deref()
+            .unwrap_or(VALUE_DEFAULT_RUNTIME_ENV)
+    ));
+    args.push(network.to_owned());
+    args
+}
+
+fn project_slug(root: &Path) -> String {
+    let slug = root
+        .file_name()
+        .map(|name| name.to_string_lossy().to_ascii_lowercase())
+        .unwrap_or_default()
+        .chars()
+        .map(|ch| if ch.is_ascii_alphanumeric() { ch } else { '-' })
+        .collect::<String>();
+    let slug = slug.trim_matches('-');
+    if slug.is_empty() {
+        "project".to_owned()
+    } else {
+        slug.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        ENSURED_NETWORKS, append_network_args, create_args, forget_network, member_list_args,
+        project_network_name, project_slug,
+    };
+    use crate::config::{Driver, Kind, ServiceConfig};
+    use std::collections::HashSet;
+    use std::path::{Path, PathBuf};
+    use std::sync::Mutex;
+
+    fn service() -> ServiceConfig {
+        ServiceConfig {
+            resolved_project_root: Some(PathBuf::from("/work/Acme Shop")),
+            ..ServiceConfig::fixture("db", Kind::Database, Driver::Mysql, "mysql:8.4")
+        }
+    }
+
+    #[test]
+    fn network_name_is_scoped_by_project_and_runtime_env() {
+        let default_env = project_network_name(&service()).unwrap_or_default();
+        assert!(default_env.starts_with("helm-acme-shop-"));
+        assert_eq!(default_env.len(), "helm-acme-shop-".len() + 8);
+
+        let mut testing = service();
+        testing.resolved_runtime_env = Some("testing".to_owned());
+        assert_eq!(
+            project_network_name(&testing),
+            Some(format!("{default_env}-testing"))
+        );
+
+        let mut other = service();
+        other.resolved_project_root = Some(PathBuf::from("/other/Acme Shop"));
+        assert_ne!(project_network_name(&other), Some(default_env));
+    }
+
+    #[test]
+    fn services_without_project_root_keep_default_network() {
+        let mut detached = service();
+        detached.resolved_project_root = None;
+        let mut args = Vec::new();
+        append_network_args(&mut args, &detached);
+
+        assert_eq!(project_network_name(&detached), None);
+        assert!(args.is_empty());
+    }
+
+    #[test]
+    fn network_args_alias_the_service_name() {
+        let mut args = Vec::new();
+        append_network_args(&mut args, &service());
+        let rendered = args.join(" ");
+
+        assert!(rendered.starts_with("--network helm-acme-shop-"));
+        assert!(rendered.ends_with("--network-alias db"));
+    }
+
+    #[test]
+    fn create_args_label_network_with_project() {
+        let rendered = create_args("helm-acme", &service()).join(" ");
+
+        assert!(rendered.starts_with("network create --label com.helm.managed=true"));
+        assert!(rendered.contains("--label com.helm.project.path=/work/Acme Shop"));
+        assert!(rendered.ends_with("--label com.helm.runtime_env=default helm-acme"));
+    }
+
+    #[test]
+    fn removed_networks_are_recreated_on_the_next_up() {
+        let ensured = || {
+            ENSURED_NETWORKS
+                .get_or_init(|| Mutex::new(HashSet::new()))
+                .lock()
+                .map(|networks| networks.contains("helm-forgotten"))
+                .unwrap_or_default()
+        };
+        if let Ok(mut networks) = ENSURED_NETWORKS
+            .get_or_init(|| Mutex::new(HashSet::new()))
+            .lock()
+        {
+            networks.insert("helm-forgotten".to_owned());
+        }
+        assert!(ensured());
+        forget_network("helm-forgotten");
+        assert!(!ensured());
+
+        assert_eq!(
+            member_list_args("helm-acme").join(" "),
+            "ps --all --filter network=helm-acme --format {{.Names}}"
+        );
+    }
+
+    #[test]
+    fn project_slug_falls_back_for_unnamed_roots() {
+        assert_eq!(project_slug(Path::new("/")), "project");
+        assert_eq!(project_slug(Path::new("/work/my_app.v2")), "my-app-v2");
+    }
+}
@@ -12,6 +12,13 @@ pub(super) fn events(
     format: Option<&str>,
     filters: &[String],
 ) -> Result<()> {
+    if !crate::docker::is_dry_run()
+        && let Some(streamed) =
+            crate::docker::api::events(since, until, format, filters, &mut std::io::stdout())
+    {
+        return streamed;
+    }
+
     let mut args = vec!["events".to_owned()];
     push_option(&mut args, "--since", since);
     push_option(&mut args, "--until", until);
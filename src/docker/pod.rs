@@ -0,0 +1,466 @@
+//! docker pod module.
+//!
+//! Groups project services into one Podman pod per runtime env when
+//! `podman_pod = true`. Ports are published on the pod and services reach
+//! each other over localhost.
+
+use anyhow::{Result, bail};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::sync::{Mutex, OnceLock};
+
+use crate::config::{Driver, ServiceConfig};
+
+use super::{
+    LABEL_MANAGED, LABEL_PROJECT, LABEL_PROJECT_PATH, LABEL_RUNTIME_ENV, VALUE_DEFAULT_RUNTIME_ENV,
+    VALUE_MANAGED_TRUE, ensure_docker_output_success, is_dry_run, pod_mode, print_docker_command,
+    project_label_value, project_network_name, run_docker_output_owned,
+    runtime_command_error_context,
+};
+
+/// Pod label listing the port specs the pod was created with.
+const LABEL_POD_PORTS: &str = "com.helm.pod.ports";
+
+/// Run flags the pod owns; members may not set them themselves.
+const POD_OWNED_FLAGS: [&str; 4] = ["-p", "--network", "--network-alias", "--add-host"];
+
+/// Port specs of every configured service, keyed by pod name.
+static POD_MEMBERS: OnceLock<Mutex<HashMap<String, Vec<PodMember>>>> = OnceLock::new();
+static ENSURED_PODS: OnceLock<Mutex<HashSet<String>>> = OnceLock::new();
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PodMember {
+    service: String,
+    container_ports: Vec<u16>,
+    publish: Vec<String>,
+}
+
+impl PodMember {
+    fn from_service(service: &ServiceConfig) -> Self {
+        Self::publishing_on(service, &super::publish_host(&service.host))
+    }
+
+    /// Builds the member with every port published on `host`.
+    fn publishing_on(service: &ServiceConfig, host: &str) -> Self {
+        let container_port = service.resolved_container_port();
+        let mut member = Self {
+            service: service.name.clone(),
+            container_ports: vec![container_port],
+            publish: vec![format!("{host}:{}:{container_port}", service.port)],
+        };
+        // Mirrors the SMTP mapping `helm serve` adds for Mailhog.
+        if service.driver == Driver::Mailhog {
+            let smtp_port = service
+                .smtp_port
+                .unwrap_or_else(|| service.port.saturating_add(1000));
+            member.container_ports.push(1025);
+            member.publish.push(format!("{host}:{smtp_port}:1025"));
+        }
+        member
+    }
+}
+
+/// Returns the pod a service joins, or `None` outside pod mode.
+fn service_pod_name(service: &ServiceConfig) -> Option<String> {
+    if !pod_mode() {
+        return None;
+    }
+    project_network_name(service)
+}
+
+/// Records the services of the loaded config so a pod is created with every
+/// member's ports; ports cannot be added to a pod after creation.
+pub(crate) fn register_pod_services(services: &[ServiceConfig]) {
+    if !pod_mode() {
+        return;
+    }
+    let mut members = POD_MEMBERS
+        .get_or_init(|| Mutex::new(HashMap::new()))
+        .lock()
+        .unwrap_or_else(|err| err.into_inner());
+    for service in services {
+        if let Some(pod) = project_network_name(service) {
+            let pod_members = members.entry(pod).or_default();
+            pod_members.retain(|member| member.service != service.name);
+            pod_members.push(PodMember::from_service(service));
+        }
+    }
+}
+
+/// Creates the service's project pod if it does not exist yet.
+///
+/// # Errors
+///
+/// Returns an error if two members listen on the same container port, if an
+/// existing pod does not publish this service's ports, or if creation fails.
+pub(crate) fn ensure_service_pod(service: &ServiceConfig) -> Result<()> {
+    let Some(pod) = service_pod_name(service) else {
+        return Ok(());
+    };
+    let mut ensured = ENSURED_PODS
+        .get_or_init(|| Mutex::new(HashSet::new()))
+        .lock()
+        .unwrap_or_else(|err| err.into_inner());
+    if ensured.contains(&pod) {
+        return Ok(());
+    }
+
+    let members = pod_members(&pod, service);
+    check_container_ports(&pod, &members)?;
+    let publish: Vec<String> = members
+        .iter()
+        .flat_map(|member| member.publish.iter().cloned())
+        .collect();
+    let inspect_args = vec![
+        "pod".to_owned(),
+        "inspect".to_owned(),
+        "--format".to_owned(),
+        format!("{{{{index .Labels \"{LABEL_POD_PORTS}\"}}}}"),
+        pod.clone(),
+    ];
+    let create_args = create_args(&pod, service, &publish);
+    if is_dry_run() {
+        print_docker_command(&inspect_args);
+        print_docker_command(&create_args);
+        return Ok(());
+    }
+
+    let inspect =
+        run_docker_output_owned(&inspect_args, &runtime_command_error_context("pod inspect"))?;
+    if inspect.status.success() {
+        let published = String::from_utf8_lossy(&inspect.stdout);
+        let published: HashSet<&str> = published.trim().split(',').collect();
+        let required = PodMember::from_service(service).publish;
+        let missing: Vec<&str> = required
+            .iter()
+            .map(String::as_str)
+            .filter(|spec| !published.contains(spec))
+            .collect();
+        if !missing.is_empty() {
+            bail!(
+                "pod {pod} does not publish {} for service '{}'; run `helm down` to recreate the pod",
+                missing.join(", "),
+                service.name
+            );
+        }
+    } else {
+        let output =
+            run_docker_output_owned(&create_args, &runtime_command_error_context("pod create"))?;
+        // Another Helm process may have created it between inspect and create.
+        if !String::from_utf8_lossy(&output.stderr).contains("already exists") {
+            ensure_docker_output_success(output, &format!("Failed to create pod {pod}"))?;
+        }
+    }
+    ensured.insert(pod);
+    Ok(())
+}
+
+/// Moves a service's `run` args into its pod.
+///
+/// Port publishing, network membership and host entries belong to the pod,
+/// so they are dropped from the container and replaced by `--pod`. Args are
+/// returned unchanged outside pod mode.
+pub(crate) fn apply_pod_args(args: Vec<String>, service: &ServiceConfig) -> Vec<String> {
+    let Some(pod) = service_pod_name(service) else {
+        return args;
+    };
+    let mut rewritten = Vec::with_capacity(args.len());
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if arg == "run" || arg == "-d" {
+            rewritten.push(arg);
+            continue;
+        }
+        if !arg.starts_with('-') {
+            rewritten.push("--pod".to_owned());
+            rewritten.push(pod);
+            rewritten.push(arg);
+            rewritten.extend(args);
+            break;
+        }
+        let value = args.next();
+        if POD_OWNED_FLAGS.contains(&arg.as_str()) {
+            continue;
+        }
+        rewritten.push(arg);
+        rewritten.extend(value);
+    }
+    rewritten
+}
+
+/// Returns the service's pod name and state, or `None` outside pod mode.
+#[must_use]
+pub(crate) fn pod_status(service: &ServiceConfig) -> Option<(String, String)> {
+    let pod = service_pod_name(service)?;
+    if is_dry_run() {
+        return Some((pod, "dry-run".to_owned()));
+    }
+    let state = run_docker_output_owned(
+        &[
+            "pod".to_owned(),
+            "inspect".to_owned(),
+            "--format".to_owned(),
+            "{{.State}}".to_owned(),
+            pod.clone(),
+        ],
+        &runtime_command_error_context("pod inspect"),
+    )
+    .ok()
+    .filter(|output| output.status.success())
+    .map(|output| {
+        String::from_utf8_lossy(&output.stdout)
+            .trim()
+            .to_ascii_lowercase()
+    })
+    .unwrap_or_else(|| "not created".to_owned());
+    Some((pod, state))
+}
+
+/// Removes the pods of the given services along with any remaining members.
+///
+/// # Errors
+///
+/// Returns an error if the runtime command cannot be executed or fails.
+pub(crate) fn remove_service_pods(services: &[&ServiceConfig]) -> Result<()> {
+    let pods: Vec<String> = services
+        .iter()
+        .filter_map(|service| service_pod_name(service))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    for pod in pods {
+        remove_pod(&pod)?;
+    }
+    Ok(())
+}
+
+/// Removes every Helm pod created for a project, in every runtime env.
+///
+/// # Errors
+///
+/// Returns an error if the pods cannot be listed.
+pub(crate) fn remove_project_pods(project_id: &str) -> Result<()> {
+    if !super::active_engine_adapter().supports_pods() {
+        return Ok(());
+    }
+    let list_args = vec![
+        "pod".to_owned(),
+        "ls".to_owned(),
+        "--filter".to_owned(),
+        format!("label={LABEL_PROJECT}={project_id}"),
+        "--format".to_owned(),
+        "{{.Name}}".to_owned(),
+    ];
+    if is_dry_run() {
+        print_docker_command(&list_args);
+        return Ok(());
+    }
+
+    let output = run_docker_output_owned(&list_args, &runtime_command_error_context("pod ls"))?;
+    let output = ensure_docker_output_success(output, "Failed to list Helm pods")?;
+    for pod in String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+    {
+        remove_pod(pod)?;
+    }
+    Ok(())
+}
+
+fn remove_pod(pod: &str) -> Result<()> {
+    let args = vec![
+        "pod".to_owned(),
+        "rm".to_owned(),
+        "--force".to_owned(),
+        "--ignore".to_owned(),
+        pod.to_owned(),
+    ];
+    ENSURED_PODS
+        .get_or_init(|| Mutex::new(HashSet::new()))
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+        .remove(pod);
+    if is_dry_run() {
+        print_docker_command(&args);
+        return Ok(());
+    }
+    let output = run_docker_output_owned(&args, &runtime_command_error_context("pod rm"))?;
+    ensure_docker_output_success(output, &format!("Failed to remove pod {pod}"))?;
+    Ok(())
+}
+
+/// Registered members of a pod, plus `service` when it was not registered.
+fn pod_members(pod: &str, service: &ServiceConfig) -> Vec<PodMember> {
+    let mut members = POD_MEMBERS
+        .get_or_init(|| Mutex::new(HashMap::new()))
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+        .get(pod)
+        .cloned()
+        .unwrap_or_default();
+    if !members.iter().any(|member| member.service == service.name) {
+        members.push(PodMember::from_service(service));
+    }
+    members
+}
+
+/// Members share one network namespace, so each container port has one owner.
+fn check_container_ports(pod: &str, members: &[PodMember]) -> Result<()> {
+    let mut owners: BTreeMap<u16, &str> = BTreeMap::new();
+    for member in members {
+        for port in &member.container_ports {
+            if let Some(owner) = owners.insert(*port, &member.service) {
+                if owner != member.service {
+                    bail!(
+                        "services '{owner}' and '{}' both listen on port {port} in pod {pod}; \
+                         set a different container_port or disable podman_pod",
+                        member.service
+                    );
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn create_args(pod: &str, service: &ServiceConfig, publish: &[String]) -> Vec<String> {
+    let mut args = vec![
+        "pod".to_owned(),
+        "create".to_owned(),
+        "--name".to_owned(),
+        pod.to_owned(),
+        "--label".to_owned(),
+        format!("{LABEL_MANAGED}={VALUE_MANAGED_TRUE}"),
+    ];
+    if let Some(root) = &service.resolved_project_root {
+        args.push("--label".to_owned());
+        args.push(format!("{LABEL_PROJECT}={}", project_label_value(root)));
+        args.push("--label".to_owned());
+        args.push(format!("{LABEL_PROJECT_PATH}={}", root.display()));
+    }
+    args.push("--label".to_owned());
+    args.push(format!(
+        "{LABEL_RUNTIME_ENV}={}",
+        service
+            .resolved_runtime_env
+            .as_deref()
+            .unwrap_or(VALUE_DEFAULT_RUNTIME_ENV)
+    ));
+    args.push("--label".to_owned());
+    args.push(format!("{LABEL_POD_PORTS}={}", publish.join(",")));
+    for spec in publish {
+        args.push("-p".to_owned());
+        args.push(spec.clone());
+    }
+    args
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PodMember, apply_pod_args, check_container_ports, create_args};
+    use crate::config::{Driver, Kind, ServiceConfig};
+    use std::path::PathBuf;
+
+    fn service(name: &str, driver: Driver, port: u16) -> ServiceConfig {
+        ServiceConfig {
+            port,
+            resolved_project_root: Some(PathBuf::from("/work/acme")),
+            ..ServiceConfig::fixture(name, Kind::Database, driver, "mysql:8.4")
+        }
+    }
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| (*value).to_owned()).collect()
+    }
+
+    #[test]
+    fn pod_args_replace_ports_and_network_before_the_image() {
+        let run_args = args(&[
+            "run",
+            "-d",
+            "--name",
+            "acme-db",
+            "-p",
+            "127.0.0.1:3306:3306",
+            "--memory",
+            "1g",
+            "--network",
+            "helm-acme",
+            "--network-alias",
+            "db",
+            "--add-host",
+            "host.docker.internal:host-gateway",
+            "mysql:8.4",
+            "mysqld",
+            "-p",
+            "3306",
+        ]);
+
+        crate::docker::with_podman_pod(|| {
+            let rewritten = apply_pod_args(run_args.clone(), &service("db", Driver::Mysql, 3306));
+            let rendered = rewritten.join(" ");
+
+            assert!(rendered.starts_with("run -d --name acme-db --memory 1g --pod helm-acme-"));
+            assert!(rendered.ends_with(" mysql:8.4 mysqld -p 3306"));
+            assert!(!rendered.contains("--network"));
+            assert!(!rendered.contains("--add-host"));
+        });
+        crate::docker::with_container_engine(crate::config::ContainerEngine::Podman, || {
+            let unchanged = apply_pod_args(run_args.clone(), &service("db", Driver::Mysql, 3306));
+            assert_eq!(unchanged, run_args);
+        });
+    }
+
+    #[test]
+    fn pod_mode_needs_an_engine_with_pods() {
+        crate::docker::with_podman_pod(|| {
+            crate::docker::set_container_engine(crate::config::ContainerEngine::Docker);
+            let run_args = args(&["run", "-d", "-p", "127.0.0.1:3306:3306", "mysql:8.4"]);
+            let service = service("db", Driver::Mysql, 3306);
+            let unchanged = apply_pod_args(run_args.clone(), &service);
+            crate::docker::set_container_engine(crate::config::ContainerEngine::Podman);
+
+            assert_eq!(unchanged, run_args);
+        });
+    }
+
+    #[test]
+    fn create_args_publish_member_ports_on_the_pod() {
+        let mailhog = service("mail", Driver::Mailhog, 8025);
+        let member = PodMember::from_service(&mailhog);
+        assert_eq!(
+            member.publish,
+            ["127.0.0.1:8025:8025", "127.0.0.1:9025:1025"]
+        );
+
+        let rendered = create_args("helm-acme", &mailhog, &member.publish).join(" ");
+        assert!(rendered.starts_with("pod create --name helm-acme --label com.helm.managed=true"));
+        assert!(
+            rendered.contains("--label com.helm.pod.ports=127.0.0.1:8025:8025,127.0.0.1:9025:1025")
+        );
+        assert!(rendered.ends_with("-p 127.0.0.1:8025:8025 -p 127.0.0.1:9025:1025"));
+    }
+
+    #[test]
+    fn every_member_port_uses_the_publish_host() {
+        let mailhog = service("mail", Driver::Mailhog, 8025);
+        let member = PodMember::publishing_on(&mailhog, "0.0.0.0");
+        assert_eq!(member.publish, ["0.0.0.0:8025:8025", "0.0.0.0:9025:1025"]);
+    }
+
+    #[test]
+    fn members_sharing_a_container_port_are_rejected() {
+        let primary = PodMember::from_service(&service("db", Driver::Mysql, 3306));
+        let replica = PodMember::from_service(&service("replica", Driver::Mysql, 3307));
+        let cache = PodMember::from_service(&service("cache", Driver::Redis, 6379));
+
+        assert!(check_container_ports("helm-acme", &[primary.clone(), cache]).is_ok());
+        let error = check_container_ports("helm-acme", &[primary, replica])
+            .expect_err("duplicate container port");
+        assert!(
+            error
+                .to_string()
+                .contains("'db' and 'replica' both listen on port 3306")
+        );
+    }
+}
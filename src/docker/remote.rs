@@ -0,0 +1,325 @@
+//! docker remote module.
+//!
+//! Resolves where the engine runs when it is targeted through `engine_host`,
+//! `engine_context`, or the CLI's own `DOCKER_HOST`/context settings, so
+//! ports, probes and URLs use the engine host instead of loopback.
+
+use anyhow::{Result, bail};
+use std::path::PathBuf;
+use std::process::Command;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, OnceLock};
+
+use super::{active_engine_adapter, run_docker_output_owned, runtime_command_error_context};
+use crate::output::{self, LogLevel, Persistence};
+
+/// Explicitly configured engine endpoint.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub(crate) enum EngineEndpoint {
+    /// Endpoint URL such as `ssh://user@box` or `tcp://10.0.0.5:2376`.
+    Host(String),
+    /// Named Docker context or Podman connection.
+    Context(String),
+}
+
+/// Endpoint the engine CLI ends up talking to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ResolvedEndpoint {
+    /// Nothing selects an endpoint, so the CLI uses its built-in default.
+    Default,
+    /// An endpoint is selected and its URL is known.
+    Url(String),
+    /// A context is selected but its URL could not be read.
+    Unknown,
+}
+
+#[derive(Default)]
+struct RemoteState {
+    endpoint: Option<EngineEndpoint>,
+    /// Cached resolution of `endpoint` and the CLI's ambient settings.
+    resolved: Option<ResolvedEndpoint>,
+}
+
+static REMOTE_STATE: OnceLock<Mutex<RemoteState>> = OnceLock::new();
+
+fn remote_state() -> std::sync::MutexGuard<'static, RemoteState> {
+    REMOTE_STATE
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+}
+
+/// Sets the engine endpoint every runtime command targets.
+///
+/// # Errors
+///
+/// Returns an error when the active engine cannot target other endpoints.
+pub(crate) fn set_engine_endpoint(endpoint: Option<EngineEndpoint>) -> Result<()> {
+    if endpoint.is_some() && active_engine_adapter().host_env_var().is_none() {
+        bail!(
+            "{} cannot target a remote engine; remove engine_host/engine_context",
+            active_engine_adapter().command_binary()
+        );
+    }
+    let mut state = remote_state();
+    state.endpoint = endpoint;
+    state.resolved = None;
+    Ok(())
+}
+
+/// Points a runtime CLI command at the configured endpoint.
+pub(crate) fn apply_engine_endpoint(command: &mut Command) {
+    let adapter = active_engine_adapter();
+    match &remote_state().endpoint {
+        Some(EngineEndpoint::Host(url)) => {
+            if let Some(variable) = adapter.host_env_var() {
+                command.env(variable, url);
+            }
+        }
+        Some(EngineEndpoint::Context(name)) => {
+            if let Some(variable) = adapter.context_env_var() {
+                command.env(variable, name);
+            }
+        }
+        None => {}
+    }
+}
+
+/// Returns the unix socket of the endpoint the CLI talks to.
+///
+/// `None` means nothing selects an endpoint, so the engine's default socket
+/// applies; `Some(None)` means the endpoint is only reachable through the
+/// CLI, such as a `tcp://` or `ssh://` host or an unreadable context.
+pub(crate) fn endpoint_api_socket() -> Option<Option<PathBuf>> {
+    api_socket_for(resolved_endpoint())
+}
+
+fn api_socket_for(resolved: ResolvedEndpoint) -> Option<Option<PathBuf>> {
+    match resolved {
+        ResolvedEndpoint::Default => None,
+        ResolvedEndpoint::Url(url) => Some(url.strip_prefix("unix://").map(PathBuf::from)),
+        ResolvedEndpoint::Unknown => Some(None),
+    }
+}
+
+/// Returns the address of a remote engine host, or `None` for a local engine.
+#[must_use]
+pub(crate) fn remote_engine_address() -> Option<String> {
+    match resolved_endpoint() {
+        ResolvedEndpoint::Url(url) => endpoint_address(&url),
+        ResolvedEndpoint::Default | ResolvedEndpoint::Unknown => None,
+    }
+}
+
+fn resolved_endpoint() -> ResolvedEndpoint {
+    if let Some(resolved) = &remote_state().resolved {
+        return resolved.clone();
+    }
+    let resolved = resolve_endpoint();
+    remote_state().resolved = Some(resolved.clone());
+    resolved
+}
+
+/// Returns the host clients outside the engine use to reach a published port.
+#[must_use]
+pub(crate) fn reachable_host(host: &str) -> String {
+    match remote_engine_address() {
+        Some(address) if is_local_host(host) => address,
+        _ => host.to_owned(),
+    }
+}
+
+/// Returns the interface a port is published on.
+///
+/// A remote engine's loopback is unreachable from this machine, so loopback
+/// binds are widened to every interface of the engine host. That exposes the
+/// port to the engine host's network, so the first widening warns.
+#[must_use]
+pub(crate) fn publish_host(host: &str) -> String {
+    if !is_local_host(host) {
+        return host.to_owned();
+    }
+    let Some(address) = remote_engine_address() else {
+        return host.to_owned();
+    };
+    if is_loopback_host(host) {
+        warn_widened_publish(&address);
+    }
+    "0.0.0.0".to_owned()
+}
+
+/// Returns whether a port bound to `host` is widened to every interface of a
+/// remote engine host.
+#[must_use]
+pub(crate) fn widens_loopback_publish(host: &str) -> bool {
+    is_loopback_host(host) && remote_engine_address().is_some()
+}
+
+fn warn_widened_publish(address: &str) {
+    static WARNED: AtomicBool = AtomicBool::new(false);
+    if WARNED.swap(true, Ordering::Relaxed) {
+        return;
+    }
+    output::event(
+        super::runtime_log_source_key(),
+        LogLevel::Warn,
+        &format!(
+            "Loopback ports are published on 0.0.0.0 of remote engine host {address}, so anyone \
+             who can reach {address} can connect to them; set `host` to an interface of \
+             {address} to limit exposure"
+        ),
+        Persistence::Persistent,
+    );
+}
+
+/// Resolves the endpoint from explicit settings, then the CLI's env and
+/// current context.
+fn resolve_endpoint() -> ResolvedEndpoint {
+    let endpoint = remote_state().endpoint.clone();
+    match endpoint {
+        Some(EngineEndpoint::Host(url)) => ResolvedEndpoint::Url(url),
+        Some(EngineEndpoint::Context(name)) => context_endpoint(&name),
+        None => ambient_endpoint(),
+    }
+}
+
+/// Reads the endpoint the engine CLI would pick up on its own.
+#[cfg(not(test))]
+fn ambient_endpoint() -> ResolvedEndpoint {
+    let adapter = active_engine_adapter();
+    let env_value = |variable: Option<&str>| {
+        std::env::var(variable?)
+            .ok()
+            .filter(|value| !value.is_empty())
+    };
+    if let Some(url) = env_value(adapter.host_env_var()) {
+        return ResolvedEndpoint::Url(url);
+    }
+    match env_value(adapter.context_env_var()).or_else(|| adapter.current_context()) {
+        Some(context) => context_endpoint(&context),
+        None => ResolvedEndpoint::Default,
+    }
+}
+
+/// Tests never pick up the host's engine env or contexts.
+#[cfg(test)]
+fn ambient_endpoint() -> ResolvedEndpoint {
+    ResolvedEndpoint::Default
+}
+
+fn context_endpoint(name: &str) -> ResolvedEndpoint {
+    context_endpoint_url(name).map_or(ResolvedEndpoint::Unknown, ResolvedEndpoint::Url)
+}
+
+fn context_endpoint_url(name: &str) -> Option<String> {
+    let args = active_engine_adapter().context_endpoint_args(name)?;
+    let output = run_docker_output_owned(&args, &runtime_command_error_context("context inspect"))
+        .ok()
+        .filter(|output| output.status.success())?;
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .map(str::to_owned)
+}
+
+/// Extracts the host of a `tcp://` or `ssh://` endpoint; local transports
+/// and loopback hosts yield `None`.
+fn endpoint_address(url: &str) -> Option<String> {
+    let (scheme, rest) = url.split_once("://")?;
+    if !matches!(scheme, "tcp" | "ssh" | "http" | "https") {
+        return None;
+    }
+    let authority = rest.split('/').next().unwrap_or_default();
+    let host_port = authority
+        .rsplit_once('@')
+        .map_or(authority, |(_, host)| host);
+    let host = if let Some(bracketed) = host_port.strip_prefix('[') {
+        bracketed.split(']').next().unwrap_or_default()
+    } else {
+        host_port.split(':').next().unwrap_or_default()
+    };
+    if host.is_empty() || is_local_host(host) {
+        return None;
+    }
+    Some(host.to_owned())
+}
+
+fn is_local_host(host: &str) -> bool {
+    is_loopback_host(host) || host == "0.0.0.0" || host.is_empty()
+}
+
+fn is_loopback_host(host: &str) -> bool {
+    host == "localhost" || host == "::1" || host.starts_with("127.")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        EngineEndpoint, ResolvedEndpoint, api_socket_for, endpoint_address, is_local_host,
+        is_loopback_host, set_engine_endpoint,
+    };
+    use crate::config::ContainerEngine;
+    use std::path::PathBuf;
+
+    #[test]
+    fn api_socket_follows_the_resolved_endpoint() {
+        assert_eq!(api_socket_for(ResolvedEndpoint::Default), None);
+        assert_eq!(
+            api_socket_for(ResolvedEndpoint::Url(
+                "unix:///Users/dev/.docker/run/docker.sock".to_owned()
+            )),
+            Some(Some(PathBuf::from("/Users/dev/.docker/run/docker.sock")))
+        );
+        assert_eq!(
+            api_socket_for(ResolvedEndpoint::Url("ssh://dev@build-box".to_owned())),
+            Some(None)
+        );
+        assert_eq!(api_socket_for(ResolvedEndpoint::Unknown), Some(None));
+    }
+
+    #[test]
+    fn endpoint_address_reads_remote_hosts_only() {
+        assert_eq!(
+            endpoint_address("ssh://dev@build-box"),
+            Some("build-box".to_owned())
+        );
+        assert_eq!(
+            endpoint_address("ssh://dev@build-box:2222"),
+            Some("build-box".to_owned())
+        );
+        assert_eq!(
+            endpoint_address("tcp://10.0.0.5:2376"),
+            Some("10.0.0.5".to_owned())
+        );
+        assert_eq!(
+            endpoint_address("tcp://[fd00::5]:2376"),
+            Some("fd00::5".to_owned())
+        );
+        assert_eq!(endpoint_address("unix:///var/run/docker.sock"), None);
+        assert_eq!(endpoint_address("tcp://127.0.0.1:2375"), None);
+        assert_eq!(endpoint_address("npipe:////./pipe/docker_engine"), None);
+        assert_eq!(endpoint_address("build-box"), None);
+    }
+
+    #[test]
+    fn set_engine_endpoint_rejects_engines_without_remote_support() {
+        crate::docker::with_container_engine(ContainerEngine::Nerdctl, || {
+            let result =
+                set_engine_endpoint(Some(EngineEndpoint::Host("ssh://dev@build-box".to_owned())));
+            assert!(result.is_err());
+        });
+    }
+
+    #[test]
+    fn loopback_and_unspecified_hosts_are_local() {
+        assert!(is_local_host("127.0.0.1"));
+        assert!(is_local_host("localhost"));
+        assert!(is_local_host("0.0.0.0"));
+        assert!(!is_local_host("10.0.0.5"));
+        assert!(is_loopback_host("127.0.0.1"));
+        assert!(!is_loopback_host("0.0.0.0"));
+        assert!(!is_loopback_host("10.0.0.5"));
+    }
+}
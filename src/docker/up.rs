@@ -18,21 +18,30 @@ mod object_store_bucket;
 mod state;
 
 /// Ensures the service container is running.
+///
+/// Services with `[service.build]` run their built image, which is built first
+/// when missing; a container running an older build is recreated.
 pub fn up(service: &ServiceConfig, pull: PullPolicy, recreate: bool) -> Result<()> {
     let container_name = service.container_name()?;
+    super::ensure_service_network(service)?;
+    let service = &*super::with_built_image(service)?;
 
     if is_dry_run() {
         return dry_run::describe(service, pull, recreate, &container_name);
     }
 
-    if state::ensure_or_start_existing(&container_name, recreate)? {
+    let recreate = recreate || super::built_image_outdated(service, &container_name);
+    if state::ensure_or_start_existing(service, &container_name, recreate)? {
         ensure_object_store_bucket_ready(service)?;
         return Ok(());
     }
 
     state::ensure_image_available(service, pull)?;
 
-    let run_args = args_builder::build_run_args(service, &container_name);
+    let run_args = super::apply_pod_args(
+        args_builder::build_run_args(service, &container_name)?,
+        service,
+    );
     let output = docker_output_owned(&run_args, &super::runtime_command_error_context("run"))?;
     ensure_success(output, "Failed to start container")?;
     ensure_object_store_bucket_ready(service)?;
@@ -46,6 +55,11 @@ pub fn up(service: &ServiceConfig, pull: PullPolicy, recreate: bool) -> Result<(
     Ok(())
 }
 
+/// Builds the `docker run` args `up` would use for a service.
+pub(crate) fn service_run_args(service: &ServiceConfig) -> Result<Vec<String>> {
+    args_builder::build_run_args(service, &service.container_name()?)
+}
+
 fn ensure_object_store_bucket_ready(service: &ServiceConfig) -> Result<()> {
     if service.kind == Kind::ObjectStore {
         super::wait_until_healthy(service, 30, 2, None)?;
@@ -81,11 +95,15 @@ pub(super) fn start_container(container_name: &str) -> Result<bool> {
         }
 
         if status == "exited" || status == "created" {
-            let start_output = docker_output(
-                &["start", container_name],
-                &super::runtime_command_error_context("start"),
-            )?;
-            ensure_success(start_output, "Failed to start container")?;
+            if let Some(started) = super::api::start_container(container_name) {
+                started.map_err(|err| anyhow::anyhow!("Failed to start container: {err}"))?;
+            } else {
+                let start_output = docker_output(
+                    &["start", container_name],
+                    &super::runtime_command_error_context("start"),
+                )?;
+                ensure_success(start_output, "Failed to start container")?;
+            }
 
             output::event(
                 container_name,
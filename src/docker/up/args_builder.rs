@@ -2,18 +2,18 @@
 //!
 //! Contains docker up args builder logic used by Helm command workflows.
 
+use anyhow::Result;
+
 use crate::config::ServiceConfig;
 
 mod entrypoint;
 mod env;
-mod labels;
 
 use entrypoint::append_entrypoint_args;
 use env::append_run_options;
-use labels::append_labels;
 
 /// Builds run args for command execution.
-pub(super) fn build_run_args(service: &ServiceConfig, container_name: &str) -> Vec<String> {
+pub(super) fn build_run_args(service: &ServiceConfig, container_name: &str) -> Result<Vec<String>> {
     let mut args = vec![
         "run".to_owned(),
         "-d".to_owned(),
@@ -22,18 +22,21 @@ pub(super) fn build_run_args(service: &ServiceConfig, container_name: &str) -> V
         "-p".to_owned(),
         format!(
             "{}:{}:{}",
-            service.host,
+            crate::docker::publish_host(&service.host),
             service.port,
             service.default_port()
         ),
     ];
 
+    args.extend(service.runtime_option_args());
+    crate::docker::append_health_args(&mut args, service);
     append_run_options(&mut args, service, container_name);
     append_host_gateway_mapping(&mut args, service);
-    append_labels(&mut args, service, container_name);
+    crate::docker::append_network_args(&mut args, service);
+    crate::docker::append_labels(&mut args, service, container_name)?;
     args.push(service.image.clone());
     append_entrypoint_args(&mut args, service);
-    args
+    Ok(args)
 }
 
 fn append_host_gateway_mapping(args: &mut Vec<String>, service: &ServiceConfig) {
@@ -87,6 +90,7 @@ mod tests {
             hook: Vec::new(),
             health_path: None,
             health_statuses: None,
+            health: None,
             localhost_tls: false,
             octane: false,
             octane_workers: None,
@@ -96,14 +100,28 @@ mod tests {
             env_mapping: None,
             javascript: None,
             container_name: Some("acme-db".to_owned()),
+            memory: None,
+            cpus: None,
+            pids_limit: None,
+            shm_size: None,
+            ulimits: None,
+            tmpfs: None,
+            platform: None,
+            user: None,
+            cap_add: None,
+            restart: None,
+            build: None,
             resolved_container_name: Some("acme-db".to_owned()),
+            resolved_project_root: None,
+            resolved_runtime_env: None,
+            secret_fields: Vec::new(),
         }
     }
 
     #[test]
     fn adds_host_gateway_mapping_for_loopback_services() {
         crate::docker::with_container_engine(crate::config::ContainerEngine::Docker, || {
-            let args = build_run_args(&service(), "acme-db");
+            let args = build_run_args(&service(), "acme-db").expect("run args");
             let rendered = args.join(" ");
 
             assert!(rendered.contains("--add-host host.docker.internal:host-gateway"));
@@ -120,17 +138,49 @@ mod tests {
                 "http://host.docker.internal:3306".to_owned(),
             )]));
 
-            let args = build_run_args(&with_env, "acme-db");
+            let args = build_run_args(&with_env, "acme-db").expect("run args");
             let rendered = args.join(" ");
 
             assert!(rendered.contains("--add-host host.docker.internal:host-gateway"));
         });
     }
 
+    #[test]
+    fn renders_runtime_options_before_image() {
+        let mut limited = service();
+        limited.memory = Some("2g".to_owned());
+        limited.cpus = Some(2.0);
+        limited.cap_add = Some(vec!["SYS_NICE".to_owned()]);
+
+        let args = build_run_args(&limited, "acme-db").expect("run args");
+        let rendered = args.join(" ");
+
+        assert!(rendered.contains("--memory 2g --cpus 2 --cap-add SYS_NICE"));
+        assert_eq!(args.last().map(String::as_str), Some("mysql:8.1"));
+    }
+
+    #[test]
+    fn joins_project_network_with_service_alias() {
+        let mut project = service();
+        project.resolved_project_root = Some(std::path::PathBuf::from("/work/acme"));
+
+        let rendered = build_run_args(&project, "acme-db")
+            .expect("run args")
+            .join(" ");
+
+        assert!(rendered.contains("--network helm-acme-"));
+        assert!(rendered.contains("--network-alias db"));
+        assert!(
+            !build_run_args(&service(), "acme-db")
+                .expect("run args")
+                .contains(&"--network".to_owned())
+        );
+    }
+
     #[test]
     fn podman_does_not_force_add_host_gateway_mapping() {
         crate::docker::with_container_engine(crate::config::ContainerEngine::Podman, || {
-            let args = build_run_args(&service(), "acme-db");
+            let args = build_run_args(&service(), "acme-db").expect("run args");
             let rendered = args.join(" ");
             assert!(!rendered.contains("--add-host"));
         });
@@ -21,6 +21,7 @@ pub(super) fn describe(
     }
 
     match pull {
+        _ if service.build.is_some() => {}
         PullPolicy::Always => print_docker_command(&["pull".to_owned(), service.image.clone()]),
         PullPolicy::Missing => {
             print_docker_command(&[
@@ -33,7 +34,10 @@ pub(super) fn describe(
         PullPolicy::Never => {}
     }
 
-    let run_args = args_builder::build_run_args(service, container_name);
+    let run_args = super::super::apply_pod_args(
+        args_builder::build_run_args(service, container_name)?,
+        service,
+    );
     print_docker_command(&run_args);
     output::event(
         &service.name,
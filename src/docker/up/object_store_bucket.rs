@@ -29,6 +29,25 @@ pub(super) fn ensure_bucket_exists(service: &ServiceConfig) -> Result<()> {
     );
     let legacy = build_host_gateway_bootstrap(service, bucket, access_key, secret_key, region);
 
+    // Engines without `--network container:` go straight to the host gateway.
+    if !crate::docker::runtime_supports_container_network() {
+        let Some(legacy) = legacy else {
+            return Err(anyhow!(
+                "cannot bootstrap object-store bucket '{}' for service '{}': the {} engine \
+                 supports neither container network mode nor a host gateway mapping",
+                bucket,
+                service.name,
+                crate::docker::container_engine().command_binary()
+            ));
+        };
+        if crate::docker::is_dry_run() {
+            crate::docker::print_docker_command(&legacy.head_args);
+            crate::docker::print_docker_command(&legacy.create_args);
+            return Ok(());
+        }
+        return run_bucket_bootstrap(service, bucket, &legacy);
+    }
+
     if crate::docker::is_dry_run() {
         crate::docker::print_docker_command(&primary.head_args);
         crate::docker::print_docker_command(&primary.create_args);
@@ -320,6 +339,7 @@ mod tests {
             hook: Vec::new(),
             health_path: None,
             health_statuses: None,
+            health: None,
             localhost_tls: false,
             octane: false,
             octane_workers: None,
@@ -329,7 +349,21 @@ mod tests {
             env_mapping: None,
             javascript: None,
             container_name: Some("acme-s3".to_owned()),
+            memory: None,
+            cpus: None,
+            pids_limit: None,
+            shm_size: None,
+            ulimits: None,
+            tmpfs: None,
+            platform: None,
+            user: None,
+            cap_add: None,
+            restart: None,
+            build: None,
             resolved_container_name: Some("acme-s3".to_owned()),
+            resolved_project_root: None,
+            resolved_runtime_env: None,
+            secret_fields: Vec::new(),
         }
     }
 
@@ -487,6 +521,38 @@ exit 1
         fs::remove_file(log).ok();
     }
 
+    #[test]
+    fn ensure_bucket_exists_skips_container_network_on_nerdctl() {
+        let log = std::env::temp_dir().join(format!("helm-bucket-nerdctl-{}.log", unique_suffix()));
+        let log_path = log.to_string_lossy().to_string();
+        with_fake_runtime_command(
+            &format!(
+                r#"
+echo "$*" >> "{}"
+if echo "$*" | grep -q "head-bucket"; then
+  exit 0
+fi
+exit 1
+"#,
+                log_path
+            ),
+            || {
+                crate::docker::with_container_engine(
+                    crate::config::ContainerEngine::Nerdctl,
+                    || {
+                        ensure_bucket_exists(&service(Driver::Rustfs))
+                            .expect("host gateway bootstrap should pass");
+                    },
+                );
+            },
+        );
+
+        let content = fs::read_to_string(Path::new(&log)).expect("read fake runtime log");
+        assert!(!content.contains("--network container:"));
+        assert!(content.contains("--add-host host.docker.internal:host-gateway"));
+        fs::remove_file(log).ok();
+    }
+
     #[test]
     fn ensure_bucket_exists_fallback_uses_published_host_port() {
         let log =
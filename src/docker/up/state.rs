@@ -5,17 +5,36 @@
 use anyhow::Result;
 
 use crate::config::ServiceConfig;
+use crate::output::{self, LogLevel, Persistence};
 
-use super::super::PullPolicy;
+use super::super::{DriftState, PullPolicy, drift_state};
 use super::{inspect_image_exists, remove_container, start_container};
 
 /// Ensures or start existing exists and is in the required state.
-pub(super) fn ensure_or_start_existing(container_name: &str, recreate: bool) -> Result<bool> {
+///
+/// Containers whose config hash label no longer matches the service are
+/// removed so the caller creates them again.
+pub(super) fn ensure_or_start_existing(
+    service: &ServiceConfig,
+    container_name: &str,
+    recreate: bool,
+) -> Result<bool> {
     if recreate {
         remove_container(container_name);
         return Ok(false);
     }
 
+    if drift_state(service, container_name)? == DriftState::Drifted {
+        output::event(
+            &service.name,
+            LogLevel::Info,
+            "Configuration changed; recreating container",
+            Persistence::Persistent,
+        );
+        remove_container(container_name);
+        return Ok(false);
+    }
+
     start_container(container_name)
 }
 
@@ -24,6 +43,9 @@ pub(super) fn ensure_image_available(
     service: &ServiceConfig,
     pull_policy: PullPolicy,
 ) -> Result<()> {
+    if service.build.is_some() {
+        return Ok(());
+    }
     match pull_policy {
         PullPolicy::Always => super::super::pull(service)?,
         PullPolicy::Missing => {
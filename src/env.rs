@@ -24,6 +24,12 @@ pub fn inferred_app_env(config: &Config) -> HashMap<String, String> {
     infer::inferred_app_env(config)
 }
 
+/// Runs `infer` with service hosts resolved as separate containers on the
+/// project network, even in `podman_pod` mode.
+pub fn with_separate_containers<R>(infer: impl FnOnce() -> R) -> R {
+    infer::with_separate_containers(infer)
+}
+
 /// Writes env values to persisted or external state.
 pub fn write_env_values(
     env_path: &Path,
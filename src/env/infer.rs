@@ -4,6 +4,7 @@
 //! services and app targets, then applies precedence rules that preserve explicit
 //! user-provided values.
 
+use std::cell::Cell;
 use std::collections::HashMap;
 
 use crate::config::{Config, Driver, Kind, ProjectType, ServiceConfig};
@@ -11,6 +12,21 @@ use crate::config::{Config, Driver, Kind, ProjectType, ServiceConfig};
 mod app_targets;
 mod service_backends;
 
+thread_local! {
+    /// Set while env is inferred for services that run as separate containers
+    /// even though `podman_pod` mode is on.
+    static SEPARATE_CONTAINERS: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Runs `infer` with hosts resolved as separate containers on the project
+/// network, ignoring `podman_pod` mode.
+pub(crate) fn with_separate_containers<R>(infer: impl FnOnce() -> R) -> R {
+    let previous = SEPARATE_CONTAINERS.with(|separate| separate.replace(true));
+    let result = infer();
+    SEPARATE_CONTAINERS.with(|separate| separate.set(previous));
+    result
+}
+
 /// Builds inferred runtime environment values for an app container from configured services.
 ///
 /// This map is intended for container runtime injection so loopback hosts use
@@ -62,7 +78,31 @@ pub(crate) fn managed_app_env(config: &Config) -> HashMap<String, String> {
 /// Resolves the host value an app container can actually reach at runtime.
 ///
 /// Why: `localhost` inside a container points to itself, not the host machine.
+/// Services on the project network are reached directly by their alias, and
+/// pod members share localhost.
 pub(super) fn runtime_host_for_app(service: &ServiceConfig) -> String {
+    if crate::docker::project_network_name(service).is_some() {
+        if crate::docker::pod_mode() && !SEPARATE_CONTAINERS.with(Cell::get) {
+            return "127.0.0.1".to_owned();
+        }
+        return service.name.clone();
+    }
+    published_host_for_app(service)
+}
+
+/// Resolves the port paired with [`runtime_host_for_app`].
+///
+/// Why: the network alias reaches the container port, not the published one,
+/// so host port shifts never leak into app env.
+pub(super) fn runtime_port_for_app(service: &ServiceConfig) -> u16 {
+    if crate::docker::project_network_name(service).is_some() {
+        return service.resolved_container_port();
+    }
+    service.port
+}
+
+/// Resolves the host-published address of a service as seen from a container.
+fn published_host_for_app(service: &ServiceConfig) -> String {
     if service.uses_host_gateway_alias() {
         return crate::docker::host_gateway_alias().to_owned();
     }
@@ -99,6 +139,17 @@ pub(super) fn service_endpoint(service: &ServiceConfig) -> String {
         "{}://{}:{}",
         service.scheme(),
         runtime_host_for_app(service),
+        runtime_port_for_app(service)
+    )
+}
+
+/// Builds the host-published endpoint for URLs handed to clients outside the
+/// project network.
+pub(super) fn published_service_endpoint(service: &ServiceConfig) -> String {
+    format!(
+        "{}://{}:{}",
+        service.scheme(),
+        published_host_for_app(service),
         service.port
     )
 }
@@ -4,7 +4,7 @@ use std::collections::HashMap;
 
 use crate::config::ServiceConfig;
 
-use super::super::{insert_if_absent, service_endpoint};
+use super::super::{insert_if_absent, published_service_endpoint, service_endpoint};
 
 /// Applies inferred S3-compatible env variables.
 ///
@@ -51,5 +51,5 @@ fn object_store_public_url(service: &ServiceConfig) -> String {
         .resolved_domain_urls()
         .into_iter()
         .next()
-        .unwrap_or_else(|| service_endpoint(service))
+        .unwrap_or_else(|| published_service_endpoint(service))
 }
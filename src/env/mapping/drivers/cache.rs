@@ -9,7 +9,7 @@ use crate::config::{Driver, ServiceConfig};
 pub(super) fn apply_cache_map(map: &mut HashMap<String, String>, service: &ServiceConfig) {
     if service.driver == Driver::Memcached {
         map.insert("CACHE_STORE".to_owned(), "memcached".to_owned());
-        map.insert("MEMCACHED_HOST".to_owned(), service.host.clone());
+        map.insert("MEMCACHED_HOST".to_owned(), service.client_host());
         map.insert("MEMCACHED_PORT".to_owned(), service.port.to_string());
         return;
     }
@@ -18,7 +18,7 @@ pub(super) fn apply_cache_map(map: &mut HashMap<String, String>, service: &Servi
     map.insert("QUEUE_CONNECTION".to_owned(), "redis".to_owned());
     map.insert("SESSION_DRIVER".to_owned(), "redis".to_owned());
     map.insert("REDIS_CLIENT".to_owned(), "phpredis".to_owned());
-    let redis_host = service.host.clone();
+    let redis_host = service.client_host();
     let redis_port = service.port.to_string();
     let redis_username = service.username.clone().unwrap_or_default();
     let redis_password = service.password.clone().unwrap_or_default();
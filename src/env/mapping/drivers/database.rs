@@ -11,7 +11,7 @@ pub(super) fn apply_database_map(map: &mut HashMap<String, String>, service: &Se
         "DB_CONNECTION".to_owned(),
         service.laravel_connection().unwrap_or("mysql").to_owned(),
     );
-    map.insert("DB_HOST".to_owned(), service.host.clone());
+    map.insert("DB_HOST".to_owned(), service.client_host());
     map.insert("DB_PORT".to_owned(), service.port.to_string());
     map.insert(
         "DB_DATABASE".to_owned(),
@@ -35,7 +35,12 @@ pub(super) fn apply_object_store_map(map: &mut HashMap<String, String>, service:
     );
     map.insert(
         "AWS_ENDPOINT".to_owned(),
-        format!("{}://{}:{}", service.scheme(), service.host, service.port),
+        format!(
+            "{}://{}:{}",
+            service.scheme(),
+            service.client_host(),
+            service.port
+        ),
     );
     map.insert("AWS_USE_PATH_STYLE_ENDPOINT".to_owned(), "true".to_owned());
 }
@@ -10,7 +10,12 @@ pub(super) fn apply_meilisearch_map(map: &mut HashMap<String, String>, service:
     map.insert("SCOUT_DRIVER".to_owned(), "meilisearch".to_owned());
     map.insert(
         "MEILISEARCH_HOST".to_owned(),
-        format!("{}://{}:{}", service.scheme(), service.host, service.port),
+        format!(
+            "{}://{}:{}",
+            service.scheme(),
+            service.client_host(),
+            service.port
+        ),
     );
     map.insert(
         "MEILISEARCH_KEY".to_owned(),
@@ -20,7 +25,7 @@ pub(super) fn apply_meilisearch_map(map: &mut HashMap<String, String>, service:
 
 pub(super) fn apply_typesense_map(map: &mut HashMap<String, String>, service: &ServiceConfig) {
     map.insert("SCOUT_DRIVER".to_owned(), "typesense".to_owned());
-    map.insert("TYPESENSE_HOST".to_owned(), service.host.clone());
+    map.insert("TYPESENSE_HOST".to_owned(), service.client_host());
     map.insert("TYPESENSE_PORT".to_owned(), service.port.to_string());
     map.insert("TYPESENSE_PROTOCOL".to_owned(), service.scheme().to_owned());
     map.insert(
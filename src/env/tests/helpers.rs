@@ -32,6 +32,7 @@ pub(super) fn svc(name: &str, kind: Kind, driver: Driver, port: u16) -> ServiceC
         hook: Vec::new(),
         health_path: None,
         health_statuses: None,
+        health: None,
         localhost_tls: false,
         octane: false,
         octane_workers: None,
@@ -41,7 +42,21 @@ pub(super) fn svc(name: &str, kind: Kind, driver: Driver, port: u16) -> ServiceC
         env_mapping: None,
         javascript: None,
         container_name: None,
+        memory: None,
+        cpus: None,
+        pids_limit: None,
+        shm_size: None,
+        ulimits: None,
+        tmpfs: None,
+        platform: None,
+        user: None,
+        cap_add: None,
+        restart: None,
+        build: None,
         resolved_container_name: None,
+        resolved_project_root: None,
+        resolved_runtime_env: None,
+        secret_fields: Vec::new(),
     }
 }
 
@@ -14,6 +14,8 @@ fn inferred_app_env_sets_app_and_asset_urls_for_localhost_tls() {
         domain_strategy: None,
         service: vec![app],
         swarm: vec![],
+        profile: std::collections::BTreeMap::new(),
+        preset: std::collections::BTreeMap::new(),
     };
 
     let vars = inferred_app_env(&config);
@@ -40,6 +42,8 @@ fn inferred_app_env_sets_app_and_asset_urls_for_domain() {
         domain_strategy: None,
         service: vec![app],
         swarm: vec![],
+        profile: std::collections::BTreeMap::new(),
+        preset: std::collections::BTreeMap::new(),
     };
 
     let vars = inferred_app_env(&config);
@@ -70,6 +74,8 @@ fn inferred_app_env_uses_primary_domain_from_domains_list() {
         domain_strategy: None,
         service: vec![app],
         swarm: vec![],
+        profile: std::collections::BTreeMap::new(),
+        preset: std::collections::BTreeMap::new(),
     };
 
     let vars = inferred_app_env(&config);
@@ -95,6 +101,8 @@ fn inferred_app_env_sets_default_app_name_for_app_targets() {
         domain_strategy: None,
         service: vec![app],
         swarm: vec![],
+        profile: std::collections::BTreeMap::new(),
+        preset: std::collections::BTreeMap::new(),
     };
 
     let vars = inferred_app_env(&config);
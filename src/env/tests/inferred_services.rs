@@ -39,6 +39,8 @@ fn inferred_app_env_includes_core_laravel_service_vars() {
             db, redis, memcached, s3, gotenberg, mailhog, reverb, dusk, rabbitmq, soketi, scheduler,
         ],
         swarm: vec![],
+        profile: std::collections::BTreeMap::new(),
+        preset: std::collections::BTreeMap::new(),
     };
 
     let vars = inferred_app_env(&config);
@@ -117,6 +119,44 @@ fn inferred_app_env_includes_core_laravel_service_vars() {
     );
 }
 
+#[test]
+fn inferred_app_env_uses_network_aliases_and_container_ports_inside_projects() {
+    let root = std::path::PathBuf::from("/work/acme");
+    let mut db = svc("db", Kind::Database, Driver::Mysql, 33060);
+    let mut redis = svc("redis", Kind::Cache, Driver::Redis, 6380);
+    let mut s3 = svc("s3", Kind::ObjectStore, Driver::Rustfs, 9100);
+    let mut mailhog = svc("mailhog", Kind::App, Driver::Mailhog, 33067);
+    mailhog.smtp_port = Some(34067);
+    for service in [&mut db, &mut redis, &mut s3, &mut mailhog] {
+        service.resolved_project_root = Some(root.clone());
+    }
+
+    let config = Config {
+        schema_version: 1,
+        project_type: crate::config::ProjectType::Project,
+        container_prefix: Some("app".to_owned()),
+        domain_strategy: None,
+        service: vec![db, redis, s3, mailhog],
+        swarm: vec![],
+        profile: std::collections::BTreeMap::new(),
+        preset: std::collections::BTreeMap::new(),
+    };
+
+    let vars = inferred_app_env(&config);
+
+    assert_eq!(vars.get("DB_HOST"), Some(&"db".to_owned()));
+    assert_eq!(vars.get("DB_PORT"), Some(&"3306".to_owned()));
+    assert_eq!(vars.get("REDIS_HOST"), Some(&"redis".to_owned()));
+    assert_eq!(vars.get("REDIS_PORT"), Some(&"6379".to_owned()));
+    assert_eq!(vars.get("AWS_ENDPOINT"), Some(&"http://s3:9000".to_owned()));
+    assert_eq!(
+        vars.get("AWS_URL"),
+        Some(&"http://host.docker.internal:9100".to_owned())
+    );
+    assert_eq!(vars.get("MAIL_HOST"), Some(&"mailhog".to_owned()));
+    assert_eq!(vars.get("MAIL_PORT"), Some(&"1025".to_owned()));
+}
+
 #[test]
 fn inferred_app_env_uses_mariadb_flavor_for_mariadb_only_database_images() {
     let mut db = svc("db", Kind::Database, Driver::Mysql, 33060);
@@ -129,6 +169,8 @@ fn inferred_app_env_uses_mariadb_flavor_for_mariadb_only_database_images() {
         domain_strategy: None,
         service: vec![db],
         swarm: vec![],
+        profile: std::collections::BTreeMap::new(),
+        preset: std::collections::BTreeMap::new(),
     };
 
     let vars = inferred_app_env(&config);
@@ -150,6 +192,8 @@ fn inferred_app_env_includes_meilisearch_scout_vars() {
         domain_strategy: None,
         service: vec![meili],
         swarm: vec![],
+        profile: std::collections::BTreeMap::new(),
+        preset: std::collections::BTreeMap::new(),
     };
 
     let vars = inferred_app_env(&config);
@@ -173,6 +217,8 @@ fn inferred_app_env_includes_typesense_scout_vars() {
         domain_strategy: None,
         service: vec![typesense],
         swarm: vec![],
+        profile: std::collections::BTreeMap::new(),
+        preset: std::collections::BTreeMap::new(),
     };
 
     let vars = inferred_app_env(&config);
@@ -197,6 +243,8 @@ fn inferred_app_env_includes_horizon_queue_defaults() {
         domain_strategy: None,
         service: vec![horizon],
         swarm: vec![],
+        profile: std::collections::BTreeMap::new(),
+        preset: std::collections::BTreeMap::new(),
     };
 
     let vars = inferred_app_env(&config);
@@ -215,6 +263,8 @@ fn inferred_app_env_prefers_object_store_domain_for_aws_url() {
         domain_strategy: None,
         service: vec![s3],
         swarm: vec![],
+        profile: std::collections::BTreeMap::new(),
+        preset: std::collections::BTreeMap::new(),
     };
 
     let vars = inferred_app_env(&config);
@@ -236,6 +286,8 @@ fn inferred_app_env_includes_scheduler_defaults() {
         domain_strategy: None,
         service: vec![scheduler],
         swarm: vec![],
+        profile: std::collections::BTreeMap::new(),
+        preset: std::collections::BTreeMap::new(),
     };
 
     let vars = inferred_app_env(&config);
@@ -256,6 +308,8 @@ fn inferred_app_env_uses_sqlsrv_connection_for_sqlserver() {
         domain_strategy: None,
         service: vec![db],
         swarm: vec![],
+        profile: std::collections::BTreeMap::new(),
+        preset: std::collections::BTreeMap::new(),
     };
 
     let vars = inferred_app_env(&config);
@@ -293,6 +347,8 @@ fn inferred_app_env_applies_service_env_mapping_without_overwriting_primary_db()
         domain_strategy: None,
         service: vec![primary, invoicing],
         swarm: vec![],
+        profile: std::collections::BTreeMap::new(),
+        preset: std::collections::BTreeMap::new(),
     };
 
     let vars = inferred_app_env(&config);
@@ -53,7 +53,7 @@ fn build_nvm_command(options: BuildNodeCommandOptions<'_>) -> Result<Vec<String>
     let joined = options
         .command
         .iter()
-        .map(|part| shell_quote(part))
+        .map(|part| crate::shell::quote(part))
         .collect::<Vec<_>>()
         .join(" ");
     let script = format!(
@@ -63,14 +63,6 @@ fn build_nvm_command(options: BuildNodeCommandOptions<'_>) -> Result<Vec<String>
     Ok(vec!["bash".to_owned(), "-lc".to_owned(), script])
 }
 
-fn shell_quote(value: &str) -> String {
-    if value.is_empty() {
-        return "''".to_owned();
-    }
-
-    format!("'{}'", value.replace('\'', "'\"'\"'"))
-}
-
 #[cfg(test)]
 mod tests {
     use super::{BuildNodeCommandOptions, build_node_command};
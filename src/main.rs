@@ -51,7 +51,9 @@ mod output;
 mod parallel;
 mod serve;
 mod share;
+mod shell;
 mod swarm;
+mod yaml;
 
 fn main() -> Result<()> {
     let cli = Cli::parse();
@@ -213,6 +213,29 @@ fn cli_parses_global_container_engine_flag() {
     assert_eq!(cli.engine, Some(crate::config::ContainerEngine::Podman));
 }
 
+#[test]
+fn cli_parses_global_engine_endpoint_flags() {
+    let cli = Cli::try_parse_from(["helm", "--engine-host", "ssh://dev@box", "up"])
+        .expect("parse engine host");
+    assert_eq!(cli.engine_host.as_deref(), Some("ssh://dev@box"));
+
+    let cli = Cli::try_parse_from(["helm", "ps", "--engine-context", "box"])
+        .expect("parse engine context");
+    assert_eq!(cli.engine_context.as_deref(), Some("box"));
+
+    assert!(
+        Cli::try_parse_from([
+            "helm",
+            "--engine-host",
+            "tcp://box:2376",
+            "--engine-context",
+            "box",
+            "up"
+        ])
+        .is_err()
+    );
+}
+
 #[test]
 fn cli_parses_about_command() {
     let cli = Cli::try_parse_from(["helm", "about"]).expect("parse about");
@@ -440,7 +463,17 @@ fn config_cli_parses_migrate_subcommand() {
     match cli.command {
         Commands::Config(args) => assert!(matches!(
             args.command,
-            Some(crate::cli::args::ConfigCommands::Migrate)
+            Some(crate::cli::args::ConfigCommands::Migrate { dry_run: false })
+        )),
+        _ => panic!("expected config command"),
+    }
+
+    let cli = Cli::try_parse_from(["helm", "config", "migrate", "--dry-run"])
+        .expect("parse config migrate --dry-run");
+    match cli.command {
+        Commands::Config(args) => assert!(matches!(
+            args.command,
+            Some(crate::cli::args::ConfigCommands::Migrate { dry_run: true })
         )),
         _ => panic!("expected config command"),
     }
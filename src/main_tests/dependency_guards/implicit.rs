@@ -31,6 +31,8 @@ fn down_guard_blocks_shared_implicit_dependencies() -> Result<()> {
                 git: None,
             },
         ],
+        profile: std::collections::BTreeMap::new(),
+        preset: std::collections::BTreeMap::new(),
     };
 
     let expanded = vec![
@@ -38,6 +38,8 @@ fn down_guard_blocks_transitive_shared_dependencies() -> Result<()> {
                 git: None,
             },
         ],
+        profile: std::collections::BTreeMap::new(),
+        preset: std::collections::BTreeMap::new(),
     };
     assert!(swarm_depends_on("other", "bill", &config));
 
@@ -34,6 +34,8 @@ fn resolve_swarm_targets_rejects_dependency_cycles() -> Result<()> {
                 git: None,
             },
         ],
+        profile: std::collections::BTreeMap::new(),
+        preset: std::collections::BTreeMap::new(),
     };
 
     let error = match resolve_swarm_targets(&config, &base, &[String::from("api")], true) {
@@ -46,6 +46,8 @@ fn resolve_swarm_targets_includes_dependencies_by_default() -> Result<()> {
                 git: None,
             },
         ],
+        profile: std::collections::BTreeMap::new(),
+        preset: std::collections::BTreeMap::new(),
     };
 
     let with_deps = resolve_swarm_targets(&config, &base, &[String::from("api")], true)?;
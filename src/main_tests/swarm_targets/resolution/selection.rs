@@ -34,6 +34,8 @@ fn resolve_swarm_targets_filters_only_and_validates_members() -> Result<()> {
                 git: None,
             },
         ],
+        profile: std::collections::BTreeMap::new(),
+        preset: std::collections::BTreeMap::new(),
     };
 
     let targets = resolve_swarm_targets(&config, &base, &[String::from("bill")], true)?;
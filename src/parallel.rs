@@ -1,8 +1,12 @@
 //! Shared parallel execution helpers.
 //!
-//! Contains reusable validation utilities for parallelism configuration.
+//! Contains parallelism validation and the dependency-aware scheduler that
+//! runs each node as soon as everything it depends on has finished.
 
 use anyhow::Result;
+use std::collections::{BTreeSet, HashMap};
+use std::panic::{AssertUnwindSafe, catch_unwind};
+use std::sync::{Condvar, Mutex};
 
 pub(crate) fn validate_parallelism(parallel: usize) -> Result<()> {
     if parallel == 0 {
@@ -12,9 +16,284 @@ pub(crate) fn validate_parallelism(parallel: usize) -> Result<()> {
     Ok(())
 }
 
+/// Runs every node once all of its dependencies have succeeded, keeping at
+/// most `parallel` nodes in flight.
+///
+/// Dependencies that are not part of `nodes` are treated as already satisfied.
+/// When a node fails, nodes that transitively depend on it are skipped while
+/// unrelated branches keep running. Ready nodes start in input order, so
+/// `parallel = 1` reproduces a serial run over a topologically ordered input.
+pub(crate) fn run_dependency_graph<T, FName, FDeps, FRun>(
+    parallel: usize,
+    nodes: &[T],
+    name_of: FName,
+    dependencies_of: FDeps,
+    run: FRun,
+) -> Result<()>
+where
+    T: Sync,
+    FName: Fn(&T) -> &str,
+    FDeps: Fn(&T) -> Vec<String>,
+    FRun: Fn(&T) -> Result<()> + Sync,
+{
+    validate_parallelism(parallel)?;
+    let graph = DependencyGraph::new(nodes, &name_of, &dependencies_of)?;
+    let schedule = Mutex::new(Schedule::new(&graph));
+    let wake = Condvar::new();
+
+    std::thread::scope(|scope| {
+        for _ in 0..parallel.min(nodes.len()) {
+            scope.spawn(|| run_worker(&graph, nodes, &run, &schedule, &wake));
+        }
+    });
+
+    schedule
+        .into_inner()
+        .map_err(|_| anyhow::anyhow!("dependency scheduler state was poisoned"))?
+        .into_result(&graph)
+}
+
+struct DependencyGraph {
+    names: Vec<String>,
+    dependents: Vec<Vec<usize>>,
+    pending: Vec<usize>,
+}
+
+impl DependencyGraph {
+    fn new<T>(
+        nodes: &[T],
+        name_of: &impl Fn(&T) -> &str,
+        dependencies_of: &impl Fn(&T) -> Vec<String>,
+    ) -> Result<Self> {
+        let names: Vec<String> = nodes.iter().map(|node| name_of(node).to_owned()).collect();
+        let index_of: HashMap<&str, usize> = names
+            .iter()
+            .enumerate()
+            .map(|(index, name)| (name.as_str(), index))
+            .collect();
+
+        let mut dependencies = vec![BTreeSet::new(); nodes.len()];
+        let mut dependents = vec![Vec::new(); nodes.len()];
+        for (index, node) in nodes.iter().enumerate() {
+            for dependency in dependencies_of(node) {
+                let Some(&dependency) = index_of.get(dependency.as_str()) else {
+                    continue;
+                };
+                if let Some(set) = dependencies.get_mut(index)
+                    && set.insert(dependency)
+                    && let Some(list) = dependents.get_mut(dependency)
+                {
+                    list.push(index);
+                }
+            }
+        }
+
+        let graph = Self {
+            names,
+            dependents,
+            pending: dependencies.iter().map(BTreeSet::len).collect(),
+        };
+        graph.ensure_acyclic(&dependencies)?;
+        Ok(graph)
+    }
+
+    /// Fails with the full cycle path when the graph cannot be scheduled.
+    fn ensure_acyclic(&self, dependencies: &[BTreeSet<usize>]) -> Result<()> {
+        let mut pending = self.pending.clone();
+        let mut ready: Vec<usize> = (0..pending.len())
+            .filter(|index| pending.get(*index) == Some(&0))
+            .collect();
+        let mut scheduled = BTreeSet::new();
+        while let Some(index) = ready.pop() {
+            scheduled.insert(index);
+            for &dependent in self.dependents.get(index).into_iter().flatten() {
+                if let Some(count) = pending.get_mut(dependent) {
+                    *count = count.saturating_sub(1);
+                    if *count == 0 {
+                        ready.push(dependent);
+                    }
+                }
+            }
+        }
+
+        let Some(start) = (0..self.names.len()).find(|index| !scheduled.contains(index)) else {
+            return Ok(());
+        };
+        // Every unscheduled node still has an unscheduled dependency, so
+        // following them must revisit a node.
+        let mut path = vec![start];
+        let mut current = start;
+        loop {
+            let Some(&next) = dependencies
+                .get(current)
+                .into_iter()
+                .flatten()
+                .find(|dependency| !scheduled.contains(*dependency))
+            else {
+                anyhow::bail!("dependency cycle detected at '{}'", self.name(current));
+            };
+            if let Some(position) = path.iter().position(|index| *index == next) {
+                let mut cycle: Vec<&str> = path
+                    .iter()
+                    .skip(position)
+                    .map(|index| self.name(*index))
+                    .collect();
+                cycle.push(self.name(next));
+                anyhow::bail!("dependency cycle detected: {}", cycle.join(" -> "));
+            }
+            path.push(next);
+            current = next;
+        }
+    }
+
+    fn name(&self, index: usize) -> &str {
+        self.names.get(index).map_or("", String::as_str)
+    }
+}
+
+enum Outcome {
+    Waiting,
+    Succeeded,
+    Failed(anyhow::Error),
+    Skipped,
+}
+
+struct Schedule {
+    ready: BTreeSet<usize>,
+    pending: Vec<usize>,
+    running: usize,
+    outcomes: Vec<Outcome>,
+}
+
+impl Schedule {
+    fn new(graph: &DependencyGraph) -> Self {
+        Self {
+            ready: (0..graph.pending.len())
+                .filter(|index| graph.pending.get(*index) == Some(&0))
+                .collect(),
+            pending: graph.pending.clone(),
+            running: 0,
+            outcomes: graph.names.iter().map(|_| Outcome::Waiting).collect(),
+        }
+    }
+
+    fn finish(&mut self, graph: &DependencyGraph, index: usize, result: Result<()>) {
+        self.running = self.running.saturating_sub(1);
+        let succeeded = result.is_ok();
+        if let Some(outcome) = self.outcomes.get_mut(index) {
+            *outcome = match result {
+                Ok(()) => Outcome::Succeeded,
+                Err(error) => Outcome::Failed(error),
+            };
+        }
+
+        if succeeded {
+            for &dependent in graph.dependents.get(index).into_iter().flatten() {
+                if let Some(count) = self.pending.get_mut(dependent) {
+                    *count = count.saturating_sub(1);
+                    if *count == 0 {
+                        self.ready.insert(dependent);
+                    }
+                }
+            }
+            return;
+        }
+
+        let mut stack: Vec<usize> = graph.dependents.get(index).cloned().unwrap_or_default();
+        while let Some(dependent) = stack.pop() {
+            if let Some(outcome @ Outcome::Waiting) = self.outcomes.get_mut(dependent) {
+                *outcome = Outcome::Skipped;
+                stack.extend(graph.dependents.get(dependent).into_iter().flatten());
+            }
+        }
+    }
+
+    fn into_result(self, graph: &DependencyGraph) -> Result<()> {
+        let mut failures = Vec::new();
+        let mut failed_names = Vec::new();
+        let mut skipped_names = Vec::new();
+        for (index, outcome) in self.outcomes.into_iter().enumerate() {
+            match outcome {
+                Outcome::Failed(error) => {
+                    failures.push(error);
+                    failed_names.push(format!("'{}'", graph.name(index)));
+                }
+                Outcome::Skipped => skipped_names.push(format!("'{}'", graph.name(index))),
+                Outcome::Waiting | Outcome::Succeeded => {}
+            }
+        }
+
+        let mut failures = failures.into_iter();
+        let Some(first) = failures.next() else {
+            return Ok(());
+        };
+        if failed_names.len() == 1 && skipped_names.is_empty() {
+            return Err(first);
+        }
+
+        let mut summary = format!("{} failed", failed_names.join(", "));
+        if !skipped_names.is_empty() {
+            summary.push_str(&format!(
+                "; skipped dependents {}",
+                skipped_names.join(", ")
+            ));
+        }
+        Err(first.context(summary))
+    }
+}
+
+fn run_worker<T, FRun>(
+    graph: &DependencyGraph,
+    nodes: &[T],
+    run: &FRun,
+    schedule: &Mutex<Schedule>,
+    wake: &Condvar,
+) where
+    FRun: Fn(&T) -> Result<()>,
+{
+    loop {
+        let Ok(mut state) = schedule.lock() else {
+            return;
+        };
+        let index = loop {
+            if let Some(index) = state.ready.pop_first() {
+                state.running += 1;
+                break index;
+            }
+            if state.running == 0 {
+                wake.notify_all();
+                return;
+            }
+            state = match wake.wait(state) {
+                Ok(state) => state,
+                Err(_) => return,
+            };
+        };
+        drop(state);
+
+        let result = nodes.get(index).map_or_else(
+            || Err(anyhow::anyhow!("scheduled node {index} is missing")),
+            |node| {
+                catch_unwind(AssertUnwindSafe(|| run(node)))
+                    .unwrap_or_else(|_| Err(anyhow::anyhow!("'{}' panicked", graph.name(index))))
+            },
+        );
+
+        let Ok(mut state) = schedule.lock() else {
+            return;
+        };
+        state.finish(graph, index, result);
+        drop(state);
+        wake.notify_all();
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::validate_parallelism;
+    use super::{run_dependency_graph, validate_parallelism};
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use std::time::Duration;
 
     #[test]
     fn validate_parallelism_rejects_zero() {
@@ -26,4 +305,161 @@ mod tests {
         assert!(validate_parallelism(1).is_ok());
         assert!(validate_parallelism(4).is_ok());
     }
+
+    type Node = (&'static str, &'static [&'static str]);
+
+    fn run_graph(
+        parallel: usize,
+        nodes: &[Node],
+        run: impl Fn(&str) -> anyhow::Result<()> + Sync,
+    ) -> anyhow::Result<()> {
+        run_dependency_graph(
+            parallel,
+            nodes,
+            |(name, _)| name,
+            |(_, deps)| deps.iter().map(|dep| (*dep).to_owned()).collect(),
+            |(name, _)| run(name),
+        )
+    }
+
+    #[test]
+    fn run_dependency_graph_serial_run_keeps_input_order() -> anyhow::Result<()> {
+        let order = Mutex::new(Vec::new());
+        run_graph(
+            1,
+            &[("db", &[]), ("cache", &[]), ("app", &["db", "cache"])],
+            |name| {
+                order
+                    .lock()
+                    .map_err(|_| anyhow::anyhow!("lock"))?
+                    .push(name.to_owned());
+                Ok(())
+            },
+        )?;
+        assert_eq!(
+            order.into_inner().map_err(|_| anyhow::anyhow!("lock"))?,
+            vec!["db", "cache", "app"]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn run_dependency_graph_starts_dependents_once_their_dependencies_finish() -> anyhow::Result<()>
+    {
+        // `slow` has no dependents, so `app` must not wait for it.
+        let order = Mutex::new(Vec::new());
+        run_graph(2, &[("slow", &[]), ("db", &[]), ("app", &["db"])], |name| {
+            if name == "slow" {
+                std::thread::sleep(Duration::from_millis(200));
+            }
+            order
+                .lock()
+                .map_err(|_| anyhow::anyhow!("lock"))?
+                .push(name.to_owned());
+            Ok(())
+        })?;
+        assert_eq!(
+            order.into_inner().map_err(|_| anyhow::anyhow!("lock"))?,
+            vec!["db", "app", "slow"]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn run_dependency_graph_limits_work_in_flight() -> anyhow::Result<()> {
+        let in_flight = Mutex::new((0_usize, 0_usize));
+        run_graph(
+            2,
+            &[("a", &[]), ("b", &[]), ("c", &[]), ("d", &[]), ("e", &[])],
+            |_| {
+                {
+                    let mut counts = in_flight.lock().map_err(|_| anyhow::anyhow!("lock"))?;
+                    counts.0 += 1;
+                    counts.1 = counts.1.max(counts.0);
+                }
+                std::thread::sleep(Duration::from_millis(20));
+                in_flight.lock().map_err(|_| anyhow::anyhow!("lock"))?.0 -= 1;
+                Ok(())
+            },
+        )?;
+        let (_, peak) = in_flight
+            .into_inner()
+            .map_err(|_| anyhow::anyhow!("lock"))?;
+        assert_eq!(peak, 2, "at most two nodes should run at once");
+        Ok(())
+    }
+
+    #[test]
+    fn run_dependency_graph_skips_downstream_of_failures_only() {
+        let started = Mutex::new(HashMap::new());
+        let error = run_graph(
+            2,
+            &[
+                ("db", &[]),
+                ("app", &["db"]),
+                ("worker", &["app"]),
+                ("cache", &[]),
+            ],
+            |name| {
+                if let Ok(mut started) = started.lock() {
+                    started.insert(name.to_owned(), true);
+                }
+                if name == "db" {
+                    anyhow::bail!("db did not become healthy");
+                }
+                Ok(())
+            },
+        )
+        .expect_err("db failure");
+
+        let started = started.into_inner().unwrap_or_default();
+        assert!(started.contains_key("cache"), "unrelated branch should run");
+        assert!(!started.contains_key("app"), "dependent should be skipped");
+        assert!(
+            !started.contains_key("worker"),
+            "transitive dependent should be skipped"
+        );
+        assert_eq!(
+            format!("{error:#}"),
+            "'db' failed; skipped dependents 'app', 'worker': db did not become healthy"
+        );
+    }
+
+    #[test]
+    fn run_dependency_graph_returns_a_lone_failure_unchanged() {
+        let error = run_graph(3, &[("db", &[]), ("cache", &[])], |name| {
+            if name == "cache" {
+                anyhow::bail!("cache failed to start");
+            }
+            Ok(())
+        })
+        .expect_err("cache failure");
+        assert_eq!(error.to_string(), "cache failed to start");
+    }
+
+    #[test]
+    fn run_dependency_graph_reports_cycles_before_running_anything() {
+        let ran = Mutex::new(false);
+        let error = run_graph(
+            2,
+            &[("app", &["db"]), ("db", &["queue"]), ("queue", &["db"])],
+            |_| {
+                if let Ok(mut ran) = ran.lock() {
+                    *ran = true;
+                }
+                Ok(())
+            },
+        )
+        .expect_err("cycle");
+        assert_eq!(
+            error.to_string(),
+            "dependency cycle detected: db -> queue -> db"
+        );
+        assert!(!ran.into_inner().unwrap_or(true), "nothing should run");
+    }
+
+    #[test]
+    fn run_dependency_graph_ignores_dependencies_outside_the_batch() -> anyhow::Result<()> {
+        run_graph(1, &[("app", &["external"])], |_| Ok(()))
+    }
 }
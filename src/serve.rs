@@ -32,8 +32,9 @@ pub(crate) use hosts::{domain_resolves_to_loopback, ensure_hosts_entry_for_domai
 pub use state::PhpExtensionCheck;
 
 use container::resolve_volume_mapping;
+pub(crate) use images::DerivedImagePlan;
 use images::{
-    mailhog_smtp_port, normalize_php_extensions, resolve_runtime_image,
+    mailhog_smtp_port, normalize_php_extensions, plan_derived_image, resolve_runtime_image,
     should_inject_frankenphp_server_name,
 };
 
@@ -48,6 +49,33 @@ use trust::trust_inner_container_ca;
 
 pub use orchestrate::{RunServeOptions, down, public_url, run};
 
+/// How `helm serve` would start a target, without touching the engine state.
+pub(crate) struct ServeRunPlan {
+    pub(crate) run_args: Vec<String>,
+    pub(crate) derived_image: Option<DerivedImagePlan>,
+}
+
+/// Plans the `docker run` invocation for a serve target.
+///
+/// # Errors
+///
+/// Returns an error if the target has no container name, a volume cannot be
+/// resolved, or base-image inspection fails.
+pub(crate) fn plan_serve_run(
+    target: &ServiceConfig,
+    project_root: &std::path::Path,
+    injected_env: &std::collections::HashMap<String, String>,
+) -> Result<ServeRunPlan> {
+    let derived_image = plan_derived_image(target, injected_env, project_root)?;
+    let runtime_image = derived_image
+        .as_ref()
+        .map_or_else(|| target.image.clone(), |plan| plan.tag.clone());
+    Ok(ServeRunPlan {
+        run_args: container::build_run_args(target, &runtime_image, project_root, injected_env)?,
+        derived_image,
+    })
+}
+
 /// Waits until app HTTP endpoint responds with a 2xx status code.
 ///
 /// # Errors
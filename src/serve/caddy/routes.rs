@@ -11,7 +11,7 @@ use super::{
 
 /// Adds/updates domains for a target and applies the resulting Caddy config.
 pub(super) fn configure_caddy(target: &ServiceConfig, ports: CaddyPorts) -> Result<()> {
-    let upstream = format!("{}:{}", target.host, target.port);
+    let upstream = format!("{}:{}", target.client_host(), target.port);
     mutate_and_apply_caddy_state(
         ports,
         |state| {
@@ -133,6 +133,7 @@ mod tests {
             hook: Vec::new(),
             health_path: None,
             health_statuses: None,
+            health: None,
             localhost_tls: true,
             octane: false,
             octane_workers: None,
@@ -142,7 +143,21 @@ mod tests {
             env_mapping: None,
             javascript: None,
             container_name: Some("app".to_owned()),
+            memory: None,
+            cpus: None,
+            pids_limit: None,
+            shm_size: None,
+            ulimits: None,
+            tmpfs: None,
+            platform: None,
+            user: None,
+            cap_add: None,
+            restart: None,
+            build: None,
             resolved_container_name: Some("app".to_owned()),
+            resolved_project_root: None,
+            resolved_runtime_env: None,
+            secret_fields: Vec::new(),
         }
     }
 
@@ -20,6 +20,16 @@ pub(super) fn ensure_container_running(
     lifecycle::ensure_container_running(target, recreate, runtime_image, project_root, injected_env)
 }
 
+/// Builds the `docker run` args a serve target starts with.
+pub(super) fn build_run_args(
+    target: &ServiceConfig,
+    runtime_image: &str,
+    project_root: &Path,
+    injected_env: &HashMap<String, String>,
+) -> Result<Vec<String>> {
+    lifecycle::build_run_args(target, runtime_image, project_root, injected_env)
+}
+
 /// Resolves an explicit or derived container command for `docker run`.
 #[cfg_attr(not(test), allow(dead_code))]
 pub(crate) fn resolved_run_command(target: &ServiceConfig) -> Option<Vec<String>> {
@@ -7,7 +7,6 @@ use std::path::Path;
 use crate::config::ServiceConfig;
 
 use super::super::{mailhog_smtp_port, should_inject_frankenphp_server_name};
-use args::build_run_args;
 use docker_cmd::force_remove_container;
 use dry_run::print_dry_run_container_start;
 use existing::handle_existing_container;
@@ -34,6 +33,7 @@ pub(super) fn ensure_container_running(
     injected_env: &HashMap<String, String>,
 ) -> Result<()> {
     let container_name = target.container_name()?;
+    crate::docker::ensure_service_network(target)?;
 
     if crate::docker::is_dry_run() {
         print_dry_run_container_start(
@@ -48,19 +48,32 @@ pub(super) fn ensure_container_running(
 
     if recreate {
         force_remove_container(&container_name);
-    } else if handle_existing_container(&container_name)? {
+    } else if handle_existing_container(target, &container_name)? {
         return Ok(());
     }
 
-    let run_args = build_run_args(
+    let run_args = crate::docker::apply_pod_args(
+        build_run_args(target, runtime_image, project_root, injected_env)?,
+        target,
+    );
+
+    start_new_container(target, &run_args)
+}
+
+/// Builds the `docker run` args `ensure_container_running` would use.
+pub(super) fn build_run_args(
+    target: &ServiceConfig,
+    runtime_image: &str,
+    project_root: &Path,
+    injected_env: &HashMap<String, String>,
+) -> Result<Vec<String>> {
+    args::build_run_args(
         target,
         runtime_image,
         project_root,
         injected_env,
         should_inject_frankenphp_server_name(target, injected_env),
-    )?;
-
-    start_new_container(target, &run_args)
+    )
 }
 
 /// Stops and removes the serve container for a target.
@@ -29,8 +29,16 @@ pub(super) fn build_run_args(
     inject_server_name: bool,
 ) -> Result<Vec<String>> {
     let mut run_args = build_base_run_args(target)?;
+    run_args.extend(target.runtime_option_args());
+    crate::docker::append_health_args(&mut run_args, target);
+    crate::docker::append_network_args(&mut run_args, target);
+    crate::docker::append_labels(&mut run_args, target, &target.container_name()?)?;
     if let Some(smtp_port) = mailhog_smtp_port(target) {
-        append_smtp_port_mapping(&mut run_args, &target.host, smtp_port);
+        append_smtp_port_mapping(
+            &mut run_args,
+            &crate::docker::publish_host(&target.host),
+            smtp_port,
+        );
     }
     append_host_gateway_mapping(&mut run_args, target, injected_env);
 
@@ -80,6 +88,7 @@ mod tests {
             hook: Vec::new(),
             health_path: None,
             health_statuses: None,
+            health: None,
             localhost_tls: false,
             octane: false,
             octane_workers: None,
@@ -89,7 +98,21 @@ mod tests {
             env_mapping: None,
             javascript: None,
             container_name: Some("acme-api-app".to_owned()),
+            memory: None,
+            cpus: None,
+            pids_limit: None,
+            shm_size: None,
+            ulimits: None,
+            tmpfs: None,
+            platform: None,
+            user: None,
+            cap_add: None,
+            restart: None,
+            build: None,
             resolved_container_name: Some("acme-api-app".to_owned()),
+            resolved_project_root: None,
+            resolved_runtime_env: None,
+            secret_fields: Vec::new(),
         }
     }
 
@@ -162,6 +185,25 @@ mod tests {
         assert!(rendered.contains("--add-host host.docker.internal:host-gateway"));
     }
 
+    #[test]
+    fn includes_runtime_options_for_serve_containers() {
+        let mut service = app_service();
+        service.shm_size = Some("2g".to_owned());
+        service.user = Some("1000:1000".to_owned());
+
+        let args = build_run_args(
+            &service,
+            "runtime-image",
+            Path::new("."),
+            &HashMap::new(),
+            false,
+        )
+        .expect("run args");
+        let rendered = args.join(" ");
+
+        assert!(rendered.contains("--user 1000:1000 --shm-size 2g"));
+    }
+
     #[test]
     fn podman_skips_host_gateway_mapping_when_loopback_host_used() {
         crate::docker::with_container_engine(crate::config::ContainerEngine::Podman, || {
@@ -14,7 +14,7 @@ pub(super) fn build_base_run_args(target: &ServiceConfig) -> Result<Vec<String>>
         "-p".to_owned(),
         format!(
             "{}:{}:{}",
-            target.host,
+            crate::docker::publish_host(&target.host),
             target.port,
             target.resolved_container_port()
         ),
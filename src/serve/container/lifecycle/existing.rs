@@ -2,6 +2,8 @@
 
 use anyhow::Result;
 
+use crate::config::ServiceConfig;
+use crate::docker::{DriftState, drift_state};
 use crate::output::{self, LogLevel, Persistence};
 
 use super::docker_cmd::{checked_output, force_remove_container};
@@ -9,12 +11,27 @@ use super::docker_cmd::{checked_output, force_remove_container};
 /// Attempts to reuse an existing container and returns whether work is complete.
 ///
 /// Returns `Ok(true)` when the target container is already running or was
-/// successfully started.
-pub(super) fn handle_existing_container(container_name: &str) -> Result<bool> {
+/// successfully started. Containers created from a different config are
+/// removed so the caller runs them again.
+pub(super) fn handle_existing_container(
+    target: &ServiceConfig,
+    container_name: &str,
+) -> Result<bool> {
     let Some(status) = crate::docker::inspect_status(container_name) else {
         return Ok(false);
     };
 
+    if drift_state(target, container_name)? == DriftState::Drifted {
+        output::event(
+            &target.name,
+            LogLevel::Info,
+            "Configuration changed; recreating container",
+            Persistence::Persistent,
+        );
+        force_remove_container(container_name);
+        return Ok(false);
+    }
+
     if status == "running" {
         emit_already_running(container_name);
         return Ok(true);
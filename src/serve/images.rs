@@ -10,6 +10,8 @@ mod derived;
 mod lock;
 mod runtime;
 
+pub(crate) use derived::DerivedImagePlan;
+
 /// Resolves the effective runtime image for a serve target.
 pub(super) fn resolve_runtime_image(
     target: &ServiceConfig,
@@ -17,7 +19,17 @@ pub(super) fn resolve_runtime_image(
     injected_env: &HashMap<String, String>,
     workspace_root: &Path,
 ) -> Result<String> {
-    derived::resolve_runtime_image(target, allow_rebuild, injected_env, workspace_root)
+    let target = crate::docker::with_built_image(target)?;
+    derived::resolve_runtime_image(&target, allow_rebuild, injected_env, workspace_root)
+}
+
+/// Plans the derived runtime image for a serve target without building it.
+pub(super) fn plan_derived_image(
+    target: &ServiceConfig,
+    injected_env: &HashMap<String, String>,
+    workspace_root: &Path,
+) -> Result<Option<DerivedImagePlan>> {
+    derived::plan_derived_image(target, injected_env, workspace_root)
 }
 
 /// Normalizes php extensions into a canonical form.
@@ -22,16 +22,22 @@ use signature::derive_image_signature;
 
 mod signature;
 
-/// Resolves the runtime image tag for this serve target.
+/// Derived runtime image a serve target needs on top of its base image.
+pub(crate) struct DerivedImagePlan {
+    pub(crate) tag: String,
+    pub(crate) dockerfile: String,
+    signature: String,
+    installable_extensions: Vec<String>,
+}
+
+/// Plans the derived image for a serve target without building it.
 ///
-/// Prefers cached derived images by signature when available; falls back to base
-/// image when no derived requirements exist or rebuild is disallowed.
-pub(super) fn resolve_runtime_image(
+/// Returns `None` when the base image already satisfies the target.
+pub(super) fn plan_derived_image(
     target: &ServiceConfig,
-    allow_rebuild: bool,
     injected_env: &HashMap<String, String>,
     workspace_root: &Path,
-) -> Result<String> {
+) -> Result<Option<DerivedImagePlan>> {
     let include_js_tooling = should_include_js_tooling(target);
     let sql_client_flavor = sql_client_flavor_from_injected_env(injected_env);
     let node_runtime = resolve_javascript_runtime(ResolveJavaScriptRuntimeOptions {
@@ -51,16 +57,15 @@ pub(super) fn resolve_runtime_image(
         .unwrap_or_default();
 
     if normalized_extensions.is_empty() && !include_js_tooling {
-        return Ok(target.image.clone());
+        return Ok(None);
     }
 
     let installable_extensions =
         filter_installable_extensions(&target.image, &normalized_extensions)?;
     if installable_extensions.is_empty() && !include_js_tooling {
-        return Ok(target.image.clone());
+        return Ok(None);
     }
 
-    let container_name = target.container_name()?;
     let dockerfile = render_derived_dockerfile(
         &target.image,
         &installable_extensions,
@@ -71,6 +76,34 @@ pub(super) fn resolve_runtime_image(
         sql_client_flavor,
     );
     let signature = derive_image_signature(&dockerfile);
+    Ok(Some(DerivedImagePlan {
+        tag: derived_image_tag(&target.container_name()?, &signature),
+        dockerfile,
+        signature,
+        installable_extensions,
+    }))
+}
+
+/// Resolves the runtime image tag for this serve target.
+///
+/// Prefers cached derived images by signature when available; falls back to base
+/// image when no derived requirements exist or rebuild is disallowed.
+pub(super) fn resolve_runtime_image(
+    target: &ServiceConfig,
+    allow_rebuild: bool,
+    injected_env: &HashMap<String, String>,
+    workspace_root: &Path,
+) -> Result<String> {
+    let Some(DerivedImagePlan {
+        tag: derived_tag,
+        dockerfile,
+        signature,
+        installable_extensions,
+    }) = plan_derived_image(target, injected_env, workspace_root)?
+    else {
+        return Ok(target.image.clone());
+    };
+
     if let Some(tag) = read_derived_image_lock()?.entries.get(&signature).cloned()
         && docker_image_exists(&tag)?
     {
@@ -95,7 +128,6 @@ pub(super) fn resolve_runtime_image(
         return Ok(target.image.clone());
     }
 
-    let derived_tag = derived_image_tag(&container_name, &signature);
     if crate::docker::is_dry_run() {
         emit_derived_event(
             target,
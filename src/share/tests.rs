@@ -96,6 +96,7 @@ fn app_service() -> ServiceConfig {
         hook: Vec::new(),
         health_path: None,
         health_statuses: None,
+        health: None,
         localhost_tls: false,
         octane: false,
         octane_workers: None,
@@ -105,6 +106,20 @@ fn app_service() -> ServiceConfig {
         env_mapping: None,
         javascript: None,
         container_name: Some("acme-api-app".to_owned()),
+        memory: None,
+        cpus: None,
+        pids_limit: None,
+        shm_size: None,
+        ulimits: None,
+        tmpfs: None,
+        platform: None,
+        user: None,
+        cap_add: None,
+        restart: None,
+        build: None,
         resolved_container_name: Some("acme-api-app".to_owned()),
+        resolved_project_root: None,
+        resolved_runtime_env: None,
+        secret_fields: Vec::new(),
     }
 }
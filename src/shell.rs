@@ -0,0 +1,26 @@
+//! Shared shell quoting helpers.
+
+/// Quotes `value` as a single POSIX shell word; plain words stay bare.
+pub(crate) fn quote(value: &str) -> String {
+    if !value.is_empty()
+        && value
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || "-_./:=@".contains(ch))
+    {
+        return value.to_owned();
+    }
+    format!("'{}'", value.replace('\'', "'\"'\"'"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::quote;
+
+    #[test]
+    fn quote_leaves_plain_words_bare_and_wraps_the_rest() {
+        assert_eq!(quote("key:generate"), "key:generate");
+        assert_eq!(quote(""), "''");
+        assert_eq!(quote("a b"), "'a b'");
+        assert_eq!(quote("it's"), "'it'\"'\"'s'");
+    }
+}
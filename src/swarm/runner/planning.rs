@@ -126,6 +126,8 @@ mod tests {
             domain_strategy: None,
             service: Vec::new(),
             swarm: vec![target("alpha"), target("beta"), target("gamma")],
+            profile: std::collections::BTreeMap::new(),
+            preset: std::collections::BTreeMap::new(),
         }
     }
 
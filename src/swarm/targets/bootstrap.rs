@@ -96,6 +96,8 @@ mod tests {
                     branch: Some("develop".to_owned()),
                 }),
             }],
+            profile: std::collections::BTreeMap::new(),
+            preset: std::collections::BTreeMap::new(),
         };
         let targets = vec![ResolvedSwarmTarget {
             name: "rate".to_owned(),
@@ -0,0 +1,10 @@
+//! Shared YAML support.
+//!
+//! Reads the subset of YAML compose files use and writes the block-style
+//! compose files Helm generates, both with insertion-ordered mappings.
+
+mod read;
+mod write;
+
+pub(crate) use read::{Yaml, parse};
+pub(crate) use write::{Node, render};
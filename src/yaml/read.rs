@@ -0,0 +1,649 @@
+//! yaml read module.
+//!
+//! Small YAML reader covering the subset compose files use: block maps and
+//! sequences, flow collections, quoted and block scalars, anchors, aliases,
+//! and `<<` merge keys. Every scalar is kept as a string.
+
+use anyhow::{Result, anyhow, bail};
+use std::collections::HashMap;
+
+/// A parsed YAML value with insertion-ordered mappings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Yaml {
+    Null,
+    Scalar(String),
+    List(Vec<Yaml>),
+    Map(Vec<(String, Yaml)>),
+}
+
+impl Yaml {
+    /// Looks up `key` in a mapping.
+    pub(crate) fn get(&self, key: &str) -> Option<&Self> {
+        match self {
+            Self::Map(entries) => entries
+                .iter()
+                .find(|(name, _)| name == key)
+                .map(|(_, value)| value),
+            Self::Null | Self::Scalar(_) | Self::List(_) => None,
+        }
+    }
+
+    pub(crate) fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::Scalar(value) => Some(value),
+            Self::Null | Self::List(_) | Self::Map(_) => None,
+        }
+    }
+
+    pub(crate) fn entries(&self) -> &[(String, Self)] {
+        match self {
+            Self::Map(entries) => entries,
+            Self::Null | Self::Scalar(_) | Self::List(_) => &[],
+        }
+    }
+
+    pub(crate) fn items(&self) -> &[Self] {
+        match self {
+            Self::List(items) => items,
+            Self::Null | Self::Scalar(_) | Self::Map(_) => &[],
+        }
+    }
+}
+
+/// Parses a single YAML document.
+pub(crate) fn parse(source: &str) -> Result<Yaml> {
+    if let Some(line) = source.lines().position(|line| {
+        line.chars()
+            .take_while(|ch| ch.is_whitespace())
+            .any(|ch| ch == '\t')
+    }) {
+        bail!("tabs are not allowed for indentation (line {})", line + 1);
+    }
+    let mut parser = Parser {
+        lines: source
+            .lines()
+            .map(|line| line.trim_end_matches('\r').to_owned())
+            .collect(),
+        cursor: 0,
+        anchors: HashMap::new(),
+    };
+    let document = parser.node(0)?;
+    if let Some(line) = parser.next_content() {
+        bail!(
+            "unexpected content at line {}: {}",
+            line.saturating_add(1),
+            parser.content(line).trim()
+        );
+    }
+    Ok(document)
+}
+
+struct Parser {
+    lines: Vec<String>,
+    cursor: usize,
+    anchors: HashMap<String, Yaml>,
+}
+
+impl Parser {
+    /// Skips blank, comment, and `---` lines; returns the next content line.
+    fn next_content(&mut self) -> Option<usize> {
+        while let Some(line) = self.lines.get(self.cursor) {
+            let content = strip_comment(line).trim();
+            if content.is_empty() || content == "---" {
+                self.cursor = self.cursor.saturating_add(1);
+                continue;
+            }
+            return Some(self.cursor);
+        }
+        None
+    }
+
+    fn content(&self, line: usize) -> String {
+        self.lines
+            .get(line)
+            .map(|text| strip_comment(text).trim_end().to_owned())
+            .unwrap_or_default()
+    }
+
+    fn indent(&self, line: usize) -> usize {
+        self.lines
+            .get(line)
+            .map_or(0, |text| text.len().saturating_sub(text.trim_start().len()))
+    }
+
+    /// Parses the block node starting at the next content line, if it is
+    /// indented at least `min_indent`.
+    fn node(&mut self, min_indent: usize) -> Result<Yaml> {
+        let Some(line) = self.next_content() else {
+            return Ok(Yaml::Null);
+        };
+        let indent = self.indent(line);
+        if indent < min_indent {
+            return Ok(Yaml::Null);
+        }
+        let content = self.content(line);
+        let body = content.trim_start();
+        if is_sequence_item(body) {
+            self.sequence(indent)
+        } else if split_key(body).is_some() {
+            self.mapping(indent)
+        } else {
+            self.cursor = self.cursor.saturating_add(1);
+            self.inline(body.trim(), line)
+        }
+    }
+
+    fn sequence(&mut self, indent: usize) -> Result<Yaml> {
+        let mut items = Vec::new();
+        while let Some(line) = self.next_content() {
+            let content = self.content(line);
+            let body = content.trim_start();
+            if self.indent(line) != indent || !is_sequence_item(body) {
+                break;
+            }
+            let rest = body.get(1..).unwrap_or_default().trim_start();
+            if rest.is_empty() {
+                self.cursor = self.cursor.saturating_add(1);
+                items.push(self.node(indent.saturating_add(1))?);
+                continue;
+            }
+            if let Some((anchor, after)) = take_anchor(rest)
+                && after.is_empty()
+            {
+                self.cursor = self.cursor.saturating_add(1);
+                let node = self.node(indent.saturating_add(1))?;
+                self.anchors.insert(anchor.to_owned(), node.clone());
+                items.push(node);
+                continue;
+            }
+            if split_key(rest).is_some() {
+                // Re-read `- key: value` as a mapping indented past the dash.
+                let nested = content.len().saturating_sub(rest.len());
+                if let Some(text) = self.lines.get_mut(line) {
+                    *text = format!("{}{rest}", " ".repeat(nested));
+                }
+                items.push(self.mapping(nested)?);
+                continue;
+            }
+            self.cursor = self.cursor.saturating_add(1);
+            items.push(self.inline(rest, line)?);
+        }
+        Ok(Yaml::List(items))
+    }
+
+    fn mapping(&mut self, indent: usize) -> Result<Yaml> {
+        let mut entries: Vec<(String, Yaml)> = Vec::new();
+        let mut merged = Vec::new();
+        while let Some(line) = self.next_content() {
+            let content = self.content(line);
+            if self.indent(line) != indent {
+                if self.indent(line) > indent {
+                    bail!("unexpected indentation at line {}", line + 1);
+                }
+                break;
+            }
+            let body = content.trim_start();
+            if is_sequence_item(body) {
+                break;
+            }
+            let Some((key, value)) = split_key(body) else {
+                break;
+            };
+            let key = unquote(key, line)?;
+            self.cursor = self.cursor.saturating_add(1);
+            let node = self.value(value, indent, line)?;
+            if key == "<<" {
+                merged.push(node);
+            } else {
+                entries.retain(|(existing, _)| *existing != key);
+                entries.push((key, node));
+            }
+        }
+
+        for source in merged {
+            let maps = match source {
+                Yaml::List(items) => items,
+                other @ (Yaml::Null | Yaml::Scalar(_) | Yaml::Map(_)) => vec![other],
+            };
+            for map in maps {
+                for (key, value) in map.entries() {
+                    if !entries.iter().any(|(existing, _)| existing == key) {
+                        entries.push((key.clone(), value.clone()));
+                    }
+                }
+            }
+        }
+        Ok(Yaml::Map(entries))
+    }
+
+    /// Parses what follows `key:` on line `line`.
+    fn value(&mut self, value: &str, indent: usize, line: usize) -> Result<Yaml> {
+        let (anchor, value) = match take_anchor(value) {
+            Some((anchor, rest)) => (Some(anchor.to_owned()), rest),
+            None => (None, value),
+        };
+        let node = if value.is_empty() {
+            // Compose files often put a key's sequence at the key's own indent.
+            match self.next_content() {
+                Some(next)
+                    if self.indent(next) == indent
+                        && is_sequence_item(self.content(next).trim_start()) =>
+                {
+                    self.sequence(indent)?
+                }
+                _ => self.node(indent.saturating_add(1))?,
+            }
+        } else if let Some(header) = value.strip_prefix(['|', '>']) {
+            self.block_scalar(value.starts_with('>'), header, indent)
+        } else {
+            self.inline(value, line)?
+        };
+        if let Some(anchor) = anchor {
+            self.anchors.insert(anchor, node.clone());
+        }
+        Ok(node)
+    }
+
+    /// Reads a `|` or `>` block scalar indented past `parent_indent`.
+    ///
+    /// An indentation indicator in the header counts from `parent_indent`;
+    /// without one the first content line sets the indent.
+    fn block_scalar(&mut self, folded: bool, header: &str, parent_indent: usize) -> Yaml {
+        let header = header.split_whitespace().next().unwrap_or_default();
+        let mut body = Vec::new();
+        let mut block_indent = header
+            .chars()
+            .find_map(|ch| ch.to_digit(10))
+            .and_then(|digit| usize::try_from(digit).ok())
+            .map(|digit| parent_indent.saturating_add(digit));
+        while let Some(text) = self.lines.get(self.cursor) {
+            let indent = text.len().saturating_sub(text.trim_start().len());
+            if text.trim().is_empty() {
+                body.push(String::new());
+            } else if indent > parent_indent {
+                let strip = *block_indent.get_or_insert(indent);
+                body.push(text.get(strip.min(indent)..).unwrap_or_default().to_owned());
+            } else {
+                break;
+            }
+            self.cursor = self.cursor.saturating_add(1);
+        }
+
+        let content_lines = body
+            .iter()
+            .rposition(|text| !text.is_empty())
+            .map_or(0, |last| last.saturating_add(1));
+        let trailing = body.len().saturating_sub(content_lines);
+        body.truncate(content_lines);
+        let mut text = if folded { fold(&body) } else { body.join("\n") };
+        if header.contains('+') {
+            text.push_str(&"\n".repeat(trailing.saturating_add(1)));
+        } else if !header.contains('-') && !text.is_empty() {
+            text.push('\n');
+        }
+        Yaml::Scalar(text)
+    }
+
+    fn inline(&self, text: &str, line: usize) -> Result<Yaml> {
+        let mut flow = Flow {
+            chars: text.chars().collect(),
+            position: 0,
+            line,
+            anchors: &self.anchors,
+        };
+        let value = flow.value(false)?;
+        flow.skip_spaces();
+        if flow.position < flow.chars.len() {
+            bail!("unexpected characters after value at line {}", line + 1);
+        }
+        Ok(value)
+    }
+}
+
+/// Parses flow collections and inline scalars.
+struct Flow<'a> {
+    chars: Vec<char>,
+    position: usize,
+    line: usize,
+    anchors: &'a HashMap<String, Yaml>,
+}
+
+impl Flow<'_> {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn skip_spaces(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.position = self.position.saturating_add(1);
+        }
+    }
+
+    fn error(&self, message: &str) -> anyhow::Error {
+        anyhow!("{message} at line {}", self.line + 1)
+    }
+
+    /// Parses one value; `nested` stops plain scalars at `,`, `]`, and `}`.
+    fn value(&mut self, nested: bool) -> Result<Yaml> {
+        self.skip_spaces();
+        match self.peek() {
+            Some('[') => self.list(),
+            Some('{') => self.map(),
+            Some('*') => {
+                self.position = self.position.saturating_add(1);
+                let name = self.plain(nested);
+                self.anchors
+                    .get(&name)
+                    .cloned()
+                    .ok_or_else(|| self.error(&format!("unknown alias '*{name}'")))
+            }
+            Some('\'' | '"') => Ok(Yaml::Scalar(self.quoted()?)),
+            Some(_) => {
+                let plain = self.plain(nested);
+                Ok(match plain.as_str() {
+                    "" | "~" | "null" | "Null" | "NULL" => Yaml::Null,
+                    _ => Yaml::Scalar(plain),
+                })
+            }
+            None => Ok(Yaml::Null),
+        }
+    }
+
+    fn list(&mut self) -> Result<Yaml> {
+        self.position = self.position.saturating_add(1);
+        let mut items = Vec::new();
+        loop {
+            self.skip_spaces();
+            match self.peek() {
+                Some(']') => {
+                    self.position = self.position.saturating_add(1);
+                    return Ok(Yaml::List(items));
+                }
+                Some(',') => self.position = self.position.saturating_add(1),
+                Some(_) => items.push(self.value(true)?),
+                None => return Err(self.error("unterminated flow sequence")),
+            }
+        }
+    }
+
+    fn map(&mut self) -> Result<Yaml> {
+        self.position = self.position.saturating_add(1);
+        let mut entries = Vec::new();
+        loop {
+            self.skip_spaces();
+            match self.peek() {
+                Some('}') => {
+                    self.position = self.position.saturating_add(1);
+                    return Ok(Yaml::Map(entries));
+                }
+                Some(',') => self.position = self.position.saturating_add(1),
+                Some(_) => {
+                    let key = match self.value(true)? {
+                        Yaml::Scalar(key) => key,
+                        Yaml::Null | Yaml::List(_) | Yaml::Map(_) => {
+                            return Err(self.error("flow mapping keys must be scalars"));
+                        }
+                    };
+                    self.skip_spaces();
+                    let value = if self.peek() == Some(':') {
+                        self.position = self.position.saturating_add(1);
+                        self.value(true)?
+                    } else {
+                        Yaml::Null
+                    };
+                    entries.push((key, value));
+                }
+                None => return Err(self.error("unterminated flow mapping")),
+            }
+        }
+    }
+
+    fn plain(&mut self, nested: bool) -> String {
+        let start = self.position;
+        while let Some(ch) = self.peek() {
+            let next = self.chars.get(self.position.saturating_add(1)).copied();
+            let ends_key = ch == ':' && next.is_none_or(char::is_whitespace);
+            if nested && (matches!(ch, ',' | ']' | '}') || ends_key) {
+                break;
+            }
+            self.position = self.position.saturating_add(1);
+        }
+        self.chars
+            .get(start..self.position)
+            .unwrap_or_default()
+            .iter()
+            .collect::<String>()
+            .trim()
+            .to_owned()
+    }
+
+    fn quoted(&mut self) -> Result<String> {
+        let quote = self.peek().unwrap_or('"');
+        self.position = self.position.saturating_add(1);
+        let mut value = String::new();
+        while let Some(ch) = self.peek() {
+            self.position = self.position.saturating_add(1);
+            match ch {
+                '\'' if quote == '\'' => {
+                    if self.peek() == Some('\'') {
+                        self.position = self.position.saturating_add(1);
+                        value.push('\'');
+                    } else {
+                        return Ok(value);
+                    }
+                }
+                '"' if quote == '"' => return Ok(value),
+                '\\' if quote == '"' => {
+                    let escaped = self
+                        .peek()
+                        .ok_or_else(|| self.error("unterminated escape"))?;
+                    self.position = self.position.saturating_add(1);
+                    value.push(match escaped {
+                        'n' => '\n',
+                        't' => '\t',
+                        'r' => '\r',
+                        '0' => '\0',
+                        other => other,
+                    });
+                }
+                other => value.push(other),
+            }
+        }
+        Err(self.error("unterminated quoted scalar"))
+    }
+}
+
+fn is_sequence_item(body: &str) -> bool {
+    body == "-" || body.starts_with("- ")
+}
+
+/// Splits `key: value` at the first `:` that ends a key, outside quotes and
+/// flow collections.
+fn split_key(body: &str) -> Option<(&str, &str)> {
+    if body.starts_with(['[', '{', '|', '>', '*', '&']) {
+        return None;
+    }
+    let mut quote = None;
+    let mut chars = body.char_indices().peekable();
+    while let Some((index, ch)) = chars.next() {
+        match (quote, ch) {
+            (None, '\'' | '"') if index == 0 => quote = Some(ch),
+            (Some(open), _) if ch == open => quote = None,
+            (None, ':') if chars.peek().is_none_or(|(_, next)| next.is_whitespace()) => {
+                let key = body.get(..index)?.trim();
+                let value = body.get(index.saturating_add(1)..)?.trim();
+                return (!key.is_empty()).then_some((key, value));
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Splits a leading `&anchor` from a value.
+fn take_anchor(value: &str) -> Option<(&str, &str)> {
+    let rest = value.strip_prefix('&')?;
+    let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+    Some((rest.get(..end)?, rest.get(end..)?.trim_start()))
+}
+
+fn unquote(key: &str, line: usize) -> Result<String> {
+    if key.starts_with(['\'', '"']) {
+        let anchors = HashMap::new();
+        let mut flow = Flow {
+            chars: key.chars().collect(),
+            position: 0,
+            line,
+            anchors: &anchors,
+        };
+        flow.quoted()
+    } else {
+        Ok(key.to_owned())
+    }
+}
+
+/// Removes a trailing `# comment` that sits outside quotes.
+fn strip_comment(line: &str) -> &str {
+    let mut quote = None;
+    let mut previous = ' ';
+    for (index, ch) in line.char_indices() {
+        match (quote, ch) {
+            (None, '\'' | '"') if previous.is_whitespace() || matches!(previous, ':' | '-') => {
+                quote = Some(ch);
+            }
+            (Some(open), _) if ch == open => quote = None,
+            (None, '#') if previous.is_whitespace() => return line.get(..index).unwrap_or(line),
+            _ => {}
+        }
+        previous = ch;
+    }
+    line
+}
+
+/// Folds `>` block lines: single breaks become spaces, blank lines newlines.
+fn fold(lines: &[String]) -> String {
+    let mut text = String::new();
+    for line in lines {
+        if line.is_empty() {
+            text.push('\n');
+        } else {
+            if !text.is_empty() && !text.ends_with('\n') {
+                text.push(' ');
+            }
+            text.push_str(line);
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Yaml, parse};
+
+    fn scalar(value: &str) -> Yaml {
+        Yaml::Scalar(value.to_owned())
+    }
+
+    #[test]
+    fn parses_block_maps_sequences_and_comments() -> anyhow::Result<()> {
+        let document = parse(
+            "# compose\nservices:\n  mysql:\n    image: 'mysql/mysql-server:8.0' # pinned\n    ports:\n      - '${FORWARD_DB_PORT:-3306}:3306'\n    networks:\n    - sail\n    command: [\"--max-connections\", 50]\n",
+        )?;
+        let mysql = document
+            .get("services")
+            .and_then(|services| services.get("mysql"))
+            .cloned()
+            .unwrap_or(Yaml::Null);
+
+        assert_eq!(
+            mysql.get("image").and_then(Yaml::as_str),
+            Some("mysql/mysql-server:8.0")
+        );
+        assert_eq!(
+            mysql.get("ports").map(Yaml::items),
+            Some([scalar("${FORWARD_DB_PORT:-3306}:3306")].as_slice())
+        );
+        assert_eq!(
+            mysql.get("networks").map(Yaml::items),
+            Some([scalar("sail")].as_slice())
+        );
+        assert_eq!(
+            mysql.get("command").map(Yaml::items),
+            Some([scalar("--max-connections"), scalar("50")].as_slice())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn parses_maps_inside_sequences_and_flow_maps() -> anyhow::Result<()> {
+        let document = parse(
+            "items:\n  - name: a\n    value: 1\n  - { name: b, value: \"two words\" }\n  - plain\n",
+        )?;
+        assert_eq!(
+            document.get("items"),
+            Some(&Yaml::List(vec![
+                Yaml::Map(vec![
+                    ("name".to_owned(), scalar("a")),
+                    ("value".to_owned(), scalar("1")),
+                ]),
+                Yaml::Map(vec![
+                    ("name".to_owned(), scalar("b")),
+                    ("value".to_owned(), scalar("two words")),
+                ]),
+                scalar("plain"),
+            ]))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn resolves_anchors_aliases_and_merge_keys() -> anyhow::Result<()> {
+        let document = parse(
+            "x-env: &env\n  APP_ENV: local\n  DEBUG: 'true'\nservices:\n  app:\n    environment:\n      <<: *env\n      DEBUG: 'false'\n    labels: *env\n",
+        )?;
+        let app = document
+            .get("services")
+            .and_then(|services| services.get("app"))
+            .cloned()
+            .unwrap_or(Yaml::Null);
+
+        assert_eq!(
+            app.get("environment"),
+            Some(&Yaml::Map(vec![
+                ("DEBUG".to_owned(), scalar("false")),
+                ("APP_ENV".to_owned(), scalar("local")),
+            ]))
+        );
+        assert_eq!(
+            app.get("labels").and_then(|labels| labels.get("APP_ENV")),
+            Some(&scalar("local"))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn parses_block_scalars_and_quoting() -> anyhow::Result<()> {
+        let document = parse(
+            "literal: |\n  line one\n  line two\nfolded: >-\n  a\n  b\nsingle: 'it''s'\ndouble: \"tab\\there\"\nempty:\nhash: 'a # b'\n",
+        )?;
+        assert_eq!(
+            document.get("literal"),
+            Some(&scalar("line one\nline two\n"))
+        );
+        assert_eq!(document.get("folded"), Some(&scalar("a b")));
+        assert_eq!(document.get("single"), Some(&scalar("it's")));
+        assert_eq!(document.get("double"), Some(&scalar("tab\there")));
+        assert_eq!(document.get("empty"), Some(&Yaml::Null));
+        assert_eq!(document.get("hash"), Some(&scalar("a # b")));
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_malformed_documents() {
+        assert!(parse("a: [1, 2\n").is_err());
+        assert!(parse("a: 'open\n").is_err());
+        assert!(parse("a: *missing\n").is_err());
+        assert!(parse("a: 1\n    b: 2\n").is_err());
+        assert!(parse("a:\n\tb: 1\n").is_err());
+    }
+}
@@ -0,0 +1,235 @@
+//! yaml write module.
+//!
+//! Minimal block-style YAML writer for generated compose files.
+
+/// A YAML value with insertion-ordered mappings.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Node {
+    /// A string, quoted when plain style would change its meaning.
+    Text(String),
+    /// A pre-validated literal such as a number, written as-is.
+    Literal(String),
+    List(Vec<Node>),
+    Map(Vec<(String, Node)>),
+}
+
+impl Node {
+    pub(crate) fn text(value: impl Into<String>) -> Self {
+        Self::Text(value.into())
+    }
+}
+
+/// Renders a document, ending with a trailing newline.
+pub(crate) fn render(node: &Node) -> String {
+    let mut out = String::new();
+    match node {
+        Node::Map(entries) if !entries.is_empty() => write_map(&mut out, entries, 0),
+        Node::List(items) if !items.is_empty() => write_list(&mut out, items, 0),
+        _ => {
+            out.push_str(&inline(node));
+            out.push('\n');
+        }
+    }
+    out
+}
+
+fn write_map(out: &mut String, entries: &[(String, Node)], indent: usize) {
+    for (key, value) in entries {
+        out.push_str(&" ".repeat(indent));
+        out.push_str(&scalar(key));
+        out.push(':');
+        write_value(out, value, indent);
+    }
+}
+
+fn write_list(out: &mut String, items: &[Node], indent: usize) {
+    for item in items {
+        out.push_str(&" ".repeat(indent));
+        out.push('-');
+        match item {
+            Node::Map(entries) if !entries.is_empty() => {
+                // The first entry shares the dash line; the rest align under it.
+                let mut nested = String::new();
+                write_map(&mut nested, entries, indent.saturating_add(2));
+                out.push(' ');
+                out.push_str(nested.get(indent.saturating_add(2)..).unwrap_or_default());
+            }
+            _ => write_value(out, item, indent),
+        }
+    }
+}
+
+/// Writes the part after `key:` or `-`, including the newline.
+fn write_value(out: &mut String, value: &Node, indent: usize) {
+    let nested = indent.saturating_add(2);
+    match value {
+        Node::Map(entries) if !entries.is_empty() => {
+            out.push('\n');
+            write_map(out, entries, nested);
+        }
+        Node::List(items) if !items.is_empty() => {
+            out.push('\n');
+            write_list(out, items, nested);
+        }
+        Node::Text(text) if text.contains('\n') => write_block(out, text, indent, nested),
+        _ => {
+            out.push(' ');
+            out.push_str(&inline(value));
+            out.push('\n');
+        }
+    }
+}
+
+/// Writes a multi-line string as a literal block scalar whose lines sit at
+/// `nested`, below a parent at `indent`.
+fn write_block(out: &mut String, text: &str, indent: usize, nested: usize) {
+    out.push_str(" |");
+    // The indentation indicator is relative to the parent node.
+    if text.starts_with(' ') {
+        out.push_str(&nested.saturating_sub(indent).to_string());
+    }
+    if !text.ends_with('\n') {
+        out.push('-');
+    }
+    out.push('\n');
+    for line in text.strip_suffix('\n').unwrap_or(text).split('\n') {
+        if !line.is_empty() {
+            out.push_str(&" ".repeat(nested));
+            out.push_str(line);
+        }
+        out.push('\n');
+    }
+}
+
+/// Renders scalars and empty collections in flow style.
+fn inline(node: &Node) -> String {
+    match node {
+        Node::Text(text) => scalar(text),
+        Node::Literal(literal) => literal.clone(),
+        Node::List(_) => "[]".to_owned(),
+        Node::Map(_) => "{}".to_owned(),
+    }
+}
+
+/// Quotes strings that YAML would otherwise read as another type or syntax.
+fn scalar(text: &str) -> String {
+    if is_plain_safe(text) {
+        text.to_owned()
+    } else {
+        serde_json::to_string(text).unwrap_or_else(|_| format!("\"{text}\""))
+    }
+}
+
+fn is_plain_safe(text: &str) -> bool {
+    const RESERVED: [&str; 11] = [
+        "true", "false", "yes", "no", "on", "off", "y", "n", "null", "~", "<<",
+    ];
+    let Some(first) = text.chars().next() else {
+        return false;
+    };
+    (first.is_ascii_alphabetic() || first == '/' || first == '_')
+        && text
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '_' | '-' | '.' | '/'))
+        && !RESERVED.contains(&text.to_ascii_lowercase().as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Node, render};
+
+    #[test]
+    fn renders_nested_maps_and_lists() {
+        let document = Node::Map(vec![(
+            "services".to_owned(),
+            Node::Map(vec![(
+                "db".to_owned(),
+                Node::Map(vec![
+                    ("image".to_owned(), Node::text("mysql:8.4")),
+                    (
+                        "ports".to_owned(),
+                        Node::List(vec![Node::text("127.0.0.1:3306:3306")]),
+                    ),
+                    ("cpus".to_owned(), Node::Literal("1.5".to_owned())),
+                    ("cap_add".to_owned(), Node::List(Vec::new())),
+                    ("volume".to_owned(), Node::Map(Vec::new())),
+                ]),
+            )]),
+        )]);
+
+        assert_eq!(
+            render(&document),
+            "services:\n  db:\n    image: \"mysql:8.4\"\n    ports:\n      - \"127.0.0.1:3306:3306\"\n    cpus: 1.5\n    cap_add: []\n    volume: {}\n"
+        );
+    }
+
+    #[test]
+    fn renders_maps_inside_lists_on_the_dash_line() {
+        let document = Node::List(vec![Node::Map(vec![
+            ("service".to_owned(), Node::text("db")),
+            ("condition".to_owned(), Node::text("healthy")),
+        ])]);
+        assert_eq!(render(&document), "- service: db\n  condition: healthy\n");
+    }
+
+    #[test]
+    fn quotes_values_yaml_would_reinterpret() {
+        let document = Node::List(
+            [
+                "yes",
+                "8080",
+                "",
+                "a: b",
+                "#x",
+                "plain-value_1.2",
+                "/var/lib/mysql",
+            ]
+            .into_iter()
+            .map(Node::text)
+            .collect(),
+        );
+        assert_eq!(
+            render(&document),
+            "- \"yes\"\n- \"8080\"\n- \"\"\n- \"a: b\"\n- \"#x\"\n- plain-value_1.2\n- /var/lib/mysql\n"
+        );
+    }
+
+    #[test]
+    fn renders_multiline_text_as_literal_blocks() {
+        let document = Node::Map(vec![(
+            "dockerfile_inline".to_owned(),
+            Node::text("FROM php\n\nRUN true\n"),
+        )]);
+        assert_eq!(
+            render(&document),
+            "dockerfile_inline: |\n  FROM php\n\n  RUN true\n"
+        );
+
+        let indented = Node::Map(vec![("script".to_owned(), Node::text("  a\nb"))]);
+        assert_eq!(render(&indented), "script: |2-\n    a\n  b\n");
+    }
+
+    #[test]
+    fn nested_block_indicators_are_relative_to_the_parent() {
+        let document = Node::Map(vec![(
+            "services".to_owned(),
+            Node::Map(vec![(
+                "app".to_owned(),
+                Node::Map(vec![("script".to_owned(), Node::text("  a\nb\n"))]),
+            )]),
+        )]);
+        let rendered = render(&document);
+        assert_eq!(
+            rendered,
+            "services:\n  app:\n    script: |2\n        a\n      b\n"
+        );
+
+        let parsed = crate::yaml::parse(&rendered).expect("rendered yaml parses");
+        let script = parsed
+            .get("services")
+            .and_then(|services| services.get("app"))
+            .and_then(|app| app.get("script"))
+            .and_then(crate::yaml::Yaml::as_str);
+        assert_eq!(script, Some("  a\nb\n"));
+    }
+}